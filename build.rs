@@ -0,0 +1,48 @@
+//! Checks the Fluent files in `src/i18n/locales` when building: each one has
+//! to parse and define exactly the messages `en.ftl` defines, so a missing
+//! translation fails the build instead of a lookup at run time.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use fluent_syntax::ast::Entry;
+use fluent_syntax::parser;
+
+const LOCALES: &str = "src/i18n/locales";
+
+fn message_ids(path: &Path) -> BTreeSet<String> {
+    let source = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("cannot read {}: {err}", path.display()));
+    let resource = parser::parse(source.as_str())
+        .unwrap_or_else(|(_, errors)| panic!("{} does not parse: {errors:?}", path.display()));
+    resource
+        .body
+        .iter()
+        .filter_map(|entry| match entry {
+            Entry::Message(message) => Some(message.id.name.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={LOCALES}");
+    let english = message_ids(&Path::new(LOCALES).join("en.ftl"));
+    let entries =
+        fs::read_dir(LOCALES).unwrap_or_else(|err| panic!("cannot read {LOCALES}: {err}"));
+    for entry in entries {
+        let path = entry.expect("locale directory entry").path();
+        if path.extension().is_none_or(|extension| extension != "ftl") {
+            continue;
+        }
+        let ids = message_ids(&path);
+        let missing: Vec<_> = english.difference(&ids).collect();
+        let extra: Vec<_> = ids.difference(&english).collect();
+        assert!(
+            missing.is_empty() && extra.is_empty(),
+            "{} does not match en.ftl: missing {missing:?}, not in en.ftl {extra:?}",
+            path.display()
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Helpers the game loop and the menus share: saving settings and scores,
+//! picking up hand edits to the config, sounds, and the window title.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::core::{DifficultyProfile, Game};
+use crate::storage::{self, HighScores, Settings};
+use crate::utils::SoundEvent;
+use crate::{i18n, input, layout, notices, render};
+
+static REPORTED_CONFIG_SAVE_ERROR: AtomicBool = AtomicBool::new(false);
+static REPORTED_SCORES_SAVE_ERROR: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn persist_settings(settings: &Settings) {
+    if let Err(err) = storage::save_settings(settings) {
+        if !REPORTED_CONFIG_SAVE_ERROR.swap(true, Ordering::Relaxed) {
+            notices::report(format!("Failed to save settings: {err}"));
+        }
+    }
+}
+
+pub(crate) fn persist_high_scores(high_scores: &HighScores) {
+    if let Err(err) = storage::save_high_scores(high_scores) {
+        if !REPORTED_SCORES_SAVE_ERROR.swap(true, Ordering::Relaxed) {
+            notices::report(format!("Failed to save high scores: {err}"));
+        }
+    }
+}
+
+/// Rings the bell for menu sounds when both the master switch and UI sounds are on.
+pub(crate) fn play_ui_sound(renderer: &mut render::Renderer, settings: &Settings) {
+    if settings.sound_on && settings.sounds.enabled(SoundEvent::Ui) {
+        render::ring_bell(renderer);
+    }
+}
+
+/// Plays the sounds raised by the last tick; `M` mutes them for the current run.
+pub(crate) fn play_game_sounds(
+    renderer: &mut render::Renderer,
+    game: &mut Game,
+    settings: &Settings,
+) {
+    for event in game.sound_events.drain(..) {
+        if !game.muted && settings.sounds.enabled(event) {
+            render::ring_bell(renderer);
+        }
+    }
+}
+
+/// Picks up settings edited by hand in the config file. A file that fails to
+/// parse (e.g. saved mid-edit) leaves the current settings in place.
+pub(crate) fn reload_settings(
+    renderer: &mut render::Renderer,
+    keymap: &input::Keymap,
+    settings: &mut Settings,
+) -> bool {
+    match storage::reload_config() {
+        Ok(config) if config.settings != *settings => {
+            *settings = config.settings;
+            renderer.set_theme(render::Theme::from_settings(settings));
+            set_render_backend(renderer, settings);
+            render::set_wide_ambiguous(settings.wide_ambiguous);
+            renderer.set_right_to_left(i18n::is_right_to_left(settings.language));
+            keymap.set(settings);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Falls back to the built-in renderer when the configured one cannot start.
+pub(crate) fn set_render_backend(renderer: &mut render::Renderer, settings: &Settings) {
+    if let Err(err) = renderer.set_backend(settings.renderer) {
+        notices::report(format!("Renderer unavailable: {err}"));
+    }
+}
+
+/// Shows `score` in the window title during a run and the game's name in the
+/// menus, or leaves the terminal's own title when the setting is off.
+pub(crate) fn update_window_title(
+    renderer: &mut render::Renderer,
+    settings: &Settings,
+    score: Option<u32>,
+) {
+    let title = settings
+        .window_title
+        .then(|| i18n::window_title(settings.language, score));
+    renderer.set_title(title.as_deref());
+}
+
+/// `profile` at the chosen speed, with vertical ticks paced for the board's
+/// cells on this terminal (see `layout::vertical_pace_percent`).
+pub(crate) fn paced_profile(profile: DifficultyProfile, settings: &Settings) -> DifficultyProfile {
+    profile
+        .with_speed(settings.speed)
+        .with_vertical_pace(layout::vertical_pace_percent(
+            settings,
+            layout::measured_cell_aspect_percent(),
+        ))
+}
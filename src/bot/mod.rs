@@ -0,0 +1,200 @@
+//! Scripted bots for `bot <file.rhai>` (`bot` feature).
+//!
+//! A bot script defines `fn turn(state)`, which runs before every tick and
+//! returns `"up"`, `"down"`, `"left"`, or `"right"`, or `()` to keep going
+//! straight. `state` is a map:
+//! - `width`, `height`: playable cells; positions are `#{ x, y }` from `0`
+//! - `snake`: body positions, head first; `direction`: current heading
+//! - `food`, `power_up` (`()` or `#{ x, y, kind, ticks_left }`)
+//! - `effects`: active power-up kinds; `score`, `tick`, `wrap_edges`
+
+use crate::core::Game;
+use crate::utils::{Direction, Position, PowerUpType};
+use rhai::{AST, CallFnOptions, Dynamic, Engine, Scope};
+use serde::Serialize;
+use std::path::Path;
+
+/// Upper bound on script work per turn, so a runaway loop fails instead of hanging.
+const MAX_OPERATIONS_PER_TURN: u64 = 1_000_000;
+
+/// The board starts two cells in from the top-left corner.
+const BOARD_ORIGIN: u16 = 2;
+
+#[derive(Serialize)]
+struct Cell {
+    x: u16,
+    y: u16,
+}
+
+impl From<Position> for Cell {
+    fn from(position: Position) -> Self {
+        Self {
+            x: position.x - BOARD_ORIGIN,
+            y: position.y - BOARD_ORIGIN,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct BotPowerUp {
+    x: u16,
+    y: u16,
+    kind: PowerUpType,
+    ticks_left: u32,
+}
+
+#[derive(Serialize)]
+struct BotState {
+    width: u16,
+    height: u16,
+    snake: Vec<Cell>,
+    direction: Direction,
+    food: Cell,
+    power_up: Option<BotPowerUp>,
+    effects: Vec<PowerUpType>,
+    score: u32,
+    tick: u32,
+    wrap_edges: bool,
+}
+
+impl BotState {
+    fn capture(game: &Game) -> Self {
+        Self {
+            width: game.width - BOARD_ORIGIN,
+            height: game.height - BOARD_ORIGIN,
+            snake: game.snake.body().iter().copied().map(Cell::from).collect(),
+            direction: game.snake.direction,
+            food: game.food.into(),
+            power_up: game.power_up.map(|power_up| {
+                let Cell { x, y } = power_up.position.into();
+                BotPowerUp {
+                    x,
+                    y,
+                    kind: power_up.power_up_type,
+                    ticks_left: power_up.ticks_left,
+                }
+            }),
+            effects: game
+                .active_effects
+                .iter()
+                .map(|effect| effect.kind)
+                .collect(),
+            score: game.score,
+            tick: game.stats.ticks,
+            wrap_edges: game.profile.wrap_edges,
+        }
+    }
+}
+
+fn parse_direction(value: Dynamic) -> Result<Option<Direction>, String> {
+    if value.is_unit() {
+        return Ok(None);
+    }
+    let name = value
+        .into_string()
+        .map_err(|kind| format!("bot turn() must return a direction string, got {kind}"))?;
+    match name.as_str() {
+        "up" => Ok(Some(Direction::Up)),
+        "down" => Ok(Some(Direction::Down)),
+        "left" => Ok(Some(Direction::Left)),
+        "right" => Ok(Some(Direction::Right)),
+        other => Err(format!("bot turn() returned unknown direction \"{other}\"")),
+    }
+}
+
+pub struct Bot {
+    engine: Engine,
+    ast: AST,
+    scope: Scope<'static>,
+}
+
+impl Bot {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+        Self::from_source(&source).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn from_source(source: &str) -> Result<Self, String> {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS_PER_TURN);
+        let ast = engine.compile(source).map_err(|err| err.to_string())?;
+        if !ast
+            .iter_functions()
+            .any(|function| function.name == "turn" && function.params.len() == 1)
+        {
+            return Err("bot script must define fn turn(state)".to_string());
+        }
+        Ok(Self {
+            engine,
+            ast,
+            scope: Scope::new(),
+        })
+    }
+
+    /// Asks the script for its next move.
+    pub fn choose(&mut self, game: &Game) -> Result<Option<Direction>, String> {
+        let state =
+            rhai::serde::to_dynamic(BotState::capture(game)).map_err(|err| err.to_string())?;
+        let value = self
+            .engine
+            .call_fn_with_options::<Dynamic>(
+                // Top-level statements are not re-run on every turn.
+                CallFnOptions::new().eval_ast(false),
+                &mut self.scope,
+                &self.ast,
+                "turn",
+                (state,),
+            )
+            .map_err(|err| format!("bot turn() failed: {err}"))?;
+        parse_direction(value)
+    }
+
+    /// Lets the script steer `game` and advances it one tick.
+    pub fn step(&mut self, game: &mut Game) -> Result<(), String> {
+        if let Some(direction) = self.choose(game)? {
+            game.update_snake_direction(direction);
+        }
+        game.tick();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Difficulty;
+
+    #[test]
+    fn bot_sees_board_coordinates_and_steers_the_snake() {
+        let mut bot = Bot::from_source(
+            r#"
+            fn turn(state) {
+                if state.snake[0].x < 0 || state.snake[0].x >= state.width {
+                    throw "head outside the board";
+                }
+                if state.direction == "left" { "up" } else { () }
+            }
+            "#,
+        )
+        .unwrap();
+        let mut game = Game::new_seeded(Difficulty::Easy, 20, 12, 0, 5);
+
+        bot.step(&mut game).unwrap();
+
+        assert_eq!(game.snake.direction, Direction::Up);
+        assert_eq!(game.stats.ticks, 1);
+    }
+
+    #[test]
+    fn bot_scripts_are_validated() {
+        assert!(Bot::from_source("fn other(state) { () }").is_err());
+        assert!(Bot::from_source("fn turn(state) {").is_err());
+
+        let game = Game::new_seeded(Difficulty::Easy, 20, 12, 0, 5);
+        let mut sideways = Bot::from_source(r#"fn turn(state) { "sideways" }"#).unwrap();
+        assert!(sideways.choose(&game).is_err());
+        let mut runaway = Bot::from_source("fn turn(state) { loop {} }").unwrap();
+        assert!(runaway.choose(&game).is_err());
+    }
+}
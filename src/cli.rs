@@ -0,0 +1,310 @@
+//! Command-line interface. Without a subcommand rustnake plays, as `play`
+//! with no options does: the main menu opens and everything is picked there.
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use log::LevelFilter;
+use std::path::PathBuf;
+
+use crate::utils::Difficulty;
+
+#[derive(Parser)]
+#[command(name = "rustnake", version, about = "Classic Snake for the terminal.")]
+pub struct Cli {
+    /// Append every crash, with a backtrace, to FILE.
+    #[arg(long, global = true, value_name = "FILE")]
+    pub crash_log: Option<PathBuf>,
+    /// Write a debug log to `rustnake.log` beside the config.
+    #[arg(long, global = true)]
+    pub verbose: bool,
+    /// Like `--verbose`, at LEVEL (error, warn, info, debug, trace, or off).
+    #[arg(long, global = true, value_name = "LEVEL")]
+    pub log_level: Option<LevelFilter>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl Cli {
+    /// The level to log at, or `None` when no log was asked for.
+    pub fn log_level(&self) -> Option<LevelFilter> {
+        self.log_level
+            .or(self.verbose.then_some(LevelFilter::Debug))
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Play (the default). `--difficulty`, `--mode`, or `--seed` skip the menu
+    /// and exit after one run.
+    #[command(long_flag = "play")]
+    Play(PlayArgs),
+    /// Print the high score tables.
+    Scores,
+    /// Find, check, back up, or restore the config.
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+    /// `--check-config`, from before `config check`.
+    #[command(hide = true, long_flag = "check-config")]
+    CheckConfig,
+    /// `--backup-config FILE`, from before `config backup`.
+    #[command(hide = true, long_flag = "backup-config")]
+    BackupConfig { file: PathBuf },
+    /// `--restore-config FILE`, from before `config restore`.
+    #[command(hide = true, long_flag = "restore-config")]
+    RestoreConfig { file: PathBuf },
+    /// Save the config, check that it was written, and exit.
+    #[command(long_flag_alias = "smoke-check")]
+    SmokeCheck,
+    /// Time synthetic worst-case frames drawn without a terminal.
+    #[command(long_flag_alias = "bench-render")]
+    BenchRender {
+        /// Frames to draw.
+        #[arg(default_value_t = 2000, value_parser = clap::value_parser!(u32).range(1..))]
+        frames: u32,
+    },
+    /// Let the autopilot play, then write the frame on screen to FILE (`-` for
+    /// stdout) as text.
+    Screenshot {
+        file: PathBuf,
+        /// Spawn food and power-ups from this seed instead of a random one.
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Ticks to play before the frame is taken; a game that ends sooner is
+        /// taken at its end.
+        #[arg(long, default_value_t = 100)]
+        ticks: u32,
+        #[arg(long, value_parser = parse_difficulty)]
+        difficulty: Option<Difficulty>,
+        /// Keep the colors, as ANSI escape sequences.
+        #[arg(long)]
+        ansi: bool,
+        /// Write the result card, with an emoji map of the board, instead of the frame.
+        #[arg(long)]
+        card: bool,
+    },
+    /// Write the high scores and run history to FILE (`.json` or `.csv`).
+    #[command(long_flag_alias = "export-scores")]
+    ExportScores { file: PathBuf },
+    /// Watch a game started with `play --broadcast ADDR`.
+    #[command(long_flag_alias = "spectate")]
+    Spectate { addr: String },
+    /// One run described in short text lines instead of drawn, for screen readers.
+    #[command(long_flag_alias = "text-mode")]
+    TextMode,
+    /// Replay a run saved with `play --record-input`.
+    #[command(long_flag_alias = "play-input")]
+    Replay {
+        file: PathBuf,
+        /// Replay without drawing and check the run ends as recorded.
+        #[arg(long)]
+        headless: bool,
+    },
+    /// Merge the high scores with the sync endpoint.
+    #[cfg(feature = "sync")]
+    #[command(long_flag_alias = "sync-scores")]
+    SyncScores,
+    /// Let a Rhai script play the default difficulty.
+    #[cfg(feature = "bot")]
+    #[command(long_flag_alias = "bot")]
+    Bot {
+        script: PathBuf,
+        /// Play without drawing and print the result.
+        #[arg(long)]
+        headless: bool,
+    },
+}
+
+#[derive(Args, Default)]
+pub struct PlayArgs {
+    /// Start a run at this difficulty instead of opening the menu.
+    #[arg(long, value_parser = parse_difficulty)]
+    pub difficulty: Option<Difficulty>,
+    /// Whether the snake wraps around the board edges or dies on them.
+    #[arg(long)]
+    pub mode: Option<EdgeMode>,
+    /// Spawn food and power-ups from this seed.
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Save the inputs of each run to FILE.
+    #[arg(long, value_name = "FILE")]
+    pub record_input: Option<PathBuf>,
+    /// Stream the game to spectators connecting to ADDR.
+    #[arg(long, value_name = "ADDR")]
+    pub broadcast: Option<String>,
+    /// Show the input-to-render latency in the top-left corner.
+    #[arg(long)]
+    pub debug_overlay: bool,
+    /// Show board coordinates and the food's offset from the snake's head,
+    /// and let U rewind a few ticks, Z toggle slow motion, and N step one tick
+    /// at a time.
+    #[arg(long)]
+    pub practice: bool,
+}
+
+impl PlayArgs {
+    /// A run set up on the command line skips the menu, and the game exits
+    /// when it ends instead of going back to the menu.
+    pub fn is_single_run(&self) -> bool {
+        self.difficulty.is_some() || self.mode.is_some() || self.seed.is_some()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum EdgeMode {
+    /// Leaving the board comes back in on the opposite side.
+    Wrap,
+    /// The border is a wall.
+    Walls,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Print where the config, scores, history, and log are kept.
+    Path,
+    /// Check the config and data files without loading or rewriting them.
+    Check,
+    /// Copy the config to FILE.
+    Backup { file: PathBuf },
+    /// Replace the config with a backup.
+    Restore { file: PathBuf },
+}
+
+/// The flags that picked a command before subcommands did, still accepted as
+/// hidden aliases, with the subcommand to use instead.
+const DEPRECATED_FLAGS: [(&str, &str); 11] = [
+    ("--smoke-check", "smoke-check"),
+    ("--bench-render", "bench-render"),
+    ("--check-config", "config check"),
+    ("--backup-config", "config backup"),
+    ("--restore-config", "config restore"),
+    ("--export-scores", "export-scores"),
+    ("--spectate", "spectate"),
+    ("--text-mode", "text-mode"),
+    ("--play-input", "replay"),
+    ("--sync-scores", "sync-scores"),
+    ("--bot", "bot"),
+];
+
+/// A warning for the first deprecated flag among `args` (the program name
+/// excluded), or `None` when there is none.
+pub fn deprecation_warning<I>(args: I) -> Option<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    args.into_iter().find_map(|arg| {
+        DEPRECATED_FLAGS
+            .iter()
+            .find(|(flag, _)| *flag == arg.as_ref())
+            .map(|(flag, command)| {
+                format!("warning: `{flag}` is deprecated; use `rustnake {command}` instead")
+            })
+    })
+}
+
+fn parse_difficulty(name: &str) -> Result<Difficulty, String> {
+    Difficulty::ALL
+        .into_iter()
+        .find(|difficulty| format!("{difficulty:?}").eq_ignore_ascii_case(name))
+        .ok_or_else(|| "expected easy, medium, hard, extreme, or custom".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn parses_subcommands_and_defaults_to_play() {
+        Cli::command().debug_assert();
+
+        let cli = Cli::try_parse_from(["rustnake", "--verbose"]).unwrap();
+        assert!(cli.command.is_none());
+        assert_eq!(cli.log_level(), Some(LevelFilter::Debug));
+
+        let cli = Cli::try_parse_from([
+            "rustnake",
+            "play",
+            "--difficulty",
+            "Hard",
+            "--seed",
+            "42",
+            "--log-level",
+            "trace",
+        ])
+        .unwrap();
+        assert_eq!(cli.log_level(), Some(LevelFilter::Trace));
+        let Some(Command::Play(play)) = cli.command else {
+            panic!("expected play");
+        };
+        assert_eq!(play.difficulty, Some(Difficulty::Hard));
+        assert_eq!(play.seed, Some(42));
+        assert!(play.is_single_run());
+
+        let cli = Cli::try_parse_from([
+            "rustnake",
+            "--play",
+            "--difficulty",
+            "extreme",
+            "--mode",
+            "walls",
+        ])
+        .unwrap();
+        let Some(Command::Play(play)) = cli.command else {
+            panic!("expected play");
+        };
+        assert_eq!(play.difficulty, Some(Difficulty::Extreme));
+        assert_eq!(play.mode, Some(EdgeMode::Walls));
+        assert!(!PlayArgs::default().is_single_run());
+
+        assert!(Cli::try_parse_from(["rustnake", "play", "--difficulty", "brutal"]).is_err());
+        assert!(Cli::try_parse_from(["rustnake", "bench-render", "0"]).is_err());
+        assert!(matches!(
+            Cli::try_parse_from(["rustnake", "config", "path"])
+                .unwrap()
+                .command,
+            Some(Command::Config {
+                command: ConfigCommand::Path
+            })
+        ));
+    }
+
+    #[test]
+    fn old_flags_still_pick_their_commands_with_a_warning() {
+        let parse = |args: &[&str]| Cli::try_parse_from(args).expect("old flag parses").command;
+        assert!(matches!(
+            parse(&["rustnake", "--smoke-check"]),
+            Some(Command::SmokeCheck)
+        ));
+        assert!(matches!(
+            parse(&["rustnake", "--bench-render", "10"]),
+            Some(Command::BenchRender { frames: 10 })
+        ));
+        assert!(matches!(
+            parse(&["rustnake", "--spectate", "127.0.0.1:7777"]),
+            Some(Command::Spectate { addr }) if addr == "127.0.0.1:7777"
+        ));
+        assert!(matches!(
+            parse(&["rustnake", "--play-input", "run.toml", "--headless"]),
+            Some(Command::Replay { headless: true, .. })
+        ));
+        assert!(matches!(
+            parse(&["rustnake", "--check-config"]),
+            Some(Command::CheckConfig)
+        ));
+
+        let help = Cli::command().render_help().to_string();
+        assert!(!help.contains("check-config") && !help.contains("--spectate"));
+
+        assert_eq!(
+            deprecation_warning(["--text-mode"]).as_deref(),
+            Some("warning: `--text-mode` is deprecated; use `rustnake text-mode` instead")
+        );
+        assert_eq!(
+            deprecation_warning(["--verbose", "--export-scores", "scores.json"]).as_deref(),
+            Some("warning: `--export-scores` is deprecated; use `rustnake export-scores` instead")
+        );
+        assert_eq!(deprecation_warning(["play", "--seed", "4"]), None);
+    }
+}
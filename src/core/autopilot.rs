@@ -0,0 +1,94 @@
+//! Simple built-in AI used by the demo mode: heads for the food along the
+//! shortest path and refuses moves that would crash on the next tick.
+
+use super::{Game, Snake};
+use crate::utils::{Direction, Position};
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+fn is_reverse(current: Direction, next: Direction) -> bool {
+    matches!(
+        (current, next),
+        (Direction::Up, Direction::Down)
+            | (Direction::Down, Direction::Up)
+            | (Direction::Left, Direction::Right)
+            | (Direction::Right, Direction::Left)
+    )
+}
+
+/// Steps from `from` to `to` along one axis of `size` playable cells.
+fn axis_distance(from: u16, to: u16, size: u16, wraps: bool) -> u16 {
+    let direct = from.abs_diff(to);
+    if wraps {
+        direct.min(size - direct)
+    } else {
+        direct
+    }
+}
+
+fn distance(game: &Game, from: Position, to: Position) -> u16 {
+    let wraps = game.profile.wrap_edges;
+    // Playable cells run from 2 to width - 1 (and height - 1).
+    axis_distance(from.x, to.x, game.width - 2, wraps)
+        + axis_distance(from.y, to.y, game.height - 2, wraps)
+}
+
+/// The turn that brings the snake closest to the food without crashing on the
+/// next tick. Keeps the current heading when every move is fatal.
+pub fn autopilot_direction(game: &Game) -> Direction {
+    let current = game.snake.direction;
+    // The tail moves out of the way unless the snake is about to eat.
+    let body = game.snake.body();
+    let blocking = body.range(..body.len() - 1);
+    let candidates = [current]
+        .into_iter()
+        .chain(
+            DIRECTIONS
+                .into_iter()
+                .filter(|direction| *direction != current),
+        )
+        .filter(|direction| !is_reverse(current, *direction));
+
+    let mut best: Option<(u16, Direction)> = None;
+    for direction in candidates {
+        let probe = Snake::with_body(vec![game.snake.head_position()], direction);
+        if !game.profile.wrap_edges && probe.next_head_leaves_board(game.width, game.height) {
+            continue;
+        }
+        let next = probe.next_head(game.width, game.height);
+        if blocking.clone().any(|pos| *pos == next) {
+            continue;
+        }
+        let steps = distance(game, next, game.food);
+        if best.is_none_or(|(best_steps, _)| steps < best_steps) {
+            best = Some((steps, direction));
+        }
+    }
+    best.map_or(current, |(_, direction)| direction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Difficulty;
+
+    #[test]
+    fn autopilot_eats_food_and_avoids_its_body() {
+        let mut game = Game::new_seeded(Difficulty::Easy, 20, 12, 0, 11);
+        for _ in 0..500 {
+            if game.game_over || game.score >= 50 {
+                break;
+            }
+            game.update_snake_direction(autopilot_direction(&game));
+            game.tick();
+        }
+
+        assert!(!game.game_over);
+        assert!(game.score >= 50);
+    }
+}
@@ -0,0 +1,95 @@
+//! End-to-end regression guard: replays the bundled reference run and checks the
+//! exact outcome. See `fixtures/demo_replay.toml` for the format.
+
+use super::*;
+use serde::Deserialize;
+
+const DEMO_REPLAY: &str = include_str!("fixtures/demo_replay.toml");
+
+#[derive(Deserialize)]
+struct DemoReplay {
+    seed: u64,
+    difficulty: Difficulty,
+    width: u16,
+    height: u16,
+    ticks: u32,
+    turns: Vec<(u32, String)>,
+    expected: DemoExpectation,
+}
+
+#[derive(Deserialize)]
+struct DemoExpectation {
+    score: u32,
+    game_over: bool,
+    food: (u16, u16),
+    snake: Vec<(u16, u16)>,
+}
+
+fn parse_direction(name: &str) -> Direction {
+    match name {
+        "up" => Direction::Up,
+        "down" => Direction::Down,
+        "left" => Direction::Left,
+        "right" => Direction::Right,
+        other => panic!("unknown direction in demo replay: {other}"),
+    }
+}
+
+fn run_demo(replay: &DemoReplay) -> Game {
+    let mut game = Game::new_seeded(
+        replay.difficulty,
+        replay.width,
+        replay.height,
+        0,
+        replay.seed,
+    );
+    game.muted = true;
+
+    let mut turns = replay.turns.iter().peekable();
+    for tick in 0..replay.ticks {
+        while let Some((_, direction)) = turns.next_if(|(turn_tick, _)| *turn_tick == tick) {
+            game.update_snake_direction(parse_direction(direction));
+        }
+        game.tick();
+        if game.game_over {
+            break;
+        }
+    }
+    game
+}
+
+#[test]
+fn demo_replay_reproduces_reference_outcome() {
+    let replay: DemoReplay = toml::from_str(DEMO_REPLAY).expect("demo replay should parse");
+    let game = run_demo(&replay);
+
+    let snake: Vec<(u16, u16)> = game.snake.body().iter().map(|pos| (pos.x, pos.y)).collect();
+    let outcome = (
+        game.score,
+        game.game_over,
+        (game.food.x, game.food.y),
+        snake,
+    );
+    let expected = (
+        replay.expected.score,
+        replay.expected.game_over,
+        replay.expected.food,
+        replay.expected.snake.clone(),
+    );
+    assert_eq!(
+        outcome, expected,
+        "demo replay diverged (score, game_over, food, snake); if this gameplay change is \
+         intentional, update [expected] in src/core/fixtures/demo_replay.toml"
+    );
+}
+
+#[test]
+fn demo_replay_is_deterministic_across_runs() {
+    let replay: DemoReplay = toml::from_str(DEMO_REPLAY).expect("demo replay should parse");
+    let first = run_demo(&replay);
+    let second = run_demo(&replay);
+
+    assert_eq!(first.snake.body(), second.snake.body());
+    assert_eq!(first.score, second.score);
+    assert_eq!(first.food, second.food);
+}
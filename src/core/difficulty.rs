@@ -0,0 +1,207 @@
+//! Difficulty tuning values consumed by `Game`.
+//! Presets cover the fixed difficulties; `Difficulty::Custom` is built from player settings.
+
+use crate::utils::{CustomDifficulty, Difficulty, GameMode, GameSpeed};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyProfile {
+    pub horizontal_tick: Duration,
+    pub vertical_tick: Duration,
+    pub effect_duration_ticks: u32,
+    pub power_up_lifetime_ticks: u32,
+    pub power_up_refresh_spawn_chance: f32,
+    pub power_up_tick_spawn_chance: f32,
+    pub progression_step_percent: u64,
+    pub progression_max_steps: u64,
+    pub wrap_edges: bool,
+    /// The speed `with_speed` set, which decides the ticks above instead of
+    /// the difficulty. Such runs are not ranked.
+    #[serde(default)]
+    pub speed: GameSpeed,
+}
+
+impl DifficultyProfile {
+    pub fn mode(&self) -> GameMode {
+        if self.wrap_edges {
+            GameMode::Classic
+        } else {
+            GameMode::Walls
+        }
+    }
+
+    /// Preset power-up lifetimes are roughly 15 seconds at each base horizontal pace.
+    pub fn for_difficulty(difficulty: Difficulty, custom: CustomDifficulty) -> Self {
+        match difficulty {
+            // Slower
+            Difficulty::Easy => Self {
+                horizontal_tick: Duration::from_millis(150),
+                vertical_tick: Duration::from_millis(300),
+                effect_duration_ticks: 120,
+                power_up_lifetime_ticks: 100,
+                power_up_refresh_spawn_chance: 0.35,
+                power_up_tick_spawn_chance: 0.025,
+                progression_step_percent: 2,
+                progression_max_steps: 12,
+                wrap_edges: true,
+                speed: GameSpeed::Difficulty,
+            },
+            // Default
+            Difficulty::Medium => Self {
+                horizontal_tick: Duration::from_millis(100),
+                vertical_tick: Duration::from_millis(200),
+                effect_duration_ticks: 100,
+                power_up_lifetime_ticks: 150,
+                power_up_refresh_spawn_chance: 0.30,
+                power_up_tick_spawn_chance: 0.020,
+                progression_step_percent: 3,
+                progression_max_steps: 15,
+                wrap_edges: true,
+                speed: GameSpeed::Difficulty,
+            },
+            // Faster
+            Difficulty::Hard => Self {
+                horizontal_tick: Duration::from_millis(60),
+                vertical_tick: Duration::from_millis(120),
+                effect_duration_ticks: 85,
+                power_up_lifetime_ticks: 250,
+                power_up_refresh_spawn_chance: 0.24,
+                power_up_tick_spawn_chance: 0.015,
+                progression_step_percent: 4,
+                progression_max_steps: 12,
+                wrap_edges: true,
+                speed: GameSpeed::Difficulty,
+            },
+            // Fastest
+            Difficulty::Extreme => Self {
+                horizontal_tick: Duration::from_millis(35),
+                vertical_tick: Duration::from_millis(70),
+                effect_duration_ticks: 70,
+                power_up_lifetime_ticks: 430,
+                power_up_refresh_spawn_chance: 0.16,
+                power_up_tick_spawn_chance: 0.010,
+                progression_step_percent: 5,
+                progression_max_steps: 13,
+                wrap_edges: true,
+                speed: GameSpeed::Difficulty,
+            },
+            Difficulty::Custom => Self::custom(custom),
+        }
+    }
+
+    /// The profile moving at `speed` instead of its own pace. Power-ups stay
+    /// on the board for as long as before; the rest of the rules are kept.
+    pub fn with_speed(self, speed: GameSpeed) -> Self {
+        let tick_ms: u64 = match speed {
+            GameSpeed::Difficulty => return self,
+            GameSpeed::Slow => 150,
+            GameSpeed::Normal => 100,
+            GameSpeed::Fast => 60,
+            GameSpeed::Insane => 35,
+        };
+        let own_tick_ms = (self.horizontal_tick.as_millis() as u64).max(1);
+        Self {
+            horizontal_tick: Duration::from_millis(tick_ms),
+            vertical_tick: Duration::from_millis(tick_ms * 2),
+            power_up_lifetime_ticks: (u64::from(self.power_up_lifetime_ticks) * own_tick_ms
+                / tick_ms) as u32,
+            speed,
+            ..self
+        }
+    }
+
+    /// The profile with a vertical tick `percent` as long as the horizontal one.
+    pub fn with_vertical_pace(self, percent: u64) -> Self {
+        Self {
+            vertical_tick: Duration::from_millis(
+                self.horizontal_tick.as_millis() as u64 * percent / 100,
+            ),
+            ..self
+        }
+    }
+
+    fn custom(custom: CustomDifficulty) -> Self {
+        let custom = custom.clamped();
+        let tick_ms = u64::from(custom.tick_ms);
+        let spawn_chance = f32::from(custom.power_up_chance_percent) / 100.0;
+        Self {
+            horizontal_tick: Duration::from_millis(tick_ms),
+            // Cells are roughly twice as tall as wide, matching the presets' 1:2 ratio.
+            vertical_tick: Duration::from_millis(tick_ms * 2),
+            effect_duration_ticks: 100,
+            // Keep power-ups on the board for about 15 seconds at the chosen pace.
+            power_up_lifetime_ticks: (15_000 / tick_ms) as u32,
+            power_up_refresh_spawn_chance: spawn_chance,
+            // Presets roll per tick at about 1/15 of their refresh chance.
+            power_up_tick_spawn_chance: spawn_chance / 15.0,
+            progression_step_percent: 3,
+            progression_max_steps: u64::from(custom.progression_steps),
+            wrap_edges: custom.wrap_edges,
+            speed: GameSpeed::Difficulty,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_custom_profile_matches_medium_pace() {
+        let medium = DifficultyProfile::for_difficulty(Difficulty::Medium, Default::default());
+        let custom = DifficultyProfile::for_difficulty(Difficulty::Custom, Default::default());
+
+        assert_eq!(custom.horizontal_tick, medium.horizontal_tick);
+        assert_eq!(custom.vertical_tick, medium.vertical_tick);
+        assert_eq!(
+            custom.power_up_lifetime_ticks,
+            medium.power_up_lifetime_ticks
+        );
+        assert_eq!(custom.progression_max_steps, medium.progression_max_steps);
+        assert!(custom.wrap_edges);
+    }
+
+    #[test]
+    fn custom_profile_clamps_out_of_range_values() {
+        let profile = DifficultyProfile::for_difficulty(
+            Difficulty::Custom,
+            CustomDifficulty {
+                tick_ms: 1,
+                progression_steps: 200,
+                power_up_chance_percent: 255,
+                wrap_edges: false,
+                seed: None,
+            },
+        );
+
+        assert_eq!(profile.horizontal_tick, Duration::from_millis(30));
+        assert_eq!(profile.progression_max_steps, 20);
+        assert!((profile.power_up_refresh_spawn_chance - 0.60).abs() < f32::EPSILON);
+        assert!(!profile.wrap_edges);
+    }
+
+    #[test]
+    fn a_speed_changes_the_pace_but_not_the_rules() {
+        let extreme = DifficultyProfile::for_difficulty(Difficulty::Extreme, Default::default());
+        assert_eq!(extreme.with_speed(GameSpeed::Difficulty), extreme);
+
+        let slow = extreme.with_speed(GameSpeed::Slow);
+        let easy = DifficultyProfile::for_difficulty(Difficulty::Easy, Default::default());
+        assert_eq!(slow.horizontal_tick, easy.horizontal_tick);
+        assert_eq!(slow.vertical_tick, easy.vertical_tick);
+        assert_eq!(slow.speed, GameSpeed::Slow);
+        assert_eq!(
+            slow.progression_step_percent,
+            extreme.progression_step_percent
+        );
+        assert_eq!(slow.effect_duration_ticks, extreme.effect_duration_ticks);
+        // About 15 seconds on the board at either pace.
+        assert_eq!(slow.power_up_lifetime_ticks, 100);
+
+        let insane = DifficultyProfile::for_difficulty(Difficulty::Easy, Default::default())
+            .with_speed(GameSpeed::Insane);
+        assert_eq!(insane.horizontal_tick, extreme.horizontal_tick);
+        assert_eq!(insane.progression_max_steps, easy.progression_max_steps);
+    }
+}
@@ -0,0 +1,115 @@
+//! Personal-best ghost: the best earlier run on a score table, kept as its
+//! replay log and played back tick by tick beside a live run, so the player
+//! can see where that run's head was at the same point.
+
+use super::replay::ReplayCursor;
+use super::{DifficultyProfile, Game, ReplayLog};
+use crate::utils::{Difficulty, Position, PowerUpSet};
+use serde::{Deserialize, Serialize};
+
+/// Everything needed to play a finished run again.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GhostRun {
+    pub difficulty: Difficulty,
+    pub profile: DifficultyProfile,
+    pub width: u16,
+    pub height: u16,
+    pub disabled_power_ups: PowerUpSet,
+    pub score: u32,
+    /// Ticks the run lasted; the ghost leaves the board after them.
+    pub ticks: u32,
+    pub replay: ReplayLog,
+}
+
+impl GhostRun {
+    pub fn of(game: &Game) -> Self {
+        Self {
+            difficulty: game.difficulty,
+            profile: game.profile,
+            width: game.width,
+            height: game.height,
+            disabled_power_ups: game.disabled_power_ups,
+            score: game.score,
+            ticks: game.stats.ticks,
+            replay: game.replay.clone(),
+        }
+    }
+}
+
+/// A `GhostRun` being played back.
+pub struct Ghost {
+    run: GhostRun,
+    game: Game,
+    cursor: ReplayCursor,
+}
+
+impl Ghost {
+    pub fn new(run: GhostRun) -> Self {
+        let mut game = Game::with_seed(
+            run.difficulty,
+            run.profile,
+            run.width,
+            run.height,
+            0,
+            run.replay.seed,
+        );
+        game.muted = true;
+        game.set_disabled_power_ups(run.disabled_power_ups);
+        Self {
+            run,
+            game,
+            cursor: ReplayCursor::default(),
+        }
+    }
+
+    pub fn score(&self) -> u32 {
+        self.run.score
+    }
+
+    /// Plays the ghost forward to `ticks` and returns where its head is then,
+    /// or `None` once the recorded run is over. The ghost never goes back, so
+    /// `ticks` is expected to only grow.
+    pub fn head_at(&mut self, ticks: u32) -> Option<Position> {
+        let end = ticks.min(self.run.ticks);
+        while self.game.stats.ticks < end && !self.game.game_over {
+            self.cursor.step(&self.run.replay, &mut self.game);
+        }
+        (ticks <= self.run.ticks && self.game.stats.ticks == ticks && !self.game.game_over)
+            .then(|| self.game.snake.head_position())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Direction;
+
+    #[test]
+    fn ghost_retraces_the_recorded_run_tick_by_tick() {
+        let mut game = Game::new_seeded(Difficulty::Medium, 40, 20, 0, u64::MAX - 3);
+        let turns = [
+            (5, Direction::Up),
+            (9, Direction::Right),
+            (14, Direction::Down),
+        ];
+        let mut heads = vec![game.snake.head_position()];
+        for tick in 0..40 {
+            if let Some((_, direction)) = turns.iter().find(|(at, _)| *at == tick) {
+                game.update_snake_direction(*direction);
+            }
+            game.set_boost((20..25).contains(&tick));
+            game.tick();
+            heads.push(game.snake.head_position());
+        }
+
+        let run = GhostRun::of(&game);
+        let json = serde_json::to_string(&run).unwrap();
+        let mut ghost = Ghost::new(serde_json::from_str(&json).unwrap());
+        assert_eq!(ghost.score(), game.score);
+        for (tick, head) in heads.iter().enumerate().step_by(3) {
+            assert_eq!(ghost.head_at(tick as u32), Some(*head), "tick {tick}");
+        }
+        assert_eq!(ghost.head_at(40), Some(heads[40]));
+        assert_eq!(ghost.head_at(41), None);
+    }
+}
@@ -1,22 +1,63 @@
 //! Game logic module for the Snake game.
 //! Contains the core game entities and mechanics.
 
-use crate::utils::{Difficulty, Direction, Position, PowerUp, PowerUpType};
-use rand::Rng;
-use std::collections::HashSet;
-use std::io::Write;
-
+use crate::scores::ScoreKey;
+use crate::utils::{
+    Difficulty, Direction, GameSpeed, Position, PowerUp, PowerUpSet, PowerUpType, SoundEvent,
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+mod autopilot;
+mod difficulty;
+mod ghost;
+mod replay;
+mod rewind;
+mod snapshot;
+mod spawn;
+mod stats;
+
+pub use autopilot::autopilot_direction;
+pub use difficulty::DifficultyProfile;
+pub use ghost::{Ghost, GhostRun};
+pub use replay::ReplayLog;
+pub use rewind::{RETRY_TICKS, REWIND_TICKS, RewindBuffer};
+pub use stats::RunStats;
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(from = "SnakeFields")]
 pub struct Snake {
-    pub body: Vec<Position>,
+    /// Head first.
+    body: VecDeque<Position>,
+    /// How many segments are on each cell. Segments share a cell right after
+    /// growing and while a shield lets the head pass through the body.
+    #[serde(skip_serializing)]
+    cells: HashMap<Position, u16>,
     pub direction: Direction,
 }
 
+/// A serialized snake, whose cell counts are worked out again on loading.
+#[derive(Deserialize)]
+struct SnakeFields {
+    body: Vec<Position>,
+    direction: Direction,
+}
+
+impl From<SnakeFields> for Snake {
+    fn from(fields: SnakeFields) -> Self {
+        Self::with_body(fields.body, fields.direction)
+    }
+}
+
 impl Snake {
     pub fn new(width: u16, height: u16) -> Self {
         let center_x = (width / 2).max(3);
         let center_y = (height / 2).max(2);
-        Snake {
-            body: vec![
+        Self::with_body(
+            vec![
                 Position {
                     x: center_x,
                     y: center_y,
@@ -30,8 +71,56 @@ impl Snake {
                     y: center_y,
                 }, // Tail
             ],
-            direction: Direction::Left,
+            Direction::Left,
+        )
+    }
+
+    pub fn with_body(body: Vec<Position>, direction: Direction) -> Self {
+        let mut snake = Snake {
+            body: VecDeque::new(),
+            cells: HashMap::new(),
+            direction,
+        };
+        snake.set_body(body);
+        snake
+    }
+
+    /// The segments, head first.
+    pub fn body(&self) -> &VecDeque<Position> {
+        &self.body
+    }
+
+    pub fn set_body(&mut self, body: Vec<Position>) {
+        self.cells.clear();
+        for pos in &body {
+            *self.cells.entry(*pos).or_default() += 1;
+        }
+        self.body = body.into();
+    }
+
+    fn add_cell(&mut self, pos: Position) {
+        *self.cells.entry(pos).or_default() += 1;
+    }
+
+    /// Takes off the last segment, if there is one.
+    pub fn pop_tail(&mut self) -> Option<Position> {
+        let tail = self.body.pop_back()?;
+        if let Some(count) = self.cells.get_mut(&tail) {
+            *count -= 1;
+            if *count == 0 {
+                self.cells.remove(&tail);
+            }
         }
+        Some(tail)
+    }
+
+    /// Adds a segment on top of the tail, which stays behind when the snake
+    /// next moves.
+    pub fn grow_tail(&mut self) -> Option<Position> {
+        let tail = self.body.back().copied()?;
+        self.body.push_back(tail);
+        self.add_cell(tail);
+        Some(tail)
     }
 
     pub fn next_head(&self, width: u16, height: u16) -> Position {
@@ -73,10 +162,11 @@ impl Snake {
 
     pub fn move_forward(&mut self, grow: bool, width: u16, height: u16) {
         let new_head = self.next_head(width, height);
-        self.body.insert(0, new_head);
+        self.body.push_front(new_head);
+        self.add_cell(new_head);
 
         if !grow {
-            self.body.pop();
+            self.pop_tail();
         }
     }
 
@@ -91,15 +181,80 @@ impl Snake {
         }
     }
 
+    /// Whether the next step would cross the border (and wrap when wrapping is on).
+    pub fn next_head_leaves_board(&self, width: u16, height: u16) -> bool {
+        let head = self.body[0];
+        match self.direction {
+            Direction::Up => head.y <= 2,
+            Direction::Down => head.y >= height - 1,
+            Direction::Left => head.x <= 2,
+            Direction::Right => head.x >= width - 1,
+        }
+    }
+
     pub fn head_position(&self) -> Position {
         self.body[0]
     }
 
     pub fn overlaps_with(&self, pos: Position) -> bool {
-        self.body.contains(&pos)
+        self.cells.contains_key(&pos)
+    }
+
+    /// Whether the head shares its cell with another segment.
+    pub fn head_hits_body(&self) -> bool {
+        self.cells
+            .get(&self.head_position())
+            .is_some_and(|&count| count > 1)
+    }
+}
+
+/// A timed power-up effect currently influencing the run.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ActiveEffect {
+    pub kind: PowerUpType,
+    pub ticks_left: u32,
+    pub stacks: u8,
+}
+
+impl ActiveEffect {
+    fn max_stacks(kind: PowerUpType) -> u8 {
+        match kind {
+            PowerUpType::ScoreMultiplier => 3,
+            _ => 1,
+        }
+    }
+
+    /// What a score multiplier effect multiplies points by: one more than its
+    /// stacks, so a single pickup already doubles them.
+    pub fn score_multiplier(&self) -> Option<u32> {
+        (self.kind == PowerUpType::ScoreMultiplier).then(|| 1 + u32::from(self.stacks))
+    }
+
+    fn speed_percent(&self) -> u64 {
+        match self.kind {
+            PowerUpType::SpeedBoost => 70,
+            PowerUpType::SlowDown => 150,
+            _ => 100,
+        }
     }
 }
 
+/// Shortest tick that pace scaling and speed effects can reach.
+const MIN_TICK: Duration = Duration::from_millis(20);
+/// With reduced motion on, the snake never moves faster than this.
+const REDUCED_MOTION_MIN_TICK: Duration = Duration::from_millis(60);
+/// Boosting shortens every tick to this percentage of its normal length.
+const BOOST_PACE_PERCENT: u64 = 60;
+/// How long an expiring power-up stays shown, then hidden, while it blinks.
+const POWER_UP_BLINK_PHASE: Duration = Duration::from_millis(250);
+/// Time scale of practice slow motion: half speed.
+pub const SLOW_MOTION_PERCENT: u64 = 50;
+/// Points each boosted tick costs.
+const BOOST_COST_PER_TICK: u32 = 1;
+/// Points between two progression speed-ups.
+const PROGRESSION_STEP_POINTS: u32 = 50;
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Game {
     pub snake: Snake,
     pub food: Position,
@@ -107,19 +262,86 @@ pub struct Game {
     pub high_score: u32,
     pub game_over: bool,
     pub difficulty: Difficulty,
+    pub profile: DifficultyProfile,
     pub paused: bool,
     pub power_up: Option<PowerUp>,
-    pub power_up_timer: Option<u32>, // Counter for how long power-up effect lasts
-    pub active_speed_effect: Option<PowerUpType>,
+    pub active_effects: Vec<ActiveEffect>, // Timed effects, in collection order
     // Positions that need to be redrawn
+    #[serde(skip)]
     pub dirty_positions: HashSet<Position>,
     pub width: u16,
     pub height: u16,
     pub muted: bool,
+    /// Accessibility setting: no blinking and a slower top speed.
+    pub reduced_motion: bool,
+    /// Accessibility setting: ticks never get shorter than this, whatever
+    /// progression, effects, and boosting add up to.
+    pub speed_cap: Duration,
+    /// The boost key is held; set through `set_boost` so replays see it.
+    pub boosting: bool,
+    /// How fast time runs, as a percentage: 50 plays at half speed. Only
+    /// practice slow motion turns it down.
+    pub time_scale_percent: u64,
+    pub disabled_power_ups: PowerUpSet,
+    pub stats: RunStats,
+    pub replay: ReplayLog,
+    /// Sounds raised since the frontend last drained this list.
+    #[serde(skip)]
+    pub sound_events: Vec<SoundEvent>,
+    // Every spawn roll goes through this so a seeded game replays identically.
+    rng: ChaCha12Rng,
 }
 
 impl Game {
+    /// Game with the preset profile for `difficulty` and a random spawn seed.
     pub fn new(difficulty: Difficulty, width: u16, height: u16, high_score: u32) -> Self {
+        let profile = DifficultyProfile::for_difficulty(difficulty, Default::default());
+        Self::with_profile(difficulty, profile, width, height, high_score)
+    }
+
+    pub fn with_profile(
+        difficulty: Difficulty,
+        profile: DifficultyProfile,
+        width: u16,
+        height: u16,
+        high_score: u32,
+    ) -> Self {
+        Self::with_seed(
+            difficulty,
+            profile,
+            width,
+            height,
+            high_score,
+            rand::random(),
+        )
+    }
+
+    /// Like `new`, but spawns follow `seed` so the run is reproducible.
+    pub fn new_seeded(
+        difficulty: Difficulty,
+        width: u16,
+        height: u16,
+        high_score: u32,
+        seed: u64,
+    ) -> Self {
+        Self::with_seed(
+            difficulty,
+            DifficultyProfile::for_difficulty(difficulty, Default::default()),
+            width,
+            height,
+            high_score,
+            seed,
+        )
+    }
+
+    pub fn with_seed(
+        difficulty: Difficulty,
+        profile: DifficultyProfile,
+        width: u16,
+        height: u16,
+        high_score: u32,
+        seed: u64,
+    ) -> Self {
         let mut game = Game {
             snake: Snake::new(width, height),
             food: Position { x: 0, y: 0 },
@@ -127,19 +349,28 @@ impl Game {
             high_score,
             game_over: false,
             difficulty,
+            profile,
             paused: false,
             power_up: None,
-            power_up_timer: None,
-            active_speed_effect: None,
+            active_effects: Vec::new(),
             dirty_positions: HashSet::new(),
             width,
             height,
             muted: false,
+            reduced_motion: false,
+            speed_cap: Duration::ZERO,
+            boosting: false,
+            time_scale_percent: 100,
+            disabled_power_ups: PowerUpSet::default(),
+            stats: RunStats::default(),
+            replay: ReplayLog::new(seed),
+            sound_events: Vec::new(),
+            rng: ChaCha12Rng::seed_from_u64(seed),
         };
         game.generate_food();
         game.generate_power_up(); // Generate initial power-up
         // Initially mark all snake positions as dirty
-        for pos in &game.snake.body {
+        for pos in game.snake.body() {
             game.dirty_positions.insert(*pos);
         }
         game.dirty_positions.insert(game.food);
@@ -149,6 +380,18 @@ impl Game {
         game
     }
 
+    pub fn set_disabled_power_ups(&mut self, disabled: PowerUpSet) {
+        self.disabled_power_ups = disabled;
+        // Drop an already spawned power-up whose type was just switched off.
+        if let Some(power_up) = self
+            .power_up
+            .filter(|power_up| disabled.contains(power_up.power_up_type))
+        {
+            self.power_up = None;
+            self.mark_position_dirty(power_up.position);
+        }
+    }
+
     pub fn toggle_pause(&mut self) {
         if !self.game_over {
             self.paused = !self.paused;
@@ -159,70 +402,47 @@ impl Game {
         self.paused
     }
 
-    pub fn get_tick_rates(&self) -> (std::time::Duration, std::time::Duration) {
-        match self.difficulty {
-            Difficulty::Easy => (
-                std::time::Duration::from_millis(150),
-                std::time::Duration::from_millis(300),
-            ), // Slower
-            Difficulty::Medium => (
-                std::time::Duration::from_millis(100),
-                std::time::Duration::from_millis(200),
-            ), // Default
-            Difficulty::Hard => (
-                std::time::Duration::from_millis(60),
-                std::time::Duration::from_millis(120),
-            ), // Faster
-            Difficulty::Extreme => (
-                std::time::Duration::from_millis(35),
-                std::time::Duration::from_millis(70),
-            ), // Fastest
-        }
+    pub fn get_tick_rates(&self) -> (Duration, Duration) {
+        (self.profile.horizontal_tick, self.profile.vertical_tick)
     }
 
-    fn speed_effect_duration_ticks(&self) -> u32 {
-        match self.difficulty {
-            Difficulty::Easy => 120,
-            Difficulty::Medium => 100,
-            Difficulty::Hard => 85,
-            Difficulty::Extreme => 70,
-        }
+    /// The high-score table this run's score belongs in.
+    pub fn score_key(&self) -> ScoreKey {
+        ScoreKey::new(
+            self.difficulty,
+            self.profile.mode(),
+            self.width,
+            self.height,
+        )
+    }
+
+    /// Ticks a timed effect lasts from the moment it is picked up.
+    pub fn effect_duration_ticks(&self) -> u32 {
+        self.profile.effect_duration_ticks
+    }
+
+    fn power_up_lifetime_ticks(&self) -> u32 {
+        self.profile.power_up_lifetime_ticks
+    }
+
+    fn power_up_warning_ticks(&self) -> u32 {
+        self.power_up_lifetime_ticks() / 5
     }
 
     fn power_up_refresh_spawn_chance(&self) -> f32 {
-        match self.difficulty {
-            Difficulty::Easy => 0.35,
-            Difficulty::Medium => 0.30,
-            Difficulty::Hard => 0.24,
-            Difficulty::Extreme => 0.16,
-        }
+        self.profile.power_up_refresh_spawn_chance
     }
 
     fn power_up_tick_spawn_chance(&self) -> f32 {
-        match self.difficulty {
-            Difficulty::Easy => 0.025,
-            Difficulty::Medium => 0.020,
-            Difficulty::Hard => 0.015,
-            Difficulty::Extreme => 0.010,
-        }
+        self.profile.power_up_tick_spawn_chance
     }
 
     fn progression_step_percent(&self) -> u64 {
-        match self.difficulty {
-            Difficulty::Easy => 2,
-            Difficulty::Medium => 3,
-            Difficulty::Hard => 4,
-            Difficulty::Extreme => 5,
-        }
+        self.profile.progression_step_percent
     }
 
     fn progression_max_steps(&self) -> u64 {
-        match self.difficulty {
-            Difficulty::Easy => 12,
-            Difficulty::Medium => 15,
-            Difficulty::Hard => 12,
-            Difficulty::Extreme => 13,
-        }
+        self.profile.progression_max_steps
     }
 
     pub fn check_power_up_collision(&mut self) {
@@ -237,171 +457,230 @@ impl Game {
     }
 
     pub fn apply_power_up_effect(&mut self, power_up_type: PowerUpType) {
+        self.stats.record_power_up(power_up_type);
         match power_up_type {
-            PowerUpType::SpeedBoost => {
-                // Temporarily increase snake speed (handled in main loop)
-                self.power_up_timer = Some(self.speed_effect_duration_ticks());
-                self.active_speed_effect = Some(PowerUpType::SpeedBoost);
-                self.play_sound(); // Play sound when collecting power-up
-            }
-            PowerUpType::SlowDown => {
-                // Temporarily decrease snake speed
-                self.power_up_timer = Some(self.speed_effect_duration_ticks());
-                self.active_speed_effect = Some(PowerUpType::SlowDown);
-                self.play_sound(); // Play sound when collecting power-up
+            PowerUpType::SpeedBoost
+            | PowerUpType::SlowDown
+            | PowerUpType::Shield
+            | PowerUpType::ScoreMultiplier => {
+                // Timed effects are resolved by the active-effects list (speed is read by the main loop)
+                self.add_effect(power_up_type);
+                self.emit_sound(SoundEvent::PowerUp);
             }
             PowerUpType::ExtraPoints => {
-                self.score += 50; // Add extra points
+                self.score += 50 * self.score_multiplier(); // Add extra points
                 self.update_high_score();
-                self.play_sound(); // Play sound when collecting power-up
+                self.emit_sound(SoundEvent::PowerUp);
             }
             PowerUpType::Grow => {
                 // Grow the snake by 2 segments
                 for _ in 0..2 {
-                    if let Some(last_segment) = self.snake.body.last().copied() {
-                        self.snake.body.push(last_segment);
+                    if let Some(last_segment) = self.snake.grow_tail() {
                         self.mark_position_dirty(last_segment);
                     }
                 }
-                self.play_sound(); // Play sound when collecting power-up
+                self.emit_sound(SoundEvent::PowerUp);
             }
             PowerUpType::Shrink => {
                 // Shrink the snake by removing 2 segments (but keep at least 3)
                 for _ in 0..2 {
-                    if self.snake.body.len() > 3 {
-                        if let Some(removed) = self.snake.body.pop() {
+                    if self.snake.body().len() > 3 {
+                        if let Some(removed) = self.snake.pop_tail() {
                             self.mark_position_dirty(removed);
                         }
                     }
                 }
-                self.play_sound(); // Play sound when collecting power-up
+                self.emit_sound(SoundEvent::PowerUp);
             }
         }
     }
 
+    pub fn update_power_up_lifetime(&mut self) {
+        let warning_ticks = self.power_up_warning_ticks();
+        let Some(power_up) = self.power_up.as_mut() else {
+            return;
+        };
+        power_up.ticks_left = power_up.ticks_left.saturating_sub(1);
+        let position = power_up.position;
+        if power_up.ticks_left == 0 {
+            self.power_up = None;
+            self.mark_position_dirty(position);
+        } else if power_up.ticks_left <= warning_ticks {
+            // Blinking toggles visibility, so the cell must be repainted every tick.
+            self.mark_position_dirty(position);
+        }
+    }
+
+    pub fn power_up_is_expiring(&self) -> bool {
+        self.power_up
+            .is_some_and(|power_up| power_up.ticks_left <= self.power_up_warning_ticks())
+    }
+
+    /// Whether the power-up shows in a frame drawn at `clock`, any steadily
+    /// running time the frontend keeps. Blinking goes by that rather than by
+    /// ticks, so it keeps an even pace between moves and at every speed.
+    pub fn power_up_blink_visible(&self, clock: Duration) -> bool {
+        if self.power_up.is_none() {
+            return false;
+        }
+        if !self.power_up_is_expiring() || self.reduced_motion {
+            return true;
+        }
+        (clock.as_millis() / POWER_UP_BLINK_PHASE.as_millis()) % 2 == 0
+    }
+
+    /// Stacking rules: collecting an effect that is already active refreshes its
+    /// duration and, for stackable kinds, adds a stack up to the kind's cap.
+    /// Different kinds always coexist and are combined by their readers below.
+    fn add_effect(&mut self, kind: PowerUpType) {
+        let duration = self.effect_duration_ticks();
+        if let Some(effect) = self.active_effects.iter_mut().find(|e| e.kind == kind) {
+            effect.ticks_left = effect.ticks_left.max(duration);
+            effect.stacks = (effect.stacks + 1).min(ActiveEffect::max_stacks(kind));
+            return;
+        }
+        self.active_effects.push(ActiveEffect {
+            kind,
+            ticks_left: duration,
+            stacks: 1,
+        });
+    }
+
     pub fn update_power_up_effects(&mut self) {
-        if let Some(timer) = &mut self.power_up_timer {
-            *timer -= 1;
-            if *timer == 0 {
-                self.power_up_timer = None; // Remove the effect when timer reaches 0
-                self.active_speed_effect = None;
-            }
+        for effect in &mut self.active_effects {
+            effect.ticks_left = effect.ticks_left.saturating_sub(1);
         }
+        // Remove effects once their timer reaches 0
+        self.active_effects.retain(|effect| effect.ticks_left > 0);
+    }
+
+    pub fn has_effect(&self, kind: PowerUpType) -> bool {
+        self.active_effects.iter().any(|effect| effect.kind == kind)
     }
 
     pub fn speed_multiplier_percent(&self) -> u64 {
-        match (self.power_up_timer, self.active_speed_effect) {
-            (Some(_), Some(PowerUpType::SpeedBoost)) => 70,
-            (Some(_), Some(PowerUpType::SlowDown)) => 150,
-            _ => 100,
-        }
+        // Speed effects compound, so SpeedBoost and SlowDown together roughly cancel out.
+        self.active_effects.iter().fold(100, |percent, effect| {
+            percent * effect.speed_percent() / 100
+        })
+    }
+
+    pub fn score_multiplier(&self) -> u32 {
+        self.active_effects
+            .iter()
+            .find_map(ActiveEffect::score_multiplier)
+            .unwrap_or(1)
     }
 
     pub fn difficulty_speed_multiplier_percent(&self) -> u64 {
         // Difficulty-specific pace scaling: harder modes accelerate faster and cap lower.
-        let steps = (self.score / 50).min(self.progression_max_steps() as u32) as u64;
-        let reduction = steps * self.progression_step_percent();
+        let reduction = self.progression_steps() * self.progression_step_percent();
         100u64.saturating_sub(reduction)
     }
 
-    pub fn speed_effect_ticks_left(&self) -> u32 {
-        self.power_up_timer.unwrap_or(0)
+    /// Progression speed-ups the score has earned so far.
+    fn progression_steps(&self) -> u64 {
+        u64::from(self.score / PROGRESSION_STEP_POINTS).min(self.progression_max_steps())
     }
 
-    pub fn update_high_score(&mut self) {
-        if self.score > self.high_score {
-            self.high_score = self.score;
-        }
+    /// Points scored toward the next progression speed-up, out of the points
+    /// each one takes. `None` once the pace has stopped speeding up.
+    pub fn progression_progress(&self) -> Option<(u32, u32)> {
+        (self.progression_steps() < self.progression_max_steps()).then_some((
+            self.score % PROGRESSION_STEP_POINTS,
+            PROGRESSION_STEP_POINTS,
+        ))
     }
 
-    pub fn mark_position_dirty(&mut self, pos: Position) {
-        self.dirty_positions.insert(pos);
+    /// Combined tick-duration percentage from progression and speed effects (lower is faster).
+    pub fn pace_percent(&self) -> u64 {
+        let pace =
+            self.difficulty_speed_multiplier_percent() * self.speed_multiplier_percent() / 100;
+        if self.boosting {
+            pace * BOOST_PACE_PERCENT / 100
+        } else {
+            pace
+        }
     }
 
-    fn interior_cells(&self) -> usize {
-        self.width.saturating_sub(2) as usize * self.height.saturating_sub(2) as usize
+    /// Starts or stops boosting: faster ticks that cost a point each.
+    pub fn set_boost(&mut self, on: bool) {
+        if self.boosting != on {
+            self.boosting = on;
+            self.replay.record_boost(self.stats.ticks, on);
+        }
     }
 
-    fn find_food_spawn_position(&self, rng: &mut rand::rngs::ThreadRng) -> Option<Position> {
-        let total_cells = self.interior_cells();
-        if total_cells == 0 {
-            return None;
-        }
+    /// How long the frontend should wait before the next `tick` while moving in
+    /// `direction`, with progression, speed effects, and the time scale applied.
+    pub fn tick_interval(&self, direction: Direction) -> Duration {
+        let base = match direction {
+            Direction::Up | Direction::Down => self.profile.vertical_tick,
+            Direction::Left | Direction::Right => self.profile.horizontal_tick,
+        };
+        let floor = if self.reduced_motion {
+            REDUCED_MOTION_MIN_TICK
+        } else {
+            MIN_TICK
+        };
+        let interval = Duration::from_millis(base.as_millis() as u64 * self.pace_percent() / 100)
+            .max(floor)
+            .max(self.speed_cap);
+        // Slow motion stretches even the fastest tick.
+        Duration::from_millis(interval.as_millis() as u64 * 100 / self.time_scale_percent.max(1))
+    }
 
-        let blocked_cells = self.snake.body.len() + usize::from(self.power_up.is_some());
-        if blocked_cells >= total_cells {
-            return None;
-        }
+    pub fn is_slow_motion(&self) -> bool {
+        self.time_scale_percent < 100
+    }
 
-        let max_attempts = total_cells.saturating_mul(2).max(16);
-        for _ in 0..max_attempts {
-            let candidate = Position {
-                x: rng.gen_range(2..self.width),
-                y: rng.gen_range(2..self.height),
-            };
-            let overlaps_power_up = self
-                .power_up
-                .map(|power_up| power_up.position == candidate)
-                .unwrap_or(false);
-            if !self.snake.overlaps_with(candidate) && !overlaps_power_up {
-                return Some(candidate);
-            }
-        }
+    /// Switches practice slow motion on or off.
+    pub fn toggle_slow_motion(&mut self) {
+        self.time_scale_percent = if self.is_slow_motion() {
+            100
+        } else {
+            SLOW_MOTION_PERCENT
+        };
+    }
 
-        for y in 2..self.height {
-            for x in 2..self.width {
-                let candidate = Position { x, y };
-                let overlaps_power_up = self
-                    .power_up
-                    .map(|power_up| power_up.position == candidate)
-                    .unwrap_or(false);
-                if !self.snake.overlaps_with(candidate) && !overlaps_power_up {
-                    return Some(candidate);
-                }
-            }
-        }
+    /// Whether practice help, a rewind, slow motion, or stepping, was used.
+    /// Such runs do not count for high scores.
+    pub fn is_assisted(&self) -> bool {
+        self.stats.rewinds > 0 || self.stats.slow_motion_ticks > 0 || self.stats.stepped_ticks > 0
+    }
 
-        None
+    /// Whether the run goes on the score tables, the leaderboard, and the
+    /// ghost: it was not assisted and moved at its difficulty's own pace.
+    pub fn is_ranked(&self) -> bool {
+        !self.is_assisted() && self.profile.speed == GameSpeed::Difficulty
     }
 
-    fn find_power_up_spawn_position(&self, rng: &mut rand::rngs::ThreadRng) -> Option<Position> {
-        let total_cells = self.interior_cells();
-        if total_cells == 0 {
-            return None;
+    pub fn update_high_score(&mut self) {
+        if self.score > self.high_score {
+            self.high_score = self.score;
         }
+    }
 
-        // Power-ups cannot overlap snake or food.
-        let blocked_cells = self.snake.body.len().saturating_add(1);
-        if blocked_cells >= total_cells {
-            return None;
-        }
+    pub fn mark_position_dirty(&mut self, pos: Position) {
+        self.dirty_positions.insert(pos);
+    }
 
-        let max_attempts = total_cells.saturating_mul(2).max(16);
-        for _ in 0..max_attempts {
-            let candidate = Position {
-                x: rng.gen_range(2..self.width),
-                y: rng.gen_range(2..self.height),
-            };
-            if !self.snake.overlaps_with(candidate) && candidate != self.food {
-                return Some(candidate);
-            }
+    /// The interior cells a new food or power-up must not land on.
+    fn occupancy(&self) -> spawn::Occupancy {
+        let mut occupied = spawn::Occupancy::new(self.width, self.height);
+        for pos in self.snake.body() {
+            occupied.insert(*pos);
         }
-
-        for y in 2..self.height {
-            for x in 2..self.width {
-                let candidate = Position { x, y };
-                if !self.snake.overlaps_with(candidate) && candidate != self.food {
-                    return Some(candidate);
-                }
-            }
+        occupied.insert(self.food);
+        if let Some(power_up) = self.power_up {
+            occupied.insert(power_up.position);
         }
-
-        None
+        occupied
     }
 
     pub fn generate_food(&mut self) {
-        let mut rng = rand::thread_rng();
-        let Some(new_food) = self.find_food_spawn_position(&mut rng) else {
+        let occupied = self.occupancy();
+        let Some(new_food) = spawn::find_free_cell(&occupied, &mut self.rng) else {
             return;
         };
 
@@ -417,27 +696,28 @@ impl Game {
             return; // Only one power-up at a time
         }
 
-        let mut rng = rand::thread_rng();
+        let enabled_types: Vec<PowerUpType> = PowerUpType::ALL
+            .into_iter()
+            .filter(|power_up_type| !self.disabled_power_ups.contains(*power_up_type))
+            .collect();
+        if enabled_types.is_empty() {
+            return;
+        }
 
         // Difficulty-specific chance to spawn a replacement/initial power-up.
-        if rng.r#gen::<f32>() < self.power_up_refresh_spawn_chance() {
-            let Some(new_power_up_pos) = self.find_power_up_spawn_position(&mut rng) else {
+        if self.rng.r#gen::<f32>() < self.power_up_refresh_spawn_chance() {
+            let occupied = self.occupancy();
+            let Some(new_power_up_pos) = spawn::find_free_cell(&occupied, &mut self.rng) else {
                 return;
             };
 
-            let power_up_types = [
-                PowerUpType::SpeedBoost,
-                PowerUpType::SlowDown,
-                PowerUpType::ExtraPoints,
-                PowerUpType::Grow,
-                PowerUpType::Shrink,
-            ];
-            let power_up_type = power_up_types[rng.gen_range(0..power_up_types.len())];
+            let power_up_type = enabled_types[self.rng.gen_range(0..enabled_types.len())];
 
             self.power_up = Some(PowerUp {
                 position: new_power_up_pos,
                 power_up_type,
                 active: true,
+                ticks_left: self.power_up_lifetime_ticks(),
             });
 
             // Mark new power-up position as dirty
@@ -450,64 +730,87 @@ impl Game {
             return;
         }
 
-        let old_body_positions = self.snake.body.clone();
+        // With wrapping off the border is a wall.
+        if !self.profile.wrap_edges && self.snake.next_head_leaves_board(self.width, self.height) {
+            self.game_over = true;
+            self.emit_sound(SoundEvent::Death);
+            return;
+        }
+
+        self.stats.record_tick(self.pace_percent());
+        if self.is_slow_motion() {
+            self.stats.slow_motion_ticks += 1;
+        }
+        if self.boosting {
+            self.score = self.score.saturating_sub(BOOST_COST_PER_TICK);
+        }
+
+        let old_tail = self.snake.body().back().copied();
         let next_head = self.snake.next_head(self.width, self.height);
         let grow = next_head == self.food;
         self.snake.move_forward(grow, self.width, self.height);
-        let head_pos = self.snake.head_position();
 
         // Check collision after movement so collision/eat behavior happens on the correct tick.
-        if self.snake.body[1..].contains(&head_pos) {
+        // An active shield lets the snake pass through its own body.
+        if self.snake.head_hits_body() && !self.has_effect(PowerUpType::Shield) {
             self.game_over = true;
-            self.play_sound(); // Play sound when game over
+            self.emit_sound(SoundEvent::Death);
         }
 
         // Check if snake ate the food
         if grow {
-            self.score += 10;
+            self.score += 10 * self.score_multiplier();
             self.update_high_score();
+            self.stats.record_food();
             // Mark old food position as dirty
             self.mark_position_dirty(self.food);
             self.generate_food();
             // Mark new food position as dirty
             self.mark_position_dirty(self.food);
-            self.play_sound(); // Play sound when food is eaten
+            self.emit_sound(SoundEvent::Food);
         }
 
         // Check for power-up collision
         self.check_power_up_collision();
+        self.stats.record_length(self.snake.body().len());
+
+        // Age the on-board power-up and despawn it once its lifetime runs out
+        self.update_power_up_lifetime();
 
         // Update power-up effects
-        if self.power_up_timer.is_some() {
-            self.update_power_up_effects();
-        }
+        self.update_power_up_effects();
 
         // Random chance to generate a new power-up occasionally
-        let mut rng = rand::thread_rng();
-        if self.power_up.is_none() && rng.r#gen::<f32>() < self.power_up_tick_spawn_chance() {
+        if self.power_up.is_none() && self.rng.r#gen::<f32>() < self.power_up_tick_spawn_chance() {
             self.generate_power_up();
         }
 
-        // Mark old and new body positions as dirty to support incremental redraw.
-        for pos in old_body_positions {
-            self.mark_position_dirty(pos);
-        }
-        let new_body_positions = self.snake.body.clone();
-        for pos in new_body_positions {
-            self.mark_position_dirty(pos);
+        // The segments are all drawn again every frame, so only the cell the
+        // tail left can need blanking. Marking just the ends keeps a tick as
+        // cheap for a long snake as for a short one.
+        if let Some(tail) = old_tail {
+            self.mark_position_dirty(tail);
         }
+        self.mark_position_dirty(self.snake.head_position());
     }
 
     pub fn update_snake_direction(&mut self, direction: Direction) {
+        let previous = self.snake.direction;
         self.snake.change_direction(direction);
+        if self.snake.direction != previous {
+            self.replay
+                .record_turn(self.stats.ticks, self.snake.direction);
+        }
     }
 
-    pub fn play_sound(&self) {
-        // Use terminal bell character to simulate sound
-        if !self.muted {
-            print!("\x07"); // Terminal bell
-            let _ = std::io::stdout().flush();
-        }
+    /// Digest of the seed, turns, and outcome, submitted with leaderboard scores.
+    pub fn replay_hash(&self) -> String {
+        self.replay
+            .hash(self.difficulty, self.width, self.height, self.score)
+    }
+
+    fn emit_sound(&mut self, event: SoundEvent) {
+        self.sound_events.push(event);
     }
 
     pub fn toggle_mute(&mut self) {
@@ -515,6 +818,9 @@ impl Game {
     }
 }
 
+#[cfg(test)]
+mod demo_fixture;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -522,51 +828,97 @@ mod tests {
     fn make_game() -> Game {
         let mut game = Game::new(Difficulty::Medium, 20, 12, 0);
         game.power_up = None;
-        game.power_up_timer = None;
-        game.active_speed_effect = None;
+        game.active_effects.clear();
         game
     }
 
     #[test]
     fn snake_wraps_left_across_border() {
-        let mut snake = Snake {
-            body: vec![
+        let mut snake = Snake::with_body(
+            vec![
                 Position { x: 2, y: 5 },
                 Position { x: 3, y: 5 },
                 Position { x: 4, y: 5 },
             ],
-            direction: Direction::Left,
-        };
+            Direction::Left,
+        );
 
         snake.move_forward(false, 20, 12);
         assert_eq!(snake.head_position(), Position { x: 19, y: 5 });
     }
 
+    #[test]
+    fn snake_cells_follow_moves_growth_and_shrinking() {
+        let tail = Position { x: 6, y: 5 };
+        let mut snake = Snake::with_body(
+            vec![Position { x: 4, y: 5 }, Position { x: 5, y: 5 }, tail],
+            Direction::Left,
+        );
+        assert_eq!(snake.grow_tail(), Some(tail));
+        snake.move_forward(false, 20, 12);
+        // The grown segment is still on the tail's cell.
+        assert!(snake.overlaps_with(tail));
+        snake.move_forward(false, 20, 12);
+        assert!(!snake.overlaps_with(tail));
+        assert!(snake.overlaps_with(Position { x: 2, y: 5 }));
+        assert!(!snake.head_hits_body());
+
+        assert_eq!(snake.pop_tail(), Some(Position { x: 5, y: 5 }));
+        assert!(!snake.overlaps_with(Position { x: 5, y: 5 }));
+        snake.set_body(vec![Position { x: 3, y: 5 }, Position { x: 3, y: 5 }]);
+        assert!(snake.head_hits_body());
+    }
+
     #[test]
     fn snake_wraps_up_across_border() {
-        let mut snake = Snake {
-            body: vec![
+        let mut snake = Snake::with_body(
+            vec![
                 Position { x: 8, y: 2 },
                 Position { x: 8, y: 3 },
                 Position { x: 8, y: 4 },
             ],
-            direction: Direction::Up,
-        };
+            Direction::Up,
+        );
 
         snake.move_forward(false, 20, 12);
         assert_eq!(snake.head_position(), Position { x: 8, y: 11 });
     }
 
+    #[test]
+    fn border_is_a_wall_when_wrapping_is_disabled() {
+        let profile = DifficultyProfile::for_difficulty(
+            Difficulty::Custom,
+            crate::utils::CustomDifficulty {
+                wrap_edges: false,
+                ..Default::default()
+            },
+        );
+        let mut game = Game::with_profile(Difficulty::Custom, profile, 20, 12, 0);
+        game.power_up = None;
+        game.snake.set_body(vec![
+            Position { x: 2, y: 5 },
+            Position { x: 3, y: 5 },
+            Position { x: 4, y: 5 },
+        ]);
+        game.snake.direction = Direction::Left;
+        game.food = Position { x: 10, y: 10 };
+
+        game.tick();
+
+        assert!(game.game_over);
+        assert_eq!(game.snake.head_position(), Position { x: 2, y: 5 });
+    }
+
     #[test]
     fn snake_cannot_reverse_direction() {
-        let mut snake = Snake {
-            body: vec![
+        let mut snake = Snake::with_body(
+            vec![
                 Position { x: 5, y: 5 },
                 Position { x: 6, y: 5 },
                 Position { x: 7, y: 5 },
             ],
-            direction: Direction::Left,
-        };
+            Direction::Left,
+        );
 
         snake.change_direction(Direction::Right);
         assert_eq!(snake.direction, Direction::Left);
@@ -574,14 +926,14 @@ mod tests {
 
     #[test]
     fn snake_can_turn_perpendicular() {
-        let mut snake = Snake {
-            body: vec![
+        let mut snake = Snake::with_body(
+            vec![
                 Position { x: 5, y: 5 },
                 Position { x: 6, y: 5 },
                 Position { x: 7, y: 5 },
             ],
-            direction: Direction::Left,
-        };
+            Direction::Left,
+        );
 
         snake.change_direction(Direction::Up);
         assert_eq!(snake.direction, Direction::Up);
@@ -590,32 +942,89 @@ mod tests {
     #[test]
     fn tick_applies_food_collision_immediately() {
         let mut game = make_game();
-        game.snake.body = vec![
+        game.snake.set_body(vec![
             Position { x: 6, y: 5 },
             Position { x: 7, y: 5 },
             Position { x: 8, y: 5 },
-        ];
+        ]);
         game.snake.direction = Direction::Left;
         game.food = Position { x: 5, y: 5 };
 
         game.tick();
 
         assert_eq!(game.score, 10);
-        assert_eq!(game.snake.body.len(), 4);
+        assert_eq!(game.snake.body().len(), 4);
         assert_eq!(game.snake.head_position(), Position { x: 5, y: 5 });
+        assert_eq!(game.sound_events, vec![SoundEvent::Food]);
+    }
+
+    #[test]
+    fn replay_records_only_accepted_turns() {
+        let mut game = Game::new_seeded(Difficulty::Medium, 20, 12, 0, 42);
+        game.snake.direction = Direction::Left;
+
+        game.update_snake_direction(Direction::Right);
+        game.update_snake_direction(Direction::Left);
+        game.tick();
+        game.update_snake_direction(Direction::Up);
+
+        assert_eq!(game.replay.seed, 42);
+        assert_eq!(game.replay.turns, vec![(1, Direction::Up)]);
+    }
+
+    #[test]
+    fn boosting_speeds_up_ticks_and_costs_a_point_each() {
+        let mut game = Game::new_seeded(Difficulty::Medium, 20, 12, 0, 42);
+        game.power_up = None;
+        game.score = 5;
+        let normal = game.tick_interval(Direction::Left);
+
+        game.set_boost(true);
+        game.set_boost(true);
+        assert!(game.tick_interval(Direction::Left) < normal);
+        assert_eq!(game.pace_percent(), BOOST_PACE_PERCENT);
+        game.tick();
+        game.set_boost(false);
+        game.tick();
+
+        assert_eq!(game.score, 4);
+        assert_eq!(game.replay.boosts, vec![(0, true), (1, false)]);
+    }
+
+    #[test]
+    fn run_stats_count_food_power_ups_and_pace() {
+        let mut game = make_game();
+        game.snake.set_body(vec![
+            Position { x: 6, y: 5 },
+            Position { x: 7, y: 5 },
+            Position { x: 8, y: 5 },
+        ]);
+        game.snake.direction = Direction::Left;
+        game.food = Position { x: 5, y: 5 };
+        game.apply_power_up_effect(PowerUpType::SpeedBoost);
+
+        game.tick();
+
+        assert_eq!(game.stats.ticks, 1);
+        assert_eq!(game.stats.food_eaten, 1);
+        assert_eq!(game.stats.max_combo, 1);
+        assert_eq!(game.stats.power_ups_collected(PowerUpType::SpeedBoost), 1);
+        assert_eq!(game.stats.total_power_ups(), 1);
+        assert_eq!(game.stats.fastest_pace_percent, Some(70));
+        assert_eq!(game.stats.max_length, 4);
     }
 
     #[test]
     fn tick_detects_self_collision_after_move() {
         let mut game = make_game();
-        game.snake.body = vec![
+        game.snake.set_body(vec![
             Position { x: 5, y: 5 },
             Position { x: 5, y: 6 },
             Position { x: 6, y: 6 },
             Position { x: 6, y: 5 },
             Position { x: 6, y: 4 },
             Position { x: 5, y: 4 },
-        ];
+        ]);
         game.snake.direction = Direction::Right;
         game.food = Position { x: 2, y: 2 };
 
@@ -632,11 +1041,168 @@ mod tests {
             position: Position { x: 2, y: 2 },
             power_up_type: PowerUpType::SlowDown,
             active: true,
+            ticks_left: 100,
         });
 
         assert_eq!(game.speed_multiplier_percent(), 70);
     }
 
+    #[test]
+    fn opposing_speed_effects_coexist_and_compound() {
+        let mut game = make_game();
+        game.apply_power_up_effect(PowerUpType::SpeedBoost);
+        game.apply_power_up_effect(PowerUpType::SlowDown);
+
+        assert_eq!(game.active_effects.len(), 2);
+        assert_eq!(game.speed_multiplier_percent(), 105);
+    }
+
+    #[test]
+    fn recollecting_an_effect_refreshes_and_stacks_up_to_cap() {
+        let mut game = make_game();
+        game.apply_power_up_effect(PowerUpType::ScoreMultiplier);
+        game.update_power_up_effects();
+        for _ in 0..5 {
+            game.apply_power_up_effect(PowerUpType::ScoreMultiplier);
+        }
+
+        assert_eq!(game.active_effects.len(), 1);
+        assert_eq!(game.active_effects[0].stacks, 3);
+        assert_eq!(
+            game.active_effects[0].ticks_left,
+            game.effect_duration_ticks()
+        );
+        assert_eq!(game.score_multiplier(), 4);
+
+        game.apply_power_up_effect(PowerUpType::SpeedBoost);
+        game.apply_power_up_effect(PowerUpType::SpeedBoost);
+        assert_eq!(game.speed_multiplier_percent(), 70);
+    }
+
+    #[test]
+    fn score_multiplier_scales_food_points() {
+        let mut game = make_game();
+        game.apply_power_up_effect(PowerUpType::ScoreMultiplier);
+        game.snake.set_body(vec![
+            Position { x: 6, y: 5 },
+            Position { x: 7, y: 5 },
+            Position { x: 8, y: 5 },
+        ]);
+        game.snake.direction = Direction::Left;
+        game.food = Position { x: 5, y: 5 };
+
+        game.tick();
+
+        assert_eq!(game.score, 20);
+    }
+
+    #[test]
+    fn shield_prevents_self_collision_while_active() {
+        let mut game = make_game();
+        game.apply_power_up_effect(PowerUpType::Shield);
+        game.snake.set_body(vec![
+            Position { x: 5, y: 5 },
+            Position { x: 5, y: 6 },
+            Position { x: 6, y: 6 },
+            Position { x: 6, y: 5 },
+            Position { x: 6, y: 4 },
+            Position { x: 5, y: 4 },
+        ]);
+        game.snake.direction = Direction::Right;
+        game.food = Position { x: 2, y: 2 };
+
+        game.tick();
+
+        assert!(!game.game_over);
+    }
+
+    #[test]
+    fn generate_power_up_only_spawns_enabled_types() {
+        let mut game = make_game();
+        let mut disabled = PowerUpSet::default();
+        for power_up_type in PowerUpType::ALL {
+            if power_up_type != PowerUpType::Grow {
+                disabled.toggle(power_up_type);
+            }
+        }
+        game.set_disabled_power_ups(disabled);
+
+        for _ in 0..200 {
+            game.power_up = None;
+            game.generate_power_up();
+            if let Some(power_up) = game.power_up {
+                assert!(power_up.power_up_type == PowerUpType::Grow);
+            }
+        }
+    }
+
+    #[test]
+    fn disabling_every_power_up_clears_the_board() {
+        let mut game = make_game();
+        game.power_up = Some(PowerUp {
+            position: Position { x: 2, y: 2 },
+            power_up_type: PowerUpType::Shrink,
+            active: true,
+            ticks_left: 100,
+        });
+        let mut disabled = PowerUpSet::default();
+        for power_up_type in PowerUpType::ALL {
+            disabled.toggle(power_up_type);
+        }
+
+        game.set_disabled_power_ups(disabled);
+        assert!(game.power_up.is_none());
+
+        for _ in 0..200 {
+            game.generate_power_up();
+        }
+        assert!(game.power_up.is_none());
+    }
+
+    #[test]
+    fn power_up_despawns_when_lifetime_runs_out() {
+        let mut game = make_game();
+        let position = Position { x: 2, y: 2 };
+        game.power_up = Some(PowerUp {
+            position,
+            power_up_type: PowerUpType::Grow,
+            active: true,
+            ticks_left: 2,
+        });
+
+        game.update_power_up_lifetime();
+        assert!(game.power_up.is_some());
+
+        game.dirty_positions.clear();
+        game.update_power_up_lifetime();
+        assert!(game.power_up.is_none());
+        assert!(game.dirty_positions.contains(&position));
+    }
+
+    #[test]
+    fn power_up_blinks_only_during_warning_window() {
+        let mut game = make_game();
+        let lifetime = game.power_up_lifetime_ticks();
+        game.power_up = Some(PowerUp {
+            position: Position { x: 2, y: 2 },
+            power_up_type: PowerUpType::Grow,
+            active: true,
+            ticks_left: lifetime,
+        });
+        let hidden_phase = POWER_UP_BLINK_PHASE + Duration::from_millis(10);
+        assert!(!game.power_up_is_expiring());
+        assert!(game.power_up_blink_visible(hidden_phase));
+
+        game.power_up.as_mut().unwrap().ticks_left = game.power_up_warning_ticks();
+        assert!(game.power_up_is_expiring());
+        assert!(game.power_up_blink_visible(Duration::ZERO));
+        assert!(!game.power_up_blink_visible(hidden_phase));
+        assert!(game.power_up_blink_visible(hidden_phase + POWER_UP_BLINK_PHASE));
+
+        game.reduced_motion = true;
+        assert!(game.power_up_blink_visible(hidden_phase));
+    }
+
     #[test]
     fn speed_effect_expires_after_timer_runs_out() {
         let mut game = make_game();
@@ -646,8 +1212,7 @@ mod tests {
             game.update_power_up_effects();
         }
 
-        assert_eq!(game.power_up_timer, None);
-        assert!(game.active_speed_effect.is_none());
+        assert!(game.active_effects.is_empty());
         assert_eq!(game.speed_multiplier_percent(), 100);
     }
 
@@ -669,6 +1234,19 @@ mod tests {
         assert_eq!(game.difficulty_speed_multiplier_percent(), 55);
     }
 
+    #[test]
+    fn progression_progress_counts_toward_the_next_step_until_the_cap() {
+        let mut game = make_game();
+        game.score = 120;
+        assert_eq!(game.progression_progress(), Some((20, 50)));
+
+        // Medium stops speeding up after 15 steps.
+        game.score = 749;
+        assert_eq!(game.progression_progress(), Some((49, 50)));
+        game.score = 750;
+        assert_eq!(game.progression_progress(), None);
+    }
+
     #[test]
     fn difficulty_tick_rates_get_faster_by_level() {
         let easy = Game::new(Difficulty::Easy, 20, 12, 0);
@@ -685,6 +1263,63 @@ mod tests {
         assert!(easy_v > med_v && med_v > hard_v && hard_v > ext_v);
     }
 
+    #[test]
+    fn tick_interval_follows_direction_and_speed_effects() {
+        let mut game = make_game();
+        let (horizontal, vertical) = game.get_tick_rates();
+
+        assert_eq!(game.tick_interval(Direction::Left), horizontal);
+        assert_eq!(game.tick_interval(Direction::Up), vertical);
+
+        game.apply_power_up_effect(PowerUpType::SpeedBoost);
+        assert_eq!(
+            game.tick_interval(Direction::Right),
+            horizontal * game.pace_percent() as u32 / 100
+        );
+        assert!(game.tick_interval(Direction::Right) < horizontal);
+
+        let mut extreme = Game::new(Difficulty::Extreme, 20, 12, 0);
+        extreme.reduced_motion = true;
+        assert_eq!(
+            extreme.tick_interval(Direction::Left),
+            REDUCED_MOTION_MIN_TICK
+        );
+        extreme.speed_cap = Duration::from_millis(100);
+        assert_eq!(
+            extreme.tick_interval(Direction::Up),
+            Duration::from_millis(100)
+        );
+        extreme.speed_cap = Duration::ZERO;
+        extreme.toggle_slow_motion();
+        assert_eq!(
+            extreme.tick_interval(Direction::Left),
+            REDUCED_MOTION_MIN_TICK * 2
+        );
+
+        assert!(!game.is_assisted());
+        game.toggle_slow_motion();
+        game.tick();
+        game.toggle_slow_motion();
+        assert_eq!(game.time_scale_percent, 100);
+        assert!(game.is_assisted());
+    }
+
+    #[test]
+    fn only_runs_at_their_difficultys_pace_are_ranked() {
+        let profile = DifficultyProfile::for_difficulty(Difficulty::Extreme, Default::default());
+        let game = Game::with_profile(Difficulty::Extreme, profile, 40, 20, 0);
+        assert!(game.is_ranked());
+        let game = Game::with_profile(
+            Difficulty::Extreme,
+            profile.with_speed(GameSpeed::Slow),
+            40,
+            20,
+            0,
+        );
+        assert!(!game.is_assisted());
+        assert!(!game.is_ranked());
+    }
+
     #[test]
     fn power_up_spawn_chances_reduce_with_harder_difficulties() {
         let easy = Game::new(Difficulty::Easy, 20, 12, 0);
@@ -705,16 +1340,16 @@ mod tests {
     }
 
     #[test]
-    fn speed_effect_duration_shortens_with_harder_difficulties() {
+    fn effect_duration_shortens_with_harder_difficulties() {
         let easy = Game::new(Difficulty::Easy, 20, 12, 0);
         let medium = Game::new(Difficulty::Medium, 20, 12, 0);
         let hard = Game::new(Difficulty::Hard, 20, 12, 0);
         let extreme = Game::new(Difficulty::Extreme, 20, 12, 0);
 
         assert!(
-            easy.speed_effect_duration_ticks() > medium.speed_effect_duration_ticks()
-                && medium.speed_effect_duration_ticks() > hard.speed_effect_duration_ticks()
-                && hard.speed_effect_duration_ticks() > extreme.speed_effect_duration_ticks()
+            easy.effect_duration_ticks() > medium.effect_duration_ticks()
+                && medium.effect_duration_ticks() > hard.effect_duration_ticks()
+                && hard.effect_duration_ticks() > extreme.effect_duration_ticks()
         );
     }
 
@@ -747,29 +1382,33 @@ mod tests {
     }
 
     #[test]
-    fn find_food_spawn_position_returns_none_when_board_is_full() {
+    fn food_cannot_spawn_when_board_is_full() {
         let mut game = Game::new(Difficulty::Medium, 6, 6, 0);
         game.power_up = None;
-        game.snake.body = (2..6)
-            .flat_map(|y| (2..6).map(move |x| Position { x, y }))
-            .collect();
+        game.snake.set_body(
+            (2..6)
+                .flat_map(|y| (2..6).map(move |x| Position { x, y }))
+                .collect(),
+        );
 
         let mut rng = rand::thread_rng();
-        assert!(game.find_food_spawn_position(&mut rng).is_none());
+        assert!(spawn::find_free_cell(&game.occupancy(), &mut rng).is_none());
     }
 
     #[test]
-    fn find_power_up_spawn_position_returns_none_when_only_food_cell_is_free() {
+    fn power_up_cannot_spawn_when_only_food_cell_is_free() {
         let mut game = Game::new(Difficulty::Medium, 6, 6, 0);
         game.food = Position { x: 2, y: 2 };
         game.power_up = None;
         let food = game.food;
-        game.snake.body = (2..6)
-            .flat_map(|y| (2..6).map(move |x| Position { x, y }))
-            .filter(|pos| *pos != food)
-            .collect();
+        game.snake.set_body(
+            (2..6)
+                .flat_map(|y| (2..6).map(move |x| Position { x, y }))
+                .filter(|pos| *pos != food)
+                .collect(),
+        );
 
         let mut rng = rand::thread_rng();
-        assert!(game.find_power_up_spawn_position(&mut rng).is_none());
+        assert!(spawn::find_free_cell(&game.occupancy(), &mut rng).is_none());
     }
 }
@@ -0,0 +1,148 @@
+//! Minimal replay log: the spawn seed plus every accepted turn and boost
+//! change. Together with the difficulty and board size this is enough to
+//! replay a run, so its hash identifies the run when submitting to the global
+//! leaderboard.
+
+use super::Game;
+use crate::utils::{Difficulty, Direction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplayLog {
+    pub seed: u64,
+    /// `(tick, direction)` for each turn, where `tick` counts ticks already played.
+    pub turns: Vec<(u32, Direction)>,
+    /// `(tick, on)` each time the boost key was pressed or released.
+    pub boosts: Vec<(u32, bool)>,
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+fn direction_byte(direction: Direction) -> u8 {
+    match direction {
+        Direction::Up => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Right => 3,
+    }
+}
+
+fn difficulty_byte(difficulty: Difficulty) -> u8 {
+    match difficulty {
+        Difficulty::Easy => 0,
+        Difficulty::Medium => 1,
+        Difficulty::Hard => 2,
+        Difficulty::Extreme => 3,
+        Difficulty::Custom => 4,
+    }
+}
+
+impl ReplayLog {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            turns: Vec::new(),
+            boosts: Vec::new(),
+        }
+    }
+
+    pub fn record_turn(&mut self, tick: u32, direction: Direction) {
+        self.turns.push((tick, direction));
+    }
+
+    pub fn record_boost(&mut self, tick: u32, on: bool) {
+        self.boosts.push((tick, on));
+    }
+
+    /// Stable 64-bit FNV-1a digest of the run, as 16 hex digits.
+    pub fn hash(&self, difficulty: Difficulty, width: u16, height: u16, score: u32) -> String {
+        let mut hash = fnv1a(FNV_OFFSET_BASIS, &self.seed.to_le_bytes());
+        hash = fnv1a(hash, &[difficulty_byte(difficulty)]);
+        hash = fnv1a(hash, &width.to_le_bytes());
+        hash = fnv1a(hash, &height.to_le_bytes());
+        for (tick, direction) in &self.turns {
+            hash = fnv1a(hash, &tick.to_le_bytes());
+            hash = fnv1a(hash, &[direction_byte(*direction)]);
+        }
+        // Runs without boosting keep the hash they had before boosts existed.
+        for (tick, on) in &self.boosts {
+            hash = fnv1a(hash, &tick.to_le_bytes());
+            hash = fnv1a(hash, &[u8::from(*on) + 4]);
+        }
+        hash = fnv1a(hash, &score.to_le_bytes());
+        format!("{hash:016x}")
+    }
+}
+
+/// How far a game playing a `ReplayLog` back has got through its turns and
+/// boost changes.
+#[derive(Default)]
+pub(super) struct ReplayCursor {
+    next_turn: usize,
+    next_boost: usize,
+}
+
+impl ReplayCursor {
+    /// The cursor for a game whose own log is the start of the one it plays.
+    pub(super) fn after(played: &ReplayLog) -> Self {
+        Self {
+            next_turn: played.turns.len(),
+            next_boost: played.boosts.len(),
+        }
+    }
+
+    /// Applies the turns and boost changes logged before the coming tick,
+    /// then plays it.
+    pub(super) fn step(&mut self, replay: &ReplayLog, game: &mut Game) {
+        let tick = game.stats.ticks;
+        while let Some(&(at, on)) = replay.boosts.get(self.next_boost) {
+            if at > tick {
+                break;
+            }
+            game.set_boost(on);
+            self.next_boost += 1;
+        }
+        while let Some(&(at, direction)) = replay.turns.get(self.next_turn) {
+            if at > tick {
+                break;
+            }
+            game.update_snake_direction(direction);
+            self.next_turn += 1;
+        }
+        game.tick();
+        game.sound_events.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_depends_on_every_part_of_the_run() {
+        let mut replay = ReplayLog::new(7);
+        replay.record_turn(3, Direction::Up);
+        let hash = replay.hash(Difficulty::Hard, 40, 20, 120);
+
+        assert_eq!(hash.len(), 16);
+        assert_eq!(hash, replay.clone().hash(Difficulty::Hard, 40, 20, 120));
+        assert_ne!(hash, replay.hash(Difficulty::Easy, 40, 20, 120));
+        assert_ne!(hash, replay.hash(Difficulty::Hard, 40, 20, 130));
+        assert_ne!(hash, ReplayLog::new(7).hash(Difficulty::Hard, 40, 20, 120));
+
+        let mut later_turn = ReplayLog::new(7);
+        later_turn.record_turn(4, Direction::Up);
+        assert_ne!(hash, later_turn.hash(Difficulty::Hard, 40, 20, 120));
+
+        let mut boosted = replay.clone();
+        boosted.record_boost(5, true);
+        assert_ne!(hash, boosted.hash(Difficulty::Hard, 40, 20, 120));
+    }
+}
@@ -0,0 +1,219 @@
+//! Practice rewind: the game as it was before each of the last few ticks,
+//! so a mistake can be taken back, or a lost run tried again from just
+//! before the end. Outside practice only a checkpoint every few ticks is
+//! kept, and a retry plays on from the nearest one.
+
+use super::Game;
+use super::replay::ReplayCursor;
+use std::collections::VecDeque;
+
+/// Ticks one rewind goes back.
+pub const REWIND_TICKS: usize = 5;
+/// Ticks of history kept, enough for a few rewinds in a row.
+const REWIND_HISTORY_TICKS: usize = 8 * REWIND_TICKS;
+/// Ticks before its end a lost run is tried again from.
+pub const RETRY_TICKS: usize = 10;
+/// Checkpoints kept outside practice: two are always enough to reach back
+/// `RETRY_TICKS` ticks.
+const RETRY_CHECKPOINTS: usize = 2;
+
+#[derive(Default)]
+pub struct RewindBuffer {
+    states: VecDeque<Game>,
+}
+
+impl RewindBuffer {
+    /// Keeps `game` as it is before its next tick. The oldest state goes once
+    /// the buffer is full.
+    pub fn record(&mut self, game: &Game) {
+        if self.states.len() == REWIND_HISTORY_TICKS {
+            self.states.pop_front();
+        }
+        self.states.push_back(game.clone());
+    }
+
+    /// Puts `game` back `REWIND_TICKS` ticks, or as far as the history goes,
+    /// and pauses it there. Returns `false` when there is nothing to go back to.
+    pub fn rewind(&mut self, game: &mut Game) -> bool {
+        let keep = self.states.len().saturating_sub(REWIND_TICKS);
+        let Some(state) = self.states.drain(keep..).next() else {
+            return false;
+        };
+        restore(game, state);
+        true
+    }
+
+    /// Keeps `game` as it is before its next tick when that tick starts a
+    /// stretch of `RETRY_TICKS`, for runs that only need a retry: a copy of
+    /// the game every few ticks rather than every tick.
+    pub fn checkpoint(&mut self, game: &Game) {
+        if game.stats.ticks % RETRY_TICKS as u32 != 0 {
+            return;
+        }
+        if self.states.len() == RETRY_CHECKPOINTS {
+            self.states.pop_front();
+        }
+        self.states.push_back(game.clone());
+    }
+
+    /// `lost` as it was `RETRY_TICKS` ticks before its fatal one, or as far
+    /// back as the history goes. A point between two recorded states is
+    /// reached by playing `lost`'s turns on from the earlier one.
+    pub fn retry_point(&self, lost: &Game) -> Option<Game> {
+        // The fatal tick is not counted, so it is the last of the ten.
+        let target = (lost.stats.ticks + 1).saturating_sub(RETRY_TICKS as u32);
+        let mut point = self
+            .states
+            .iter()
+            .rev()
+            .find(|state| state.stats.ticks <= target)
+            .or_else(|| self.states.front())?
+            .clone();
+        let mut cursor = ReplayCursor::after(&point.replay);
+        while point.stats.ticks < target && !point.game_over {
+            cursor.step(&lost.replay, &mut point);
+        }
+        Some(point)
+    }
+
+    /// Puts `game` back to `point` from `retry_point`, paused, as often as
+    /// the player likes. The history after `point` goes with it.
+    pub fn retry(&mut self, game: &mut Game, point: &Game) {
+        while self
+            .states
+            .back()
+            .is_some_and(|state| state.stats.ticks >= point.stats.ticks)
+        {
+            self.states.pop_back();
+        }
+        restore(game, point.clone());
+    }
+}
+
+/// Replaces `game` with the earlier `state`, paused, and counts it as a
+/// rewind so the run no longer qualifies for the high scores.
+fn restore(game: &mut Game, mut state: Game) {
+    // What the abandoned ticks drew has to be cleared on the next frame.
+    state
+        .dirty_positions
+        .extend(game.snake.body().iter().copied());
+    state.dirty_positions.insert(game.food);
+    if let Some(power_up) = game.power_up {
+        state.dirty_positions.insert(power_up.position);
+    }
+    state.muted = game.muted;
+    state.time_scale_percent = game.time_scale_percent;
+    state.paused = true;
+    state.stats.rewinds = game.stats.rewinds + 1;
+    *game = state;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::DifficultyProfile;
+    use crate::utils::{CustomDifficulty, Difficulty, Direction};
+
+    #[test]
+    fn rewind_goes_back_a_few_ticks_and_replays_the_same_spawns() {
+        let mut game = Game::new_seeded(Difficulty::Medium, 40, 20, 0, 11);
+        let mut rewind = RewindBuffer::default();
+        assert!(!rewind.rewind(&mut game));
+
+        let mut heads = Vec::new();
+        for _ in 0..12 {
+            rewind.record(&game);
+            heads.push(game.snake.head_position());
+            game.tick();
+        }
+        let ahead = game.food;
+        let left_behind = game.snake.head_position();
+
+        assert!(rewind.rewind(&mut game));
+        assert_eq!(game.stats.ticks, 12 - REWIND_TICKS as u32);
+        assert_eq!(game.snake.head_position(), heads[12 - REWIND_TICKS]);
+        assert!(game.is_paused());
+        assert_eq!(game.stats.rewinds, 1);
+        assert!(game.dirty_positions.contains(&left_behind));
+
+        // The restored spawn rolls give the same board as before.
+        game.toggle_pause();
+        for _ in 0..REWIND_TICKS {
+            game.tick();
+        }
+        assert_eq!(game.food, ahead);
+        assert_eq!(game.snake.head_position(), left_behind);
+
+        game.update_snake_direction(Direction::Up);
+        assert!(rewind.rewind(&mut game));
+        assert_eq!(game.stats.ticks, 12 - 2 * REWIND_TICKS as u32);
+        assert_eq!(game.stats.rewinds, 2);
+    }
+
+    #[test]
+    fn a_lost_run_is_retried_from_the_same_point_every_time() {
+        // Walls, so heading up ends the run at the top border.
+        let profile = DifficultyProfile::for_difficulty(
+            Difficulty::Custom,
+            CustomDifficulty {
+                wrap_edges: false,
+                ..Default::default()
+            },
+        );
+        let mut game = Game::with_seed(Difficulty::Custom, profile, 40, 30, 0, 5);
+        let mut rewind = RewindBuffer::default();
+        game.update_snake_direction(Direction::Up);
+        while !game.game_over {
+            rewind.record(&game);
+            game.tick();
+        }
+        // The fatal tick is not counted, so it is the last of the ten.
+        let died_at = game.stats.ticks + 1;
+        let point = rewind.retry_point(&game).expect("the run has a history");
+        assert_eq!(point.stats.ticks, died_at - RETRY_TICKS as u32);
+
+        for attempt in 1..=2 {
+            rewind.retry(&mut game, &point);
+            assert!(!game.game_over && game.is_paused());
+            assert_eq!(game.snake.body(), point.snake.body());
+            assert_eq!(game.stats.rewinds, attempt);
+            game.toggle_pause();
+            while !game.game_over {
+                rewind.record(&game);
+                game.tick();
+            }
+            // The same moves end the same way.
+            assert_eq!(game.stats.ticks + 1, died_at);
+        }
+        // Undo still goes back within the latest attempt.
+        assert!(rewind.rewind(&mut game));
+        assert_eq!(game.stats.ticks, died_at - REWIND_TICKS as u32);
+        assert!(game.stats.ticks > point.stats.ticks);
+    }
+
+    #[test]
+    fn outside_practice_a_few_checkpoints_reach_the_same_retry_point() {
+        let mut game = Game::new_seeded(Difficulty::Medium, 40, 20, 0, 3);
+        let mut every_tick = RewindBuffer::default();
+        let mut checkpoints = RewindBuffer::default();
+        for tick in 0..137 {
+            match tick % 9 {
+                2 => game.update_snake_direction(Direction::Up),
+                5 => game.update_snake_direction(Direction::Left),
+                7 => game.update_snake_direction(Direction::Down),
+                _ => {}
+            }
+            game.set_boost((40..48).contains(&tick));
+            every_tick.record(&game);
+            checkpoints.checkpoint(&game);
+            assert!(checkpoints.states.len() <= RETRY_CHECKPOINTS);
+            game.tick();
+        }
+        assert!(!game.game_over);
+
+        let exact = every_tick.retry_point(&game).expect("recorded every tick");
+        let replayed = checkpoints.retry_point(&game).expect("checkpoints kept");
+        assert_eq!(replayed.stats.ticks, exact.stats.ticks);
+        assert_eq!(replayed.to_snapshot(), exact.to_snapshot());
+    }
+}
@@ -0,0 +1,79 @@
+//! Game snapshots: a whole run, spawn generator included, as text that
+//! loads back into a game that carries on exactly where the first one was.
+
+use super::Game;
+use serde::{Deserialize, Serialize};
+
+/// Bumped whenever a field of `Game` changes, so an older snapshot is turned
+/// away rather than loaded wrong.
+const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct SnapshotRef<'a> {
+    version: u32,
+    game: &'a Game,
+}
+
+#[derive(Deserialize)]
+struct Snapshot {
+    version: u32,
+    game: Game,
+}
+
+impl Game {
+    /// The run as it is now, as JSON.
+    pub fn to_snapshot(&self) -> String {
+        serde_json::to_string(&SnapshotRef {
+            version: SNAPSHOT_VERSION,
+            game: self,
+        })
+        .unwrap_or_default()
+    }
+
+    /// The run `to_snapshot` wrote. Nothing is marked for redrawing, so
+    /// the caller draws the board in full.
+    pub fn from_snapshot(text: &str) -> Result<Self, String> {
+        let snapshot: Snapshot =
+            serde_json::from_str(text).map_err(|err| format!("unreadable game snapshot: {err}"))?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(format!(
+                "game snapshot version {} is not supported (expected {SNAPSHOT_VERSION})",
+                snapshot.version
+            ));
+        }
+        Ok(snapshot.game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{Difficulty, Direction, PowerUpType};
+
+    #[test]
+    fn a_restored_game_plays_on_exactly_like_the_original() {
+        let mut game = Game::new_seeded(Difficulty::Hard, 30, 16, 120, 9);
+        for tick in 0..40 {
+            if tick % 7 == 0 {
+                game.update_snake_direction(Direction::Up);
+            } else if tick % 7 == 3 {
+                game.update_snake_direction(Direction::Left);
+            }
+            game.tick();
+        }
+        game.add_effect(PowerUpType::ScoreMultiplier);
+
+        let mut restored = Game::from_snapshot(&game.to_snapshot()).expect("snapshot loads");
+        assert_eq!(restored.snake.body(), game.snake.body());
+        for _ in 0..200 {
+            game.tick();
+            restored.tick();
+        }
+        assert_eq!(restored.to_snapshot(), game.to_snapshot());
+
+        let stale = game
+            .to_snapshot()
+            .replacen("\"version\":1", "\"version\":0", 1);
+        assert!(Game::from_snapshot(&stale).is_err());
+    }
+}
@@ -0,0 +1,159 @@
+//! Where new food and power-ups appear: a random interior cell that nothing
+//! on the board takes up.
+
+use crate::utils::Position;
+use rand::Rng;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// The interior cells of a board that a spawn must not land on, one bit per
+/// cell.
+pub struct Occupancy {
+    width: u16,
+    height: u16,
+    bits: Vec<u64>,
+}
+
+impl Occupancy {
+    /// An empty board of `width` by `height` cells, border included.
+    pub fn new(width: u16, height: u16) -> Self {
+        let cells = usize::from(width.saturating_sub(2)) * usize::from(height.saturating_sub(2));
+        let mut bits = vec![0; cells.div_ceil(WORD_BITS)];
+        // The bits past the last cell count as taken, so they are never picked.
+        if let Some(last) = bits.last_mut() {
+            let used = cells % WORD_BITS;
+            if used > 0 {
+                *last = u64::MAX << used;
+            }
+        }
+        Self {
+            width,
+            height,
+            bits,
+        }
+    }
+
+    fn index(&self, pos: Position) -> Option<usize> {
+        let inside = (2..self.width).contains(&pos.x) && (2..self.height).contains(&pos.y);
+        inside
+            .then(|| usize::from(pos.y - 2) * usize::from(self.width - 2) + usize::from(pos.x - 2))
+    }
+
+    /// Marks `pos` as taken. Cells off the interior are ignored.
+    pub fn insert(&mut self, pos: Position) {
+        if let Some(index) = self.index(pos) {
+            self.bits[index / WORD_BITS] |= 1 << (index % WORD_BITS);
+        }
+    }
+
+    #[cfg(test)]
+    fn contains(&self, pos: Position) -> bool {
+        self.index(pos)
+            .is_some_and(|index| self.bits[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0)
+    }
+
+    fn free_cells(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_zeros() as usize)
+            .sum()
+    }
+
+    /// The `n`th free cell, counting row by row from the top left.
+    fn nth_free(&self, mut n: usize) -> Option<Position> {
+        let interior_width = usize::from(self.width.saturating_sub(2)).max(1);
+        for (word_index, word) in self.bits.iter().enumerate() {
+            let mut free = !word;
+            let count = free.count_ones() as usize;
+            if n >= count {
+                n -= count;
+                continue;
+            }
+            for _ in 0..n {
+                free &= free - 1;
+            }
+            let index = word_index * WORD_BITS + free.trailing_zeros() as usize;
+            return Some(Position {
+                x: 2 + u16::try_from(index % interior_width).ok()?,
+                y: 2 + u16::try_from(index / interior_width).ok()?,
+            });
+        }
+        None
+    }
+}
+
+/// A free interior cell picked uniformly at random, or `None` when every
+/// cell is taken. One draw picks the cell, however crowded the board is.
+pub fn find_free_cell(occupied: &Occupancy, rng: &mut impl Rng) -> Option<Position> {
+    let free = occupied.free_cells();
+    if free == 0 {
+        return None;
+    }
+    occupied.nth_free(rng.gen_range(0..free))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use std::collections::HashMap;
+
+    #[test]
+    fn free_cells_are_counted_off_row_by_row() {
+        let mut occupied = Occupancy::new(7, 5);
+        occupied.insert(Position { x: 2, y: 2 });
+        occupied.insert(Position { x: 4, y: 3 });
+        // The border is not part of the interior.
+        occupied.insert(Position { x: 1, y: 3 });
+
+        let free: Vec<Position> = (0..occupied.free_cells())
+            .map(|n| occupied.nth_free(n).expect("n is below the free count"))
+            .collect();
+        assert_eq!(free.len(), 5 * 3 - 2);
+        assert_eq!(free[0], Position { x: 3, y: 2 });
+        assert_eq!(free[5], Position { x: 3, y: 3 });
+        assert!(free.iter().all(|pos| !occupied.contains(*pos)));
+        assert_eq!(occupied.nth_free(free.len()), None);
+    }
+
+    #[test]
+    fn spawns_are_spread_evenly_over_the_free_cells() {
+        let mut occupied = Occupancy::new(8, 8);
+        for x in 2..8 {
+            occupied.insert(Position { x, y: 4 });
+        }
+        let free = occupied.free_cells();
+        let draws = 300 * free;
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut counts: HashMap<Position, usize> = HashMap::new();
+        for _ in 0..draws {
+            let pos = find_free_cell(&occupied, &mut rng).expect("the board has room");
+            assert!(!occupied.contains(pos));
+            *counts.entry(pos).or_default() += 1;
+        }
+        assert_eq!(counts.len(), free);
+        assert!(counts.values().all(|&count| (240..=360).contains(&count)));
+    }
+
+    #[test]
+    fn the_last_free_cell_is_found_and_a_full_board_has_none() {
+        let mut occupied = Occupancy::new(12, 12);
+        let last = Position { x: 7, y: 9 };
+        for y in 2..12 {
+            for x in 2..12 {
+                if (Position { x, y }) != last {
+                    occupied.insert(Position { x, y });
+                }
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..20 {
+            assert_eq!(find_free_cell(&occupied, &mut rng), Some(last));
+        }
+        occupied.insert(last);
+        assert_eq!(find_free_cell(&occupied, &mut rng), None);
+    }
+}
@@ -0,0 +1,102 @@
+//! Per-run counters shown on the game-over summary.
+
+use crate::utils::PowerUpType;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Food eaten within this many ticks of the previous one extends the combo.
+pub const COMBO_WINDOW_TICKS: u32 = 40;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunStats {
+    /// Summed tick intervals, so paused time is not counted.
+    pub play_time: Duration,
+    pub ticks: u32,
+    pub food_eaten: u32,
+    /// Lowest tick-duration percentage seen (lower is faster, as in the HUD pace).
+    pub fastest_pace_percent: Option<u64>,
+    pub combo: u32,
+    pub max_combo: u32,
+    /// Longest the snake got during the run.
+    pub max_length: u32,
+    /// Times the run was rewound in practice.
+    #[serde(default)]
+    pub rewinds: u32,
+    /// Ticks played in practice slow motion.
+    #[serde(default)]
+    pub slow_motion_ticks: u32,
+    /// Ticks played one at a time in practice stepping.
+    #[serde(default)]
+    pub stepped_ticks: u32,
+    last_food_tick: Option<u32>,
+    power_ups_collected: [u32; PowerUpType::ALL.len()],
+}
+
+impl RunStats {
+    pub fn record_tick(&mut self, pace_percent: u64) {
+        self.ticks += 1;
+        self.fastest_pace_percent = Some(
+            self.fastest_pace_percent
+                .map_or(pace_percent, |fastest| fastest.min(pace_percent)),
+        );
+    }
+
+    pub fn record_food(&mut self) {
+        self.food_eaten += 1;
+        let chained = self
+            .last_food_tick
+            .is_some_and(|last| self.ticks.saturating_sub(last) <= COMBO_WINDOW_TICKS);
+        self.combo = if chained { self.combo + 1 } else { 1 };
+        self.max_combo = self.max_combo.max(self.combo);
+        self.last_food_tick = Some(self.ticks);
+    }
+
+    pub fn record_length(&mut self, length: usize) {
+        self.max_length = self
+            .max_length
+            .max(u32::try_from(length).unwrap_or(u32::MAX));
+    }
+
+    pub fn record_power_up(&mut self, kind: PowerUpType) {
+        if let Some(index) = PowerUpType::ALL.iter().position(|entry| *entry == kind) {
+            self.power_ups_collected[index] += 1;
+        }
+    }
+
+    pub fn power_ups_collected(&self, kind: PowerUpType) -> u32 {
+        PowerUpType::ALL
+            .iter()
+            .position(|entry| *entry == kind)
+            .map_or(0, |index| self.power_ups_collected[index])
+    }
+
+    pub fn total_power_ups(&self) -> u32 {
+        self.power_ups_collected.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combo_chains_within_window_and_resets_after_it() {
+        let mut stats = RunStats::default();
+        stats.record_tick(100);
+        stats.record_food();
+        for _ in 0..COMBO_WINDOW_TICKS {
+            stats.record_tick(100);
+        }
+        stats.record_food();
+        assert_eq!(stats.combo, 2);
+
+        for _ in 0..=COMBO_WINDOW_TICKS {
+            stats.record_tick(100);
+        }
+        stats.record_food();
+
+        assert_eq!(stats.combo, 1);
+        assert_eq!(stats.max_combo, 2);
+        assert_eq!(stats.food_eaten, 3);
+    }
+}
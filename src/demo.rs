@@ -0,0 +1,134 @@
+//! Attract mode, and the autoplay loop it shares with replays and bots.
+
+use std::{
+    convert::Infallible,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use crate::app::{paced_profile, play_game_sounds};
+use crate::core::{self, DifficultyProfile, Game};
+use crate::input::{self, GameInput, InputWait};
+use crate::storage::Settings;
+use crate::{layout, render, timing, utils};
+
+/// Why `autoplay` handed control back to its caller.
+pub(crate) enum AutoplayEnd {
+    GameOver,
+    Input(GameInput),
+    Disconnected,
+}
+
+/// Plays `game` on screen with `steer` choosing each turn. Resizes are handled
+/// here; any other input, or the end of the game, returns to the caller.
+pub(crate) fn autoplay<E>(
+    renderer: &mut render::Renderer,
+    rx: &mpsc::Receiver<GameInput>,
+    term_size: &mut (u16, u16),
+    settings: &Settings,
+    game: &mut Game,
+    mut steer: impl FnMut(&mut Game) -> Result<Option<utils::Direction>, E>,
+) -> Result<AutoplayEnd, E> {
+    let mut active_layout: Option<layout::Layout> = None;
+    let mut clock = timing::FixedTimestep::new(Instant::now());
+
+    loop {
+        let mut next_deadline = None;
+        match layout::compute_layout(
+            term_size.0,
+            term_size.1,
+            game.width,
+            game.height,
+            settings.square_cells,
+            layout::Hud::from_settings(settings),
+            settings.language,
+        ) {
+            Ok(layout) => {
+                let layout = layout.following(game.snake.head_position(), active_layout);
+                if active_layout != Some(layout) {
+                    render::draw_frame(renderer, active_layout, &layout);
+                    active_layout = Some(layout);
+                }
+                if !game.is_paused() {
+                    clock.advance(Instant::now());
+                    loop {
+                        let step = game.tick_interval(game.snake.direction);
+                        if game.game_over || !clock.try_step(step) {
+                            next_deadline = Some(clock.next_step_at(step));
+                            break;
+                        }
+                        if let Some(direction) = steer(game)? {
+                            game.update_snake_direction(direction);
+                        }
+                        game.tick();
+                        play_game_sounds(renderer, game, settings);
+                        game.stats.play_time += step;
+                    }
+                }
+                render::draw(renderer, game, &layout, settings.language);
+                if game.game_over {
+                    return Ok(AutoplayEnd::GameOver);
+                }
+            }
+            Err(size_check) => {
+                render::draw_size_warning(renderer, size_check, settings.language);
+                active_layout = None;
+                clock.hold(Instant::now());
+            }
+        }
+
+        match input::wait_for_input(rx, next_deadline) {
+            InputWait::TimedOut => {}
+            InputWait::Input(GameInput::Resize(width, height)) => *term_size = (width, height),
+            InputWait::Input(
+                GameInput::ConfigChanged | GameInput::FocusLost | GameInput::FocusGained,
+            ) => {}
+            InputWait::Input(input_cmd) => return Ok(AutoplayEnd::Input(input_cmd)),
+            InputWait::Disconnected => return Ok(AutoplayEnd::Disconnected),
+        }
+    }
+}
+
+/// How long the main menu sits untouched before the demo starts.
+pub(crate) const DEMO_IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+/// How long a finished demo game stays on screen before the menu returns.
+const DEMO_GAME_OVER_HOLD: Duration = Duration::from_secs(3);
+
+/// Attract mode: the autopilot plays a muted game on the default difficulty
+/// until a key is pressed or the snake dies. Returns false if the player quit.
+pub(crate) fn run_demo(
+    renderer: &mut render::Renderer,
+    rx: &mpsc::Receiver<GameInput>,
+    term_size: &mut (u16, u16),
+    settings: &Settings,
+) -> bool {
+    let difficulty = settings.default_difficulty;
+    let mut game = Game::with_profile(
+        difficulty,
+        paced_profile(
+            DifficultyProfile::for_difficulty(difficulty, settings.custom_difficulty),
+            settings,
+        ),
+        utils::WIDTH,
+        utils::HEIGHT,
+        0,
+    );
+    game.muted = true;
+    game.reduced_motion = settings.reduced_motion;
+    game.speed_cap = settings.speed_cap();
+    game.set_disabled_power_ups(settings.disabled_power_ups);
+
+    let Ok(end) = autoplay::<Infallible>(renderer, rx, term_size, settings, &mut game, |game| {
+        Ok(Some(core::autopilot_direction(game)))
+    });
+    let keep_running = match end {
+        AutoplayEnd::GameOver => !matches!(
+            input::wait_for_input(rx, Some(Instant::now() + DEMO_GAME_OVER_HOLD)),
+            InputWait::Input(GameInput::Quit) | InputWait::Disconnected
+        ),
+        AutoplayEnd::Input(GameInput::Quit) | AutoplayEnd::Disconnected => false,
+        AutoplayEnd::Input(_) => true,
+    };
+    render::clear_for_menu_entry(renderer);
+    keep_running
+}
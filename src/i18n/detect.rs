@@ -0,0 +1,79 @@
+//! Picks the language from the system locale, for configs that do not set
+//! one yet.
+
+use std::env;
+
+use crate::utils::Language;
+
+/// The language of the user's locale: `LC_ALL`, `LC_MESSAGES`, or `LANG`,
+/// whichever is set first, then the Windows user locale. Locales without a
+/// translation get English.
+pub fn detect() -> Language {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|locale| !locale.is_empty())
+        .or_else(system_locale)
+        .and_then(|locale| from_locale(&locale))
+        .unwrap_or(Language::En)
+}
+
+/// The language of a locale name such as `pt_BR.UTF-8` or `zh-Hans-CN`.
+fn from_locale(locale: &str) -> Option<Language> {
+    let code = locale
+        .split(['_', '-', '.', '@'])
+        .next()?
+        .to_ascii_lowercase();
+    match code.as_str() {
+        "en" => Some(Language::En),
+        "es" => Some(Language::Es),
+        "ja" => Some(Language::Ja),
+        "pt" => Some(Language::Pt),
+        "zh" => Some(Language::Zh),
+        "de" => Some(Language::De),
+        "fr" => Some(Language::Fr),
+        "ru" => Some(Language::Ru),
+        "ko" => Some(Language::Ko),
+        "ar" => Some(Language::Ar),
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn system_locale() -> Option<String> {
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetUserDefaultLocaleName(name: *mut u16, len: i32) -> i32;
+    }
+    // LOCALE_NAME_MAX_LENGTH, with the terminating NUL.
+    let mut name = [0u16; 85];
+    // SAFETY: the buffer is writable for the length passed with it.
+    let written = unsafe { GetUserDefaultLocaleName(name.as_mut_ptr(), name.len() as i32) };
+    // The count includes the NUL; zero means the call failed.
+    let len = usize::try_from(written).ok()?.checked_sub(1)?;
+    Some(String::from_utf16_lossy(&name[..len]))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn system_locale() -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_names_map_to_languages() {
+        assert_eq!(from_locale("pt_BR.UTF-8"), Some(Language::Pt));
+        assert_eq!(from_locale("es_ES@euro"), Some(Language::Es));
+        assert_eq!(from_locale("ja-JP"), Some(Language::Ja));
+        assert_eq!(from_locale("zh-Hans-CN"), Some(Language::Zh));
+        assert_eq!(from_locale("EN_us"), Some(Language::En));
+        assert_eq!(from_locale("ko_KR.UTF-8"), Some(Language::Ko));
+        assert_eq!(from_locale("ar_EG.UTF-8"), Some(Language::Ar));
+        assert_eq!(from_locale("nl_NL.UTF-8"), None);
+        assert_eq!(from_locale("C"), None);
+        assert_eq!(from_locale("POSIX"), None);
+    }
+}
@@ -0,0 +1,186 @@
+# Arabic, written right to left. Every locale defines the messages of
+# en.ftl; the build fails when one is missing.
+
+controls-text = WASD/الأسهم:تحرك P:إيقاف M:كتم SPACE:القائمة Q:خروج
+menu-title = لعبة الثعبان
+menu-play = العب
+menu-difficulty = الصعوبة
+menu-settings = الإعدادات
+menu-high-scores = أعلى النتائج
+menu-quit = خروج
+menu-rainbow-unlocked = تم فتح سمة قوس قزح!
+high-scores-menu-title = كل أعلى النتائج
+leaderboard-initials-label = الاسم
+leaderboard-date-label = التاريخ
+leaderboard-switch-hint = استخدم ←→ لتغيير الصعوبة
+high-scores-actions-hint = ↑↓ للوضع واللوحة، O للترتيب، R للمسح
+menu-global-leaderboard = لوحة الصدارة العالمية
+global-leaderboard-title = أفضل 20 عالميًا
+global-leaderboard-hint = ←→ للصعوبة، ↑↓ للصفحة
+global-leaderboard-loading = جارٍ التحميل...
+global-leaderboard-unavailable = لوحة الصدارة غير متاحة
+new-record-title = نتيجة قياسية جديدة!
+new-record-rank-label = المرتبة
+new-record-subtitle = { status-score-label }: { $score }  { new-record-rank-label }: #{ $rank }
+initials-save-label = حفظ
+config-notice-title = مشكلة في الإعدادات
+config-notice-continue = المتابعة بالقيم الافتراضية
+recover-title = استعادة آخر لعبة؟
+recover-resume = متابعة
+recover-discard = تجاهل
+high-scores-back-hint = اضغط ENTER/SPACE للرجوع
+menu-back = رجوع
+difficulty-menu-title = اختر الصعوبة
+custom-difficulty-title = صعوبة مخصصة
+custom-adjust-hint = استخدم ←→ للتعديل
+custom-tick-rate-label = معدل النبض
+custom-progression-label = التسريع
+custom-power-up-chance-label = فرصة التعزيز
+custom-wrap-label = عبور الحواف
+custom-seed-label = البذرة
+custom-seed-random = عشوائية
+settings-pause-on-focus-loss-label = إيقاف عند فقد التركيز
+settings-resume-on-focus-gain-label = استئناف عند استعادة التركيز
+settings-idle-pause-label = إيقاف عند الخمول
+settings-sound-label = الصوت
+settings-ui-compact-label = واجهة مضغوطة
+settings-theme-label = السمة
+settings-colorblind-label = وضع عمى الألوان
+settings-high-contrast-label = تباين عالٍ
+settings-reduced-motion-label = حركة أقل
+settings-speed-label = السرعة
+game-speed-difficulty = حسب الصعوبة
+game-speed-slow = بطيئة
+game-speed-normal = عادية
+game-speed-fast = سريعة
+game-speed-insane = جنونية
+settings-speed-cap-label = حد السرعة
+settings-square-cells-label = خلايا مربعة
+settings-axis-pace-label = سرعة المحاور
+axis-pace-auto = تلقائي
+settings-window-title-label = عنوان النافذة
+settings-symbols-label = الرموز
+symbol-set-fancy = مزخرفة
+symbol-set-compatible = متوافقة
+settings-board-pattern-label = نقش اللوحة
+board-pattern-plain = بلا
+board-pattern-dots = نقاط
+board-pattern-checkerboard = شطرنج
+settings-hud-position-label = موضع لوحة المعلومات
+hud-position-below = أسفل
+hud-position-above = أعلى
+hud-position-right = يمين
+settings-hud-compact-label = لوحة معلومات مضغوطة
+settings-sidebar-label = لوحة التأثيرات
+settings-ghost-label = شبح أفضل جولة
+settings-controls-label = التحكم
+control-scheme-arrows = الأسهم
+control-scheme-custom = مخصص
+settings-power-ups-label = التعزيزات
+sound-menu-title = إعدادات الصوت
+sound-event-food = الطعام
+sound-event-power-up = التعزيزات
+sound-event-death = انتهاء اللعبة
+sound-event-ui = القوائم
+power-ups-menu-title = تفعيل التعزيزات
+power-up-speed-boost = تسريع
+power-up-slow-down = إبطاء
+power-up-extra-points = نقاط إضافية
+power-up-grow = نمو
+power-up-shrink = تقلص
+power-up-shield = درع
+power-up-score-multiplier = مضاعف النقاط
+settings-reset-high-scores-label = مسح أعلى النتائج
+reset-high-scores-title = مسح أعلى النتائج؟
+reset-score-table-title = مسح هذا الجدول؟
+reset-difficulty-title = مسح نتائج { $difficulty }؟
+reset-scores-all = كل مستويات الصعوبة
+quit-confirm-title = الخروج من اللعبة؟
+leave-confirm-title = العودة إلى القائمة؟
+quit-confirm-warning = ستضيع هذه الجولة
+confirm-yes = نعم
+confirm-no = لا
+setting-on = تشغيل
+setting-off = إيقاف
+menu-navigation-hint = استخدم الأسهم ↑↓ أو WASD للتنقل
+menu-confirm-hint = ENTER/SPACE للاختيار، Q للخروج
+language-name = العربية
+language-popup-title = اختر اللغة
+language-label = اللغة
+small-window-title = النافذة صغيرة جدًا
+small-window-current-label = الحالي
+small-window-minimum-label = الأدنى
+spectate-waiting-title = بانتظار لعبة...
+spectate-quit-hint = وضع المشاهدة. اضغط Q للخروج.
+small-window-hint = كبّر الطرفية للمتابعة. اضغط Q للخروج.
+status-score-label = النقاط
+window-title = Rustnake
+window-title-score = Rustnake — { status-score-label }: { $score }
+status-difficulty-label = الصعوبة
+status-paused = متوقف
+status-resuming = استئناف خلال { $seconds }
+status-muted = مكتوم
+status-slow-motion = بطيء
+text-food-label = الطعام
+text-length-label = الطول
+practice-food-offset = { text-food-label } x{ $dx } y{ $dy }
+toast-new-high-score = نتيجة قياسية جديدة!
+toast-card-saved = حُفظت في { $file }
+toast-idle-paused = أُوقف مؤقتًا بعد { $seconds } ث دون إدخال
+toast-card-failed = تعذّر حفظ البطاقة
+toast-result-copied = نُسخت النتيجة
+bearing-up-left = أعلى اليسار
+bearing-up = أعلى
+bearing-up-right = أعلى اليمين
+bearing-left = يسار
+bearing-here = هنا
+bearing-right = يمين
+bearing-down-left = أسفل اليسار
+bearing-down = أسفل
+bearing-down-right = أسفل اليمين
+info-best-label = الأفضل
+info-pace-label = الوتيرة
+info-speed-up-label = التسريع التالي
+info-effect-label = التأثير
+sidebar-effects-title = التأثيرات
+sidebar-legend-title = دليل الرموز
+difficulty-easy = سهل
+difficulty-medium = متوسط
+difficulty-hard = صعب
+difficulty-extreme = قاسٍ
+difficulty-custom = مخصص
+game-mode-classic = كلاسيكي
+game-mode-walls = جدران
+effect-short-speed-boost = سرعة
+effect-short-slow-down = بطء
+effect-short-shield = درع
+effect-short-score-multiplier = مضاعف
+game-over-title = انتهت اللعبة!
+game-over-menu-hint = اضغط SPACE للقائمة
+game-over-quit-hint = أو 'q' للخروج
+game-over-share-hint = 'c' نسخ، 'e' حفظ البطاقة
+game-over-retry-hint = 'r' إعادة آخر { $ticks } نبضة
+points = { $count ->
+        [one] نقطة واحدة
+        [two] نقطتان
+        [few] { $count } نقاط
+       *[other] { $count } نقطة
+    }
+summary-time-label = الوقت
+summary-food-label = الطعام المأكول
+summary-power-ups-label = التعزيزات
+summary-fastest-pace-label = أسرع وتيرة
+summary-best-combo-label = أفضل سلسلة
+summary-max-length-label = أقصى طول
+summary-score = { status-score-label }: { $score }
+summary-time = { summary-time-label }: { $time }
+summary-food = { summary-food-label }: { $count }
+summary-power-ups = { summary-power-ups-label }: { $count }
+summary-fastest-pace = { summary-fastest-pace-label }: { $percent }%
+summary-best-combo = { summary-best-combo-label }: { $count }
+summary-max-length = { summary-max-length-label }: { $length ->
+        [one] خلية واحدة
+        [two] خليتان
+        [few] { $length } خلايا
+       *[other] { $length } خلية
+    }
@@ -0,0 +1,182 @@
+# German. Every locale defines the messages of en.ftl; the build
+# fails when one is missing.
+
+controls-text = WASD/Pfeile:Bewegen P:Pause M:Ton aus LEER:Menü Q:Ende
+menu-title = SNAKE-SPIEL
+menu-play = Spielen
+menu-difficulty = Schwierigkeit
+menu-settings = Einstellungen
+menu-high-scores = Bestenliste
+menu-quit = Beenden
+menu-rainbow-unlocked = Regenbogen-Design freigeschaltet!
+high-scores-menu-title = Alle Bestenlisten
+leaderboard-initials-label = Name
+leaderboard-date-label = Datum
+leaderboard-switch-hint = ←→ wechselt die Schwierigkeit
+high-scores-actions-hint = ↑↓ Modus und Feld, O sortiert, R setzt zurück
+menu-global-leaderboard = Globale Bestenliste
+global-leaderboard-title = Globale Top 20
+global-leaderboard-hint = ←→ Schwierigkeit, ↑↓ Seite
+global-leaderboard-loading = Wird geladen...
+global-leaderboard-unavailable = Bestenliste nicht verfügbar
+new-record-title = Neuer Rekord!
+new-record-rank-label = Platz
+new-record-subtitle = { status-score-label }: { $score }  { new-record-rank-label }: #{ $rank }
+initials-save-label = Speichern
+config-notice-title = Konfigurationsproblem
+config-notice-continue = Mit Standardwerten fortfahren
+recover-title = Letztes Spiel wiederherstellen?
+recover-resume = Fortsetzen
+recover-discard = Verwerfen
+high-scores-back-hint = ENTER/LEER drücken, um zurückzugehen
+menu-back = Zurück
+difficulty-menu-title = Schwierigkeit wählen
+custom-difficulty-title = Eigene Schwierigkeit
+custom-adjust-hint = ←→ zum Einstellen
+custom-tick-rate-label = Takt
+custom-progression-label = Beschleunigung
+custom-power-up-chance-label = Power-up-Chance
+custom-wrap-label = Ränder verbinden
+custom-seed-label = Seed
+custom-seed-random = zufällig
+settings-pause-on-focus-loss-label = Pause bei Fokusverlust
+settings-resume-on-focus-gain-label = Fortsetzen bei Fokus
+settings-idle-pause-label = Pause bei Inaktivität
+settings-sound-label = Ton
+settings-ui-compact-label = Kompakte Oberfläche
+settings-theme-label = Design
+settings-colorblind-label = Farbenblind-Modus
+settings-high-contrast-label = Hoher Kontrast
+settings-reduced-motion-label = Weniger Bewegung
+settings-speed-label = Tempo
+game-speed-difficulty = Wie Schwierigkeit
+game-speed-slow = Langsam
+game-speed-normal = Normal
+game-speed-fast = Schnell
+game-speed-insane = Wahnsinn
+settings-speed-cap-label = Tempolimit
+settings-square-cells-label = Quadratische Zellen
+settings-axis-pace-label = Achsentempo
+axis-pace-auto = Auto
+settings-window-title-label = Fenstertitel
+settings-symbols-label = Symbole
+symbol-set-fancy = Schick
+symbol-set-compatible = Kompatibel
+settings-board-pattern-label = Spielfeldmuster
+board-pattern-plain = Keins
+board-pattern-dots = Punkte
+board-pattern-checkerboard = Schachbrett
+settings-hud-position-label = HUD-Position
+hud-position-below = Unten
+hud-position-above = Oben
+hud-position-right = Rechts
+settings-hud-compact-label = Kompaktes HUD
+settings-sidebar-label = Effektleiste
+settings-ghost-label = Rekord-Geist
+settings-controls-label = Steuerung
+control-scheme-arrows = Pfeile
+control-scheme-custom = Eigene
+settings-power-ups-label = Power-ups
+sound-menu-title = Toneinstellungen
+sound-event-food = Futter
+sound-event-power-up = Power-ups
+sound-event-death = Spielende
+sound-event-ui = Menüs
+power-ups-menu-title = Power-ups umschalten
+power-up-speed-boost = Turbo
+power-up-slow-down = Zeitlupe
+power-up-extra-points = Extrapunkte
+power-up-grow = Wachsen
+power-up-shrink = Schrumpfen
+power-up-shield = Schild
+power-up-score-multiplier = Punktemultiplikator
+settings-reset-high-scores-label = Bestenliste zurücksetzen
+reset-high-scores-title = Bestenliste zurücksetzen?
+reset-score-table-title = Diese Tabelle zurücksetzen?
+reset-difficulty-title = { $difficulty }-Bestenliste zurücksetzen?
+reset-scores-all = Alle Schwierigkeiten
+quit-confirm-title = Spiel beenden?
+leave-confirm-title = Zurück zum Menü?
+quit-confirm-warning = Diese Runde geht verloren
+confirm-yes = Ja
+confirm-no = Nein
+setting-on = An
+setting-off = Aus
+menu-navigation-hint = Mit ↑↓ oder WASD navigieren
+menu-confirm-hint = ENTER/LEER wählt, Q beendet
+language-name = Deutsch
+language-popup-title = Sprache wählen
+language-label = Sprache
+small-window-title = FENSTER ZU KLEIN
+small-window-current-label = Aktuell
+small-window-minimum-label = Minimum
+spectate-waiting-title = Warte auf ein Spiel...
+spectate-quit-hint = Zuschauermodus. Q zum Beenden.
+small-window-hint = Terminal vergrößern, um fortzufahren. Q zum Beenden.
+status-score-label = Punkte
+window-title = Rustnake
+window-title-score = Rustnake — { status-score-label }: { $score }
+status-difficulty-label = Stufe
+status-paused = PAUSE
+status-resuming = WEITER IN { $seconds }
+status-muted = STUMM
+status-slow-motion = ZEITLUPE
+text-food-label = Futter
+text-length-label = Länge
+practice-food-offset = { text-food-label } x{ $dx } y{ $dy }
+toast-new-high-score = Neuer Rekord!
+toast-card-saved = { $file } gespeichert
+toast-idle-paused = Pausiert nach { $seconds } s ohne Eingabe
+toast-card-failed = Karte nicht gespeichert
+toast-result-copied = Ergebnis kopiert
+bearing-up-left = oben links
+bearing-up = oben
+bearing-up-right = oben rechts
+bearing-left = links
+bearing-here = hier
+bearing-right = rechts
+bearing-down-left = unten links
+bearing-down = unten
+bearing-down-right = unten rechts
+info-best-label = Rekord
+info-pace-label = Tempo
+info-speed-up-label = Steigerung
+info-effect-label = Effekt
+sidebar-effects-title = Effekte
+sidebar-legend-title = Legende
+difficulty-easy = Leicht
+difficulty-medium = Mittel
+difficulty-hard = Schwer
+difficulty-extreme = Extrem
+difficulty-custom = Eigene
+game-mode-classic = Klassisch
+game-mode-walls = Wände
+effect-short-speed-boost = Turbo
+effect-short-slow-down = Lupe
+effect-short-shield = Schild
+effect-short-score-multiplier = Bonus
+game-over-title = SPIEL VORBEI!
+game-over-menu-hint = LEER für das Menü
+game-over-quit-hint = oder 'q' zum Beenden
+game-over-share-hint = 'c' kopieren, 'e' Karte speichern
+game-over-retry-hint = 'r' die letzten { $ticks } Ticks wiederholen
+points = { $count ->
+        [one] { $count } Punkt
+       *[other] { $count } Punkte
+    }
+summary-time-label = Zeit
+summary-food-label = Gefressen
+summary-power-ups-label = Power-ups
+summary-fastest-pace-label = Höchstes Tempo
+summary-best-combo-label = Beste Kombo
+summary-max-length-label = Maximale Länge
+summary-score = { status-score-label }: { $score }
+summary-time = { summary-time-label }: { $time }
+summary-food = { summary-food-label }: { $count }
+summary-power-ups = { summary-power-ups-label }: { $count }
+summary-fastest-pace = { summary-fastest-pace-label }: { $percent }%
+summary-best-combo = { summary-best-combo-label }: { $count }
+summary-max-length = { summary-max-length-label }: { $length ->
+        [one] { $length } Feld
+       *[other] { $length } Felder
+    }
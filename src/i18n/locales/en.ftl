@@ -0,0 +1,182 @@
+# English. Every locale defines the messages of en.ftl; the build
+# fails when one is missing.
+
+controls-text = WASD/Arrows:Move P:Pause M:Mute SPACE:Menu Q:Quit
+menu-title = SNAKE GAME
+menu-play = Play
+menu-difficulty = Difficulty
+menu-settings = Settings
+menu-high-scores = High Scores
+menu-quit = Quit
+menu-rainbow-unlocked = Rainbow theme unlocked!
+high-scores-menu-title = All High Scores
+leaderboard-initials-label = Name
+leaderboard-date-label = Date
+leaderboard-switch-hint = Use ←→ to switch difficulty
+high-scores-actions-hint = ↑↓ for mode and board, O to sort, R to reset
+menu-global-leaderboard = Global Leaderboard
+global-leaderboard-title = Global Top 20
+global-leaderboard-hint = Use ←→ for difficulty, ↑↓ for page
+global-leaderboard-loading = Loading...
+global-leaderboard-unavailable = Leaderboard unavailable
+new-record-title = New High Score!
+new-record-rank-label = Rank
+new-record-subtitle = { status-score-label }: { $score }  { new-record-rank-label }: #{ $rank }
+initials-save-label = Save
+config-notice-title = Config Problem
+config-notice-continue = Continue with defaults
+recover-title = Recover last game?
+recover-resume = Resume
+recover-discard = Discard
+high-scores-back-hint = Press ENTER/SPACE to go back
+menu-back = Back
+difficulty-menu-title = Select Difficulty
+custom-difficulty-title = Custom Difficulty
+custom-adjust-hint = Use ←→ to adjust
+custom-tick-rate-label = Tick Rate
+custom-progression-label = Speed-ups
+custom-power-up-chance-label = Power-up Chance
+custom-wrap-label = Wrap Edges
+custom-seed-label = Seed
+custom-seed-random = random
+settings-pause-on-focus-loss-label = Pause on Focus Loss
+settings-resume-on-focus-gain-label = Resume on Focus Gain
+settings-idle-pause-label = Idle pause
+settings-sound-label = Sound
+settings-ui-compact-label = Compact UI
+settings-theme-label = Theme
+settings-colorblind-label = Colorblind mode
+settings-high-contrast-label = High contrast
+settings-reduced-motion-label = Reduced motion
+settings-speed-label = Speed
+game-speed-difficulty = By difficulty
+game-speed-slow = Slow
+game-speed-normal = Normal
+game-speed-fast = Fast
+game-speed-insane = Insane
+settings-speed-cap-label = Speed cap
+settings-square-cells-label = Square cells
+settings-axis-pace-label = Axis pacing
+axis-pace-auto = Auto
+settings-window-title-label = Window title
+settings-symbols-label = Symbols
+symbol-set-fancy = Fancy
+symbol-set-compatible = Compatible
+settings-board-pattern-label = Board pattern
+board-pattern-plain = None
+board-pattern-dots = Dots
+board-pattern-checkerboard = Checkerboard
+settings-hud-position-label = HUD position
+hud-position-below = Below
+hud-position-above = Above
+hud-position-right = Right
+settings-hud-compact-label = Compact HUD
+settings-sidebar-label = Effects panel
+settings-ghost-label = Best-run ghost
+settings-controls-label = Controls
+control-scheme-arrows = Arrows
+control-scheme-custom = Custom
+settings-power-ups-label = Power-ups
+sound-menu-title = Sound Settings
+sound-event-food = Food
+sound-event-power-up = Power-ups
+sound-event-death = Game over
+sound-event-ui = Menus
+power-ups-menu-title = Toggle Power-ups
+power-up-speed-boost = Speed Boost
+power-up-slow-down = Slow Down
+power-up-extra-points = Extra Points
+power-up-grow = Grow
+power-up-shrink = Shrink
+power-up-shield = Shield
+power-up-score-multiplier = Score Multiplier
+settings-reset-high-scores-label = Reset High Scores
+reset-high-scores-title = Reset High Scores?
+reset-score-table-title = Reset this table?
+reset-difficulty-title = Reset { $difficulty } scores?
+reset-scores-all = All difficulties
+quit-confirm-title = Quit the game?
+leave-confirm-title = Back to the menu?
+quit-confirm-warning = This run will be lost
+confirm-yes = Yes
+confirm-no = No
+setting-on = On
+setting-off = Off
+menu-navigation-hint = Use ↑↓ arrows or WASD to navigate
+menu-confirm-hint = Press ENTER/SPACE to select, Q to quit
+language-name = English
+language-popup-title = Select Language
+language-label = Language
+small-window-title = WINDOW TOO SMALL
+small-window-current-label = Current
+small-window-minimum-label = Minimum
+spectate-waiting-title = Waiting for a game...
+spectate-quit-hint = Spectating. Press Q to quit.
+small-window-hint = Resize terminal to continue. Press Q to quit.
+status-score-label = Score
+window-title = Rustnake
+window-title-score = Rustnake — { status-score-label }: { $score }
+status-difficulty-label = Diff
+status-paused = PAUSED
+status-resuming = RESUMING IN { $seconds }
+status-muted = MUTED
+status-slow-motion = SLOW
+text-food-label = Food
+text-length-label = Length
+practice-food-offset = { text-food-label } x{ $dx } y{ $dy }
+toast-new-high-score = New high score!
+toast-card-saved = Saved { $file }
+toast-idle-paused = Paused after { $seconds } s idle
+toast-card-failed = Cannot save the card
+toast-result-copied = Result copied
+bearing-up-left = up-left
+bearing-up = up
+bearing-up-right = up-right
+bearing-left = left
+bearing-here = here
+bearing-right = right
+bearing-down-left = down-left
+bearing-down = down
+bearing-down-right = down-right
+info-best-label = Best
+info-pace-label = Pace
+info-speed-up-label = Speed-up
+info-effect-label = Effect
+sidebar-effects-title = Effects
+sidebar-legend-title = Legend
+difficulty-easy = Easy
+difficulty-medium = Medium
+difficulty-hard = Hard
+difficulty-extreme = Extreme
+difficulty-custom = Custom
+game-mode-classic = Classic
+game-mode-walls = Walls
+effect-short-speed-boost = Boost
+effect-short-slow-down = Slow
+effect-short-shield = Shield
+effect-short-score-multiplier = Bonus
+game-over-title = GAME OVER!
+game-over-menu-hint = Press SPACE for menu
+game-over-quit-hint = or 'q' to quit
+game-over-share-hint = 'c' copy, 'e' save card
+game-over-retry-hint = 'r' retry the last { $ticks } ticks
+points = { $count ->
+        [one] { $count } point
+       *[other] { $count } points
+    }
+summary-time-label = Time
+summary-food-label = Food eaten
+summary-power-ups-label = Power-ups
+summary-fastest-pace-label = Fastest pace
+summary-best-combo-label = Best combo
+summary-max-length-label = Max length
+summary-score = { status-score-label }: { $score }
+summary-time = { summary-time-label }: { $time }
+summary-food = { summary-food-label }: { $count }
+summary-power-ups = { summary-power-ups-label }: { $count }
+summary-fastest-pace = { summary-fastest-pace-label }: { $percent }%
+summary-best-combo = { summary-best-combo-label }: { $count }
+summary-max-length = { summary-max-length-label }: { $length ->
+        [one] { $length } cell
+       *[other] { $length } cells
+    }
@@ -0,0 +1,182 @@
+# Spanish. Every locale defines the messages of en.ftl; the build
+# fails when one is missing.
+
+controls-text = WASD/Flechas:Mover P:Pausa M:Mutear ESPACIO:Menú Q:Salir
+menu-title = SNAKE GAME
+menu-play = Jugar
+menu-difficulty = Dificultad
+menu-settings = Ajustes
+menu-high-scores = Puntuaciones
+menu-quit = Salir
+menu-rainbow-unlocked = ¡Tema arcoíris desbloqueado!
+high-scores-menu-title = Todas las puntuaciones
+leaderboard-initials-label = Nombre
+leaderboard-date-label = Fecha
+leaderboard-switch-hint = Usa ←→ para cambiar dificultad
+high-scores-actions-hint = ↑↓ modo y tablero, O ordenar, R reiniciar
+menu-global-leaderboard = Clasificación global
+global-leaderboard-title = Top 20 mundial
+global-leaderboard-hint = Usa ←→ para dificultad, ↑↓ para página
+global-leaderboard-loading = Cargando...
+global-leaderboard-unavailable = Clasificación no disponible
+new-record-title = ¡Nueva puntuación récord!
+new-record-rank-label = Puesto
+new-record-subtitle = { status-score-label }: { $score }  { new-record-rank-label }: #{ $rank }
+initials-save-label = Guardar
+config-notice-title = Problema de configuración
+config-notice-continue = Continuar con valores predeterminados
+recover-title = ¿Recuperar la última partida?
+recover-resume = Reanudar
+recover-discard = Descartar
+high-scores-back-hint = Pulsa ENTER/ESPACIO para volver
+menu-back = Atras
+difficulty-menu-title = Selecciona dificultad
+custom-difficulty-title = Dificultad personalizada
+custom-adjust-hint = Usa ←→ para ajustar
+custom-tick-rate-label = Intervalo
+custom-progression-label = Aceleraciones
+custom-power-up-chance-label = Prob. potenciador
+custom-wrap-label = Bordes abiertos
+custom-seed-label = Semilla
+custom-seed-random = aleatoria
+settings-pause-on-focus-loss-label = Pausar al perder enfoque
+settings-resume-on-focus-gain-label = Reanudar al recuperar enfoque
+settings-idle-pause-label = Pausa por inactividad
+settings-sound-label = Sonido
+settings-ui-compact-label = IU compacta
+settings-theme-label = Tema
+settings-colorblind-label = Modo daltónico
+settings-high-contrast-label = Alto contraste
+settings-reduced-motion-label = Movimiento reducido
+settings-speed-label = Velocidad
+game-speed-difficulty = Según dificultad
+game-speed-slow = Lenta
+game-speed-normal = Normal
+game-speed-fast = Rápida
+game-speed-insane = Demencial
+settings-speed-cap-label = Límite de velocidad
+settings-square-cells-label = Celdas cuadradas
+settings-axis-pace-label = Ritmo por eje
+axis-pace-auto = Auto
+settings-window-title-label = Título de ventana
+settings-symbols-label = Símbolos
+symbol-set-fancy = Elegantes
+symbol-set-compatible = Compatibles
+settings-board-pattern-label = Fondo del tablero
+board-pattern-plain = Ninguno
+board-pattern-dots = Puntos
+board-pattern-checkerboard = Ajedrez
+settings-hud-position-label = Posición del HUD
+hud-position-below = Abajo
+hud-position-above = Arriba
+hud-position-right = Derecha
+settings-hud-compact-label = HUD compacto
+settings-sidebar-label = Panel de efectos
+settings-ghost-label = Fantasma del récord
+settings-controls-label = Controles
+control-scheme-arrows = Flechas
+control-scheme-custom = Personalizado
+settings-power-ups-label = Potenciadores
+sound-menu-title = Ajustes de sonido
+sound-event-food = Comida
+sound-event-power-up = Potenciadores
+sound-event-death = Fin de partida
+sound-event-ui = Menús
+power-ups-menu-title = Activar potenciadores
+power-up-speed-boost = Turbo
+power-up-slow-down = Cámara lenta
+power-up-extra-points = Puntos extra
+power-up-grow = Crecer
+power-up-shrink = Encoger
+power-up-shield = Escudo
+power-up-score-multiplier = Multiplicador
+settings-reset-high-scores-label = Reiniciar puntuaciones
+reset-high-scores-title = Reiniciar puntuaciones?
+reset-score-table-title = Reiniciar esta tabla?
+reset-difficulty-title = Reiniciar puntuaciones de { $difficulty }?
+reset-scores-all = Todas las dificultades
+quit-confirm-title = Salir del juego?
+leave-confirm-title = Volver al menú?
+quit-confirm-warning = Se perderá esta partida
+confirm-yes = Si
+confirm-no = No
+setting-on = Activado
+setting-off = Desactivado
+menu-navigation-hint = Usa ↑↓ o WASD para navegar
+menu-confirm-hint = Pulsa ENTER/ESPACIO para elegir, Q para salir
+language-name = Español
+language-popup-title = Selecciona idioma
+language-label = Idioma
+small-window-title = VENTANA MUY PEQUEÑA
+small-window-current-label = Actual
+small-window-minimum-label = Mínimo
+spectate-waiting-title = Esperando una partida...
+spectate-quit-hint = Modo espectador. Pulsa Q para salir.
+small-window-hint = Ajusta la terminal para continuar. Pulsa Q para salir.
+status-score-label = Puntos
+window-title = Rustnake
+window-title-score = Rustnake — { status-score-label }: { $score }
+status-difficulty-label = Nivel
+status-paused = PAUSA
+status-resuming = REANUDANDO EN { $seconds }
+status-muted = MUTEADO
+status-slow-motion = LENTO
+text-food-label = Comida
+text-length-label = Longitud
+practice-food-offset = { text-food-label } x{ $dx } y{ $dy }
+toast-new-high-score = ¡Nuevo récord!
+toast-card-saved = Guardado { $file }
+toast-idle-paused = En pausa tras { $seconds } s sin actividad
+toast-card-failed = No se pudo guardar la tarjeta
+toast-result-copied = Resultado copiado
+bearing-up-left = arriba a la izquierda
+bearing-up = arriba
+bearing-up-right = arriba a la derecha
+bearing-left = izquierda
+bearing-here = aquí
+bearing-right = derecha
+bearing-down-left = abajo a la izquierda
+bearing-down = abajo
+bearing-down-right = abajo a la derecha
+info-best-label = Mejor
+info-pace-label = Ritmo
+info-speed-up-label = Aceleración
+info-effect-label = Efecto
+sidebar-effects-title = Efectos
+sidebar-legend-title = Leyenda
+difficulty-easy = Fácil
+difficulty-medium = Medio
+difficulty-hard = Difícil
+difficulty-extreme = Extremo
+difficulty-custom = Personalizado
+game-mode-classic = Clásico
+game-mode-walls = Muros
+effect-short-speed-boost = Turbo
+effect-short-slow-down = Lento
+effect-short-shield = Escudo
+effect-short-score-multiplier = Bonus
+game-over-title = FIN DEL JUEGO
+game-over-menu-hint = Pulsa ESPACIO para menú
+game-over-quit-hint = o 'q' para salir
+game-over-share-hint = 'c' copiar, 'e' guardar tarjeta
+game-over-retry-hint = 'r' reintentar los últimos { $ticks } ticks
+points = { $count ->
+        [one] { $count } punto
+       *[other] { $count } puntos
+    }
+summary-time-label = Tiempo
+summary-food-label = Comida
+summary-power-ups-label = Potenciadores
+summary-fastest-pace-label = Ritmo máximo
+summary-best-combo-label = Mejor combo
+summary-max-length-label = Longitud máxima
+summary-score = { status-score-label }: { $score }
+summary-time = { summary-time-label }: { $time }
+summary-food = { summary-food-label }: { $count }
+summary-power-ups = { summary-power-ups-label }: { $count }
+summary-fastest-pace = { summary-fastest-pace-label }: { $percent }%
+summary-best-combo = { summary-best-combo-label }: { $count }
+summary-max-length = { summary-max-length-label }: { $length ->
+        [one] { $length } celda
+       *[other] { $length } celdas
+    }
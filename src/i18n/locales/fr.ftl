@@ -0,0 +1,182 @@
+# French. Every locale defines the messages of en.ftl; the build
+# fails when one is missing.
+
+controls-text = WASD/Flèches:Bouger P:Pause M:Muet ESPACE:Menu Q:Quitter
+menu-title = JEU DU SERPENT
+menu-play = Jouer
+menu-difficulty = Difficulté
+menu-settings = Paramètres
+menu-high-scores = Meilleurs scores
+menu-quit = Quitter
+menu-rainbow-unlocked = Thème arc-en-ciel débloqué !
+high-scores-menu-title = Tous les meilleurs scores
+leaderboard-initials-label = Nom
+leaderboard-date-label = Date
+leaderboard-switch-hint = ←→ pour changer de difficulté
+high-scores-actions-hint = ↑↓ mode et plateau, O trier, R effacer
+menu-global-leaderboard = Classement mondial
+global-leaderboard-title = Top 20 mondial
+global-leaderboard-hint = ←→ difficulté, ↑↓ page
+global-leaderboard-loading = Chargement...
+global-leaderboard-unavailable = Classement indisponible
+new-record-title = Nouveau record !
+new-record-rank-label = Rang
+new-record-subtitle = { status-score-label } : { $score }  { new-record-rank-label } : #{ $rank }
+initials-save-label = Enregistrer
+config-notice-title = Problème de configuration
+config-notice-continue = Continuer avec les valeurs par défaut
+recover-title = Récupérer la dernière partie ?
+recover-resume = Reprendre
+recover-discard = Abandonner
+high-scores-back-hint = ENTRÉE/ESPACE pour revenir
+menu-back = Retour
+difficulty-menu-title = Choisir la difficulté
+custom-difficulty-title = Difficulté personnalisée
+custom-adjust-hint = ←→ pour régler
+custom-tick-rate-label = Cadence
+custom-progression-label = Accélérations
+custom-power-up-chance-label = Chance de bonus
+custom-wrap-label = Bords traversables
+custom-seed-label = Graine
+custom-seed-random = aléatoire
+settings-pause-on-focus-loss-label = Pause si perte de focus
+settings-resume-on-focus-gain-label = Reprise au retour du focus
+settings-idle-pause-label = Pause si inactif
+settings-sound-label = Son
+settings-ui-compact-label = Interface compacte
+settings-theme-label = Thème
+settings-colorblind-label = Mode daltonien
+settings-high-contrast-label = Contraste élevé
+settings-reduced-motion-label = Animations réduites
+settings-speed-label = Vitesse
+game-speed-difficulty = Selon difficulté
+game-speed-slow = Lente
+game-speed-normal = Normale
+game-speed-fast = Rapide
+game-speed-insane = Démente
+settings-speed-cap-label = Vitesse maximale
+settings-square-cells-label = Cases carrées
+settings-axis-pace-label = Rythme par axe
+axis-pace-auto = Auto
+settings-window-title-label = Titre de fenêtre
+settings-symbols-label = Symboles
+symbol-set-fancy = Élégants
+symbol-set-compatible = Compatibles
+settings-board-pattern-label = Motif du plateau
+board-pattern-plain = Aucun
+board-pattern-dots = Points
+board-pattern-checkerboard = Damier
+settings-hud-position-label = Position du HUD
+hud-position-below = En bas
+hud-position-above = En haut
+hud-position-right = À droite
+settings-hud-compact-label = HUD compact
+settings-sidebar-label = Panneau des effets
+settings-ghost-label = Fantôme du record
+settings-controls-label = Commandes
+control-scheme-arrows = Flèches
+control-scheme-custom = Perso
+settings-power-ups-label = Bonus
+sound-menu-title = Réglages du son
+sound-event-food = Nourriture
+sound-event-power-up = Bonus
+sound-event-death = Fin de partie
+sound-event-ui = Menus
+power-ups-menu-title = Activer les bonus
+power-up-speed-boost = Turbo
+power-up-slow-down = Ralenti
+power-up-extra-points = Points bonus
+power-up-grow = Grandir
+power-up-shrink = Rétrécir
+power-up-shield = Bouclier
+power-up-score-multiplier = Multiplicateur
+settings-reset-high-scores-label = Effacer les scores
+reset-high-scores-title = Effacer les scores ?
+reset-score-table-title = Effacer ce tableau ?
+reset-difficulty-title = Effacer les scores du niveau { $difficulty } ?
+reset-scores-all = Toutes les difficultés
+quit-confirm-title = Quitter le jeu ?
+leave-confirm-title = Retourner au menu ?
+quit-confirm-warning = Cette partie sera perdue
+confirm-yes = Oui
+confirm-no = Non
+setting-on = Oui
+setting-off = Non
+menu-navigation-hint = ↑↓ ou WASD pour naviguer
+menu-confirm-hint = ENTRÉE/ESPACE choisit, Q quitte
+language-name = Français
+language-popup-title = Choisir la langue
+language-label = Langue
+small-window-title = FENÊTRE TROP PETITE
+small-window-current-label = Actuelle
+small-window-minimum-label = Minimum
+spectate-waiting-title = En attente d'une partie...
+spectate-quit-hint = Mode spectateur. Q pour quitter.
+small-window-hint = Agrandissez le terminal pour continuer. Q pour quitter.
+status-score-label = Score
+window-title = Rustnake
+window-title-score = Rustnake — { status-score-label } : { $score }
+status-difficulty-label = Niv
+status-paused = PAUSE
+status-resuming = REPRISE DANS { $seconds }
+status-muted = MUET
+status-slow-motion = RALENTI
+text-food-label = Nourriture
+text-length-label = Longueur
+practice-food-offset = { text-food-label } x{ $dx } y{ $dy }
+toast-new-high-score = Nouveau record !
+toast-card-saved = { $file } enregistré
+toast-idle-paused = En pause après { $seconds } s d'inactivité
+toast-card-failed = Carte non enregistrée
+toast-result-copied = Résultat copié
+bearing-up-left = en haut à gauche
+bearing-up = en haut
+bearing-up-right = en haut à droite
+bearing-left = à gauche
+bearing-here = ici
+bearing-right = à droite
+bearing-down-left = en bas à gauche
+bearing-down = en bas
+bearing-down-right = en bas à droite
+info-best-label = Record
+info-pace-label = Allure
+info-speed-up-label = Accélération
+info-effect-label = Effet
+sidebar-effects-title = Effets
+sidebar-legend-title = Légende
+difficulty-easy = Facile
+difficulty-medium = Moyen
+difficulty-hard = Difficile
+difficulty-extreme = Extrême
+difficulty-custom = Perso
+game-mode-classic = Classique
+game-mode-walls = Murs
+effect-short-speed-boost = Turbo
+effect-short-slow-down = Lent
+effect-short-shield = Bouclier
+effect-short-score-multiplier = Bonus
+game-over-title = PARTIE TERMINÉE !
+game-over-menu-hint = ESPACE pour le menu
+game-over-quit-hint = ou 'q' pour quitter
+game-over-share-hint = 'c' copier, 'e' carte
+game-over-retry-hint = 'r' rejouer les { $ticks } derniers ticks
+points = { $count ->
+        [one] { $count } point
+       *[other] { $count } points
+    }
+summary-time-label = Temps
+summary-food-label = Nourriture mangée
+summary-power-ups-label = Bonus
+summary-fastest-pace-label = Allure max
+summary-best-combo-label = Meilleur combo
+summary-max-length-label = Longueur max
+summary-score = { status-score-label } : { $score }
+summary-time = { summary-time-label } : { $time }
+summary-food = { summary-food-label } : { $count }
+summary-power-ups = { summary-power-ups-label } : { $count }
+summary-fastest-pace = { summary-fastest-pace-label } : { $percent }%
+summary-best-combo = { summary-best-combo-label } : { $count }
+summary-max-length = { summary-max-length-label } : { $length ->
+        [one] { $length } case
+       *[other] { $length } cases
+    }
@@ -0,0 +1,176 @@
+# Japanese. Every locale defines the messages of en.ftl; the build
+# fails when one is missing.
+
+controls-text = WASD/矢印:移動 P:一時停止 M:ミュート SPACE:メニュー Q:終了
+menu-title = スネークゲーム
+menu-play = プレイ
+menu-difficulty = 難易度
+menu-settings = 設定
+menu-high-scores = ハイスコア
+menu-quit = 終了
+menu-rainbow-unlocked = レインボーテーマ解放！
+high-scores-menu-title = すべてのハイスコア
+leaderboard-initials-label = 名前
+leaderboard-date-label = 日付
+leaderboard-switch-hint = ←→ で難易度を切替
+high-scores-actions-hint = ↑↓ でモードと盤面、O で並び替え、R でリセット
+menu-global-leaderboard = 世界ランキング
+global-leaderboard-title = 世界トップ20
+global-leaderboard-hint = ←→ で難易度、↑↓ でページ切替
+global-leaderboard-loading = 読み込み中...
+global-leaderboard-unavailable = ランキングを取得できません
+new-record-title = ハイスコア更新！
+new-record-rank-label = 順位
+new-record-subtitle = { status-score-label }: { $score }  { new-record-rank-label }: #{ $rank }
+initials-save-label = 保存
+config-notice-title = 設定ファイルの問題
+config-notice-continue = 既定値で続行
+recover-title = 前回のゲームを復元しますか？
+recover-resume = 再開
+recover-discard = 破棄
+high-scores-back-hint = ENTER/SPACE で戻る
+menu-back = 戻る
+difficulty-menu-title = 難易度を選択
+custom-difficulty-title = カスタム難易度
+custom-adjust-hint = ←→ で調整
+custom-tick-rate-label = 更新間隔
+custom-progression-label = 加速段階
+custom-power-up-chance-label = 出現率
+custom-wrap-label = 画面端ワープ
+custom-seed-label = シード
+custom-seed-random = ランダム
+settings-pause-on-focus-loss-label = フォーカス喪失で一時停止
+settings-resume-on-focus-gain-label = フォーカス復帰で再開
+settings-idle-pause-label = 放置で一時停止
+settings-sound-label = サウンド
+settings-ui-compact-label = コンパクトUI
+settings-theme-label = テーマ
+settings-colorblind-label = 色覚サポート
+settings-high-contrast-label = ハイコントラスト
+settings-reduced-motion-label = 動きを減らす
+settings-speed-label = 速度
+game-speed-difficulty = 難易度どおり
+game-speed-slow = 遅い
+game-speed-normal = 普通
+game-speed-fast = 速い
+game-speed-insane = 超高速
+settings-speed-cap-label = 速度上限
+settings-square-cells-label = 正方形マス
+settings-axis-pace-label = 縦横の速度補正
+axis-pace-auto = 自動
+settings-window-title-label = ウィンドウタイトル
+settings-symbols-label = 記号
+symbol-set-fancy = 装飾
+symbol-set-compatible = 互換
+settings-board-pattern-label = 盤面の模様
+board-pattern-plain = なし
+board-pattern-dots = 点
+board-pattern-checkerboard = 市松
+settings-hud-position-label = HUDの位置
+hud-position-below = 下
+hud-position-above = 上
+hud-position-right = 右
+settings-hud-compact-label = HUDを簡潔に
+settings-sidebar-label = 効果パネル
+settings-ghost-label = ベストのゴースト
+settings-controls-label = 操作
+control-scheme-arrows = 矢印
+control-scheme-custom = カスタム
+settings-power-ups-label = パワーアップ
+sound-menu-title = サウンド設定
+sound-event-food = エサ
+sound-event-power-up = パワーアップ
+sound-event-death = ゲームオーバー
+sound-event-ui = メニュー
+power-ups-menu-title = パワーアップ設定
+power-up-speed-boost = 加速
+power-up-slow-down = 減速
+power-up-extra-points = ボーナス得点
+power-up-grow = 伸びる
+power-up-shrink = 縮む
+power-up-shield = シールド
+power-up-score-multiplier = スコア倍率
+settings-reset-high-scores-label = ハイスコアをリセット
+reset-high-scores-title = ハイスコアをリセットしますか？
+reset-score-table-title = この表をリセットしますか？
+reset-difficulty-title = { $difficulty } のハイスコアをリセットしますか？
+reset-scores-all = すべての難易度
+quit-confirm-title = ゲームを終了しますか？
+leave-confirm-title = メニューに戻りますか？
+quit-confirm-warning = このプレイは失われます
+confirm-yes = はい
+confirm-no = いいえ
+setting-on = オン
+setting-off = オフ
+menu-navigation-hint = ↑↓ または WASD で移動
+menu-confirm-hint = ENTER/SPACE で決定、Q で終了
+language-name = 日本語
+language-popup-title = 言語を選択
+language-label = 言語
+small-window-title = ウィンドウが小さすぎます
+small-window-current-label = 現在
+small-window-minimum-label = 最小
+spectate-waiting-title = ゲームを待っています...
+spectate-quit-hint = 観戦中。Qで終了。
+small-window-hint = 端末サイズを広げて続行。Qで終了。
+status-score-label = 得点
+window-title = Rustnake
+window-title-score = Rustnake — { status-score-label }: { $score }
+status-difficulty-label = 難易度
+status-paused = 一時停止
+status-resuming = { $seconds } 秒後に再開
+status-muted = 消音
+status-slow-motion = スロー
+text-food-label = エサ
+text-length-label = 長さ
+practice-food-offset = { text-food-label } x{ $dx } y{ $dy }
+toast-new-high-score = ハイスコア更新！
+toast-card-saved = { $file } に保存
+toast-idle-paused = { $seconds } 秒操作がないため一時停止
+toast-card-failed = カードを保存できません
+toast-result-copied = 結果をコピーしました
+bearing-up-left = 左上
+bearing-up = 上
+bearing-up-right = 右上
+bearing-left = 左
+bearing-here = ここ
+bearing-right = 右
+bearing-down-left = 左下
+bearing-down = 下
+bearing-down-right = 右下
+info-best-label = 最高
+info-pace-label = 速度
+info-speed-up-label = 次の加速
+info-effect-label = 効果
+sidebar-effects-title = 効果
+sidebar-legend-title = 凡例
+difficulty-easy = 簡単
+difficulty-medium = 普通
+difficulty-hard = 難しい
+difficulty-extreme = 極限
+difficulty-custom = カスタム
+game-mode-classic = クラシック
+game-mode-walls = 壁あり
+effect-short-speed-boost = 加速
+effect-short-slow-down = 減速
+effect-short-shield = シールド
+effect-short-score-multiplier = 倍率
+game-over-title = ゲームオーバー
+game-over-menu-hint = SPACEでメニューへ
+game-over-quit-hint = 'q'で終了
+game-over-share-hint = 'c'コピー 'e'カード保存
+game-over-retry-hint = 'r'最後の{ $ticks }ティックを再挑戦
+points = { $count }点
+summary-time-label = 時間
+summary-food-label = エサ
+summary-power-ups-label = パワーアップ
+summary-fastest-pace-label = 最速ペース
+summary-best-combo-label = 最大コンボ
+summary-max-length-label = 最大の長さ
+summary-score = { status-score-label }: { $score }
+summary-time = { summary-time-label }: { $time }
+summary-food = { summary-food-label }: { $count }個
+summary-power-ups = { summary-power-ups-label }: { $count }個
+summary-fastest-pace = { summary-fastest-pace-label }: { $percent }%
+summary-best-combo = { summary-best-combo-label }: { $count }連
+summary-max-length = { summary-max-length-label }: { $length }マス
@@ -0,0 +1,176 @@
+# Korean. Every locale defines the messages of en.ftl; the build
+# fails when one is missing.
+
+controls-text = WASD/방향키:이동 P:일시정지 M:음소거 SPACE:메뉴 Q:종료
+menu-title = 스네이크 게임
+menu-play = 시작
+menu-difficulty = 난이도
+menu-settings = 설정
+menu-high-scores = 최고 점수
+menu-quit = 종료
+menu-rainbow-unlocked = 무지개 테마 잠금 해제!
+high-scores-menu-title = 전체 최고 점수
+leaderboard-initials-label = 이름
+leaderboard-date-label = 날짜
+leaderboard-switch-hint = ←→로 난이도 변경
+high-scores-actions-hint = ↑↓ 모드와 보드, O 정렬, R 초기화
+menu-global-leaderboard = 세계 순위
+global-leaderboard-title = 세계 상위 20
+global-leaderboard-hint = ←→ 난이도, ↑↓ 페이지
+global-leaderboard-loading = 불러오는 중...
+global-leaderboard-unavailable = 순위를 불러올 수 없음
+new-record-title = 최고 기록 달성!
+new-record-rank-label = 순위
+new-record-subtitle = { status-score-label }: { $score }  { new-record-rank-label }: #{ $rank }
+initials-save-label = 저장
+config-notice-title = 설정 문제
+config-notice-continue = 기본값으로 계속
+recover-title = 마지막 게임을 복구할까요?
+recover-resume = 이어하기
+recover-discard = 버리기
+high-scores-back-hint = ENTER/SPACE로 돌아가기
+menu-back = 뒤로
+difficulty-menu-title = 난이도 선택
+custom-difficulty-title = 사용자 난이도
+custom-adjust-hint = ←→로 조정
+custom-tick-rate-label = 틱 간격
+custom-progression-label = 가속 단계
+custom-power-up-chance-label = 아이템 확률
+custom-wrap-label = 가장자리 통과
+custom-seed-label = 시드
+custom-seed-random = 무작위
+settings-pause-on-focus-loss-label = 포커스 잃으면 일시정지
+settings-resume-on-focus-gain-label = 포커스 돌아오면 재개
+settings-idle-pause-label = 입력 없으면 일시정지
+settings-sound-label = 소리
+settings-ui-compact-label = 간단한 UI
+settings-theme-label = 테마
+settings-colorblind-label = 색각 이상 모드
+settings-high-contrast-label = 고대비
+settings-reduced-motion-label = 움직임 줄이기
+settings-speed-label = 속도
+game-speed-difficulty = 난이도에 따름
+game-speed-slow = 느림
+game-speed-normal = 보통
+game-speed-fast = 빠름
+game-speed-insane = 광속
+settings-speed-cap-label = 속도 제한
+settings-square-cells-label = 정사각형 칸
+settings-axis-pace-label = 축 속도 보정
+axis-pace-auto = 자동
+settings-window-title-label = 창 제목
+settings-symbols-label = 기호
+symbol-set-fancy = 화려하게
+symbol-set-compatible = 호환
+settings-board-pattern-label = 판 무늬
+board-pattern-plain = 없음
+board-pattern-dots = 점
+board-pattern-checkerboard = 체크무늬
+settings-hud-position-label = HUD 위치
+hud-position-below = 아래
+hud-position-above = 위
+hud-position-right = 오른쪽
+settings-hud-compact-label = 간단한 HUD
+settings-sidebar-label = 효과 패널
+settings-ghost-label = 최고 기록 고스트
+settings-controls-label = 조작
+control-scheme-arrows = 방향키
+control-scheme-custom = 사용자
+settings-power-ups-label = 아이템
+sound-menu-title = 소리 설정
+sound-event-food = 먹이
+sound-event-power-up = 아이템
+sound-event-death = 게임 오버
+sound-event-ui = 메뉴
+power-ups-menu-title = 아이템 켜기/끄기
+power-up-speed-boost = 가속
+power-up-slow-down = 감속
+power-up-extra-points = 추가 점수
+power-up-grow = 늘리기
+power-up-shrink = 줄이기
+power-up-shield = 방패
+power-up-score-multiplier = 점수 배율
+settings-reset-high-scores-label = 최고 점수 초기화
+reset-high-scores-title = 최고 점수를 초기화할까요?
+reset-score-table-title = 이 기록표를 초기화할까요?
+reset-difficulty-title = { $difficulty } 최고 점수를 초기화할까요?
+reset-scores-all = 모든 난이도
+quit-confirm-title = 게임을 종료할까요?
+leave-confirm-title = 메뉴로 돌아갈까요?
+quit-confirm-warning = 이번 판은 사라집니다
+confirm-yes = 예
+confirm-no = 아니요
+setting-on = 켬
+setting-off = 끔
+menu-navigation-hint = ↑↓ 또는 WASD로 이동
+menu-confirm-hint = ENTER/SPACE 선택, Q 종료
+language-name = 한국어
+language-popup-title = 언어 선택
+language-label = 언어
+small-window-title = 창이 너무 작음
+small-window-current-label = 현재
+small-window-minimum-label = 최소
+spectate-waiting-title = 게임 기다리는 중...
+spectate-quit-hint = 관전 중. Q로 종료.
+small-window-hint = 계속하려면 터미널을 키우세요. Q로 종료.
+status-score-label = 점수
+window-title = Rustnake
+window-title-score = Rustnake — { status-score-label }: { $score }
+status-difficulty-label = 난이도
+status-paused = 일시정지
+status-resuming = { $seconds }초 후 재개
+status-muted = 음소거
+status-slow-motion = 슬로모션
+text-food-label = 먹이
+text-length-label = 길이
+practice-food-offset = { text-food-label } x{ $dx } y{ $dy }
+toast-new-high-score = 최고 기록!
+toast-card-saved = { $file } 저장됨
+toast-idle-paused = { $seconds }초 동안 입력이 없어 일시정지
+toast-card-failed = 카드를 저장할 수 없음
+toast-result-copied = 결과 복사됨
+bearing-up-left = 왼쪽 위
+bearing-up = 위
+bearing-up-right = 오른쪽 위
+bearing-left = 왼쪽
+bearing-here = 여기
+bearing-right = 오른쪽
+bearing-down-left = 왼쪽 아래
+bearing-down = 아래
+bearing-down-right = 오른쪽 아래
+info-best-label = 최고
+info-pace-label = 속도
+info-speed-up-label = 다음 가속
+info-effect-label = 효과
+sidebar-effects-title = 효과
+sidebar-legend-title = 범례
+difficulty-easy = 쉬움
+difficulty-medium = 보통
+difficulty-hard = 어려움
+difficulty-extreme = 극한
+difficulty-custom = 사용자
+game-mode-classic = 클래식
+game-mode-walls = 벽
+effect-short-speed-boost = 가속
+effect-short-slow-down = 감속
+effect-short-shield = 방패
+effect-short-score-multiplier = 배율
+game-over-title = 게임 오버!
+game-over-menu-hint = SPACE로 메뉴
+game-over-quit-hint = 'q'로 종료
+game-over-share-hint = 'c' 복사, 'e' 카드 저장
+game-over-retry-hint = 'r' 마지막 { $ticks }틱 재도전
+points = { $count }점
+summary-time-label = 시간
+summary-food-label = 먹은 먹이
+summary-power-ups-label = 아이템
+summary-fastest-pace-label = 최고 속도
+summary-best-combo-label = 최고 콤보
+summary-max-length-label = 최대 길이
+summary-score = { status-score-label }: { $score }
+summary-time = { summary-time-label }: { $time }
+summary-food = { summary-food-label }: { $count }개
+summary-power-ups = { summary-power-ups-label }: { $count }개
+summary-fastest-pace = { summary-fastest-pace-label }: { $percent }%
+summary-best-combo = { summary-best-combo-label }: { $count }연속
+summary-max-length = { summary-max-length-label }: { $length }칸
@@ -0,0 +1,182 @@
+# Portuguese. Every locale defines the messages of en.ftl; the build
+# fails when one is missing.
+
+controls-text = WASD/Setas:Mover P:Pausa M:Silenciar ESPAÇO:Menu Q:Sair
+menu-title = SNAKE GAME
+menu-play = Jogar
+menu-difficulty = Dificuldade
+menu-settings = Configuracoes
+menu-high-scores = Pontuacoes
+menu-quit = Sair
+menu-rainbow-unlocked = Tema arco-íris desbloqueado!
+high-scores-menu-title = Todas as pontuacoes
+leaderboard-initials-label = Nome
+leaderboard-date-label = Data
+leaderboard-switch-hint = Use ←→ para trocar dificuldade
+high-scores-actions-hint = ↑↓ modo e tabuleiro, O ordenar, R resetar
+menu-global-leaderboard = Ranking global
+global-leaderboard-title = Top 20 mundial
+global-leaderboard-hint = Use ←→ para dificuldade, ↑↓ para página
+global-leaderboard-loading = Carregando...
+global-leaderboard-unavailable = Ranking indisponível
+new-record-title = Nova pontuação recorde!
+new-record-rank-label = Posição
+new-record-subtitle = { status-score-label }: { $score }  { new-record-rank-label }: #{ $rank }
+initials-save-label = Salvar
+config-notice-title = Problema de configuração
+config-notice-continue = Continuar com padrões
+recover-title = Recuperar o último jogo?
+recover-resume = Retomar
+recover-discard = Descartar
+high-scores-back-hint = Pressione ENTER/ESPAÇO para voltar
+menu-back = Voltar
+difficulty-menu-title = Selecionar dificuldade
+custom-difficulty-title = Dificuldade personalizada
+custom-adjust-hint = Use ←→ para ajustar
+custom-tick-rate-label = Intervalo
+custom-progression-label = Acelerações
+custom-power-up-chance-label = Chance de power-up
+custom-wrap-label = Bordas abertas
+custom-seed-label = Semente
+custom-seed-random = aleatória
+settings-pause-on-focus-loss-label = Pausar ao perder foco
+settings-resume-on-focus-gain-label = Retomar ao recuperar foco
+settings-idle-pause-label = Pausa por inatividade
+settings-sound-label = Som
+settings-ui-compact-label = UI compacta
+settings-theme-label = Tema
+settings-colorblind-label = Modo daltônico
+settings-high-contrast-label = Alto contraste
+settings-reduced-motion-label = Movimento reduzido
+settings-speed-label = Velocidade
+game-speed-difficulty = Pela dificuldade
+game-speed-slow = Lenta
+game-speed-normal = Normal
+game-speed-fast = Rápida
+game-speed-insane = Insana
+settings-speed-cap-label = Limite de velocidade
+settings-square-cells-label = Células quadradas
+settings-axis-pace-label = Ritmo por eixo
+axis-pace-auto = Auto
+settings-window-title-label = Título da janela
+settings-symbols-label = Símbolos
+symbol-set-fancy = Elegantes
+symbol-set-compatible = Compatíveis
+settings-board-pattern-label = Fundo do tabuleiro
+board-pattern-plain = Nenhum
+board-pattern-dots = Pontos
+board-pattern-checkerboard = Xadrez
+settings-hud-position-label = Posição do HUD
+hud-position-below = Abaixo
+hud-position-above = Acima
+hud-position-right = Direita
+settings-hud-compact-label = HUD compacto
+settings-sidebar-label = Painel de efeitos
+settings-ghost-label = Fantasma do recorde
+settings-controls-label = Controles
+control-scheme-arrows = Setas
+control-scheme-custom = Personalizado
+settings-power-ups-label = Power-ups
+sound-menu-title = Configurações de som
+sound-event-food = Comida
+sound-event-power-up = Power-ups
+sound-event-death = Fim de jogo
+sound-event-ui = Menus
+power-ups-menu-title = Ativar power-ups
+power-up-speed-boost = Turbo
+power-up-slow-down = Câmera lenta
+power-up-extra-points = Pontos extras
+power-up-grow = Crescer
+power-up-shrink = Encolher
+power-up-shield = Escudo
+power-up-score-multiplier = Multiplicador
+settings-reset-high-scores-label = Resetar pontuacoes
+reset-high-scores-title = Resetar pontuacoes?
+reset-score-table-title = Resetar esta tabela?
+reset-difficulty-title = Resetar pontuacoes de { $difficulty }?
+reset-scores-all = Todas as dificuldades
+quit-confirm-title = Sair do jogo?
+leave-confirm-title = Voltar ao menu?
+quit-confirm-warning = Esta partida será perdida
+confirm-yes = Sim
+confirm-no = Nao
+setting-on = Ligado
+setting-off = Desligado
+menu-navigation-hint = Use ↑↓ ou WASD para navegar
+menu-confirm-hint = Pressione ENTER/ESPAÇO para escolher, Q para sair
+language-name = Português
+language-popup-title = Selecionar idioma
+language-label = Idioma
+small-window-title = JANELA MUITO PEQUENA
+small-window-current-label = Atual
+small-window-minimum-label = Mínimo
+spectate-waiting-title = Aguardando uma partida...
+spectate-quit-hint = Assistindo. Pressione Q para sair.
+small-window-hint = Ajuste o terminal para continuar. Pressione Q para sair.
+status-score-label = Pontos
+window-title = Rustnake
+window-title-score = Rustnake — { status-score-label }: { $score }
+status-difficulty-label = Nível
+status-paused = PAUSADO
+status-resuming = RETOMANDO EM { $seconds }
+status-muted = SEM SOM
+status-slow-motion = LENTO
+text-food-label = Comida
+text-length-label = Comprimento
+practice-food-offset = { text-food-label } x{ $dx } y{ $dy }
+toast-new-high-score = Novo recorde!
+toast-card-saved = Salvo { $file }
+toast-idle-paused = Pausado após { $seconds } s sem atividade
+toast-card-failed = Não foi possível salvar o cartão
+toast-result-copied = Resultado copiado
+bearing-up-left = acima à esquerda
+bearing-up = acima
+bearing-up-right = acima à direita
+bearing-left = esquerda
+bearing-here = aqui
+bearing-right = direita
+bearing-down-left = abaixo à esquerda
+bearing-down = abaixo
+bearing-down-right = abaixo à direita
+info-best-label = Melhor
+info-pace-label = Ritmo
+info-speed-up-label = Aceleração
+info-effect-label = Efeito
+sidebar-effects-title = Efeitos
+sidebar-legend-title = Legenda
+difficulty-easy = Fácil
+difficulty-medium = Médio
+difficulty-hard = Difícil
+difficulty-extreme = Extremo
+difficulty-custom = Personalizado
+game-mode-classic = Clássico
+game-mode-walls = Paredes
+effect-short-speed-boost = Turbo
+effect-short-slow-down = Lento
+effect-short-shield = Escudo
+effect-short-score-multiplier = Bônus
+game-over-title = FIM DE JOGO
+game-over-menu-hint = Pressione ESPAÇO para o menu
+game-over-quit-hint = ou 'q' para sair
+game-over-share-hint = 'c' copiar, 'e' salvar cartão
+game-over-retry-hint = 'r' repetir os últimos { $ticks } ticks
+points = { $count ->
+        [one] { $count } ponto
+       *[other] { $count } pontos
+    }
+summary-time-label = Tempo
+summary-food-label = Comida
+summary-power-ups-label = Power-ups
+summary-fastest-pace-label = Ritmo máximo
+summary-best-combo-label = Melhor combo
+summary-max-length-label = Comprimento máximo
+summary-score = { status-score-label }: { $score }
+summary-time = { summary-time-label }: { $time }
+summary-food = { summary-food-label }: { $count }
+summary-power-ups = { summary-power-ups-label }: { $count }
+summary-fastest-pace = { summary-fastest-pace-label }: { $percent }%
+summary-best-combo = { summary-best-combo-label }: { $count }
+summary-max-length = { summary-max-length-label }: { $length ->
+        [one] { $length } célula
+       *[other] { $length } células
+    }
@@ -0,0 +1,184 @@
+# Russian. Every locale defines the messages of en.ftl; the build
+# fails when one is missing.
+
+controls-text = WASD/Стрелки:Движение P:Пауза M:Звук ПРОБЕЛ:Меню Q:Выход
+menu-title = ЗМЕЙКА
+menu-play = Играть
+menu-difficulty = Сложность
+menu-settings = Настройки
+menu-high-scores = Рекорды
+menu-quit = Выход
+menu-rainbow-unlocked = Радужная тема открыта!
+high-scores-menu-title = Все рекорды
+leaderboard-initials-label = Имя
+leaderboard-date-label = Дата
+leaderboard-switch-hint = ←→ меняет сложность
+high-scores-actions-hint = ↑↓ режим и поле, O сортировка, R сброс
+menu-global-leaderboard = Мировой рейтинг
+global-leaderboard-title = Мировой топ-20
+global-leaderboard-hint = ←→ сложность, ↑↓ страница
+global-leaderboard-loading = Загрузка...
+global-leaderboard-unavailable = Рейтинг недоступен
+new-record-title = Новый рекорд!
+new-record-rank-label = Место
+new-record-subtitle = { status-score-label }: { $score }  { new-record-rank-label }: #{ $rank }
+initials-save-label = Сохранить
+config-notice-title = Ошибка настроек
+config-notice-continue = Продолжить со значениями по умолчанию
+recover-title = Восстановить последнюю игру?
+recover-resume = Продолжить
+recover-discard = Удалить
+high-scores-back-hint = ENTER/ПРОБЕЛ, чтобы вернуться
+menu-back = Назад
+difficulty-menu-title = Выберите сложность
+custom-difficulty-title = Своя сложность
+custom-adjust-hint = ←→ для настройки
+custom-tick-rate-label = Такт
+custom-progression-label = Ускорения
+custom-power-up-chance-label = Шанс бонуса
+custom-wrap-label = Сквозные края
+custom-seed-label = Сид
+custom-seed-random = случайный
+settings-pause-on-focus-loss-label = Пауза при потере фокуса
+settings-resume-on-focus-gain-label = Продолжать при возврате фокуса
+settings-idle-pause-label = Пауза при бездействии
+settings-sound-label = Звук
+settings-ui-compact-label = Компактный вид
+settings-theme-label = Тема
+settings-colorblind-label = Для дальтоников
+settings-high-contrast-label = Высокий контраст
+settings-reduced-motion-label = Меньше анимации
+settings-speed-label = Скорость
+game-speed-difficulty = По сложности
+game-speed-slow = Медленно
+game-speed-normal = Обычно
+game-speed-fast = Быстро
+game-speed-insane = Безумно
+settings-speed-cap-label = Предел скорости
+settings-square-cells-label = Квадратные клетки
+settings-axis-pace-label = Темп по осям
+axis-pace-auto = Авто
+settings-window-title-label = Заголовок окна
+settings-symbols-label = Символы
+symbol-set-fancy = Красивые
+symbol-set-compatible = Совместимые
+settings-board-pattern-label = Узор поля
+board-pattern-plain = Нет
+board-pattern-dots = Точки
+board-pattern-checkerboard = Шахматы
+settings-hud-position-label = Положение HUD
+hud-position-below = Снизу
+hud-position-above = Сверху
+hud-position-right = Справа
+settings-hud-compact-label = Компактный HUD
+settings-sidebar-label = Панель эффектов
+settings-ghost-label = Призрак рекорда
+settings-controls-label = Управление
+control-scheme-arrows = Стрелки
+control-scheme-custom = Свои
+settings-power-ups-label = Бонусы
+sound-menu-title = Настройки звука
+sound-event-food = Еда
+sound-event-power-up = Бонусы
+sound-event-death = Конец игры
+sound-event-ui = Меню
+power-ups-menu-title = Включить бонусы
+power-up-speed-boost = Ускорение
+power-up-slow-down = Замедление
+power-up-extra-points = Доп. очки
+power-up-grow = Рост
+power-up-shrink = Сжатие
+power-up-shield = Щит
+power-up-score-multiplier = Множитель очков
+settings-reset-high-scores-label = Сбросить рекорды
+reset-high-scores-title = Сбросить рекорды?
+reset-score-table-title = Сбросить эту таблицу?
+reset-difficulty-title = Сбросить рекорды уровня «{ $difficulty }»?
+reset-scores-all = Все уровни сложности
+quit-confirm-title = Выйти из игры?
+leave-confirm-title = Вернуться в меню?
+quit-confirm-warning = Эта игра будет потеряна
+confirm-yes = Да
+confirm-no = Нет
+setting-on = Вкл
+setting-off = Выкл
+menu-navigation-hint = ↑↓ или WASD для выбора
+menu-confirm-hint = ENTER/ПРОБЕЛ выбрать, Q выход
+language-name = Русский
+language-popup-title = Выберите язык
+language-label = Язык
+small-window-title = ОКНО СЛИШКОМ МАЛО
+small-window-current-label = Сейчас
+small-window-minimum-label = Минимум
+spectate-waiting-title = Ожидание игры...
+spectate-quit-hint = Режим зрителя. Q для выхода.
+small-window-hint = Увеличьте терминал, чтобы продолжить. Q для выхода.
+status-score-label = Очки
+window-title = Rustnake
+window-title-score = Rustnake — { status-score-label }: { $score }
+status-difficulty-label = Ур.
+status-paused = ПАУЗА
+status-resuming = ПРОДОЛЖЕНИЕ ЧЕРЕЗ { $seconds }
+status-muted = БЕЗ ЗВУКА
+status-slow-motion = ЗАМЕДЛЕНИЕ
+text-food-label = Еда
+text-length-label = Длина
+practice-food-offset = { text-food-label } x{ $dx } y{ $dy }
+toast-new-high-score = Новый рекорд!
+toast-card-saved = Сохранено: { $file }
+toast-idle-paused = Пауза после { $seconds } с бездействия
+toast-card-failed = Не удалось сохранить карточку
+toast-result-copied = Результат скопирован
+bearing-up-left = вверху слева
+bearing-up = вверху
+bearing-up-right = вверху справа
+bearing-left = слева
+bearing-here = здесь
+bearing-right = справа
+bearing-down-left = внизу слева
+bearing-down = внизу
+bearing-down-right = внизу справа
+info-best-label = Рекорд
+info-pace-label = Темп
+info-speed-up-label = Ускорение
+info-effect-label = Эффект
+sidebar-effects-title = Эффекты
+sidebar-legend-title = Легенда
+difficulty-easy = Легко
+difficulty-medium = Средне
+difficulty-hard = Сложно
+difficulty-extreme = Экстрим
+difficulty-custom = Своя
+game-mode-classic = Классика
+game-mode-walls = Стены
+effect-short-speed-boost = Турбо
+effect-short-slow-down = Медл.
+effect-short-shield = Щит
+effect-short-score-multiplier = Бонус
+game-over-title = ИГРА ОКОНЧЕНА!
+game-over-menu-hint = ПРОБЕЛ для меню
+game-over-quit-hint = или 'q' для выхода
+game-over-share-hint = 'c' копировать, 'e' карточка
+game-over-retry-hint = 'r' переиграть последние { $ticks } тиков
+points = { $count ->
+        [one] { $count } очко
+        [few] { $count } очка
+       *[other] { $count } очков
+    }
+summary-time-label = Время
+summary-food-label = Съедено
+summary-power-ups-label = Бонусы
+summary-fastest-pace-label = Макс. темп
+summary-best-combo-label = Лучшее комбо
+summary-max-length-label = Макс. длина
+summary-score = { status-score-label }: { $score }
+summary-time = { summary-time-label }: { $time }
+summary-food = { summary-food-label }: { $count }
+summary-power-ups = { summary-power-ups-label }: { $count }
+summary-fastest-pace = { summary-fastest-pace-label }: { $percent }%
+summary-best-combo = { summary-best-combo-label }: { $count }
+summary-max-length = { summary-max-length-label }: { $length ->
+        [one] { $length } клетка
+        [few] { $length } клетки
+       *[other] { $length } клеток
+    }
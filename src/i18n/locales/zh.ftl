@@ -0,0 +1,176 @@
+# Simplified Chinese. Every locale defines the messages of en.ftl; the build
+# fails when one is missing.
+
+controls-text = WASD/方向键:移动 P:暂停 M:静音 SPACE:菜单 Q:退出
+menu-title = 贪吃蛇
+menu-play = 开始
+menu-difficulty = 难度
+menu-settings = 设置
+menu-high-scores = 最高分
+menu-quit = 退出
+menu-rainbow-unlocked = 彩虹主题已解锁！
+high-scores-menu-title = 全部最高分
+leaderboard-initials-label = 名字
+leaderboard-date-label = 日期
+leaderboard-switch-hint = 使用 ←→ 切换难度
+high-scores-actions-hint = ↑↓ 切换模式和棋盘，O 排序，R 重置
+menu-global-leaderboard = 全球排行榜
+global-leaderboard-title = 全球前20名
+global-leaderboard-hint = ←→ 切换难度，↑↓ 翻页
+global-leaderboard-loading = 加载中...
+global-leaderboard-unavailable = 排行榜不可用
+new-record-title = 新的最高分！
+new-record-rank-label = 排名
+new-record-subtitle = { status-score-label }: { $score }  { new-record-rank-label }: #{ $rank }
+initials-save-label = 保存
+config-notice-title = 配置文件问题
+config-notice-continue = 使用默认值继续
+recover-title = 恢复上一局游戏？
+recover-resume = 继续
+recover-discard = 放弃
+high-scores-back-hint = 按 ENTER/SPACE 返回
+menu-back = 返回
+difficulty-menu-title = 选择难度
+custom-difficulty-title = 自定义难度
+custom-adjust-hint = 使用 ←→ 调整
+custom-tick-rate-label = 刷新间隔
+custom-progression-label = 加速级数
+custom-power-up-chance-label = 道具概率
+custom-wrap-label = 穿墙
+custom-seed-label = 种子
+custom-seed-random = 随机
+settings-pause-on-focus-loss-label = 失去焦点时暂停
+settings-resume-on-focus-gain-label = 恢复焦点时继续
+settings-idle-pause-label = 闲置时暂停
+settings-sound-label = 声音
+settings-ui-compact-label = 紧凑界面
+settings-theme-label = 主题
+settings-colorblind-label = 色盲模式
+settings-high-contrast-label = 高对比度
+settings-reduced-motion-label = 减少动态效果
+settings-speed-label = 速度
+game-speed-difficulty = 跟随难度
+game-speed-slow = 慢
+game-speed-normal = 正常
+game-speed-fast = 快
+game-speed-insane = 疯狂
+settings-speed-cap-label = 速度上限
+settings-square-cells-label = 方形格子
+settings-axis-pace-label = 纵横速度校正
+axis-pace-auto = 自动
+settings-window-title-label = 窗口标题
+settings-symbols-label = 符号
+symbol-set-fancy = 精美
+symbol-set-compatible = 兼容
+settings-board-pattern-label = 棋盘背景
+board-pattern-plain = 无
+board-pattern-dots = 圆点
+board-pattern-checkerboard = 棋盘格
+settings-hud-position-label = HUD 位置
+hud-position-below = 下方
+hud-position-above = 上方
+hud-position-right = 右侧
+settings-hud-compact-label = 紧凑 HUD
+settings-sidebar-label = 效果面板
+settings-ghost-label = 最佳成绩幽灵
+settings-controls-label = 操作
+control-scheme-arrows = 方向键
+control-scheme-custom = 自定义
+settings-power-ups-label = 道具
+sound-menu-title = 声音设置
+sound-event-food = 食物
+sound-event-power-up = 道具
+sound-event-death = 游戏结束
+sound-event-ui = 菜单
+power-ups-menu-title = 道具开关
+power-up-speed-boost = 加速
+power-up-slow-down = 减速
+power-up-extra-points = 额外分数
+power-up-grow = 变长
+power-up-shrink = 变短
+power-up-shield = 护盾
+power-up-score-multiplier = 分数倍增
+settings-reset-high-scores-label = 重置最高分
+reset-high-scores-title = 重置最高分？
+reset-score-table-title = 重置此排行榜？
+reset-difficulty-title = 重置{ $difficulty }的最高分？
+reset-scores-all = 所有难度
+quit-confirm-title = 退出游戏？
+leave-confirm-title = 返回菜单？
+quit-confirm-warning = 本局进度将丢失
+confirm-yes = 是
+confirm-no = 否
+setting-on = 开
+setting-off = 关
+menu-navigation-hint = 使用 ↑↓ 或 WASD 进行选择
+menu-confirm-hint = 按 ENTER/SPACE 确认，Q 退出
+language-name = 简体中文
+language-popup-title = 选择语言
+language-label = 语言
+small-window-title = 窗口太小
+small-window-current-label = 当前
+small-window-minimum-label = 最小
+spectate-waiting-title = 等待游戏开始...
+spectate-quit-hint = 观战中。按 Q 退出。
+small-window-hint = 请调整终端大小后继续。按 Q 退出。
+status-score-label = 分数
+window-title = Rustnake
+window-title-score = Rustnake — { status-score-label }: { $score }
+status-difficulty-label = 难度
+status-paused = 暂停
+status-resuming = { $seconds } 秒后继续
+status-muted = 静音
+status-slow-motion = 慢动作
+text-food-label = 食物
+text-length-label = 长度
+practice-food-offset = { text-food-label } x{ $dx } y{ $dy }
+toast-new-high-score = 新纪录！
+toast-card-saved = 已保存 { $file }
+toast-idle-paused = 闲置 { $seconds } 秒，已暂停
+toast-card-failed = 无法保存卡片
+toast-result-copied = 成绩已复制
+bearing-up-left = 左上
+bearing-up = 上
+bearing-up-right = 右上
+bearing-left = 左
+bearing-here = 这里
+bearing-right = 右
+bearing-down-left = 左下
+bearing-down = 下
+bearing-down-right = 右下
+info-best-label = 最佳
+info-pace-label = 速度
+info-speed-up-label = 下次加速
+info-effect-label = 效果
+sidebar-effects-title = 效果
+sidebar-legend-title = 图例
+difficulty-easy = 简单
+difficulty-medium = 普通
+difficulty-hard = 困难
+difficulty-extreme = 极限
+difficulty-custom = 自定义
+game-mode-classic = 经典
+game-mode-walls = 有墙
+effect-short-speed-boost = 加速
+effect-short-slow-down = 减速
+effect-short-shield = 护盾
+effect-short-score-multiplier = 倍率
+game-over-title = 游戏结束
+game-over-menu-hint = 按 SPACE 返回菜单
+game-over-quit-hint = 或按 'q' 退出
+game-over-share-hint = 'c' 复制，'e' 保存卡片
+game-over-retry-hint = 'r' 重试最后 { $ticks } 个刻
+points = { $count } 分
+summary-time-label = 时间
+summary-food-label = 食物
+summary-power-ups-label = 道具
+summary-fastest-pace-label = 最快速度
+summary-best-combo-label = 最高连击
+summary-max-length-label = 最大长度
+summary-score = { status-score-label }: { $score }
+summary-time = { summary-time-label }: { $time }
+summary-food = { summary-food-label }: { $count } 个
+summary-power-ups = { summary-power-ups-label }: { $count } 个
+summary-fastest-pace = { summary-fastest-pace-label }: { $percent }%
+summary-best-combo = { summary-best-combo-label }: { $count } 连
+summary-max-length = { summary-max-length-label }: { $length } 格
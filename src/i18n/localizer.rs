@@ -0,0 +1,161 @@
+//! Messages from the Fluent files in `locales/`, one per language, embedded
+//! at compile time. `build.rs` fails the build when a file does not parse or
+//! lacks a message `en.ftl` has, so every lookup of a known id succeeds.
+//! Translation packs follow the built-in languages; a pack message that
+//! does not format falls back to English.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use fluent_syntax::ast::Entry;
+use unic_langid::LanguageIdentifier;
+
+use crate::utils::Language;
+
+use super::packs::packs;
+
+/// Code, writing direction, and Fluent source of each language, in
+/// `Language::ALL` order.
+const SOURCES: [(&str, bool, &str); Language::ALL.len()] = [
+    ("en", LEFT_TO_RIGHT, ENGLISH_SOURCE),
+    ("es", LEFT_TO_RIGHT, include_str!("locales/es.ftl")),
+    ("ja", LEFT_TO_RIGHT, include_str!("locales/ja.ftl")),
+    ("pt", LEFT_TO_RIGHT, include_str!("locales/pt.ftl")),
+    ("zh", LEFT_TO_RIGHT, include_str!("locales/zh.ftl")),
+    ("de", LEFT_TO_RIGHT, include_str!("locales/de.ftl")),
+    ("fr", LEFT_TO_RIGHT, include_str!("locales/fr.ftl")),
+    ("ru", LEFT_TO_RIGHT, include_str!("locales/ru.ftl")),
+    ("ko", LEFT_TO_RIGHT, include_str!("locales/ko.ftl")),
+    ("ar", RIGHT_TO_LEFT, include_str!("locales/ar.ftl")),
+];
+
+/// The messages every language defines.
+pub(super) const ENGLISH_SOURCE: &str = include_str!("locales/en.ftl");
+
+const LEFT_TO_RIGHT: bool = false;
+const RIGHT_TO_LEFT: bool = true;
+
+/// Whether `language` is written right to left.
+pub fn is_right_to_left(language: Language) -> bool {
+    match SOURCES.get(language.to_index()) {
+        Some((_, right_to_left, _)) => *right_to_left,
+        None => packs()
+            .get(language.to_index() - SOURCES.len())
+            .is_some_and(|pack| pack.right_to_left),
+    }
+}
+
+/// Ids of the messages in `resource`.
+pub(super) fn message_ids(resource: &FluentResource) -> Vec<String> {
+    resource
+        .entries()
+        .filter_map(|entry| match entry {
+            Entry::Message(message) => Some(message.id.name.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+pub struct Localizer {
+    bundles: Vec<FluentBundle<FluentResource>>,
+    /// Messages that take no arguments, formatted once up front so lookups
+    /// can hand out plain string slices.
+    texts: Vec<HashMap<String, String>>,
+}
+
+impl Localizer {
+    fn new() -> Self {
+        let mut bundles = Vec::new();
+        let mut texts = Vec::new();
+        let built_in = SOURCES
+            .iter()
+            .map(|(code, _, source)| (*code, source.to_string()));
+        let packs = packs()
+            .iter()
+            .map(|pack| (pack.code.as_str(), pack.source.clone()));
+        for (code, source) in built_in.chain(packs) {
+            let resource = FluentResource::try_new(source)
+                .unwrap_or_else(|_| panic!("the {code} messages do not parse"));
+            let ids = message_ids(&resource);
+            let language: LanguageIdentifier = code.parse().expect("locale codes are valid");
+            let mut bundle = FluentBundle::new_concurrent(vec![language]);
+            // The terminal lays text out itself; bidi isolation marks would
+            // only show up as stray characters.
+            bundle.set_use_isolating(false);
+            bundle
+                .add_resource(resource)
+                .unwrap_or_else(|_| panic!("the {code} messages define one twice"));
+
+            let formatted = ids
+                .into_iter()
+                .filter_map(|id| {
+                    let pattern = bundle.get_message(&id)?.value()?;
+                    let mut errors = Vec::new();
+                    let value = bundle.format_pattern(pattern, None, &mut errors);
+                    // Messages that need arguments go through `format` instead.
+                    errors.is_empty().then(|| (id, value.into_owned()))
+                })
+                .collect();
+            bundles.push(bundle);
+            texts.push(formatted);
+        }
+        Self { bundles, texts }
+    }
+
+    /// Message `id`, which takes no arguments.
+    pub fn text(&self, language: Language, id: &str) -> &str {
+        self.texts[language.to_index()]
+            .get(id)
+            .or_else(|| self.texts[Language::En.to_index()].get(id))
+            .unwrap_or_else(|| panic!("no message {id} without arguments"))
+    }
+
+    /// Message `id` with its `{ $name }` placeables filled in from `args`.
+    pub fn format(&self, language: Language, id: &str, args: &FluentArgs) -> String {
+        let bundle = &self.bundles[language.to_index()];
+        let pattern = bundle
+            .get_message(id)
+            .and_then(|message| message.value())
+            .unwrap_or_else(|| panic!("no message {id}"));
+        let mut errors = Vec::new();
+        let value = bundle.format_pattern(pattern, Some(args), &mut errors);
+        debug_assert!(
+            errors.is_empty() || matches!(language, Language::Pack(_)),
+            "formatting {id}: {errors:?}"
+        );
+        value.into_owned()
+    }
+}
+
+/// The localizer, loaded on first use.
+pub fn localizer() -> &'static Localizer {
+    static LOCALIZER: OnceLock<Localizer> = OnceLock::new();
+    LOCALIZER.get_or_init(Localizer::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_resolve_references_and_arguments() {
+        let localizer = localizer();
+        assert_eq!(localizer.text(Language::Es, "menu-play"), "Jugar");
+        assert_eq!(localizer.text(Language::Ar, "menu-play"), "العب");
+        assert!(is_right_to_left(Language::Ar) && !is_right_to_left(Language::En));
+        let mut args = FluentArgs::new();
+        args.set("score", 1200);
+        assert_eq!(
+            localizer.format(Language::En, "window-title-score", &args),
+            "Rustnake — Score: 1200"
+        );
+        for language in Language::ALL {
+            assert_eq!(
+                localizer.texts[language.to_index()].len(),
+                localizer.texts[0].len()
+            );
+        }
+    }
+}
@@ -1,458 +1,710 @@
-//! Translation helpers for all user-facing text.
+//! Translation helpers for all user-facing text. The text itself lives in
+//! the Fluent files under `locales/`; these functions pick the message.
 
-use crate::utils::{Difficulty, Language, PowerUpType};
-use unicode_width::UnicodeWidthStr;
+use crate::utils::{
+    BoardPattern, ControlScheme, CustomDifficulty, Difficulty, GameMode, GameSpeed, HudPosition,
+    Language, PowerUpType, SoundEvent, SymbolSet,
+};
+use fluent_bundle::FluentValue;
+use rustnake::scores::ScoreKey;
 
+mod detect;
+mod localizer;
+mod packs;
+
+pub use detect::detect;
+pub use localizer::is_right_to_left;
+use localizer::localizer;
+pub use packs::load_packs;
+
+/// Columns `text` takes on screen, counted the way the renderer lays it out.
 fn text_width(text: &str) -> u16 {
-    UnicodeWidthStr::width(text) as u16
+    crate::render::display_width(text)
+}
+
+fn text(language: Language, id: &str) -> &'static str {
+    localizer().text(language, id)
+}
+
+/// Message `id` with its `{ $name }` placeables filled in from `args`.
+/// Numbers also pick the message's plural variant by the rules of
+/// `language`, so "1 point" and "2 points" come from one message.
+fn format<'a>(
+    language: Language,
+    id: &str,
+    args: impl IntoIterator<Item = (&'a str, FluentValue<'a>)>,
+) -> String {
+    localizer().format(language, id, &args.into_iter().collect())
 }
 
 pub fn controls_text(language: Language) -> &'static str {
-    match language {
-        Language::En => "WASD/Arrows:Move P:Pause M:Mute SPACE:Menu Q:Quit",
-        Language::Es => "WASD/Flechas:Mover P:Pausa M:Mutear ESPACIO:Menú Q:Salir",
-        Language::Ja => "WASD/矢印:移動 P:一時停止 M:ミュート SPACE:メニュー Q:終了",
-        Language::Pt => "WASD/Setas:Mover P:Pausa M:Silenciar ESPAÇO:Menu Q:Sair",
-        Language::Zh => "WASD/方向键:移动 P:暂停 M:静音 SPACE:菜单 Q:退出",
-    }
+    text(language, "controls-text")
 }
 
 pub fn menu_title(language: Language) -> &'static str {
-    match language {
-        Language::En => "SNAKE GAME",
-        Language::Es => "SNAKE GAME",
-        Language::Ja => "スネークゲーム",
-        Language::Pt => "SNAKE GAME",
-        Language::Zh => "贪吃蛇",
-    }
+    text(language, "menu-title")
 }
 
 pub fn menu_play(language: Language) -> &'static str {
-    match language {
-        Language::En => "Play",
-        Language::Es => "Jugar",
-        Language::Ja => "プレイ",
-        Language::Pt => "Jogar",
-        Language::Zh => "开始",
-    }
+    text(language, "menu-play")
 }
 
 pub fn menu_difficulty(language: Language) -> &'static str {
-    match language {
-        Language::En => "Difficulty",
-        Language::Es => "Dificultad",
-        Language::Ja => "難易度",
-        Language::Pt => "Dificuldade",
-        Language::Zh => "难度",
-    }
+    text(language, "menu-difficulty")
 }
 
 pub fn menu_settings(language: Language) -> &'static str {
-    match language {
-        Language::En => "Settings",
-        Language::Es => "Ajustes",
-        Language::Ja => "設定",
-        Language::Pt => "Configuracoes",
-        Language::Zh => "设置",
-    }
+    text(language, "menu-settings")
 }
 
 pub fn menu_high_scores(language: Language) -> &'static str {
-    match language {
-        Language::En => "High Scores",
-        Language::Es => "Puntuaciones",
-        Language::Ja => "ハイスコア",
-        Language::Pt => "Pontuacoes",
-        Language::Zh => "最高分",
-    }
+    text(language, "menu-high-scores")
 }
 
 pub fn menu_quit(language: Language) -> &'static str {
-    match language {
-        Language::En => "Quit",
-        Language::Es => "Salir",
-        Language::Ja => "終了",
-        Language::Pt => "Sair",
-        Language::Zh => "退出",
-    }
+    text(language, "menu-quit")
+}
+
+/// Main menu subtitle right after the Konami code is entered.
+pub fn menu_rainbow_unlocked(language: Language) -> &'static str {
+    text(language, "menu-rainbow-unlocked")
 }
 
 pub fn high_scores_menu_title(language: Language) -> &'static str {
-    match language {
-        Language::En => "All High Scores",
-        Language::Es => "Todas las puntuaciones",
-        Language::Ja => "すべてのハイスコア",
-        Language::Pt => "Todas as pontuacoes",
-        Language::Zh => "全部最高分",
-    }
+    text(language, "high-scores-menu-title")
+}
+
+pub fn leaderboard_initials_label(language: Language) -> &'static str {
+    text(language, "leaderboard-initials-label")
+}
+
+pub fn leaderboard_date_label(language: Language) -> &'static str {
+    text(language, "leaderboard-date-label")
+}
+
+pub fn leaderboard_switch_hint(language: Language) -> &'static str {
+    text(language, "leaderboard-switch-hint")
+}
+
+pub fn high_scores_actions_hint(language: Language) -> &'static str {
+    text(language, "high-scores-actions-hint")
+}
+
+pub fn menu_global_leaderboard(language: Language) -> &'static str {
+    text(language, "menu-global-leaderboard")
+}
+
+pub fn global_leaderboard_title(language: Language) -> &'static str {
+    text(language, "global-leaderboard-title")
+}
+
+pub fn global_leaderboard_hint(language: Language) -> &'static str {
+    text(language, "global-leaderboard-hint")
+}
+
+pub fn global_leaderboard_loading(language: Language) -> &'static str {
+    text(language, "global-leaderboard-loading")
+}
+
+pub fn global_leaderboard_unavailable(language: Language) -> &'static str {
+    text(language, "global-leaderboard-unavailable")
+}
+
+pub fn new_record_title(language: Language) -> &'static str {
+    text(language, "new-record-title")
+}
+
+/// "Score: 430  Rank: #3" under the new-record title; `rank` counts from 1.
+pub fn new_record_subtitle(language: Language, score: u32, rank: usize) -> String {
+    format(
+        language,
+        "new-record-subtitle",
+        [("score", score.into()), ("rank", rank.into())],
+    )
+}
+
+pub fn initials_save_label(language: Language) -> &'static str {
+    text(language, "initials-save-label")
+}
+
+pub fn config_notice_title(language: Language) -> &'static str {
+    text(language, "config-notice-title")
+}
+
+pub fn config_notice_continue(language: Language) -> &'static str {
+    text(language, "config-notice-continue")
+}
+
+pub fn recover_title(language: Language) -> &'static str {
+    text(language, "recover-title")
+}
+
+pub fn recover_resume(language: Language) -> &'static str {
+    text(language, "recover-resume")
+}
+
+pub fn recover_discard(language: Language) -> &'static str {
+    text(language, "recover-discard")
 }
 
 pub fn high_scores_back_hint(language: Language) -> &'static str {
-    match language {
-        Language::En => "Press ENTER/SPACE to go back",
-        Language::Es => "Pulsa ENTER/ESPACIO para volver",
-        Language::Ja => "ENTER/SPACE で戻る",
-        Language::Pt => "Pressione ENTER/ESPAÇO para voltar",
-        Language::Zh => "按 ENTER/SPACE 返回",
-    }
+    text(language, "high-scores-back-hint")
 }
 
 pub fn menu_back(language: Language) -> &'static str {
-    match language {
-        Language::En => "Back",
-        Language::Es => "Atras",
-        Language::Ja => "戻る",
-        Language::Pt => "Voltar",
-        Language::Zh => "返回",
-    }
+    text(language, "menu-back")
 }
 
 pub fn difficulty_menu_title(language: Language) -> &'static str {
-    match language {
-        Language::En => "Select Difficulty",
-        Language::Es => "Selecciona dificultad",
-        Language::Ja => "難易度を選択",
-        Language::Pt => "Selecionar dificuldade",
-        Language::Zh => "选择难度",
-    }
+    text(language, "difficulty-menu-title")
+}
+
+pub fn custom_difficulty_title(language: Language) -> &'static str {
+    text(language, "custom-difficulty-title")
+}
+
+pub fn custom_adjust_hint(language: Language) -> &'static str {
+    text(language, "custom-adjust-hint")
+}
+
+pub fn custom_tick_rate_label(language: Language) -> &'static str {
+    text(language, "custom-tick-rate-label")
+}
+
+pub fn custom_progression_label(language: Language) -> &'static str {
+    text(language, "custom-progression-label")
+}
+
+pub fn custom_power_up_chance_label(language: Language) -> &'static str {
+    text(language, "custom-power-up-chance-label")
+}
+
+pub fn custom_wrap_label(language: Language) -> &'static str {
+    text(language, "custom-wrap-label")
+}
+
+pub fn custom_seed_label(language: Language) -> &'static str {
+    text(language, "custom-seed-label")
+}
+
+/// Shown as the seed while none is set.
+pub fn custom_seed_random(language: Language) -> &'static str {
+    text(language, "custom-seed-random")
 }
 
 pub fn settings_pause_on_focus_loss_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Pause on Focus Loss",
-        Language::Es => "Pausar al perder enfoque",
-        Language::Ja => "フォーカス喪失で一時停止",
-        Language::Pt => "Pausar ao perder foco",
-        Language::Zh => "失去焦点时暂停",
-    }
+    text(language, "settings-pause-on-focus-loss-label")
+}
+
+pub fn settings_resume_on_focus_gain_label(language: Language) -> &'static str {
+    text(language, "settings-resume-on-focus-gain-label")
+}
+
+pub fn settings_idle_pause_label(language: Language) -> &'static str {
+    text(language, "settings-idle-pause-label")
 }
 
 pub fn settings_sound_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Sound",
-        Language::Es => "Sonido",
-        Language::Ja => "サウンド",
-        Language::Pt => "Som",
-        Language::Zh => "声音",
-    }
+    text(language, "settings-sound-label")
 }
 
 pub fn settings_ui_compact_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Compact UI",
-        Language::Es => "IU compacta",
-        Language::Ja => "コンパクトUI",
-        Language::Pt => "UI compacta",
-        Language::Zh => "紧凑界面",
+    text(language, "settings-ui-compact-label")
+}
+
+pub fn settings_theme_label(language: Language) -> &'static str {
+    text(language, "settings-theme-label")
+}
+
+pub fn settings_colorblind_label(language: Language) -> &'static str {
+    text(language, "settings-colorblind-label")
+}
+
+pub fn settings_high_contrast_label(language: Language) -> &'static str {
+    text(language, "settings-high-contrast-label")
+}
+
+pub fn settings_reduced_motion_label(language: Language) -> &'static str {
+    text(language, "settings-reduced-motion-label")
+}
+
+pub fn settings_speed_label(language: Language) -> &'static str {
+    text(language, "settings-speed-label")
+}
+
+pub fn game_speed_name(language: Language, speed: GameSpeed) -> &'static str {
+    match speed {
+        GameSpeed::Difficulty => text(language, "game-speed-difficulty"),
+        GameSpeed::Slow => text(language, "game-speed-slow"),
+        GameSpeed::Normal => text(language, "game-speed-normal"),
+        GameSpeed::Fast => text(language, "game-speed-fast"),
+        GameSpeed::Insane => text(language, "game-speed-insane"),
     }
 }
 
-pub fn settings_reset_high_scores_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Reset High Scores",
-        Language::Es => "Reiniciar puntuaciones",
-        Language::Ja => "ハイスコアをリセット",
-        Language::Pt => "Resetar pontuacoes",
-        Language::Zh => "重置最高分",
+pub fn settings_speed_cap_label(language: Language) -> &'static str {
+    text(language, "settings-speed-cap-label")
+}
+
+pub fn settings_square_cells_label(language: Language) -> &'static str {
+    text(language, "settings-square-cells-label")
+}
+
+pub fn settings_axis_pace_label(language: Language) -> &'static str {
+    text(language, "settings-axis-pace-label")
+}
+
+pub fn axis_pace_auto(language: Language) -> &'static str {
+    text(language, "axis-pace-auto")
+}
+
+pub fn settings_window_title_label(language: Language) -> &'static str {
+    text(language, "settings-window-title-label")
+}
+
+pub fn settings_controls_label(language: Language) -> &'static str {
+    text(language, "settings-controls-label")
+}
+
+pub fn settings_symbols_label(language: Language) -> &'static str {
+    text(language, "settings-symbols-label")
+}
+
+pub fn symbol_set_name(language: Language, symbols: SymbolSet) -> &'static str {
+    match symbols {
+        SymbolSet::Fancy => text(language, "symbol-set-fancy"),
+        SymbolSet::Compatible => text(language, "symbol-set-compatible"),
     }
 }
 
-pub fn reset_high_scores_title(language: Language) -> &'static str {
-    match language {
-        Language::En => "Reset High Scores?",
-        Language::Es => "Reiniciar puntuaciones?",
-        Language::Ja => "ハイスコアをリセットしますか？",
-        Language::Pt => "Resetar pontuacoes?",
-        Language::Zh => "重置最高分？",
+pub fn settings_board_pattern_label(language: Language) -> &'static str {
+    text(language, "settings-board-pattern-label")
+}
+
+pub fn board_pattern_name(language: Language, pattern: BoardPattern) -> &'static str {
+    match pattern {
+        BoardPattern::Plain => text(language, "board-pattern-plain"),
+        BoardPattern::Dots => text(language, "board-pattern-dots"),
+        BoardPattern::Checkerboard => text(language, "board-pattern-checkerboard"),
     }
 }
 
-pub fn confirm_yes(language: Language) -> &'static str {
-    match language {
-        Language::En => "Yes",
-        Language::Es => "Si",
-        Language::Ja => "はい",
-        Language::Pt => "Sim",
-        Language::Zh => "是",
+pub fn settings_hud_position_label(language: Language) -> &'static str {
+    text(language, "settings-hud-position-label")
+}
+
+pub fn hud_position_name(language: Language, position: HudPosition) -> &'static str {
+    match position {
+        HudPosition::Below => text(language, "hud-position-below"),
+        HudPosition::Above => text(language, "hud-position-above"),
+        HudPosition::Right => text(language, "hud-position-right"),
     }
 }
 
-pub fn confirm_no(language: Language) -> &'static str {
-    match language {
-        Language::En => "No",
-        Language::Es => "No",
-        Language::Ja => "いいえ",
-        Language::Pt => "Nao",
-        Language::Zh => "否",
+pub fn settings_hud_compact_label(language: Language) -> &'static str {
+    text(language, "settings-hud-compact-label")
+}
+
+pub fn settings_sidebar_label(language: Language) -> &'static str {
+    text(language, "settings-sidebar-label")
+}
+
+pub fn settings_ghost_label(language: Language) -> &'static str {
+    text(language, "settings-ghost-label")
+}
+
+pub fn control_scheme_name(language: Language, scheme: ControlScheme) -> &'static str {
+    match scheme {
+        ControlScheme::Wasd => "WASD",
+        ControlScheme::Hjkl => "HJKL",
+        ControlScheme::Arrows => text(language, "control-scheme-arrows"),
+        ControlScheme::Custom => text(language, "control-scheme-custom"),
     }
 }
 
+pub fn settings_power_ups_label(language: Language) -> &'static str {
+    text(language, "settings-power-ups-label")
+}
+
+pub fn sound_menu_title(language: Language) -> &'static str {
+    text(language, "sound-menu-title")
+}
+
+pub fn sound_event_name(language: Language, event: SoundEvent) -> &'static str {
+    let id = match event {
+        SoundEvent::Food => "sound-event-food",
+        SoundEvent::PowerUp => "sound-event-power-up",
+        SoundEvent::Death => "sound-event-death",
+        SoundEvent::Ui => "sound-event-ui",
+    };
+    text(language, id)
+}
+
+pub fn power_ups_menu_title(language: Language) -> &'static str {
+    text(language, "power-ups-menu-title")
+}
+
+pub fn power_up_name(language: Language, power_up_type: PowerUpType) -> &'static str {
+    let id = match power_up_type {
+        PowerUpType::SpeedBoost => "power-up-speed-boost",
+        PowerUpType::SlowDown => "power-up-slow-down",
+        PowerUpType::ExtraPoints => "power-up-extra-points",
+        PowerUpType::Grow => "power-up-grow",
+        PowerUpType::Shrink => "power-up-shrink",
+        PowerUpType::Shield => "power-up-shield",
+        PowerUpType::ScoreMultiplier => "power-up-score-multiplier",
+    };
+    text(language, id)
+}
+
+pub fn settings_reset_high_scores_label(language: Language) -> &'static str {
+    text(language, "settings-reset-high-scores-label")
+}
+
+pub fn reset_high_scores_title(language: Language) -> &'static str {
+    text(language, "reset-high-scores-title")
+}
+
+pub fn reset_score_table_title(language: Language) -> &'static str {
+    text(language, "reset-score-table-title")
+}
+
+pub fn reset_difficulty_title(language: Language, difficulty: Difficulty) -> String {
+    format(
+        language,
+        "reset-difficulty-title",
+        [("difficulty", difficulty_label(language, difficulty).into())],
+    )
+}
+
+pub fn reset_scores_all(language: Language) -> &'static str {
+    text(language, "reset-scores-all")
+}
+
+pub fn quit_confirm_title(language: Language) -> &'static str {
+    text(language, "quit-confirm-title")
+}
+
+/// The title of the same dialog when Space or Enter would leave the run for
+/// the menu.
+pub fn leave_confirm_title(language: Language) -> &'static str {
+    text(language, "leave-confirm-title")
+}
+
+pub fn quit_confirm_warning(language: Language) -> &'static str {
+    text(language, "quit-confirm-warning")
+}
+
+pub fn confirm_yes(language: Language) -> &'static str {
+    text(language, "confirm-yes")
+}
+
+pub fn confirm_no(language: Language) -> &'static str {
+    text(language, "confirm-no")
+}
+
 pub fn setting_on(language: Language) -> &'static str {
-    match language {
-        Language::En => "On",
-        Language::Es => "Activado",
-        Language::Ja => "オン",
-        Language::Pt => "Ligado",
-        Language::Zh => "开",
-    }
+    text(language, "setting-on")
 }
 
 pub fn setting_off(language: Language) -> &'static str {
-    match language {
-        Language::En => "Off",
-        Language::Es => "Desactivado",
-        Language::Ja => "オフ",
-        Language::Pt => "Desligado",
-        Language::Zh => "关",
-    }
+    text(language, "setting-off")
 }
 
 pub fn menu_navigation_hint(language: Language) -> &'static str {
-    match language {
-        Language::En => "Use ↑↓ arrows or WASD to navigate",
-        Language::Es => "Usa ↑↓ o WASD para navegar",
-        Language::Ja => "↑↓ または WASD で移動",
-        Language::Pt => "Use ↑↓ ou WASD para navegar",
-        Language::Zh => "使用 ↑↓ 或 WASD 进行选择",
-    }
+    text(language, "menu-navigation-hint")
 }
 
 pub fn menu_confirm_hint(language: Language) -> &'static str {
-    match language {
-        Language::En => "Press ENTER/SPACE to select, Q to quit",
-        Language::Es => "Pulsa ENTER/ESPACIO para elegir, Q para salir",
-        Language::Ja => "ENTER/SPACE で決定、Q で終了",
-        Language::Pt => "Pressione ENTER/ESPAÇO para escolher, Q para sair",
-        Language::Zh => "按 ENTER/SPACE 确认，Q 退出",
-    }
+    text(language, "menu-confirm-hint")
 }
 
 pub fn language_name(language: Language) -> &'static str {
-    match language {
-        Language::En => "English",
-        Language::Es => "Español",
-        Language::Ja => "日本語",
-        Language::Pt => "Português",
-        Language::Zh => "简体中文",
-    }
+    text(language, "language-name")
 }
 
 pub fn language_popup_title(language: Language) -> &'static str {
-    match language {
-        Language::En => "Select Language",
-        Language::Es => "Selecciona idioma",
-        Language::Ja => "言語を選択",
-        Language::Pt => "Selecionar idioma",
-        Language::Zh => "选择语言",
-    }
+    text(language, "language-popup-title")
 }
 
 pub fn language_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Language",
-        Language::Es => "Idioma",
-        Language::Ja => "言語",
-        Language::Pt => "Idioma",
-        Language::Zh => "语言",
-    }
+    text(language, "language-label")
 }
 
 pub fn small_window_title(language: Language) -> &'static str {
-    match language {
-        Language::En => "WINDOW TOO SMALL",
-        Language::Es => "VENTANA MUY PEQUEÑA",
-        Language::Ja => "ウィンドウが小さすぎます",
-        Language::Pt => "JANELA MUITO PEQUENA",
-        Language::Zh => "窗口太小",
-    }
+    text(language, "small-window-title")
 }
 
 pub fn small_window_current_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Current",
-        Language::Es => "Actual",
-        Language::Ja => "現在",
-        Language::Pt => "Atual",
-        Language::Zh => "当前",
-    }
+    text(language, "small-window-current-label")
 }
 
 pub fn small_window_minimum_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Minimum",
-        Language::Es => "Mínimo",
-        Language::Ja => "最小",
-        Language::Pt => "Mínimo",
-        Language::Zh => "最小",
-    }
+    text(language, "small-window-minimum-label")
+}
+
+pub fn spectate_waiting_title(language: Language) -> &'static str {
+    text(language, "spectate-waiting-title")
+}
+
+pub fn spectate_quit_hint(language: Language) -> &'static str {
+    text(language, "spectate-quit-hint")
 }
 
 pub fn small_window_hint(language: Language) -> &'static str {
-    match language {
-        Language::En => "Resize terminal to continue. Press Q to quit.",
-        Language::Es => "Ajusta la terminal para continuar. Pulsa Q para salir.",
-        Language::Ja => "端末サイズを広げて続行。Qで終了。",
-        Language::Pt => "Ajuste o terminal para continuar. Pressione Q para sair.",
-        Language::Zh => "请调整终端大小后继续。按 Q 退出。",
-    }
+    text(language, "small-window-hint")
 }
 
 pub fn status_score_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Score",
-        Language::Es => "Puntos",
-        Language::Ja => "得点",
-        Language::Pt => "Pontos",
-        Language::Zh => "分数",
+    text(language, "status-score-label")
+}
+
+/// Terminal title during a run; the menus show just the game's name.
+pub fn window_title(language: Language, score: Option<u32>) -> String {
+    match score {
+        Some(score) => format(language, "window-title-score", [("score", score.into())]),
+        None => text(language, "window-title").to_string(),
     }
 }
 
 pub fn status_difficulty_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Diff",
-        Language::Es => "Nivel",
-        Language::Ja => "難易度",
-        Language::Pt => "Nível",
-        Language::Zh => "难度",
-    }
+    text(language, "status-difficulty-label")
 }
 
 pub fn status_paused(language: Language) -> &'static str {
-    match language {
-        Language::En => "PAUSED",
-        Language::Es => "PAUSA",
-        Language::Ja => "一時停止",
-        Language::Pt => "PAUSADO",
-        Language::Zh => "暂停",
-    }
+    text(language, "status-paused")
+}
+
+/// Shown instead of the paused status while a run counts down to resuming.
+pub fn status_resuming(language: Language, seconds: u64) -> String {
+    format(language, "status-resuming", [("seconds", seconds.into())])
 }
 
 pub fn status_muted(language: Language) -> &'static str {
-    match language {
-        Language::En => "MUTED",
-        Language::Es => "MUTEADO",
-        Language::Ja => "消音",
-        Language::Pt => "SEM SOM",
-        Language::Zh => "静音",
-    }
+    text(language, "status-muted")
+}
+
+pub fn status_slow_motion(language: Language) -> &'static str {
+    text(language, "status-slow-motion")
+}
+
+pub fn text_food_label(language: Language) -> &'static str {
+    text(language, "text-food-label")
+}
+
+pub fn text_length_label(language: Language) -> &'static str {
+    text(language, "text-length-label")
+}
+
+/// The food's offset from the snake's head, for the practice overlay.
+pub fn practice_food_offset(language: Language, dx: i32, dy: i32) -> String {
+    format(
+        language,
+        "practice-food-offset",
+        [
+            ("dx", FluentValue::from(format!("{dx:+}"))),
+            ("dy", FluentValue::from(format!("{dy:+}"))),
+        ],
+    )
+}
+
+pub fn toast_new_high_score(language: Language) -> &'static str {
+    text(language, "toast-new-high-score")
+}
+
+/// Toast after `e` wrote the result card to `file`.
+pub fn toast_card_saved(language: Language, file: &str) -> String {
+    format(language, "toast-card-saved", [("file", file.into())])
+}
+
+/// Toast when a run paused itself after `seconds` without input.
+pub fn toast_idle_paused(language: Language, seconds: u16) -> String {
+    format(language, "toast-idle-paused", [("seconds", seconds.into())])
+}
+
+pub fn toast_card_failed(language: Language) -> &'static str {
+    text(language, "toast-card-failed")
+}
+
+pub fn toast_result_copied(language: Language) -> &'static str {
+    text(language, "toast-result-copied")
+}
+
+/// Compass word for an offset from the snake's head; only the signs matter.
+pub fn text_bearing(language: Language, dx: i32, dy: i32) -> &'static str {
+    const BEARINGS: [&str; 9] = [
+        "bearing-up-left",
+        "bearing-up",
+        "bearing-up-right",
+        "bearing-left",
+        "bearing-here",
+        "bearing-right",
+        "bearing-down-left",
+        "bearing-down",
+        "bearing-down-right",
+    ];
+    text(
+        language,
+        BEARINGS[((dy.signum() + 1) * 3 + dx.signum() + 1) as usize],
+    )
 }
 
 pub fn info_best_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Best",
-        Language::Es => "Mejor",
-        Language::Ja => "最高",
-        Language::Pt => "Melhor",
-        Language::Zh => "最佳",
-    }
+    text(language, "info-best-label")
 }
 
 pub fn info_pace_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Pace",
-        Language::Es => "Ritmo",
-        Language::Ja => "速度",
-        Language::Pt => "Ritmo",
-        Language::Zh => "速度",
-    }
+    text(language, "info-pace-label")
+}
+
+pub fn info_speed_up_label(language: Language) -> &'static str {
+    text(language, "info-speed-up-label")
 }
 
 pub fn info_effect_label(language: Language) -> &'static str {
-    match language {
-        Language::En => "Effect",
-        Language::Es => "Efecto",
-        Language::Ja => "効果",
-        Language::Pt => "Efeito",
-        Language::Zh => "效果",
-    }
+    text(language, "info-effect-label")
+}
+
+pub fn sidebar_effects_title(language: Language) -> &'static str {
+    text(language, "sidebar-effects-title")
+}
+
+pub fn sidebar_legend_title(language: Language) -> &'static str {
+    text(language, "sidebar-legend-title")
 }
 
 pub fn difficulty_label(language: Language, difficulty: Difficulty) -> &'static str {
-    match (language, difficulty) {
-        (Language::En, Difficulty::Easy) => "Easy",
-        (Language::En, Difficulty::Medium) => "Medium",
-        (Language::En, Difficulty::Hard) => "Hard",
-        (Language::En, Difficulty::Extreme) => "Extreme",
-        (Language::Es, Difficulty::Easy) => "Fácil",
-        (Language::Es, Difficulty::Medium) => "Medio",
-        (Language::Es, Difficulty::Hard) => "Difícil",
-        (Language::Es, Difficulty::Extreme) => "Extremo",
-        (Language::Ja, Difficulty::Easy) => "簡単",
-        (Language::Ja, Difficulty::Medium) => "普通",
-        (Language::Ja, Difficulty::Hard) => "難しい",
-        (Language::Ja, Difficulty::Extreme) => "極限",
-        (Language::Pt, Difficulty::Easy) => "Fácil",
-        (Language::Pt, Difficulty::Medium) => "Médio",
-        (Language::Pt, Difficulty::Hard) => "Difícil",
-        (Language::Pt, Difficulty::Extreme) => "Extremo",
-        (Language::Zh, Difficulty::Easy) => "简单",
-        (Language::Zh, Difficulty::Medium) => "普通",
-        (Language::Zh, Difficulty::Hard) => "困难",
-        (Language::Zh, Difficulty::Extreme) => "极限",
-    }
+    let id = match difficulty {
+        Difficulty::Easy => "difficulty-easy",
+        Difficulty::Medium => "difficulty-medium",
+        Difficulty::Hard => "difficulty-hard",
+        Difficulty::Extreme => "difficulty-extreme",
+        Difficulty::Custom => "difficulty-custom",
+    };
+    text(language, id)
 }
 
-pub fn speed_effect_short(language: Language, power_up_type: PowerUpType) -> &'static str {
-    match (language, power_up_type) {
-        (Language::En, PowerUpType::SpeedBoost) => "Boost",
-        (Language::En, PowerUpType::SlowDown) => "Slow",
-        (Language::Es, PowerUpType::SpeedBoost) => "Turbo",
-        (Language::Es, PowerUpType::SlowDown) => "Lento",
-        (Language::Ja, PowerUpType::SpeedBoost) => "加速",
-        (Language::Ja, PowerUpType::SlowDown) => "減速",
-        (Language::Pt, PowerUpType::SpeedBoost) => "Turbo",
-        (Language::Pt, PowerUpType::SlowDown) => "Lento",
-        (Language::Zh, PowerUpType::SpeedBoost) => "加速",
-        (Language::Zh, PowerUpType::SlowDown) => "减速",
-        (_, _) => "",
+pub fn game_mode_name(language: Language, mode: GameMode) -> &'static str {
+    let id = match mode {
+        GameMode::Classic => "game-mode-classic",
+        GameMode::Walls => "game-mode-walls",
+    };
+    text(language, id)
+}
+
+/// What sets a score table apart beyond its difficulty: the mode, and the
+/// board size when it is not the default one.
+pub fn score_table_variant(language: Language, key: ScoreKey) -> String {
+    let mode = game_mode_name(language, key.mode);
+    if key.is_default_board() {
+        mode.to_string()
+    } else {
+        format!("{mode} {}x{}", key.width, key.height)
     }
 }
 
+pub fn effect_short(language: Language, power_up_type: PowerUpType) -> &'static str {
+    let id = match power_up_type {
+        PowerUpType::SpeedBoost => "effect-short-speed-boost",
+        PowerUpType::SlowDown => "effect-short-slow-down",
+        PowerUpType::Shield => "effect-short-shield",
+        PowerUpType::ScoreMultiplier => "effect-short-score-multiplier",
+        // Instant power-ups leave no effect behind.
+        PowerUpType::ExtraPoints | PowerUpType::Grow | PowerUpType::Shrink => return "",
+    };
+    text(language, id)
+}
+
 pub fn game_over_title(language: Language) -> &'static str {
-    match language {
-        Language::En => "GAME OVER!",
-        Language::Es => "FIN DEL JUEGO",
-        Language::Ja => "ゲームオーバー",
-        Language::Pt => "FIM DE JOGO",
-        Language::Zh => "游戏结束",
-    }
+    text(language, "game-over-title")
 }
 
 pub fn game_over_menu_hint(language: Language) -> &'static str {
-    match language {
-        Language::En => "Press SPACE for menu",
-        Language::Es => "Pulsa ESPACIO para menú",
-        Language::Ja => "SPACEでメニューへ",
-        Language::Pt => "Pressione ESPAÇO para o menu",
-        Language::Zh => "按 SPACE 返回菜单",
-    }
+    text(language, "game-over-menu-hint")
 }
 
 pub fn game_over_quit_hint(language: Language) -> &'static str {
-    match language {
-        Language::En => "or 'q' to quit",
-        Language::Es => "o 'q' para salir",
-        Language::Ja => "'q'で終了",
-        Language::Pt => "ou 'q' para sair",
-        Language::Zh => "或按 'q' 退出",
-    }
+    text(language, "game-over-quit-hint")
+}
+
+pub fn game_over_share_hint(language: Language) -> &'static str {
+    text(language, "game-over-share-hint")
+}
+
+/// Game-over hint for trying the last `ticks` ticks of a lost run again.
+pub fn game_over_retry_hint(language: Language, ticks: usize) -> String {
+    format(language, "game-over-retry-hint", [("ticks", ticks.into())])
+}
+
+/// `count` points, as in "430 points".
+pub fn points(language: Language, count: u32) -> String {
+    format(language, "points", [("count", count.into())])
+}
+
+pub fn summary_time_label(language: Language) -> &'static str {
+    text(language, "summary-time-label")
+}
+
+/// The summary lines of the game-over panel, label and value together.
+pub fn summary_score(language: Language, score: u32) -> String {
+    format(language, "summary-score", [("score", score.into())])
+}
+
+pub fn summary_time(language: Language, time: &str) -> String {
+    format(language, "summary-time", [("time", time.into())])
+}
+
+pub fn summary_food(language: Language, count: u32) -> String {
+    format(language, "summary-food", [("count", count.into())])
+}
+
+pub fn summary_power_ups(language: Language, count: u32) -> String {
+    format(language, "summary-power-ups", [("count", count.into())])
+}
+
+pub fn summary_fastest_pace(language: Language, percent: u64) -> String {
+    format(
+        language,
+        "summary-fastest-pace",
+        [("percent", percent.into())],
+    )
+}
+
+pub fn summary_best_combo(language: Language, count: u32) -> String {
+    format(language, "summary-best-combo", [("count", count.into())])
+}
+
+pub fn summary_max_length(language: Language, length: u32) -> String {
+    format(language, "summary-max-length", [("length", length.into())])
+}
+
+fn max_difficulty_for_width(language: Language) -> Difficulty {
+    Difficulty::ALL
+        .into_iter()
+        .max_by_key(|difficulty| text_width(difficulty_label(language, *difficulty)))
+        .unwrap_or(Difficulty::Medium)
+}
+
+fn pad_label(label: &str, width: usize) -> String {
+    let padding = width.saturating_sub(usize::from(text_width(label)));
+    format!("{}{}", label, " ".repeat(padding))
 }
 
 pub fn minimum_ui_width(language: Language) -> u16 {
     let option_overhead = 6u16; // selector marker + shortcut token + spacing
-    let max_difficulty = difficulty_label(language, Difficulty::Extreme);
+    let max_difficulty = difficulty_label(language, max_difficulty_for_width(language));
     let difficulty_main_line = format!("{}: {}", menu_difficulty(language), max_difficulty);
-    let pause_value = if text_width(setting_on(language)) >= text_width(setting_off(language)) {
+    let sound_value = if text_width(setting_on(language)) >= text_width(setting_off(language)) {
         setting_on(language)
     } else {
         setting_off(language)
     };
-    let sound_value = if text_width(setting_on(language)) >= text_width(setting_off(language)) {
+    let compact_value = if text_width(setting_on(language)) >= text_width(setting_off(language)) {
         setting_on(language)
     } else {
         setting_off(language)
     };
-    let compact_value = if text_width(setting_on(language)) >= text_width(setting_off(language)) {
+    let power_up_value = if text_width(setting_on(language)) >= text_width(setting_off(language)) {
         setting_on(language)
     } else {
         setting_off(language)
@@ -462,6 +714,7 @@ pub fn minimum_ui_width(language: Language) -> u16 {
         menu_play(language).to_string(),
         difficulty_main_line,
         menu_high_scores(language).to_string(),
+        menu_global_leaderboard(language).to_string(),
         menu_settings(language).to_string(),
         menu_quit(language).to_string(),
     ];
@@ -470,23 +723,129 @@ pub fn minimum_ui_width(language: Language) -> u16 {
         difficulty_label(language, Difficulty::Medium).to_string(),
         difficulty_label(language, Difficulty::Hard).to_string(),
         difficulty_label(language, Difficulty::Extreme).to_string(),
+        difficulty_label(language, Difficulty::Custom).to_string(),
         menu_back(language).to_string(),
     ];
-    let settings_options = [
-        format!("{}: {}", language_label(language), language_name(language)),
+    let wrap_value = if text_width(setting_on(language)) >= text_width(setting_off(language)) {
+        setting_on(language)
+    } else {
+        setting_off(language)
+    };
+    let custom_options = [
+        format!("{}: < 300 ms >", custom_tick_rate_label(language)),
+        format!("{}: < 20 >", custom_progression_label(language)),
+        format!("{}: < 60% >", custom_power_up_chance_label(language)),
+        format!("{}: < {} >", custom_wrap_label(language), wrap_value),
         format!(
             "{}: {}",
-            settings_pause_on_focus_loss_label(language),
-            pause_value
+            custom_seed_label(language),
+            "9".repeat(CustomDifficulty::SEED_DIGITS)
+        ),
+        format!(
+            "{}: {}",
+            custom_seed_label(language),
+            custom_seed_random(language)
+        ),
+        menu_back(language).to_string(),
+    ];
+    // Settings rows are two columns wide: the labels padded to the longest
+    // one, then the value cells.
+    let settings_labels = [
+        language_label(language),
+        settings_pause_on_focus_loss_label(language),
+        settings_resume_on_focus_gain_label(language),
+        settings_idle_pause_label(language),
+        settings_sound_label(language),
+        settings_ui_compact_label(language),
+        settings_theme_label(language),
+        settings_colorblind_label(language),
+        settings_high_contrast_label(language),
+        settings_reduced_motion_label(language),
+        settings_speed_label(language),
+        settings_speed_cap_label(language),
+        settings_square_cells_label(language),
+        settings_axis_pace_label(language),
+        settings_symbols_label(language),
+        settings_board_pattern_label(language),
+        settings_hud_position_label(language),
+        settings_hud_compact_label(language),
+        settings_sidebar_label(language),
+        settings_ghost_label(language),
+        settings_window_title_label(language),
+        settings_controls_label(language),
+        settings_power_ups_label(language),
+    ];
+    let settings_values = Language::available()
+        .into_iter()
+        .map(language_name)
+        .chain(
+            ControlScheme::ALL
+                .iter()
+                .map(|scheme| control_scheme_name(language, *scheme)),
+        )
+        .chain(
+            SymbolSet::ALL
+                .iter()
+                .map(|symbols| symbol_set_name(language, *symbols)),
+        )
+        .chain(
+            BoardPattern::ALL
+                .iter()
+                .map(|pattern| board_pattern_name(language, *pattern)),
+        )
+        .chain(
+            HudPosition::ALL
+                .iter()
+                .map(|position| hud_position_name(language, *position)),
+        )
+        .chain(
+            GameSpeed::ALL
+                .iter()
+                .map(|speed| game_speed_name(language, *speed)),
+        )
+        .chain([
+            compact_value,
+            axis_pace_auto(language),
+            "classic",
+            "rainbow",
+        ]);
+    let settings_options = [
+        format!(
+            "{}  < {} >",
+            pad_label(
+                "",
+                settings_labels
+                    .iter()
+                    .map(|label| usize::from(text_width(label)))
+                    .max()
+                    .unwrap_or(0)
+            ),
+            settings_values
+                .max_by_key(|value| text_width(value))
+                .unwrap_or_default()
         ),
-        format!("{}: {}", settings_sound_label(language), sound_value),
-        format!("{}: {}", settings_ui_compact_label(language), compact_value),
         settings_reset_high_scores_label(language).to_string(),
         menu_back(language).to_string(),
     ];
-    let language_options: Vec<String> = Language::ALL
+    let power_up_options: Vec<String> = PowerUpType::ALL
         .iter()
-        .map(|lang| language_name(*lang).to_string())
+        .map(|power_up_type| {
+            format!(
+                "{}: {}",
+                power_up_name(language, *power_up_type),
+                power_up_value
+            )
+        })
+        .chain(std::iter::once(menu_back(language).to_string()))
+        .collect();
+    let sound_options: Vec<String> = SoundEvent::ALL
+        .iter()
+        .map(|event| format!("{}: {}", sound_event_name(language, *event), sound_value))
+        .chain(std::iter::once(menu_back(language).to_string()))
+        .collect();
+    let language_options: Vec<String> = Language::available()
+        .into_iter()
+        .map(|lang| language_name(lang).to_string())
         .chain(std::iter::once(menu_back(language).to_string()))
         .collect();
     let reset_options = [
@@ -496,49 +855,93 @@ pub fn minimum_ui_width(language: Language) -> u16 {
     let max_score = u32::MAX.to_string();
     let high_scores_options = [
         format!(
-            "{}: {}",
-            difficulty_label(language, Difficulty::Easy),
-            max_score
+            "<  III {}  (5/5)  >",
+            difficulty_label(language, max_difficulty_for_width(language))
         ),
         format!(
-            "{}: {}",
-            difficulty_label(language, Difficulty::Medium),
-            max_score
+            "10.  {}  {}  {}",
+            pad_label(leaderboard_initials_label(language), 3),
+            pad_label(status_score_label(language), max_score.len()),
+            pad_label(leaderboard_date_label(language), 10)
         ),
-        format!(
-            "{}: {}",
-            difficulty_label(language, Difficulty::Hard),
-            max_score
-        ),
-        format!(
-            "{}: {}",
-            difficulty_label(language, Difficulty::Extreme),
-            max_score
-        ),
-        menu_back(language).to_string(),
     ];
+    let new_record_options = [
+        format!("{}: WWW", leaderboard_initials_label(language)),
+        initials_save_label(language).to_string(),
+        config_notice_continue(language).to_string(),
+        recover_resume(language).to_string(),
+        recover_discard(language).to_string(),
+        new_record_subtitle(language, u32::MAX, 10),
+    ];
+
+    let mut summary_lines = vec![
+        summary_time(language, "999:59"),
+        summary_fastest_pace(language, 100),
+    ];
+    // Plural variants differ in width, so try a count from each category.
+    for count in [1, 2, 3, 11, u32::MAX] {
+        summary_lines.extend([
+            summary_food(language, count),
+            summary_power_ups(language, count),
+            summary_best_combo(language, count),
+            summary_max_length(language, count),
+        ]);
+    }
 
     let mut max_width = text_width(controls_text(language))
         .max(text_width(menu_navigation_hint(language)))
         .max(text_width(menu_confirm_hint(language)))
         .max(text_width(high_scores_back_hint(language)))
+        .max(text_width(leaderboard_switch_hint(language)))
+        .max(text_width(global_leaderboard_hint(language)))
+        .max(text_width(&format!(
+            "{}  11-20",
+            global_leaderboard_title(language)
+        )))
+        .max(text_width(global_leaderboard_loading(language)))
+        .max(text_width(global_leaderboard_unavailable(language)))
+        .max(text_width(new_record_title(language)))
+        .max(text_width(config_notice_title(language)))
+        .max(text_width(recover_title(language)))
         .max(text_width(small_window_hint(language)))
+        .max(text_width(spectate_waiting_title(language)))
+        .max(text_width(spectate_quit_hint(language)))
         .max(text_width(difficulty_menu_title(language)))
         .max(text_width(high_scores_menu_title(language)))
         .max(text_width(language_popup_title(language)))
+        .max(text_width(power_ups_menu_title(language)))
+        .max(text_width(sound_menu_title(language)))
+        .max(text_width(custom_difficulty_title(language)))
+        .max(text_width(custom_adjust_hint(language)))
         .max(text_width(menu_title(language)))
         .max(text_width(reset_high_scores_title(language)))
+        .max(text_width(reset_score_table_title(language)))
+        .max(text_width(&reset_difficulty_title(
+            language,
+            max_difficulty_for_width(language),
+        )))
+        .max(text_width(reset_scores_all(language)))
+        .max(text_width(quit_confirm_title(language)))
+        .max(text_width(leave_confirm_title(language)))
+        .max(text_width(quit_confirm_warning(language)))
         .max(text_width(game_over_title(language)))
         .max(text_width(game_over_menu_hint(language)))
-        .max(text_width(game_over_quit_hint(language)));
+        .max(text_width(game_over_quit_hint(language)))
+        .max(text_width(game_over_share_hint(language)))
+        .max(text_width(&game_over_retry_hint(language, 10)));
 
     for option in main_options
         .iter()
         .chain(difficulty_options.iter())
+        .chain(custom_options.iter())
         .chain(settings_options.iter())
+        .chain(power_up_options.iter())
+        .chain(sound_options.iter())
         .chain(language_options.iter())
         .chain(reset_options.iter())
         .chain(high_scores_options.iter())
+        .chain(new_record_options.iter())
+        .chain(summary_lines.iter())
     {
         max_width = max_width.max(text_width(option).saturating_add(option_overhead));
     }
@@ -558,6 +961,7 @@ mod tests {
         assert!(!menu_high_scores(language).is_empty());
         assert!(!menu_settings(language).is_empty());
         assert!(!menu_quit(language).is_empty());
+        assert!(!menu_rainbow_unlocked(language).is_empty());
         assert!(!menu_back(language).is_empty());
         assert!(!difficulty_menu_title(language).is_empty());
         assert!(!high_scores_menu_title(language).is_empty());
@@ -568,13 +972,60 @@ mod tests {
         assert!(!language_popup_title(language).is_empty());
         assert!(!language_label(language).is_empty());
         assert!(!settings_pause_on_focus_loss_label(language).is_empty());
+        assert!(!settings_resume_on_focus_gain_label(language).is_empty());
+        assert!(!settings_idle_pause_label(language).is_empty());
         assert!(!settings_sound_label(language).is_empty());
         assert!(!settings_ui_compact_label(language).is_empty());
+        assert!(!settings_theme_label(language).is_empty());
+        assert!(!settings_colorblind_label(language).is_empty());
+        assert!(!settings_high_contrast_label(language).is_empty());
+        assert!(!text_food_label(language).is_empty());
+        assert!(!text_length_label(language).is_empty());
+        assert!(!toast_new_high_score(language).is_empty());
+        assert!(!toast_result_copied(language).is_empty());
+        assert!(toast_card_saved(language, "card.txt").contains("card.txt"));
+        assert!(!toast_card_failed(language).is_empty());
+        assert!(toast_idle_paused(language, 90).contains("90"));
+        for (dx, dy) in [(-1, -1), (0, 1), (1, 0)] {
+            assert!(!text_bearing(language, dx, dy).is_empty());
+        }
+        assert!(!settings_reduced_motion_label(language).is_empty());
+        assert!(!settings_speed_label(language).is_empty());
+        for speed in GameSpeed::ALL {
+            assert!(!game_speed_name(language, speed).is_empty());
+        }
+        assert!(!settings_speed_cap_label(language).is_empty());
+        assert!(!settings_square_cells_label(language).is_empty());
+        assert!(!settings_axis_pace_label(language).is_empty());
+        assert!(!axis_pace_auto(language).is_empty());
+        assert!(!settings_window_title_label(language).is_empty());
+        assert!(!settings_power_ups_label(language).is_empty());
+        assert!(!settings_controls_label(language).is_empty());
+        for scheme in ControlScheme::ALL {
+            assert!(!control_scheme_name(language, scheme).is_empty());
+        }
+        assert!(!power_ups_menu_title(language).is_empty());
+        for power_up_type in PowerUpType::ALL {
+            assert!(!power_up_name(language, power_up_type).is_empty());
+        }
+        assert!(!sound_menu_title(language).is_empty());
+        for event in SoundEvent::ALL {
+            assert!(!sound_event_name(language, event).is_empty());
+        }
         assert!(!settings_reset_high_scores_label(language).is_empty());
         assert!(!reset_high_scores_title(language).is_empty());
+        assert!(!reset_score_table_title(language).is_empty());
+        assert!(
+            reset_difficulty_title(language, Difficulty::Hard)
+                .contains(difficulty_label(language, Difficulty::Hard))
+        );
+        assert!(!reset_scores_all(language).is_empty());
         assert!(!setting_on(language).is_empty());
         assert!(!setting_off(language).is_empty());
         assert!(!confirm_yes(language).is_empty());
+        assert!(!quit_confirm_title(language).is_empty());
+        assert!(!leave_confirm_title(language).is_empty());
+        assert!(!quit_confirm_warning(language).is_empty());
         assert!(!confirm_no(language).is_empty());
         assert!(!small_window_title(language).is_empty());
         assert!(!small_window_current_label(language).is_empty());
@@ -583,7 +1034,9 @@ mod tests {
         assert!(!status_score_label(language).is_empty());
         assert!(!status_difficulty_label(language).is_empty());
         assert!(!status_paused(language).is_empty());
+        assert!(status_resuming(language, 3).contains('3'));
         assert!(!status_muted(language).is_empty());
+        assert!(!status_slow_motion(language).is_empty());
         assert!(!info_best_label(language).is_empty());
         assert!(!info_pace_label(language).is_empty());
         assert!(!info_effect_label(language).is_empty());
@@ -591,11 +1044,76 @@ mod tests {
         assert!(!difficulty_label(language, Difficulty::Medium).is_empty());
         assert!(!difficulty_label(language, Difficulty::Hard).is_empty());
         assert!(!difficulty_label(language, Difficulty::Extreme).is_empty());
-        assert!(!speed_effect_short(language, PowerUpType::SpeedBoost).is_empty());
-        assert!(!speed_effect_short(language, PowerUpType::SlowDown).is_empty());
+        assert!(!difficulty_label(language, Difficulty::Custom).is_empty());
+        assert!(!custom_difficulty_title(language).is_empty());
+        assert!(!custom_adjust_hint(language).is_empty());
+        assert!(!custom_tick_rate_label(language).is_empty());
+        assert!(!custom_progression_label(language).is_empty());
+        assert!(!custom_power_up_chance_label(language).is_empty());
+        assert!(!custom_wrap_label(language).is_empty());
+        assert!(!custom_seed_label(language).is_empty());
+        assert!(!custom_seed_random(language).is_empty());
+        assert!(!effect_short(language, PowerUpType::SpeedBoost).is_empty());
+        assert!(!effect_short(language, PowerUpType::SlowDown).is_empty());
+        assert!(!effect_short(language, PowerUpType::Shield).is_empty());
+        assert!(!effect_short(language, PowerUpType::ScoreMultiplier).is_empty());
         assert!(!game_over_title(language).is_empty());
         assert!(!game_over_menu_hint(language).is_empty());
         assert!(!game_over_quit_hint(language).is_empty());
+        assert!(!game_over_share_hint(language).is_empty());
+        assert!(game_over_retry_hint(language, 10).contains("10"));
+        assert!(!points(language, 1).is_empty());
+        assert!(!leaderboard_initials_label(language).is_empty());
+        assert!(!leaderboard_date_label(language).is_empty());
+        assert!(!leaderboard_switch_hint(language).is_empty());
+        assert!(!high_scores_actions_hint(language).is_empty());
+        assert!(!menu_global_leaderboard(language).is_empty());
+        assert!(!global_leaderboard_title(language).is_empty());
+        assert!(!global_leaderboard_hint(language).is_empty());
+        assert!(!global_leaderboard_loading(language).is_empty());
+        assert!(!global_leaderboard_unavailable(language).is_empty());
+        assert!(!new_record_title(language).is_empty());
+        assert!(!new_record_subtitle(language, 430, 1).is_empty());
+        assert!(!initials_save_label(language).is_empty());
+        assert!(!config_notice_title(language).is_empty());
+        assert!(!config_notice_continue(language).is_empty());
+        assert!(!recover_title(language).is_empty());
+        assert!(!recover_resume(language).is_empty());
+        assert!(!recover_discard(language).is_empty());
+        assert!(!spectate_waiting_title(language).is_empty());
+        assert!(!spectate_quit_hint(language).is_empty());
+        assert!(!summary_time_label(language).is_empty());
+        assert!(!summary_score(language, 430).is_empty());
+        assert!(!summary_food(language, 1).is_empty());
+        assert!(!summary_power_ups(language, 1).is_empty());
+        assert!(!summary_fastest_pace(language, 100).is_empty());
+        assert!(!summary_best_combo(language, 1).is_empty());
+        assert!(!summary_max_length(language, 1).is_empty());
+    }
+
+    #[test]
+    fn counts_pick_the_plural_form_of_the_language() {
+        assert_eq!(points(Language::En, 1), "1 point");
+        assert_eq!(points(Language::En, 430), "430 points");
+        assert_eq!(points(Language::Ja, 1), "1点");
+        let cells: Vec<String> = [1, 3, 5, 21]
+            .into_iter()
+            .map(|length| summary_max_length(Language::Ru, length))
+            .collect();
+        assert_eq!(
+            cells,
+            [
+                "Макс. длина: 1 клетка",
+                "Макс. длина: 3 клетки",
+                "Макс. длина: 5 клеток",
+                "Макс. длина: 21 клетка",
+            ]
+        );
+        assert_eq!(summary_food(Language::Ja, 12), "エサ: 12個");
+        assert_eq!(
+            new_record_subtitle(Language::Fr, 430, 3),
+            "Score : 430  Rang : #3"
+        );
     }
 
     #[test]
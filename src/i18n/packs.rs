@@ -0,0 +1,188 @@
+//! Translation packs: `lang-xx.toml` files beside the config that add a
+//! language without rebuilding the game. A pack gives every message of
+//! `en.ftl` under `[messages]`, in the same Fluent syntax:
+//!
+//! ```toml
+//! right_to_left = false
+//!
+//! [messages]
+//! menu-play = "Ludi"
+//! window-title-score = "Rustnake — { status-score-label }: { $score }"
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use fluent_bundle::FluentResource;
+use serde::Deserialize;
+use unic_langid::LanguageIdentifier;
+
+use crate::utils::Language;
+
+use super::localizer::{ENGLISH_SOURCE, message_ids};
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackFile {
+    #[serde(default)]
+    right_to_left: bool,
+    messages: BTreeMap<String, String>,
+}
+
+pub(super) struct Pack {
+    pub(super) code: String,
+    pub(super) right_to_left: bool,
+    /// The messages as a Fluent file.
+    pub(super) source: String,
+}
+
+static PACKS: OnceLock<Vec<Pack>> = OnceLock::new();
+
+/// The packs `load_packs` accepted, in `Language::Pack` order.
+pub(super) fn packs() -> &'static [Pack] {
+    PACKS.get().map_or(&[], Vec::as_slice)
+}
+
+/// Loads the packs at `paths` and registers their languages, returning why
+/// any were left out. Call once at startup, before reading the config.
+pub fn load_packs<P: AsRef<Path>>(paths: &[P]) -> Vec<String> {
+    let mut packs: Vec<Pack> = Vec::new();
+    let mut problems = Vec::new();
+    for path in paths {
+        let path = path.as_ref();
+        match read_pack(path) {
+            Ok(pack) if packs.iter().any(|loaded| loaded.code == pack.code) => {
+                problems.push(format!(
+                    "{}: `{}` is loaded twice",
+                    path.display(),
+                    pack.code
+                ));
+            }
+            Ok(_) if packs.len() > usize::from(u8::MAX) => {
+                problems.push(format!("{}: too many translation packs", path.display()));
+            }
+            Ok(pack) => packs.push(pack),
+            Err(err) => problems.push(format!("{}: {err}", path.display())),
+        }
+    }
+    Language::register_packs(packs.iter().map(|pack| pack.code.clone()).collect());
+    let _ = PACKS.set(packs);
+    problems
+}
+
+fn read_pack(path: &Path) -> Result<Pack, String> {
+    let code = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| stem.strip_prefix("lang-"))
+        .ok_or("translation packs are named `lang-<code>.toml`")?;
+    if code.parse::<LanguageIdentifier>().is_err() {
+        return Err(format!("`{code}` is not a language code"));
+    }
+    if Language::ALL.iter().any(|language| language.code() == code) {
+        return Err(format!("`{code}` is built in"));
+    }
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let file: PackFile =
+        toml::from_str(&contents).map_err(|err| err.message().trim().to_string())?;
+    pack_from_file(code, file)
+}
+
+fn pack_from_file(code: &str, file: PackFile) -> Result<Pack, String> {
+    let english = FluentResource::try_new(ENGLISH_SOURCE.to_string())
+        .unwrap_or_else(|_| panic!("locales/en.ftl does not parse"));
+    let required = message_ids(&english);
+    let missing: Vec<&str> = required
+        .iter()
+        .filter(|id| !file.messages.contains_key(id.as_str()))
+        .map(String::as_str)
+        .collect();
+    match missing.len() {
+        0 => {}
+        1..=3 => return Err(format!("missing {}", missing.join(", "))),
+        count => {
+            return Err(format!(
+                "missing {} and {} more",
+                missing[..3].join(", "),
+                count - 3
+            ));
+        }
+    }
+    let mut source = String::new();
+    for (id, value) in &file.messages {
+        if !required.contains(id) {
+            return Err(format!("unknown message `{id}`"));
+        }
+        // Fluent continues a message on indented lines.
+        let message = format!("{id} = {}\n", value.replace('\n', "\n    "));
+        if FluentResource::try_new(message.clone()).is_err() {
+            return Err(format!("`{id}` is not a valid Fluent message"));
+        }
+        source.push_str(&message);
+    }
+    Ok(Pack {
+        code: code.to_string(),
+        right_to_left: file.right_to_left,
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn english_messages() -> BTreeMap<String, String> {
+        let english = FluentResource::try_new(ENGLISH_SOURCE.to_string()).unwrap();
+        message_ids(&english)
+            .into_iter()
+            .map(|id| (id.clone(), format!("eo {id}")))
+            .collect()
+    }
+
+    #[test]
+    fn packs_need_every_message_and_nothing_else() {
+        let mut messages = english_messages();
+        let pack = pack_from_file(
+            "eo",
+            PackFile {
+                right_to_left: false,
+                messages: messages.clone(),
+            },
+        )
+        .expect("a full pack loads");
+        assert!(pack.source.contains("menu-play = eo menu-play\n"));
+
+        messages.remove("menu-play");
+        let missing = PackFile {
+            right_to_left: false,
+            messages: messages.clone(),
+        };
+        assert_eq!(
+            pack_from_file("eo", missing).err().as_deref(),
+            Some("missing menu-play")
+        );
+
+        messages.insert("menu-play".to_string(), "{ $broken".to_string());
+        let broken = PackFile {
+            right_to_left: false,
+            messages: messages.clone(),
+        };
+        assert_eq!(
+            pack_from_file("eo", broken).err().as_deref(),
+            Some("`menu-play` is not a valid Fluent message")
+        );
+
+        messages.insert("menu-play".to_string(), "Ludi".to_string());
+        messages.insert("menu-ply".to_string(), "Ludi".to_string());
+        let typo = PackFile {
+            right_to_left: false,
+            messages,
+        };
+        assert_eq!(
+            pack_from_file("eo", typo).err().as_deref(),
+            Some("unknown message `menu-ply`")
+        );
+    }
+}
@@ -1,80 +1,403 @@
 //! Input handling module for the Snake game.
 //! Manages keyboard input and translates it to game commands.
 
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
-use std::sync::mpsc;
+use crate::storage::Settings;
+use crate::utils::{Difficulty, Direction};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, ModifierKeyCode,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 
-#[derive(Debug, Clone)]
+mod recording;
+mod text_field;
+
+pub use recording::{InputRecording, Playback};
+pub use text_field::TextField;
+
+/// How often the input thread checks the config file for external edits.
+/// It is the only timer left running on an idle screen, so it is coarse: the
+/// process sleeps through menus, pauses, and game over otherwise.
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum GameInput {
-    Direction(crate::utils::Direction),
+    Direction(Direction),
     Pause,
     Quit,
     MenuSelect(usize),
     MenuConfirm,
+    /// Esc: the previous menu screen, or pause during a run.
+    Back,
+    /// PageUp/PageDown: a page through a long menu; `true` is down.
+    Page(bool),
+    /// Alt+1 to Alt+4 on the main menu: play that difficulty right away.
+    QuickStart(Difficulty),
     ToggleMute,
+    /// The boost key went down (`true`) or came back up.
+    Boost(bool),
     FocusLost,
+    FocusGained,
     Resize(u16, u16),
+    ConfigChanged,
+    /// A key press with no binding of its own.
+    OtherKey,
+    /// The last key press completed `KONAMI_CODE`.
+    SecretCode,
+    /// A character typed while text entry is on.
+    Char(char),
+    /// Backspace while text entry is on.
+    Backspace,
+    /// F2: show or hide the practice overlay.
+    TogglePracticeOverlay,
+    /// F3: show or hide the debug overlay.
+    ToggleDebugOverlay,
+    /// `c` when it does not steer: copy the result of a finished run.
+    Copy,
+    /// `e` when it does not steer: save a result card of a finished run.
+    SaveCard,
+    /// `l` when it does not steer: switch to the next language.
+    CycleLanguage,
+    /// `u` when it does not steer: rewind a few ticks in practice mode.
+    Undo,
+    /// `z` when it does not steer: slow motion on or off in practice mode.
+    SlowMotion,
+    /// `n` when it does not steer: play one tick at a time in practice mode.
+    Step,
+    /// `o` when it does not steer: order a score table by date or by score.
+    SortOrder,
+    /// `r` when it does not steer: reset the score table on screen, or retry
+    /// the last few ticks of a lost run.
+    Reset,
+}
+
+#[derive(Debug)]
+pub enum InputWait {
+    Input(GameInput),
+    TimedOut,
+    Disconnected,
+}
+
+/// Blocks until the next input arrives or `deadline` passes, whichever comes first.
+/// Passing no deadline waits indefinitely, so idle screens cost no CPU between events.
+pub fn wait_for_input(rx: &mpsc::Receiver<GameInput>, deadline: Option<Instant>) -> InputWait {
+    let Some(deadline) = deadline else {
+        return rx.recv().map_or(InputWait::Disconnected, InputWait::Input);
+    };
+    match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+        Ok(input) => InputWait::Input(input),
+        Err(RecvTimeoutError::Timeout) => InputWait::TimedOut,
+        Err(RecvTimeoutError::Disconnected) => InputWait::Disconnected,
+    }
+}
+
+fn is_reverse_direction(current: Direction, next: Direction) -> bool {
+    matches!(
+        (current, next),
+        (Direction::Up, Direction::Down)
+            | (Direction::Down, Direction::Up)
+            | (Direction::Left, Direction::Right)
+            | (Direction::Right, Direction::Left)
+    )
+}
+
+/// Queues a turn to apply on a later tick, keeping at most two pending so quick
+/// double turns (e.g. up then left) both land. Turns that would not change the
+/// heading, or would reverse it, are dropped.
+pub fn queue_direction(queue: &mut VecDeque<Direction>, current: Direction, direction: Direction) {
+    let reference_direction = queue.back().copied().unwrap_or(current);
+    if direction == reference_direction || is_reverse_direction(reference_direction, direction) {
+        return;
+    }
+    if queue.len() >= 2 {
+        queue.pop_back();
+    }
+    queue.push_back(direction);
+}
+
+/// Tracks the config file's modification time so hand edits can be picked up.
+struct ConfigWatcher {
+    path: PathBuf,
+    last_modified: Option<SystemTime>,
+}
+
+impl ConfigWatcher {
+    fn new(path: PathBuf) -> Self {
+        let last_modified = Self::modified(&path);
+        Self {
+            path,
+            last_modified,
+        }
+    }
+
+    fn modified(path: &Path) -> Option<SystemTime> {
+        fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    fn changed(&mut self) -> bool {
+        let modified = Self::modified(&self.path);
+        if modified == self.last_modified {
+            return false;
+        }
+        self.last_modified = modified;
+        modified.is_some()
+    }
+}
+
+/// Held boost keys repeat; without a repeat or release for this long the
+/// boost ends, which covers terminals that never report releases.
+const BOOST_HOLD_TIMEOUT: Duration = Duration::from_millis(700);
+
+#[derive(Clone, Copy)]
+struct Bindings {
+    /// Up, down, left, and right letters, or `None` when only the arrows steer.
+    steer: Option<[char; 4]>,
+    boost: char,
+    /// A text field has focus, so characters are typed rather than bound.
+    text_entry: bool,
+}
+
+impl Bindings {
+    fn from_settings(settings: &Settings) -> Self {
+        Self {
+            steer: settings.control_scheme.letters(settings.custom_keys),
+            boost: settings.boost_key,
+            text_entry: false,
+        }
+    }
+}
+
+/// Key bindings from the settings, shared with the input thread so a change
+/// in Settings applies from the next key press.
+#[derive(Clone)]
+pub struct Keymap(Arc<Mutex<Bindings>>);
+
+impl Keymap {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self(Arc::new(Mutex::new(Bindings::from_settings(settings))))
+    }
+
+    pub fn set(&self, settings: &Settings) {
+        let mut bindings = self.lock();
+        *bindings = Bindings {
+            text_entry: bindings.text_entry,
+            ..Bindings::from_settings(settings)
+        };
+    }
+
+    /// While on, printable keys arrive as `GameInput::Char` and Backspace as
+    /// `GameInput::Backspace`, for screens with a `TextField`.
+    pub fn set_text_entry(&self, on: bool) {
+        self.lock().text_entry = on;
+    }
+
+    fn bindings(&self) -> Bindings {
+        *self.lock()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Bindings> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// ↑↑↓↓←→←→BA. Entered on the main menu, it unlocks the rainbow theme.
+const KONAMI_CODE: [KeyCode; 10] = [
+    KeyCode::Up,
+    KeyCode::Up,
+    KeyCode::Down,
+    KeyCode::Down,
+    KeyCode::Left,
+    KeyCode::Right,
+    KeyCode::Left,
+    KeyCode::Right,
+    KeyCode::Char('b'),
+    KeyCode::Char('a'),
+];
+
+/// The latest key presses, enough to spot `KONAMI_CODE`.
+#[derive(Default)]
+struct RecentKeys(VecDeque<KeyCode>);
+
+impl RecentKeys {
+    /// Adds a key press and reports whether it completes the code.
+    fn push(&mut self, code: KeyCode) -> bool {
+        let code = match code {
+            // Shift for an uppercase letter is not part of the sequence.
+            KeyCode::Modifier(_) => return false,
+            KeyCode::Char(ch) => KeyCode::Char(ch.to_ascii_lowercase()),
+            other => other,
+        };
+        if self.0.len() == KONAMI_CODE.len() {
+            self.0.pop_front();
+        }
+        self.0.push_back(code);
+        self.0.iter().eq(&KONAMI_CODE)
+    }
 }
 
-pub fn setup_input_handler() -> mpsc::Receiver<GameInput> {
+/// What a key press means under the given bindings.
+fn key_input(code: KeyCode, modifiers: KeyModifiers, bindings: Bindings) -> GameInput {
+    let typing =
+        bindings.text_entry && !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+    match code {
+        KeyCode::Char(ch) if typing && !ch.is_control() => GameInput::Char(ch),
+        KeyCode::Backspace if typing => GameInput::Backspace,
+        KeyCode::Char('q') | KeyCode::Char('Q') => GameInput::Quit,
+        KeyCode::Char('p') | KeyCode::Char('P') => GameInput::Pause,
+        KeyCode::Char('m') | KeyCode::Char('M') => GameInput::ToggleMute,
+        KeyCode::Up => GameInput::Direction(Direction::Up),
+        KeyCode::Down => GameInput::Direction(Direction::Down),
+        KeyCode::Left => GameInput::Direction(Direction::Left),
+        KeyCode::Right => GameInput::Direction(Direction::Right),
+        KeyCode::Char(digit @ '1'..='4') if modifiers.contains(KeyModifiers::ALT) => {
+            GameInput::QuickStart(Difficulty::ALL[usize::from(digit as u8 - b'1')])
+        }
+        KeyCode::Char(digit @ '1'..='9') => GameInput::MenuSelect(usize::from(digit as u8 - b'1')),
+        KeyCode::Enter | KeyCode::Char('\n') => GameInput::MenuConfirm,
+        KeyCode::Char(' ') => GameInput::MenuConfirm, // Use space to confirm menu selections
+        KeyCode::Esc => GameInput::Back,
+        KeyCode::PageUp => GameInput::Page(false),
+        KeyCode::PageDown => GameInput::Page(true),
+        KeyCode::F(2) => GameInput::TogglePracticeOverlay,
+        KeyCode::F(3) => GameInput::ToggleDebugOverlay,
+        // Shift on its own is only reported by terminals with key release events.
+        KeyCode::Modifier(ModifierKeyCode::LeftShift | ModifierKeyCode::RightShift) => {
+            GameInput::Boost(true)
+        }
+        KeyCode::Char(ch) if ch.eq_ignore_ascii_case(&bindings.boost) => GameInput::Boost(true),
+        KeyCode::Char(ch) => bindings
+            .steer
+            .and_then(|letters| {
+                letters
+                    .iter()
+                    .position(|letter| letter.eq_ignore_ascii_case(&ch))
+            })
+            .map_or(letter_input(ch), |index| {
+                GameInput::Direction(
+                    [
+                        Direction::Up,
+                        Direction::Down,
+                        Direction::Left,
+                        Direction::Right,
+                    ][index],
+                )
+            }),
+        // Only screens waiting for "any key" care about the rest.
+        _ => GameInput::OtherKey,
+    }
+}
+
+/// What a letter that does not steer means.
+fn letter_input(ch: char) -> GameInput {
+    match ch.to_ascii_lowercase() {
+        'c' => GameInput::Copy,
+        'e' => GameInput::SaveCard,
+        'l' => GameInput::CycleLanguage,
+        'u' => GameInput::Undo,
+        'z' => GameInput::SlowMotion,
+        'n' => GameInput::Step,
+        'o' => GameInput::SortOrder,
+        'r' => GameInput::Reset,
+        _ => GameInput::OtherKey,
+    }
+}
+
+/// What a key event of `kind` that maps to `input` sends. Boost lasts from
+/// its press to its release, tracked in `boost_until`; any other held key
+/// repeats like fresh presses, and its release sends nothing.
+fn key_event_input(
+    input: GameInput,
+    kind: KeyEventKind,
+    boost_until: &mut Option<Instant>,
+) -> Option<GameInput> {
+    let boost_key = matches!(input, GameInput::Boost(_));
+    match kind {
+        KeyEventKind::Release if boost_key => {
+            *boost_until = None;
+            Some(GameInput::Boost(false))
+        }
+        KeyEventKind::Press | KeyEventKind::Repeat if boost_key => {
+            let starting = boost_until.is_none();
+            *boost_until = Some(Instant::now() + BOOST_HOLD_TIMEOUT);
+            starting.then_some(input)
+        }
+        KeyEventKind::Press | KeyEventKind::Repeat => Some(input),
+        KeyEventKind::Release => None,
+    }
+}
+
+pub fn setup_input_handler(config_path: PathBuf, keymap: Keymap) -> mpsc::Receiver<GameInput> {
     let (tx, rx) = mpsc::channel();
 
     thread::spawn(move || {
+        let mut config_watcher = ConfigWatcher::new(config_path);
+        let mut boost_until: Option<Instant> = None;
+        let mut recent_keys = RecentKeys::default();
         loop {
+            // Poll with a timeout so config edits are noticed while no keys are pressed.
+            let timeout = boost_until.map_or(CONFIG_POLL_INTERVAL, |until| {
+                until
+                    .saturating_duration_since(Instant::now())
+                    .min(CONFIG_POLL_INTERVAL)
+            });
+            if !event::poll(timeout).unwrap_or(false) {
+                if boost_until.is_some_and(|until| Instant::now() >= until) {
+                    boost_until = None;
+                    if tx.send(GameInput::Boost(false)).is_err() {
+                        break;
+                    }
+                }
+                if config_watcher.changed() && tx.send(GameInput::ConfigChanged).is_err() {
+                    break;
+                }
+                continue;
+            }
             if let Ok(event) = event::read() {
+                let completes_code = match event {
+                    Event::Key(KeyEvent {
+                        code,
+                        kind: KeyEventKind::Press,
+                        ..
+                    }) => recent_keys.push(code),
+                    _ => false,
+                };
                 let maybe_input = match event {
                     Event::Resize(width, height) => Some(GameInput::Resize(width, height)),
                     Event::FocusLost => Some(GameInput::FocusLost),
-                    Event::Key(KeyEvent { code, kind, .. }) => {
-                        if kind != KeyEventKind::Press {
-                            None
-                        } else {
-                            match code {
-                                KeyCode::Char('q') | KeyCode::Char('Q') => Some(GameInput::Quit),
-                                KeyCode::Char('p') | KeyCode::Char('P') => Some(GameInput::Pause),
-                                KeyCode::Char('m') | KeyCode::Char('M') => {
-                                    Some(GameInput::ToggleMute)
-                                }
-                                KeyCode::Char('w') | KeyCode::Char('W') | KeyCode::Up => {
-                                    Some(GameInput::Direction(crate::utils::Direction::Up))
-                                }
-                                KeyCode::Char('s') | KeyCode::Char('S') | KeyCode::Down => {
-                                    Some(GameInput::Direction(crate::utils::Direction::Down))
-                                }
-                                KeyCode::Char('a') | KeyCode::Char('A') | KeyCode::Left => {
-                                    Some(GameInput::Direction(crate::utils::Direction::Left))
-                                }
-                                KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Right => {
-                                    Some(GameInput::Direction(crate::utils::Direction::Right))
-                                }
-                                KeyCode::Char('1') => Some(GameInput::MenuSelect(0)),
-                                KeyCode::Char('2') => Some(GameInput::MenuSelect(1)),
-                                KeyCode::Char('3') => Some(GameInput::MenuSelect(2)),
-                                KeyCode::Char('4') => Some(GameInput::MenuSelect(3)),
-                                KeyCode::Char('5') => Some(GameInput::MenuSelect(4)),
-                                KeyCode::Char('6') => Some(GameInput::MenuSelect(5)),
-                                KeyCode::Enter | KeyCode::Char('\n') => {
-                                    Some(GameInput::MenuConfirm)
-                                }
-                                KeyCode::Char(' ') => Some(GameInput::MenuConfirm), // Use space to confirm menu selections
-                                _ => None, // Ignore other keys
-                            }
-                        }
+                    Event::FocusGained => Some(GameInput::FocusGained),
+                    Event::Key(KeyEvent {
+                        code,
+                        modifiers,
+                        kind,
+                        ..
+                    }) => {
+                        let input = key_input(code, modifiers, keymap.bindings());
+                        key_event_input(input, kind, &mut boost_until)
                     }
                     _ => None,
                 };
 
-                let Some(input) = maybe_input else {
-                    continue;
-                };
-
-                if tx.send(input.clone()).is_err() {
-                    // Channel closed, exit the thread
-                    break;
+                // Q can be taken back (the quit dialog), so the thread keeps
+                // reading until the receiving end goes away.
+                if let Some(input) = maybe_input {
+                    log::debug!("input {input:?}");
+                    if tx.send(input).is_err() {
+                        // Channel closed, exit the thread
+                        break;
+                    }
                 }
-
-                if let GameInput::Quit = input {
+                if completes_code && tx.send(GameInput::SecretCode).is_err() {
                     break;
                 }
             }
@@ -83,3 +406,216 @@ pub fn setup_input_handler() -> mpsc::Receiver<GameInput> {
 
     rx
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::ControlScheme;
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn wait_for_input_returns_queued_input_before_deadline() {
+        let (tx, rx) = mpsc::channel();
+        tx.send(GameInput::Pause).unwrap();
+
+        let outcome = wait_for_input(&rx, Some(Instant::now() + Duration::from_secs(5)));
+        assert!(matches!(outcome, InputWait::Input(GameInput::Pause)));
+    }
+
+    #[test]
+    fn wait_for_input_times_out_at_deadline() {
+        let (_tx, rx) = mpsc::channel::<GameInput>();
+
+        let outcome = wait_for_input(&rx, Some(Instant::now()));
+        assert!(matches!(outcome, InputWait::TimedOut));
+    }
+
+    #[test]
+    fn wait_for_input_reports_closed_channel_without_deadline() {
+        let (tx, rx) = mpsc::channel::<GameInput>();
+        drop(tx);
+
+        assert!(matches!(wait_for_input(&rx, None), InputWait::Disconnected));
+    }
+
+    #[test]
+    fn wait_for_input_without_deadline_sleeps_until_the_next_key() {
+        let (tx, rx) = mpsc::channel::<GameInput>();
+        let sender = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            tx.send(GameInput::Pause).expect("receiver is waiting");
+        });
+
+        // No timeout wakes the wait early; only the key ends it.
+        let started = Instant::now();
+        let outcome = wait_for_input(&rx, None);
+        assert!(matches!(outcome, InputWait::Input(GameInput::Pause)));
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        sender.join().expect("sender thread finishes");
+    }
+
+    #[test]
+    fn control_schemes_pick_the_steering_letters() {
+        let steer = |control_scheme: ControlScheme, ch: char| {
+            let settings = Settings {
+                control_scheme,
+                ..Settings::default()
+            };
+            match key_input(
+                KeyCode::Char(ch),
+                KeyModifiers::NONE,
+                Bindings::from_settings(&settings),
+            ) {
+                GameInput::Direction(direction) => Some(direction),
+                _ => None,
+            }
+        };
+        assert_eq!(steer(ControlScheme::Wasd, 'W'), Some(Direction::Up));
+        assert_eq!(steer(ControlScheme::Hjkl, 'h'), Some(Direction::Left));
+        assert_eq!(steer(ControlScheme::Hjkl, 'j'), Some(Direction::Down));
+        assert_eq!(steer(ControlScheme::Hjkl, 'w'), None);
+        assert_eq!(steer(ControlScheme::Custom, 'i'), Some(Direction::Up));
+        assert_eq!(steer(ControlScheme::Arrows, 'a'), None);
+        let bindings = Bindings::from_settings(&Settings::default());
+        assert!(matches!(
+            key_input(KeyCode::Left, KeyModifiers::NONE, bindings),
+            GameInput::Direction(Direction::Left)
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('4'), KeyModifiers::NONE, bindings),
+            GameInput::MenuSelect(3)
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('B'), KeyModifiers::NONE, bindings),
+            GameInput::Boost(true)
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Esc, KeyModifiers::NONE, bindings),
+            GameInput::Back
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('C'), KeyModifiers::NONE, bindings),
+            GameInput::Copy
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('e'), KeyModifiers::NONE, bindings),
+            GameInput::SaveCard
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('L'), KeyModifiers::NONE, bindings),
+            GameInput::CycleLanguage
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('u'), KeyModifiers::NONE, bindings),
+            GameInput::Undo
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('Z'), KeyModifiers::NONE, bindings),
+            GameInput::SlowMotion
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('n'), KeyModifiers::NONE, bindings),
+            GameInput::Step
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('o'), KeyModifiers::NONE, bindings),
+            GameInput::SortOrder
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('R'), KeyModifiers::NONE, bindings),
+            GameInput::Reset
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Char('3'), KeyModifiers::ALT, bindings),
+            GameInput::QuickStart(Difficulty::Hard)
+        ));
+        let typing = Bindings {
+            text_entry: true,
+            ..bindings
+        };
+        assert!(matches!(
+            key_input(KeyCode::Char('q'), KeyModifiers::NONE, typing),
+            GameInput::Char('q')
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Backspace, KeyModifiers::NONE, typing),
+            GameInput::Backspace
+        ));
+        assert!(matches!(
+            key_input(KeyCode::Enter, KeyModifiers::NONE, typing),
+            GameInput::MenuConfirm
+        ));
+    }
+
+    #[test]
+    fn held_keys_repeat_and_only_boost_cares_about_releases() {
+        let bindings = Bindings::from_settings(&Settings::default());
+        let up = key_input(KeyCode::Up, KeyModifiers::NONE, bindings);
+        let mut boost_until = None;
+        for kind in [KeyEventKind::Press, KeyEventKind::Repeat] {
+            assert!(matches!(
+                key_event_input(up.clone(), kind, &mut boost_until),
+                Some(GameInput::Direction(Direction::Up))
+            ));
+        }
+        assert!(key_event_input(up, KeyEventKind::Release, &mut boost_until).is_none());
+        assert!(boost_until.is_none());
+
+        let boost = GameInput::Boost(true);
+        assert!(key_event_input(boost.clone(), KeyEventKind::Press, &mut boost_until).is_some());
+        // Repeats only keep the boost going.
+        assert!(key_event_input(boost.clone(), KeyEventKind::Repeat, &mut boost_until).is_none());
+        assert!(boost_until.is_some());
+        assert!(matches!(
+            key_event_input(boost, KeyEventKind::Release, &mut boost_until),
+            Some(GameInput::Boost(false))
+        ));
+        assert!(boost_until.is_none());
+    }
+
+    #[test]
+    fn konami_code_completes_on_its_last_key_only() {
+        let mut recent = RecentKeys::default();
+        let mut completed = Vec::new();
+        // A stray extra ↑ in front still leaves the whole sequence at the end.
+        for code in [KeyCode::Up].iter().chain(&KONAMI_CODE[..9]) {
+            completed.push(recent.push(*code));
+        }
+        recent.push(KeyCode::Modifier(ModifierKeyCode::LeftShift));
+        completed.push(recent.push(KeyCode::Char('A')));
+        assert_eq!(completed.iter().filter(|done| **done).count(), 1);
+        assert!(completed[10]);
+        assert!(!recent.push(KeyCode::Char('a')));
+    }
+
+    #[test]
+    fn config_watcher_reports_each_modification_once() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "rustnake-watch-{}-{}.toml",
+            std::process::id(),
+            nanos
+        ));
+        let mut watcher = ConfigWatcher::new(path.clone());
+        assert!(!watcher.changed());
+
+        fs::write(&path, "").unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+        assert!(watcher.changed());
+
+        fs::remove_file(&path).unwrap();
+        assert!(!watcher.changed());
+        assert!(watcher.last_modified.is_none());
+    }
+}
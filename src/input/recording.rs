@@ -0,0 +1,220 @@
+//! `play --record-input` and `replay`: the inputs of one run, each stamped with
+//! the number of ticks played when the game loop handled it. With the seed and
+//! difficulty they replay the run exactly, so a recording doubles as a bug
+//! report and as an end-to-end check of the game loop.
+
+use super::{GameInput, queue_direction};
+use crate::core::{DifficultyProfile, Game};
+use crate::utils::{Difficulty, Direction, Position, PowerUpSet};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::Path;
+
+/// How a recorded run ended, checked again when it is played back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedEnd {
+    pub ticks: u32,
+    pub score: u32,
+    pub head: Position,
+    pub length: usize,
+    pub game_over: bool,
+}
+
+impl RecordedEnd {
+    fn of(game: &Game) -> Self {
+        Self {
+            ticks: game.stats.ticks,
+            score: game.score,
+            head: game.snake.head_position(),
+            length: game.snake.body().len(),
+            game_over: game.game_over,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputRecording {
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    pub width: u16,
+    pub height: u16,
+    pub disabled_power_ups: PowerUpSet,
+    /// `(tick, input)` in the order the inputs arrived.
+    pub profile: DifficultyProfile,
+    pub inputs: Vec<(u32, GameInput)>,
+    pub end: RecordedEnd,
+}
+
+impl InputRecording {
+    /// Starts a recording of `game`, which has not been played yet.
+    pub fn start(game: &Game) -> Self {
+        Self {
+            seed: game.replay.seed,
+            difficulty: game.difficulty,
+            width: game.width,
+            height: game.height,
+            disabled_power_ups: game.disabled_power_ups,
+            profile: game.profile,
+            inputs: Vec::new(),
+            end: RecordedEnd::of(game),
+        }
+    }
+
+    pub fn record(&mut self, tick: u32, input: &GameInput) {
+        self.inputs.push((tick, input.clone()));
+    }
+
+    pub fn finish(&mut self, game: &Game) {
+        self.end = RecordedEnd::of(game);
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+        serde_json::from_str(&source).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        // JSON rather than TOML, which cannot hold seeds past `i64::MAX`.
+        let contents = serde_json::to_string_pretty(self).map_err(|err| err.to_string())?;
+        std::fs::write(path, contents)
+            .map_err(|err| format!("cannot write {}: {err}", path.display()))
+    }
+
+    /// A fresh game set up like the recorded one.
+    pub fn new_game(&self) -> Game {
+        let mut game = Game::with_seed(
+            self.difficulty,
+            self.profile,
+            self.width,
+            self.height,
+            0,
+            self.seed,
+        );
+        game.muted = true;
+        game.set_disabled_power_ups(self.disabled_power_ups);
+        game
+    }
+
+    /// Replays the whole run as fast as possible.
+    pub fn play_headless(&self) -> Game {
+        let mut game = self.new_game();
+        let mut playback = Playback::new(self);
+        while let Some(direction) = playback.next_tick(&mut game) {
+            if let Some(direction) = direction {
+                game.update_snake_direction(direction);
+            }
+            game.tick();
+        }
+        game
+    }
+
+    /// Compares a replayed game with how the recorded run ended.
+    pub fn verify(&self, game: &Game) -> Result<(), String> {
+        let replayed = RecordedEnd::of(game);
+        if replayed == self.end {
+            Ok(())
+        } else {
+            Err(format!(
+                "replay diverged: recorded {:?}, replayed {replayed:?}",
+                self.end
+            ))
+        }
+    }
+}
+
+/// Feeds recorded inputs to a game the way the game loop handled them live.
+/// Pausing and muting never change the outcome, since no ticks run while
+/// paused, so only turns, boosts, and the end of the run are replayed.
+pub struct Playback<'a> {
+    recording: &'a InputRecording,
+    next_input: usize,
+    direction_queue: VecDeque<Direction>,
+    ended: bool,
+}
+
+impl<'a> Playback<'a> {
+    pub fn new(recording: &'a InputRecording) -> Self {
+        Self {
+            recording,
+            next_input: 0,
+            direction_queue: VecDeque::with_capacity(2),
+            ended: false,
+        }
+    }
+
+    /// Applies the inputs recorded before the coming tick. Returns the turn to
+    /// take on it, or `None` once the recorded run is over.
+    pub fn next_tick(&mut self, game: &mut Game) -> Option<Option<Direction>> {
+        let inputs = &self.recording.inputs[self.next_input..];
+        let due = inputs
+            .iter()
+            .take_while(|(tick, _)| *tick <= game.stats.ticks)
+            .count();
+        self.next_input += due;
+        for (_, input) in &inputs[..due] {
+            match input {
+                GameInput::Quit | GameInput::MenuConfirm => self.ended = true,
+                GameInput::Boost(on) if !game.game_over => game.set_boost(*on),
+                GameInput::Direction(direction) if !game.game_over => {
+                    queue_direction(&mut self.direction_queue, game.snake.direction, *direction);
+                }
+                _ => {}
+            }
+        }
+        if self.ended || game.game_over || game.stats.ticks >= self.recording.end.ticks {
+            return None;
+        }
+        Some(self.direction_queue.pop_front())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recordings_round_trip_and_replay_to_the_recorded_end() {
+        let mut game = Game::new_seeded(Difficulty::Medium, 40, 20, 0, u64::MAX - 7);
+        let mut recording = InputRecording::start(&game);
+        let script = [
+            (17, GameInput::Direction(Direction::Up)),
+            (17, GameInput::Pause),
+            (17, GameInput::Pause),
+            (18, GameInput::Direction(Direction::Right)),
+            (30, GameInput::Boost(true)),
+            (40, GameInput::Boost(false)),
+            (45, GameInput::Direction(Direction::Down)),
+        ];
+        let mut queue = VecDeque::new();
+        for tick in 0..60 {
+            for (_, input) in script.iter().filter(|(at, _)| *at == tick) {
+                recording.record(tick, input);
+                match input {
+                    GameInput::Direction(direction) => {
+                        queue_direction(&mut queue, game.snake.direction, *direction);
+                    }
+                    GameInput::Boost(on) => game.set_boost(*on),
+                    _ => {}
+                }
+            }
+            if let Some(direction) = queue.pop_front() {
+                game.update_snake_direction(direction);
+            }
+            game.tick();
+        }
+        recording.record(60, &GameInput::Quit);
+        recording.finish(&game);
+
+        let saved = serde_json::to_string(&recording).unwrap();
+        let loaded: InputRecording = serde_json::from_str(&saved).unwrap();
+        assert_eq!(loaded.inputs.len(), script.len() + 1);
+        let replayed = loaded.play_headless();
+        assert_eq!(replayed.snake.body(), game.snake.body());
+        assert!(loaded.verify(&replayed).is_ok());
+
+        let mut tampered = loaded.clone();
+        tampered.inputs.retain(|(tick, _)| *tick != 45);
+        assert!(tampered.verify(&tampered.play_headless()).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+//! A one-line text field for menus: initials, seeds, and anything else the
+//! player types rather than picks. Screens hold a `TextField`, feed it the
+//! inputs the input thread sends while `Keymap::set_text_entry` is on, and
+//! pass `cursor` to the menu renderer so the caret shows on the field's row.
+
+use super::GameInput;
+use crate::utils::Direction;
+
+pub struct TextField {
+    chars: Vec<char>,
+    /// Index into `chars` the next character is typed at.
+    cursor: usize,
+    max_len: usize,
+    /// Maps a typed character to the one stored, or rejects it with `None`.
+    filter: fn(char) -> Option<char>,
+}
+
+impl TextField {
+    /// A field holding `text` (cut to `max_len`), with the cursor at its start.
+    pub fn new(text: &str, max_len: usize, filter: fn(char) -> Option<char>) -> Self {
+        Self {
+            chars: text.chars().filter_map(filter).take(max_len).collect(),
+            cursor: 0,
+            max_len,
+            filter,
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    /// Characters before the cursor.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn move_to_end(&mut self) {
+        self.cursor = self.chars.len();
+    }
+
+    /// Types `ch` at the cursor. A full field overwrites the character under
+    /// the cursor instead, so fixed-length text such as initials is retyped
+    /// from the start without deleting it first.
+    pub fn insert(&mut self, ch: char) -> bool {
+        let Some(ch) = (self.filter)(ch) else {
+            return false;
+        };
+        if self.chars.len() < self.max_len {
+            self.chars.insert(self.cursor, ch);
+        } else if let Some(slot) = self.chars.get_mut(self.cursor) {
+            *slot = ch;
+        } else {
+            return false;
+        }
+        self.cursor += 1;
+        true
+    }
+
+    pub fn backspace(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor -= 1;
+        self.chars.remove(self.cursor);
+        true
+    }
+
+    /// Applies typing, Backspace, and ←/→. Returns whether `input` was one of
+    /// those; anything else is left to the screen.
+    pub fn handle(&mut self, input: &GameInput) -> bool {
+        match input {
+            GameInput::Char(ch) => {
+                self.insert(*ch);
+            }
+            GameInput::Backspace => {
+                self.backspace();
+            }
+            GameInput::Direction(Direction::Left) => self.cursor = self.cursor.saturating_sub(1),
+            GameInput::Direction(Direction::Right) => {
+                self.cursor = (self.cursor + 1).min(self.chars.len())
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typing_inserts_until_full_then_overwrites() {
+        let mut field = TextField::new("ab", 4, |ch| ch.is_ascii_digit().then_some(ch));
+        assert_eq!(field.text(), "");
+
+        for ch in "12x3".chars() {
+            field.handle(&GameInput::Char(ch));
+        }
+        assert_eq!((field.text().as_str(), field.cursor()), ("123", 3));
+
+        field.handle(&GameInput::Direction(Direction::Left));
+        field.handle(&GameInput::Direction(Direction::Left));
+        field.handle(&GameInput::Char('9'));
+        assert_eq!(field.text(), "1923");
+        field.handle(&GameInput::Char('8'));
+        assert_eq!((field.text().as_str(), field.cursor()), ("1983", 3));
+
+        field.handle(&GameInput::Backspace);
+        field.move_to_end();
+        field.handle(&GameInput::Direction(Direction::Right));
+        assert_eq!((field.text().as_str(), field.cursor()), ("193", 3));
+        assert!(!field.handle(&GameInput::MenuConfirm));
+    }
+}
@@ -1,9 +1,70 @@
 //! Terminal layout calculations for responsive rendering.
 
-use crate::{i18n, utils::Language};
+use crate::storage::Settings;
+use crate::{
+    i18n,
+    utils::{AxisPace, HudPosition, Language, Position},
+};
 use crossterm::terminal;
 
 pub const HUD_BOTTOM_PADDING: u16 = 5;
+/// Rows of the compact HUD: a blank row and the status line.
+const COMPACT_HUD_ROWS: u16 = 2;
+/// Columns of the HUD panel beside the board.
+pub const SIDE_PANEL_WIDTH: u16 = 28;
+/// Blank columns between the board and the HUD panel.
+const SIDE_PANEL_GAP: u16 = 2;
+/// Columns of the effects panel, and the blank columns before it.
+pub const SIDEBAR_WIDTH: u16 = 22;
+const SIDEBAR_GAP: u16 = 2;
+/// Height of a terminal cell over its width, in percent, when the terminal
+/// does not report its cell size: the 2:1 the difficulty presets assume.
+const DEFAULT_CELL_ASPECT_PERCENT: u16 = 200;
+/// Cell aspects outside this are taken to be mismeasured or mistyped.
+pub const CELL_ASPECT_RANGE: (u16, u16) = (100, 300);
+/// The fewest board cells across and down a camera shows on a terminal too
+/// small for the whole board.
+const MIN_VIEW_COLUMNS: u16 = 24;
+const MIN_VIEW_ROWS: u16 = 10;
+/// Cells the camera keeps between the snake's head and the edge of its view.
+const CAMERA_MARGIN: u16 = 4;
+
+/// Where the HUD goes and how much of it there is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Hud {
+    pub position: HudPosition,
+    /// One status line without the controls.
+    pub compact: bool,
+    /// The effects panel on the right, when the terminal has room for it.
+    pub sidebar: bool,
+}
+
+impl Hud {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            position: settings.hud_position,
+            compact: settings.hud_compact,
+            sidebar: settings.sidebar,
+        }
+    }
+
+    /// Rows the HUD takes above or below the board.
+    fn rows(self) -> u16 {
+        match (self.position, self.compact) {
+            (HudPosition::Right, _) => 0,
+            (_, true) => COMPACT_HUD_ROWS,
+            (_, false) => HUD_BOTTOM_PADDING,
+        }
+    }
+
+    /// Columns the HUD takes beside the board.
+    fn columns(self) -> u16 {
+        match self.position {
+            HudPosition::Right => SIDE_PANEL_GAP + SIDE_PANEL_WIDTH,
+            HudPosition::Below | HudPosition::Above => 0,
+        }
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Layout {
@@ -13,6 +74,19 @@ pub struct Layout {
     pub map_height: u16,
     pub origin_x: u16,
     pub origin_y: u16,
+    /// Terminal columns per board cell: 2 in square-cell mode, which makes up
+    /// for terminal cells being about twice as tall as they are wide.
+    pub cell_width: u16,
+    pub hud: Hud,
+    /// Leftmost column of the effects panel, when it is shown.
+    pub sidebar_x: Option<u16>,
+    /// Interior cells of the whole board, across and down. The border may
+    /// show fewer, a camera's view of them.
+    pub board_columns: u16,
+    pub board_rows: u16,
+    /// Interior cells the camera leaves out to the left and above.
+    pub scroll_x: u16,
+    pub scroll_y: u16,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -37,44 +111,198 @@ impl Layout {
         self.origin_y + self.map_height - 1
     }
 
+    /// Leftmost column and row of board cell `(x, y)`. The border stays one
+    /// column wide, so playable cells start right after it.
     pub fn board_to_screen(&self, x: u16, y: u16) -> (u16, u16) {
-        (self.origin_x + x - 1, self.origin_y + y - 1)
+        let x = x.saturating_sub(self.scroll_x);
+        let y = y.saturating_sub(self.scroll_y);
+        (
+            (self.origin_x + 1 + x * self.cell_width).saturating_sub(2 * self.cell_width),
+            self.origin_y + y - 1,
+        )
+    }
+
+    /// Board cells across the inside of the border.
+    pub fn view_columns(&self) -> u16 {
+        self.map_width.saturating_sub(2) / self.cell_width
+    }
+
+    /// Board cells down the inside of the border.
+    pub fn view_rows(&self) -> u16 {
+        self.map_height.saturating_sub(2)
+    }
+
+    /// Whether the border shows only part of the board, which the camera
+    /// moves to follow the snake.
+    pub fn scrolls(&self) -> bool {
+        self.view_columns() < self.board_columns || self.view_rows() < self.board_rows
+    }
+
+    /// Whether board cell `pos` is in the camera's view. Without a camera
+    /// the whole board is.
+    pub fn shows(&self, pos: Position) -> bool {
+        let first = Position {
+            x: 2 + self.scroll_x,
+            y: 2 + self.scroll_y,
+        };
+        !self.scrolls()
+            || ((first.x..first.x + self.view_columns()).contains(&pos.x)
+                && (first.y..first.y + self.view_rows()).contains(&pos.y))
+    }
+
+    /// The layout with its camera, if it has one, moved so `head` stays
+    /// `CAMERA_MARGIN` cells inside the view where the board allows. The
+    /// camera carries on from `previous` when that was the same view, and
+    /// starts centered on `head` otherwise.
+    pub fn following(mut self, head: Position, previous: Option<Layout>) -> Self {
+        if !self.scrolls() {
+            return self;
+        }
+        let previous = previous.filter(|previous| {
+            Layout {
+                scroll_x: self.scroll_x,
+                scroll_y: self.scroll_y,
+                ..*previous
+            } == self
+        });
+        self.scroll_x = follow(
+            previous.map(|previous| previous.scroll_x),
+            head.x.saturating_sub(2),
+            self.view_columns(),
+            self.board_columns,
+        );
+        self.scroll_y = follow(
+            previous.map(|previous| previous.scroll_y),
+            head.y.saturating_sub(2),
+            self.view_rows(),
+            self.board_rows,
+        );
+        self
+    }
+
+    /// First row of a HUD above or below the board, which starts with the
+    /// status line. A blank row separates the HUD from the board.
+    fn hud_top(&self) -> u16 {
+        match self.hud.position {
+            HudPosition::Above => self.origin_y - self.hud.rows(),
+            HudPosition::Below | HudPosition::Right => self.map_bottom() + 2,
+        }
     }
 
     pub fn hud_score_y(&self) -> u16 {
-        self.map_bottom() + 2
+        self.hud_top()
     }
 
     pub fn hud_info_y(&self) -> u16 {
-        self.map_bottom() + 3
+        self.hud_top() + 1
     }
 
     pub fn hud_controls_y(&self) -> u16 {
-        self.map_bottom() + HUD_BOTTOM_PADDING
+        self.hud_top() + 3
+    }
+
+    /// Leftmost column of the HUD panel beside the board.
+    pub fn hud_panel_x(&self) -> u16 {
+        self.map_right() + 1 + SIDE_PANEL_GAP
+    }
+}
+
+/// Where a camera `view` cells long starts along one side of a board
+/// `board` cells long so it keeps `cell` in sight: where it was at
+/// `scroll`, unless that puts `cell` too near an edge of the view.
+fn follow(scroll: Option<u16>, cell: u16, view: u16, board: u16) -> u16 {
+    let margin = CAMERA_MARGIN.min(view / 4);
+    let scroll = match scroll {
+        None => cell.saturating_sub(view / 2),
+        Some(scroll) if cell < scroll + margin => cell.saturating_sub(margin),
+        Some(scroll) if cell + margin >= scroll + view => cell + margin + 1 - view,
+        Some(scroll) => scroll,
+    };
+    scroll.min(board.saturating_sub(view))
+}
+
+/// The terminal's cell aspect from its size in pixels, which not every
+/// terminal reports.
+pub fn measured_cell_aspect_percent() -> Option<u16> {
+    let size = terminal::window_size().ok()?;
+    if size.width == 0 || size.rows == 0 {
+        return None;
+    }
+    let aspect = u64::from(size.height) * u64::from(size.columns) * 100
+        / (u64::from(size.width) * u64::from(size.rows));
+    u16::try_from(aspect).ok()
+}
+
+/// How long a vertical tick is next to a horizontal one, in percent, so the
+/// snake crosses the screen as fast either way. A board cell is one terminal
+/// cell, or two side by side with square cells.
+pub fn vertical_pace_percent(settings: &Settings, measured_aspect: Option<u16>) -> u64 {
+    let aspect = match settings.axis_pace {
+        AxisPace::Off => return 100,
+        AxisPace::Auto => measured_aspect.unwrap_or(DEFAULT_CELL_ASPECT_PERCENT),
+        AxisPace::Ratio => settings.cell_aspect_percent,
     }
+    .clamp(CELL_ASPECT_RANGE.0, CELL_ASPECT_RANGE.1);
+    let cell_width = if settings.square_cells { 2 } else { 1 };
+    u64::from(aspect) / cell_width
 }
 
-pub fn terminal_size() -> (u16, u16) {
-    terminal::size().unwrap_or((80, 24))
+/// Terminal columns the board border spans for a board `board_width` cells wide.
+fn screen_map_width(board_width: u16, cell_width: u16) -> u16 {
+    board_width.saturating_sub(2) * cell_width + 2
 }
 
-pub fn min_terminal_size(map_width: u16, map_height: u16, language: Language) -> MinSize {
-    let min_width = map_width.max(i18n::minimum_ui_width(language));
-    let min_height = map_height + HUD_BOTTOM_PADDING;
+pub fn min_terminal_size(
+    map_width: u16,
+    map_height: u16,
+    square_cells: bool,
+    hud: Hud,
+    language: Language,
+) -> MinSize {
+    let cell_width = if square_cells { 2 } else { 1 };
+    let min_width = (screen_map_width(map_width, cell_width) + hud.columns())
+        .max(i18n::minimum_ui_width(language));
+    let min_height = map_height + hud.rows();
     MinSize {
         width: min_width,
         height: min_height,
     }
 }
 
+/// The smallest terminal a run can be played in. Below
+/// `min_terminal_size` the border shows a camera's view of at least
+/// `MIN_VIEW_COLUMNS` by `MIN_VIEW_ROWS` cells that follows the snake.
+pub fn min_play_size(
+    map_width: u16,
+    map_height: u16,
+    square_cells: bool,
+    hud: Hud,
+    language: Language,
+) -> MinSize {
+    let whole = min_terminal_size(map_width, map_height, square_cells, hud, language);
+    let view = min_terminal_size(
+        map_width.min(MIN_VIEW_COLUMNS + 2),
+        map_height.min(MIN_VIEW_ROWS + 2),
+        square_cells,
+        hud,
+        language,
+    );
+    MinSize {
+        width: view.width.min(whole.width),
+        height: view.height.min(whole.height),
+    }
+}
+
 pub fn compute_layout(
     term_width: u16,
     term_height: u16,
     map_width: u16,
     map_height: u16,
+    square_cells: bool,
+    hud: Hud,
     language: Language,
 ) -> Result<Layout, SizeCheck> {
-    let minimum = min_terminal_size(map_width, map_height, language);
+    let minimum = min_play_size(map_width, map_height, square_cells, hud, language);
     if term_width < minimum.width || term_height < minimum.height {
         return Err(SizeCheck {
             current_width: term_width,
@@ -83,9 +311,26 @@ pub fn compute_layout(
         });
     }
 
-    let total_height = map_height + HUD_BOTTOM_PADDING;
-    let origin_x = ((term_width - map_width) / 2) + 1;
-    let origin_y = ((term_height - total_height) / 2) + 1;
+    let cell_width = if square_cells { 2 } else { 1 };
+    let whole = min_terminal_size(map_width, map_height, square_cells, hud, language);
+    let board_columns = map_width.saturating_sub(2);
+    let board_rows = map_height.saturating_sub(2);
+    // Past the whole board the border shows as much of it as fits.
+    let view_columns =
+        ((term_width - hud.columns()).saturating_sub(2) / cell_width).min(board_columns);
+    let view_rows = (term_height - hud.rows()).saturating_sub(2).min(board_rows);
+    let map_width = screen_map_width(view_columns + 2, cell_width);
+    let map_height = view_rows + 2;
+    let total_height = map_height + hud.rows();
+    // The effects panel only comes along when it fits next to everything else.
+    let sidebar_columns = SIDEBAR_GAP + SIDEBAR_WIDTH;
+    let sidebar = hud.sidebar && term_width >= whole.width + sidebar_columns;
+    let extra_columns = if sidebar { sidebar_columns } else { 0 };
+    let origin_x = ((term_width - map_width - hud.columns() - extra_columns) / 2) + 1;
+    let mut origin_y = ((term_height - total_height) / 2) + 1;
+    if hud.position == HudPosition::Above {
+        origin_y += hud.rows();
+    }
 
     Ok(Layout {
         term_width,
@@ -94,6 +339,13 @@ pub fn compute_layout(
         map_height,
         origin_x,
         origin_y,
+        cell_width,
+        hud,
+        sidebar_x: sidebar.then(|| origin_x + map_width + hud.columns() + SIDEBAR_GAP),
+        board_columns,
+        board_rows,
+        scroll_x: 0,
+        scroll_y: 0,
     })
 }
 
@@ -102,25 +354,170 @@ mod tests {
     use super::*;
     use crate::utils::Language;
 
+    #[test]
+    fn vertical_pace_follows_the_cell_aspect() {
+        let mut settings = Settings::default();
+        assert_eq!(vertical_pace_percent(&settings, None), 200);
+        assert_eq!(vertical_pace_percent(&settings, Some(215)), 215);
+        // A 1x1 "terminal" reporting nonsense.
+        assert_eq!(vertical_pace_percent(&settings, Some(20)), 100);
+        settings.square_cells = true;
+        assert_eq!(vertical_pace_percent(&settings, Some(215)), 107);
+
+        settings.axis_pace = AxisPace::Ratio;
+        settings.cell_aspect_percent = 240;
+        assert_eq!(vertical_pace_percent(&settings, Some(215)), 120);
+        settings.axis_pace = AxisPace::Off;
+        assert_eq!(vertical_pace_percent(&settings, Some(215)), 100);
+    }
+
     #[test]
     fn rejects_too_small_terminal() {
-        let result = compute_layout(20, 10, 40, 20, Language::En);
+        let result = compute_layout(20, 10, 40, 20, false, Hud::default(), Language::En);
         assert!(result.is_err());
     }
 
     #[test]
     fn centers_map_on_larger_terminal() {
-        let layout = compute_layout(100, 40, 40, 20, Language::En).unwrap();
+        let layout = compute_layout(100, 40, 40, 20, false, Hud::default(), Language::En).unwrap();
         assert_eq!(layout.origin_x, 31);
         assert_eq!(layout.origin_y, 8);
         assert_eq!(layout.map_right(), 70);
         assert_eq!(layout.map_bottom(), 27);
+        assert_eq!(layout.board_to_screen(2, 2), (32, 9));
+    }
+
+    #[test]
+    fn square_cells_double_the_board_width() {
+        // Too narrow for the whole board: the border shows part of it.
+        let layout = compute_layout(60, 40, 40, 20, true, Hud::default(), Language::En).unwrap();
+        assert!(layout.scrolls());
+        assert_eq!(layout.view_columns(), 29);
+
+        let layout = compute_layout(100, 40, 40, 20, true, Hud::default(), Language::En).unwrap();
+        assert_eq!(layout.map_width, 78);
+        assert_eq!(layout.origin_x, 12);
+        // Playable cells are two columns wide and sit flush with both borders.
+        assert_eq!(layout.board_to_screen(2, 2), (13, 9));
+        assert_eq!(layout.board_to_screen(3, 2), (15, 9));
+        assert_eq!(layout.board_to_screen(39, 2).0 + 2, layout.map_right());
+        // A fresh game's placeholder food at (0, 0) is off the board but still mapped.
+        assert_eq!(layout.board_to_screen(0, 0), (9, 7));
+    }
+
+    #[test]
+    fn hud_position_moves_the_board() {
+        let above = Hud {
+            position: HudPosition::Above,
+            ..Hud::default()
+        };
+        let layout = compute_layout(100, 40, 40, 20, false, above, Language::En).unwrap();
+        assert_eq!(layout.origin_y, 13);
+        assert_eq!(layout.hud_score_y(), 8);
+        assert_eq!(layout.hud_controls_y(), 11);
+
+        let compact = Hud {
+            compact: true,
+            ..Hud::default()
+        };
+        let layout = compute_layout(100, 40, 40, 20, false, compact, Language::En).unwrap();
+        assert_eq!(layout.origin_y, 10);
+        assert_eq!(layout.hud_score_y(), layout.map_bottom() + 2);
+        assert_eq!(
+            min_terminal_size(40, 20, false, compact, Language::En).height,
+            22
+        );
+
+        let right = Hud {
+            position: HudPosition::Right,
+            ..Hud::default()
+        };
+        assert!(compute_layout(50, 40, 40, 20, false, right, Language::En).is_err());
+        assert!(
+            compute_layout(60, 40, 40, 20, false, right, Language::En)
+                .unwrap()
+                .scrolls()
+        );
+        let layout = compute_layout(100, 40, 40, 20, false, right, Language::En).unwrap();
+        assert_eq!((layout.origin_x, layout.origin_y), (16, 11));
+        assert_eq!(layout.hud_panel_x(), 58);
+        assert_eq!(layout.hud_panel_x() + SIDE_PANEL_WIDTH, 86);
+    }
+
+    #[test]
+    fn sidebar_shows_only_when_it_fits() {
+        let hud = Hud {
+            sidebar: true,
+            ..Hud::default()
+        };
+        let layout = compute_layout(100, 40, 40, 20, false, hud, Language::En).unwrap();
+        assert_eq!(layout.origin_x, 19);
+        assert_eq!(layout.sidebar_x, Some(layout.map_right() + 1 + SIDEBAR_GAP));
+        assert_eq!(layout.sidebar_x.unwrap() + SIDEBAR_WIDTH, 83);
+
+        // Too narrow for the panel, but still wide enough to play.
+        let layout = compute_layout(60, 40, 40, 20, false, hud, Language::En).unwrap();
+        assert_eq!(layout.sidebar_x, None);
+        assert_eq!(layout.origin_x, 11);
+
+        let beside_hud = Hud {
+            position: HudPosition::Right,
+            compact: false,
+            sidebar: true,
+        };
+        let layout = compute_layout(120, 40, 40, 20, false, beside_hud, Language::En).unwrap();
+        assert_eq!(
+            layout.sidebar_x,
+            Some(layout.hud_panel_x() + SIDE_PANEL_WIDTH + SIDEBAR_GAP)
+        );
+    }
+
+    #[test]
+    fn a_camera_follows_the_head_where_the_board_does_not_fit() {
+        assert_eq!(
+            min_play_size(40, 20, false, Hud::default(), Language::En).height,
+            MIN_VIEW_ROWS + 2 + HUD_BOTTOM_PADDING
+        );
+        let layout = compute_layout(80, 20, 40, 20, false, Hud::default(), Language::En).unwrap();
+        assert!(
+            !compute_layout(80, 25, 40, 20, false, Hud::default(), Language::En)
+                .unwrap()
+                .scrolls()
+        );
+        assert!(layout.scrolls());
+        assert_eq!((layout.view_columns(), layout.view_rows()), (38, 13));
+
+        // A new camera starts centered on the head, as far as the board goes.
+        let layout = layout.following(Position { x: 20, y: 18 }, None);
+        assert_eq!((layout.scroll_x, layout.scroll_y), (0, 5));
+        let layout = layout.following(Position { x: 20, y: 4 }, None);
+        assert_eq!(layout.scroll_y, 0);
+        assert!(layout.shows(Position { x: 20, y: 4 }));
+        assert!(!layout.shows(Position { x: 20, y: 15 }));
+        assert_eq!(
+            layout.board_to_screen(2, 2),
+            (layout.origin_x + 1, layout.origin_y + 1)
+        );
+
+        // Then it only moves to keep the head away from the edge of the view.
+        let mut moved = layout;
+        for y in 4..=19 {
+            moved = moved.following(Position { x: 20, y }, Some(moved));
+            assert!(moved.shows(Position { x: 20, y }));
+        }
+        assert_eq!(moved.scroll_y, 5);
+        let (_, head_y) = moved.board_to_screen(20, 19);
+        assert_eq!(head_y, moved.map_bottom() - 1);
+        let back = moved.following(Position { x: 20, y: 14 }, Some(moved));
+        assert_eq!(back.scroll_y, moved.scroll_y);
+        let back = back.following(Position { x: 20, y: 9 }, Some(back));
+        assert_eq!(back.scroll_y, 9 - 2 - 3);
     }
 
     #[test]
     fn minimum_width_covers_all_localized_ui_strings() {
         for language in Language::ALL {
-            let minimum = min_terminal_size(40, 20, language);
+            let minimum = min_terminal_size(40, 20, false, Hud::default(), language);
             assert!(minimum.width >= i18n::minimum_ui_width(language));
         }
     }
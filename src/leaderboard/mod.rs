@@ -0,0 +1,209 @@
+//! Opt-in global leaderboard client (`leaderboard` feature).
+//!
+//! The server is configured in `leaderboard.toml` beside the config. Requests
+//! run on one background thread so menus and the game loop never wait on the
+//! network:
+//! - `POST {url}/scores` with `{ initials, difficulty, score, replay_hash }`
+//! - `GET {url}/top?difficulty=<name>&limit=20`, answered with a JSON list of
+//!   `{ score, initials, date }` entries, best first.
+
+use crate::storage::{EndpointSettings, HighScoreEntry};
+use crate::utils::{Difficulty, Initials};
+use serde::Serialize;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+pub const GLOBAL_TOP_SIZE: usize = 20;
+
+/// What the global leaderboard screen shows for one difficulty.
+pub enum BoardState<'a> {
+    Loading,
+    Failed,
+    Ready(&'a [HighScoreEntry]),
+}
+
+/// Boards fetched since the global leaderboard screen was opened, one per difficulty.
+#[derive(Default)]
+pub struct GlobalBoards {
+    boards: [Option<Result<Vec<HighScoreEntry>, String>>; Difficulty::ALL.len()],
+    requested: [bool; Difficulty::ALL.len()],
+}
+
+fn difficulty_index(difficulty: Difficulty) -> usize {
+    Difficulty::ALL
+        .iter()
+        .position(|entry| *entry == difficulty)
+        .unwrap_or(0)
+}
+
+impl GlobalBoards {
+    /// Requests `difficulty` if it has not been fetched yet and collects any
+    /// answers that have arrived.
+    pub fn refresh(&mut self, client: &LeaderboardClient, difficulty: Difficulty) {
+        let index = difficulty_index(difficulty);
+        if !self.requested[index] {
+            self.requested[index] = true;
+            client.request_top(difficulty);
+        }
+        while let Some(top) = client.poll() {
+            self.boards[difficulty_index(top.difficulty)] = Some(top.result);
+        }
+    }
+
+    pub fn state(&self, difficulty: Difficulty) -> BoardState<'_> {
+        match &self.boards[difficulty_index(difficulty)] {
+            None => BoardState::Loading,
+            Some(Err(_)) => BoardState::Failed,
+            Some(Ok(entries)) => BoardState::Ready(entries),
+        }
+    }
+}
+
+/// Bounds how long a pending submission can hold up exit.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Submission {
+    pub initials: Initials,
+    pub difficulty: Difficulty,
+    pub score: u32,
+    pub replay_hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopScores {
+    pub difficulty: Difficulty,
+    pub result: Result<Vec<HighScoreEntry>, String>,
+}
+
+enum Request {
+    Submit(Submission),
+    Top(Difficulty),
+}
+
+pub struct LeaderboardClient {
+    requests: Sender<Request>,
+    responses: Receiver<TopScores>,
+    worker: JoinHandle<()>,
+}
+
+fn endpoint(settings: &EndpointSettings, path: &str) -> String {
+    format!("{}/{path}", settings.url.trim_end_matches('/'))
+}
+
+fn authorized(request: ureq::Request, settings: &EndpointSettings) -> ureq::Request {
+    if settings.token.is_empty() {
+        request
+    } else {
+        request.set("Authorization", &format!("Bearer {}", settings.token))
+    }
+}
+
+fn submit(
+    agent: &ureq::Agent,
+    settings: &EndpointSettings,
+    submission: &Submission,
+) -> Result<(), String> {
+    authorized(agent.post(&endpoint(settings, "scores")), settings)
+        .send_json(submission)
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+fn fetch_top(
+    agent: &ureq::Agent,
+    settings: &EndpointSettings,
+    difficulty: Difficulty,
+) -> Result<Vec<HighScoreEntry>, String> {
+    let mut entries = authorized(agent.get(&endpoint(settings, "top")), settings)
+        .query("difficulty", difficulty.name())
+        .query("limit", &GLOBAL_TOP_SIZE.to_string())
+        .call()
+        .map_err(|err| err.to_string())?
+        .into_json::<Vec<HighScoreEntry>>()
+        .map_err(|err| format!("invalid response from {}: {err}", settings.url))?;
+    entries.sort_by(|a, b| b.score.cmp(&a.score));
+    entries.truncate(GLOBAL_TOP_SIZE);
+    Ok(entries)
+}
+
+impl LeaderboardClient {
+    pub fn spawn(settings: EndpointSettings) -> Self {
+        let (requests, request_rx) = mpsc::channel::<Request>();
+        let (response_tx, responses) = mpsc::channel();
+        let worker = thread::spawn(move || {
+            let agent = ureq::AgentBuilder::new().timeout(REQUEST_TIMEOUT).build();
+            for request in request_rx {
+                match request {
+                    // Submissions are best effort; a failed one is not retried.
+                    Request::Submit(submission) => {
+                        let _ = submit(&agent, &settings, &submission);
+                    }
+                    Request::Top(difficulty) => {
+                        let result = fetch_top(&agent, &settings, difficulty);
+                        if response_tx.send(TopScores { difficulty, result }).is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+        Self {
+            requests,
+            responses,
+            worker,
+        }
+    }
+
+    pub fn submit(&self, submission: Submission) {
+        let _ = self.requests.send(Request::Submit(submission));
+    }
+
+    /// Queues a fetch; the answer arrives through `poll`.
+    pub fn request_top(&self, difficulty: Difficulty) {
+        let _ = self.requests.send(Request::Top(difficulty));
+    }
+
+    pub fn poll(&self) -> Option<TopScores> {
+        self.responses.try_recv().ok()
+    }
+
+    /// Lets queued submissions finish before the process exits.
+    pub fn shutdown(self) {
+        drop(self.requests);
+        let _ = self.worker.join();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn submission_serializes_lowercase_difficulty() {
+        let submission = Submission {
+            initials: Initials::from("ABC".to_string()),
+            difficulty: Difficulty::Extreme,
+            score: 340,
+            replay_hash: "00ff00ff00ff00ff".to_string(),
+        };
+
+        let json = serde_json::to_value(&submission).unwrap();
+
+        assert_eq!(json["initials"], "ABC");
+        assert_eq!(json["difficulty"], Difficulty::Extreme.name());
+        assert_eq!(json["score"], 340);
+        assert_eq!(json["replay_hash"], "00ff00ff00ff00ff");
+        assert_eq!(
+            endpoint(
+                &EndpointSettings {
+                    url: "https://example.test/api/".to_string(),
+                    token: String::new(),
+                },
+                "top"
+            ),
+            "https://example.test/api/top"
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! Game engine behind the `rustnake` terminal frontend.
+//!
+//! The engine never touches the terminal or the clock. Frontends drive a
+//! [`core::Game`] by calling `tick` once per [`core::Game::tick_interval`],
+//! feed it turns through `update_snake_direction`, and read the board, score,
+//! and drained `sound_events` back out. Seed games with `Game::with_seed` to get
+//! reproducible spawns for bots, tests, and replays.
+
+pub mod core;
+pub mod scores;
+pub mod utils;
@@ -0,0 +1,145 @@
+//! `--verbose` and `--log-level <level>`: a debug log written beside the
+//! config, for chasing issues players report. Nothing is logged without one
+//! of the flags. The file rolls over to `<name>.1` past `MAX_LOG_BYTES`, so a
+//! long session never grows it without bound.
+
+use log::{LevelFilter, Log, Metadata, Record};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Instant,
+};
+
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+struct LogFile {
+    path: PathBuf,
+    file: File,
+    len: u64,
+}
+
+impl LogFile {
+    fn open(path: &Path) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let len = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            len,
+        })
+    }
+
+    /// Moves the current file to `<name>.1`, replacing the previous one, and
+    /// starts an empty file in its place.
+    fn rotate(&mut self) -> std::io::Result<()> {
+        let mut rotated = self.path.clone().into_os_string();
+        rotated.push(".1");
+        fs::rename(&self.path, rotated)?;
+        *self = Self::open(&self.path)?;
+        Ok(())
+    }
+
+    fn write_line(&mut self, line: &str) {
+        if self.len >= MAX_LOG_BYTES && self.rotate().is_err() {
+            return;
+        }
+        if self.file.write_all(line.as_bytes()).is_ok() {
+            self.len += line.len() as u64;
+        }
+    }
+}
+
+struct FileLogger {
+    level: LevelFilter,
+    started: Instant,
+    file: Mutex<LogFile>,
+}
+
+impl Log for FileLogger {
+    /// Only the game's own records; crossterm's event loop logs every poll.
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level && metadata.target().starts_with("rustnake")
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        // Seconds since startup line up with the game's own timings better
+        // than wall-clock time, which the header line records once.
+        let line = format!(
+            "{:>10.3} {:<5} {}: {}\n",
+            self.started.elapsed().as_secs_f64(),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        self.file
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .write_line(&line);
+    }
+
+    fn flush(&self) {
+        let _ = self
+            .file
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .file
+            .flush();
+    }
+}
+
+/// Starts logging at `level` to the file at `path`.
+pub fn init(level: LevelFilter, path: &Path) -> Result<(), String> {
+    let mut file =
+        LogFile::open(path).map_err(|err| format!("cannot open {}: {err}", path.display()))?;
+    file.write_line(&format!(
+        "--- rustnake {} started {} (log level {level})\n",
+        env!("CARGO_PKG_VERSION"),
+        crate::storage::current_utc_date()
+    ));
+    log::set_boxed_logger(Box::new(FileLogger {
+        level,
+        started: Instant::now(),
+        file: Mutex::new(file),
+    }))
+    .map_err(|err| err.to_string())?;
+    log::set_max_level(level);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn log_file_rolls_over_past_the_size_limit() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path =
+            std::env::temp_dir().join(format!("rustnake-log-{}-{nanos}.log", std::process::id()));
+        let rotated = PathBuf::from(format!("{}.1", path.display()));
+
+        let mut file = LogFile::open(&path).unwrap();
+        let line = format!("{}\n", "x".repeat(1023));
+        for _ in 0..MAX_LOG_BYTES / 1024 {
+            file.write_line(&line);
+        }
+        assert!(!rotated.exists());
+        file.write_line("after\n");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "after\n");
+        assert_eq!(fs::metadata(&rotated).unwrap().len(), MAX_LOG_BYTES);
+
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(&rotated);
+    }
+}
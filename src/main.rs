@@ -1,420 +1,267 @@
 //! Main entry point for the Snake game.
 //! Orchestrates the game loop, input handling, and rendering.
 
+use clap::Parser;
 use crossterm::{
     cursor::{Hide, Show},
-    event::{DisableFocusChange, EnableFocusChange},
+    event::{
+        DisableFocusChange, EnableFocusChange, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
+    style::Print,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use std::{
     collections::VecDeque,
-    io::stdout,
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        mpsc,
-    },
-    thread,
+    io::{Write, stdout},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
     time::{Duration, Instant},
 };
 
-mod core;
+mod app;
+#[cfg(feature = "bot")]
+mod bot;
+mod cli;
+mod demo;
 mod i18n;
 mod input;
 mod layout;
+#[cfg(feature = "leaderboard")]
+mod leaderboard;
+mod logging;
+mod menu;
+mod notices;
 mod render;
+mod spectate;
 mod storage;
-mod utils;
+#[cfg(feature = "sync")]
+mod sync;
+mod timing;
 
-use core::Game;
-use input::GameInput;
-use storage::{HighScores, Settings};
+use rustnake::{core, utils};
+
+use app::{
+    paced_profile, persist_high_scores, persist_settings, play_game_sounds, play_ui_sound,
+    reload_settings, set_render_backend, update_window_title,
+};
+use core::{DifficultyProfile, Game};
+use demo::{AutoplayEnd, autoplay};
+use input::{GameInput, InputWait};
+use storage::{HighScoreEntry, HighScores, RunRecord, ScoreKey, Settings};
 use utils::{Difficulty, Language};
 
+/// Restores the terminal when dropped. Create it right after switching to the
+/// alternate screen.
 struct TerminalGuard;
-static REPORTED_CONFIG_SAVE_ERROR: AtomicBool = AtomicBool::new(false);
+/// Whether the alternate screen is up, so the terminal is restored only once
+/// when both the panic hook and `TerminalGuard` get to it.
+static IN_ALTERNATE_SCREEN: AtomicBool = AtomicBool::new(false);
+/// The window title was saved on startup and is put back on exit.
+static TITLE_SAVED: AtomicBool = AtomicBool::new(false);
+static REPORTED_HISTORY_SAVE_ERROR: AtomicBool = AtomicBool::new(false);
+static REPORTED_RECORDING_SAVE_ERROR: AtomicBool = AtomicBool::new(false);
+static REPORTED_GHOST_SAVE_ERROR: AtomicBool = AtomicBool::new(false);
+static REPORTED_AUTOSAVE_ERROR: AtomicBool = AtomicBool::new(false);
+
+impl TerminalGuard {
+    fn new() -> Self {
+        IN_ALTERNATE_SCREEN.store(true, Ordering::Relaxed);
+        Self
+    }
+}
 
 impl Drop for TerminalGuard {
     fn drop(&mut self) {
-        let _ = disable_raw_mode();
-        let mut stdout = stdout();
-        let _ = execute!(stdout, DisableFocusChange, LeaveAlternateScreen, Show);
+        restore_terminal();
+    }
+}
+
+/// Hands the terminal back to the shell: cooked mode, main screen, cursor shown.
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    if !IN_ALTERNATE_SCREEN.swap(false, Ordering::Relaxed) {
+        return;
+    }
+    let mut stdout = stdout();
+    if TITLE_SAVED.swap(false, Ordering::Relaxed) {
+        let _ = execute!(stdout, Print(render::RESTORE_TITLE));
     }
+    let _ = execute!(
+        stdout,
+        PopKeyboardEnhancementFlags,
+        DisableFocusChange,
+        LeaveAlternateScreen,
+        Show
+    );
 }
 
-fn persist_config(high_scores: &HighScores, settings: Settings) {
-    let config = storage::AppConfig {
-        high_scores: *high_scores,
-        settings,
+/// Restores the terminal before the default hook prints a panic. Waiting for
+/// `TerminalGuard` would print the message onto the alternate screen, which is
+/// wiped when unwinding reaches the guard, and a panic on another thread never
+/// reaches it at all. With `--crash-log <file>` the panic is also appended
+/// there with a backtrace.
+fn install_panic_hook(crash_log: Option<PathBuf>) {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        log::error!("{info}");
+        default_hook(info);
+        if let Some(path) = &crash_log {
+            match append_crash_log(path, info) {
+                Ok(()) => eprintln!("crash log written to {}", path.display()),
+                Err(err) => eprintln!("warning: failed to write {}: {err}", path.display()),
+            }
+        }
+    }));
+}
+
+fn append_crash_log(path: &Path, info: &std::panic::PanicHookInfo) -> std::io::Result<()> {
+    let backtrace = std::backtrace::Backtrace::force_capture();
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    writeln!(
+        file,
+        "{} rustnake {}\n{info}\n{backtrace}",
+        storage::current_utc_date(),
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+fn record_run_history(game: &Game) {
+    // Runs abandoned before the first tick never started and are not worth keeping.
+    if game.stats.ticks == 0 {
+        return;
+    }
+    let record = RunRecord {
+        play_secs: game.stats.play_time.as_secs(),
+        food_eaten: game.stats.food_eaten,
+        power_ups: game.stats.total_power_ups(),
+        best_combo: game.stats.max_combo,
+        max_length: game.stats.max_length,
+        ..RunRecord::new(game.difficulty, game.score)
     };
-    if let Err(err) = storage::save_config(&config) {
-        if !REPORTED_CONFIG_SAVE_ERROR.swap(true, Ordering::Relaxed) {
-            eprintln!("warning: failed to save rustnake config: {err}");
+    if let Err(err) = storage::append_history(record) {
+        if !REPORTED_HISTORY_SAVE_ERROR.swap(true, Ordering::Relaxed) {
+            notices::report(format!("Failed to save run history: {err}"));
         }
     }
 }
 
-#[derive(Clone, Copy)]
-enum MenuScreen {
-    Main,
-    Difficulty,
-    HighScores,
-    Settings,
-    Language,
-    ResetScoresConfirm,
+/// How often a live run is saved, to offer back after a crash.
+const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+fn autosave_run(game: &Game) {
+    if let Err(err) = storage::save_autosave(game) {
+        if !REPORTED_AUTOSAVE_ERROR.swap(true, Ordering::Relaxed) {
+            notices::report(format!("Failed to autosave the run: {err}"));
+        }
+    }
 }
 
-fn difficulty_to_index(difficulty: Difficulty) -> usize {
-    match difficulty {
-        Difficulty::Easy => 0,
-        Difficulty::Medium => 1,
-        Difficulty::Hard => 2,
-        Difficulty::Extreme => 3,
+fn discard_autosave() {
+    if let Err(err) = storage::clear_autosave() {
+        log::warn!("could not remove the autosave: {err}");
     }
 }
 
-fn difficulty_from_index(index: usize) -> Difficulty {
-    match index {
-        0 => Difficulty::Easy,
-        1 => Difficulty::Medium,
-        2 => Difficulty::Hard,
-        3 => Difficulty::Extreme,
-        _ => Difficulty::Medium,
+/// Where a finished run goes on its score table, if anywhere. Assisted runs,
+/// runs at a speed other than their difficulty's, and runs that never started
+/// stay off the tables, whichever mode played them.
+fn high_score_rank(game: &Game, high_scores: &HighScores) -> Option<usize> {
+    if game.stats.ticks == 0 || !game.is_ranked() {
+        return None;
     }
+    high_scores.rank_for(game.score_key(), game.score)
 }
 
-fn menu_required_min_size(language: Language) -> layout::MinSize {
-    let gameplay_min = layout::min_terminal_size(utils::WIDTH, utils::HEIGHT, language);
-    let menu_floor = layout::MinSize {
-        width: 34,
-        height: 23,
-    };
-    layout::MinSize {
-        width: gameplay_min.width.max(menu_floor.width),
-        height: gameplay_min.height.max(menu_floor.height),
+/// Keeps the run for the ghost to replay if it is the best yet on its table.
+fn record_ghost_run(game: &Game) {
+    if game.score == 0 || !game.is_ranked() {
+        return;
+    }
+    if let Err(err) = storage::save_ghost(game.score_key(), core::GhostRun::of(game)) {
+        if !REPORTED_GHOST_SAVE_ERROR.swap(true, Ordering::Relaxed) {
+            notices::report(format!("Failed to save the ghost run: {err}"));
+        }
     }
 }
 
-fn show_menu(
-    rx: &mpsc::Receiver<GameInput>,
-    term_size: &mut (u16, u16),
-    settings: &mut Settings,
-    selected_difficulty: &mut Difficulty,
-    high_scores: &mut HighScores,
-) -> Option<Difficulty> {
-    render::clear_for_menu_entry();
+/// How long a run paused on focus loss counts down once the focus is back.
+const FOCUS_RESUME_COUNTDOWN: Duration = Duration::from_secs(3);
 
-    let mut screen = MenuScreen::Main;
-    let mut main_selected = 0usize;
-    let mut difficulty_selected = difficulty_to_index(*selected_difficulty);
-    let mut settings_selected = 0usize;
-    let mut language_selected = settings.language.to_index();
-    let mut reset_selected = 1usize; // Default to "No"
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RunEnd {
+    Menu,
+    Quit,
+}
 
-    loop {
-        let ui_language = settings.language;
-        let gameplay_min = layout::min_terminal_size(utils::WIDTH, utils::HEIGHT, ui_language);
-        let required_min = menu_required_min_size(ui_language);
-        let can_start_game =
-            term_size.0 >= gameplay_min.width && term_size.1 >= gameplay_min.height;
-        let can_render_menu =
-            term_size.0 >= required_min.width && term_size.1 >= required_min.height;
-
-        if can_render_menu {
-            if matches!(screen, MenuScreen::HighScores) {
-                render::draw_high_scores_menu(render::HighScoresRenderRequest {
-                    high_scores,
-                    term_width: term_size.0,
-                    term_height: term_size.1,
-                    language: ui_language,
-                    compact: settings.ui_compact,
-                });
-            } else {
-                let (screen_tag, title, subtitle, options, selected, danger_option) = match screen {
-                    MenuScreen::Main => (
-                        "MENU",
-                        i18n::menu_title(ui_language),
-                        Some(format!(
-                            "{}: {}",
-                            i18n::menu_difficulty(ui_language),
-                            i18n::difficulty_label(ui_language, *selected_difficulty)
-                        )),
-                        vec![
-                            i18n::menu_play(ui_language).to_string(),
-                            format!(
-                                "{}: {}",
-                                i18n::menu_difficulty(ui_language),
-                                i18n::difficulty_label(ui_language, *selected_difficulty)
-                            ),
-                            i18n::menu_high_scores(ui_language).to_string(),
-                            i18n::menu_settings(ui_language).to_string(),
-                            i18n::menu_quit(ui_language).to_string(),
-                        ],
-                        main_selected,
-                        None,
-                    ),
-                    MenuScreen::Difficulty => (
-                        "DIFFICULTY",
-                        i18n::difficulty_menu_title(ui_language),
-                        Some(format!(
-                            "{}: {}",
-                            i18n::menu_difficulty(ui_language),
-                            i18n::difficulty_label(
-                                ui_language,
-                                difficulty_from_index(difficulty_selected.min(3))
-                            )
-                        )),
-                        vec![
-                            i18n::difficulty_label(ui_language, Difficulty::Easy).to_string(),
-                            i18n::difficulty_label(ui_language, Difficulty::Medium).to_string(),
-                            i18n::difficulty_label(ui_language, Difficulty::Hard).to_string(),
-                            i18n::difficulty_label(ui_language, Difficulty::Extreme).to_string(),
-                            i18n::menu_back(ui_language).to_string(),
-                        ],
-                        difficulty_selected,
-                        None,
-                    ),
-                    MenuScreen::Settings => (
-                        "SETTINGS",
-                        i18n::menu_settings(ui_language),
-                        Some(format!(
-                            "{}: {}  {}: {}",
-                            i18n::language_label(ui_language),
-                            i18n::language_name(settings.language),
-                            i18n::settings_sound_label(ui_language),
-                            if settings.sound_on {
-                                i18n::setting_on(ui_language)
-                            } else {
-                                i18n::setting_off(ui_language)
-                            }
-                        )),
-                        vec![
-                            format!(
-                                "{}: {}",
-                                i18n::language_label(ui_language),
-                                i18n::language_name(settings.language)
-                            ),
-                            format!(
-                                "{}: {}",
-                                i18n::settings_pause_on_focus_loss_label(ui_language),
-                                if settings.pause_on_focus_loss {
-                                    i18n::setting_on(ui_language)
-                                } else {
-                                    i18n::setting_off(ui_language)
-                                }
-                            ),
-                            format!(
-                                "{}: {}",
-                                i18n::settings_sound_label(ui_language),
-                                if settings.sound_on {
-                                    i18n::setting_on(ui_language)
-                                } else {
-                                    i18n::setting_off(ui_language)
-                                }
-                            ),
-                            format!(
-                                "{}: {}",
-                                i18n::settings_ui_compact_label(ui_language),
-                                if settings.ui_compact {
-                                    i18n::setting_on(ui_language)
-                                } else {
-                                    i18n::setting_off(ui_language)
-                                }
-                            ),
-                            i18n::settings_reset_high_scores_label(ui_language).to_string(),
-                            i18n::menu_back(ui_language).to_string(),
-                        ],
-                        settings_selected,
-                        Some(4),
-                    ),
-                    MenuScreen::Language => {
-                        let mut options: Vec<String> = Language::ALL
-                            .iter()
-                            .map(|language| i18n::language_name(*language).to_string())
-                            .collect();
-                        options.push(i18n::menu_back(ui_language).to_string());
-                        (
-                            "LANGUAGE",
-                            i18n::language_popup_title(ui_language),
-                            Some(format!(
-                                "{}: {}",
-                                i18n::language_label(ui_language),
-                                i18n::language_name(settings.language)
-                            )),
-                            options,
-                            language_selected,
-                            None,
-                        )
-                    }
-                    MenuScreen::ResetScoresConfirm => (
-                        "RESET",
-                        i18n::reset_high_scores_title(ui_language),
-                        Some(i18n::settings_reset_high_scores_label(ui_language).to_string()),
-                        vec![
-                            i18n::confirm_yes(ui_language).to_string(),
-                            i18n::confirm_no(ui_language).to_string(),
-                        ],
-                        reset_selected,
-                        Some(0),
-                    ),
-                    MenuScreen::HighScores => unreachable!(),
-                };
-                render::draw_menu(render::MenuRenderRequest {
-                    screen_tag,
-                    title,
-                    subtitle: subtitle.as_deref(),
-                    options: &options,
-                    selected_option: selected,
-                    danger_option,
-                    term_width: term_size.0,
-                    term_height: term_size.1,
-                    language: ui_language,
-                    compact: settings.ui_compact,
-                });
-            }
-        } else {
-            render::draw_size_warning(
-                layout::SizeCheck {
-                    current_width: term_size.0,
-                    current_height: term_size.1,
-                    minimum: required_min,
-                },
-                ui_language,
-            );
+/// The dialog `Q`, or Space and Enter, open over a run in progress, which is
+/// paused meanwhile.
+struct QuitPrompt {
+    /// 0 is Yes, 1 is No.
+    selected: usize,
+    /// The run was going when the dialog opened, so closing it resumes play.
+    resume: bool,
+    /// Where a Yes leads: out of the game, or back to the menu.
+    leave: RunEnd,
+}
+
+impl QuitPrompt {
+    /// Pauses `game` and asks before leaving it for `leave`, with No selected.
+    fn open(game: &mut Game, leave: RunEnd) -> Self {
+        let resume = !game.is_paused();
+        if resume {
+            game.toggle_pause();
+        }
+        Self {
+            selected: 1,
+            resume,
+            leave,
         }
+    }
 
-        let input_cmd = match rx.recv() {
-            Ok(input_cmd) => input_cmd,
-            Err(_) => return None,
-        };
-        let max_index = match screen {
-            MenuScreen::Main => 4,
-            MenuScreen::Difficulty => 4,
-            MenuScreen::Settings => 5,
-            MenuScreen::Language => Language::ALL.len(),
-            MenuScreen::ResetScoresConfirm => 1,
-            MenuScreen::HighScores => 0,
-        };
-        match input_cmd {
-            GameInput::Resize(width, height) => {
-                *term_size = (width, height);
-                // Force a full redraw on resize to prevent stale content from
-                // reappearing when terminals restore previously hidden columns.
-                render::clear_for_menu_entry();
+    /// Takes a key while the dialog is up: `Some(true)` leaves the run,
+    /// `Some(false)` closes the dialog, and `None` keeps it open.
+    fn answer(&mut self, input: &GameInput) -> Option<bool> {
+        match input {
+            GameInput::Direction(utils::Direction::Up | utils::Direction::Left) => {
+                self.selected = 0;
+                None
             }
-            GameInput::MenuSelect(option) => {
-                let selection = option.min(max_index);
-                match screen {
-                    MenuScreen::Main => main_selected = selection,
-                    MenuScreen::Difficulty => difficulty_selected = selection,
-                    MenuScreen::Settings => settings_selected = selection,
-                    MenuScreen::Language => language_selected = selection,
-                    MenuScreen::ResetScoresConfirm => reset_selected = selection,
-                    MenuScreen::HighScores => {}
-                }
+            GameInput::Direction(utils::Direction::Down | utils::Direction::Right) => {
+                self.selected = 1;
+                None
             }
-            GameInput::Direction(utils::Direction::Up) => match screen {
-                MenuScreen::Main => main_selected = main_selected.saturating_sub(1),
-                MenuScreen::Difficulty => {
-                    difficulty_selected = difficulty_selected.saturating_sub(1)
-                }
-                MenuScreen::Settings => settings_selected = settings_selected.saturating_sub(1),
-                MenuScreen::Language => language_selected = language_selected.saturating_sub(1),
-                MenuScreen::ResetScoresConfirm => reset_selected = reset_selected.saturating_sub(1),
-                MenuScreen::HighScores => {}
-            },
-            GameInput::Direction(utils::Direction::Down) => match screen {
-                MenuScreen::Main => main_selected = (main_selected + 1).min(4),
-                MenuScreen::Difficulty => difficulty_selected = (difficulty_selected + 1).min(4),
-                MenuScreen::Settings => settings_selected = (settings_selected + 1).min(5),
-                MenuScreen::Language => {
-                    language_selected = (language_selected + 1).min(Language::ALL.len())
-                }
-                MenuScreen::ResetScoresConfirm => reset_selected = (reset_selected + 1).min(1),
-                MenuScreen::HighScores => {}
-            },
-            GameInput::MenuConfirm => match screen {
-                MenuScreen::Main => match main_selected {
-                    0 => {
-                        if can_start_game {
-                            return Some(*selected_difficulty);
-                        }
-                    }
-                    1 => {
-                        difficulty_selected = difficulty_to_index(*selected_difficulty);
-                        screen = MenuScreen::Difficulty;
-                    }
-                    2 => screen = MenuScreen::HighScores,
-                    3 => screen = MenuScreen::Settings,
-                    4 => return None,
-                    _ => {}
-                },
-                MenuScreen::Difficulty => {
-                    if difficulty_selected <= 3 {
-                        *selected_difficulty = difficulty_from_index(difficulty_selected);
-                        settings.default_difficulty = *selected_difficulty;
-                        persist_config(high_scores, *settings);
-                    }
-                    screen = MenuScreen::Main;
-                }
-                MenuScreen::Settings => match settings_selected {
-                    0 => {
-                        language_selected = settings.language.to_index();
-                        screen = MenuScreen::Language;
-                    }
-                    1 => {
-                        settings.pause_on_focus_loss = !settings.pause_on_focus_loss;
-                        persist_config(high_scores, *settings);
-                    }
-                    2 => {
-                        settings.sound_on = !settings.sound_on;
-                        persist_config(high_scores, *settings);
-                    }
-                    3 => {
-                        settings.ui_compact = !settings.ui_compact;
-                        persist_config(high_scores, *settings);
-                    }
-                    4 => {
-                        reset_selected = 1;
-                        screen = MenuScreen::ResetScoresConfirm;
-                    }
-                    5 => screen = MenuScreen::Main,
-                    _ => {}
-                },
-                MenuScreen::Language => {
-                    if language_selected < Language::ALL.len() {
-                        settings.language = Language::ALL[language_selected];
-                        persist_config(high_scores, *settings);
-                    }
-                    screen = MenuScreen::Settings;
-                }
-                MenuScreen::ResetScoresConfirm => {
-                    if reset_selected == 0 {
-                        *high_scores = HighScores::default();
-                        persist_config(high_scores, *settings);
-                    }
-                    screen = MenuScreen::Settings;
-                }
-                MenuScreen::HighScores => {
-                    screen = MenuScreen::Main;
-                }
-            },
-            GameInput::Quit => {
-                return None;
+            GameInput::MenuSelect(option) => {
+                self.selected = (*option).min(1);
+                None
             }
-            _ => {} // Ignore other inputs
+            GameInput::MenuConfirm => Some(self.selected == 0),
+            // A second Q answers yes.
+            GameInput::Quit => Some(true),
+            GameInput::Back | GameInput::Pause => Some(false),
+            _ => None,
         }
     }
 }
 
-fn is_reverse_direction(current: utils::Direction, next: utils::Direction) -> bool {
-    matches!(
-        (current, next),
-        (utils::Direction::Up, utils::Direction::Down)
-            | (utils::Direction::Down, utils::Direction::Up)
-            | (utils::Direction::Left, utils::Direction::Right)
-            | (utils::Direction::Right, utils::Direction::Left)
-    )
+/// How a key that leaves a run treats `game`: over or paused runs end right
+/// away, while a live one asks first, so a stray Space or Q loses nothing.
+/// `None` for keys that do not leave.
+fn leave_run(game: &Game, input: &GameInput) -> Option<(RunEnd, bool)> {
+    let leave = match input {
+        GameInput::Quit => RunEnd::Quit,
+        GameInput::MenuConfirm => RunEnd::Menu,
+        _ => return None,
+    };
+    // Q always asks while the run could still go on; Space on a paused run
+    // is how the HUD says to get back to the menu.
+    let confirm = !game.game_over && (leave == RunEnd::Quit || !game.is_paused());
+    Some((leave, confirm))
 }
 
 fn run_smoke_check() -> Result<(), String> {
@@ -431,224 +278,1381 @@ fn run_smoke_check() -> Result<(), String> {
     Ok(())
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    if std::env::args().any(|arg| arg == "--smoke-check") {
-        if let Err(err) = run_smoke_check() {
-            return Err(std::io::Error::other(err).into());
+/// `bench-render [frames]`: times synthetic worst-case frames drawn into a
+/// sink instead of the terminal.
+fn run_bench_render(frames: u32) -> Result<(), String> {
+    let report = render::bench_render(frames);
+    println!(
+        "rustnake bench-render: {} frames in {:.3}s, {:.1} frames/s, {} bytes/frame",
+        report.frames,
+        report.elapsed.as_secs_f64(),
+        report.frames_per_sec(),
+        report.bytes_per_frame()
+    );
+    Ok(())
+}
+
+/// Writes the result card of a finished run to the current directory and
+/// says where in a toast.
+fn save_result_card(renderer: &mut render::Renderer, game: &Game, language: Language) {
+    let date = storage::current_utc_date();
+    let file = format!("rustnake-{date}-{}.txt", game.score);
+    let toast = match std::fs::write(&file, render::result_card(game, language, &date, false)) {
+        Ok(()) => i18n::toast_card_saved(language, &file),
+        Err(err) => {
+            log::warn!("cannot write {file}: {err}");
+            i18n::toast_card_failed(language).to_string()
+        }
+    };
+    renderer.show_toast(toast);
+}
+
+/// `screenshot <file>`: the autopilot plays up to `ticks` ticks, and the
+/// frame the game shows then, or its result card, is written to `path`
+/// (`-` for stdout).
+fn run_screenshot(
+    path: &Path,
+    seed: Option<u64>,
+    ticks: u32,
+    difficulty: Option<Difficulty>,
+    ansi: bool,
+    card: bool,
+) -> Result<(), String> {
+    let config = storage::load_config();
+    let settings = config.settings;
+    let difficulty = difficulty.unwrap_or(settings.default_difficulty);
+    let profile = DifficultyProfile::for_difficulty(difficulty, settings.custom_difficulty);
+    let high_score = config.high_scores.best(ScoreKey::new(
+        difficulty,
+        profile.mode(),
+        utils::WIDTH,
+        utils::HEIGHT,
+    ));
+    let mut game = match seed {
+        Some(seed) => Game::with_seed(
+            difficulty,
+            profile,
+            utils::WIDTH,
+            utils::HEIGHT,
+            high_score,
+            seed,
+        ),
+        None => Game::with_profile(difficulty, profile, utils::WIDTH, utils::HEIGHT, high_score),
+    };
+    game.set_disabled_power_ups(settings.disabled_power_ups);
+    while !game.game_over && game.stats.ticks < ticks {
+        game.stats.play_time += game.tick_interval(game.snake.direction);
+        game.update_snake_direction(core::autopilot_direction(&game));
+        game.tick();
+    }
+
+    let frame = if card {
+        render::result_card(&game, settings.language, &storage::current_utc_date(), ansi)
+    } else {
+        render::screenshot(
+            &mut game,
+            render::Theme::from_settings(&settings),
+            settings.square_cells,
+            settings.language,
+            ansi,
+        )
+    };
+    if path == Path::new("-") {
+        print!("{frame}");
+        return Ok(());
+    }
+    std::fs::write(path, frame).map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+    println!(
+        "rustnake screenshot {}: {difficulty:?}, seed {}, tick {}, score {}",
+        path.display(),
+        game.replay.seed,
+        game.stats.ticks,
+        game.score
+    );
+    Ok(())
+}
+
+fn run_config_path() -> Result<(), String> {
+    for (name, path) in [
+        ("config", storage::config_path_for_current_user()),
+        ("scores", storage::data_path_for_current_user()),
+        ("history", storage::history_path_for_current_user()),
+        ("ghosts", storage::ghosts_path_for_current_user()),
+        ("log", storage::log_path_for_current_user()),
+    ] {
+        println!("{name:<8}{}", path.display());
+    }
+    Ok(())
+}
+
+fn run_scores() -> Result<(), String> {
+    let config = storage::load_config();
+    let language = config.settings.language;
+    for (index, key) in config.high_scores.listed_keys().into_iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        println!(
+            "{} · {}",
+            i18n::difficulty_label(language, key.difficulty),
+            i18n::score_table_variant(language, key)
+        );
+        let entries = config.high_scores.entries(key);
+        if entries.is_empty() {
+            println!("   -");
+        }
+        for (rank, entry) in entries.iter().enumerate() {
+            println!(
+                "{:>2}. {} {:>6}  {}",
+                rank + 1,
+                entry.initials,
+                entry.score,
+                entry.date
+            );
+        }
+    }
+    Ok(())
+}
+
+fn run_check_config() -> Result<(), String> {
+    println!(
+        "rustnake config: {}",
+        storage::config_path_for_current_user().display()
+    );
+    println!(
+        "rustnake data: {}",
+        storage::data_path_for_current_user().display()
+    );
+    let problems = storage::check_config();
+    for problem in &problems {
+        let level = if problem.is_fatal() {
+            "error"
+        } else {
+            "warning"
+        };
+        println!("{level}: {problem}");
+    }
+    let errors = problems.iter().filter(|problem| problem.is_fatal()).count();
+    if errors > 0 {
+        return Err(format!(
+            "{errors} file(s) could not be loaded and would be replaced by defaults"
+        ));
+    }
+    println!("rustnake config check ok");
+    Ok(())
+}
+
+/// Merges local high scores with the `sync.toml` endpoint, if one is configured.
+/// Returns the number of entries in the merged tables.
+#[cfg(feature = "sync")]
+fn sync_scores() -> Result<Option<usize>, String> {
+    let Some(sync_settings) = storage::load_endpoint_settings("sync")? else {
+        return Ok(None);
+    };
+    let local = storage::load_config().high_scores;
+    let merged = sync::sync_high_scores(&sync_settings, &local)?;
+    if merged != local {
+        storage::save_high_scores(&merged)?;
+    }
+    Ok(Some(
+        merged.keys().map(|key| merged.entries(key).len()).sum(),
+    ))
+}
+
+/// A failed sync at startup is shown in the menu banner. At exit the terminal
+/// is already restored, so there it is a plain warning.
+#[cfg(feature = "sync")]
+fn sync_scores_or_warn(at_startup: bool) {
+    if let Err(err) = sync_scores() {
+        if at_startup {
+            notices::report(format!("Score sync failed: {err}"));
+        } else {
+            eprintln!("warning: rustnake score sync failed: {err}");
+        }
+    }
+}
+
+/// Starts the global leaderboard client when `leaderboard.toml` is set up.
+#[cfg(feature = "leaderboard")]
+fn leaderboard_client_or_warn() -> Option<leaderboard::LeaderboardClient> {
+    match storage::load_endpoint_settings("leaderboard") {
+        Ok(settings) => settings.map(leaderboard::LeaderboardClient::spawn),
+        Err(err) => {
+            notices::report(format!("Global leaderboard disabled: {err}"));
+            None
         }
+    }
+}
+
+#[cfg(feature = "sync")]
+fn run_sync_scores() -> Result<(), String> {
+    match sync_scores()? {
+        Some(entries) => println!("rustnake synced {entries} high scores"),
+        None => println!(
+            "rustnake score sync is not configured (create {})",
+            storage::config_path_for_current_user()
+                .with_file_name("sync.toml")
+                .display()
+        ),
+    }
+    Ok(())
+}
+
+fn run_export_scores(path: &Path) -> Result<(), String> {
+    let config = storage::load_config();
+    let history = storage::load_history();
+    storage::export_scores(path, &config, &history)?;
+    println!(
+        "rustnake exported {} high scores and {} runs to {}",
+        config
+            .high_scores
+            .keys()
+            .map(|key| config.high_scores.entries(key).len())
+            .sum::<usize>(),
+        history.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+fn run_backup_config(path: &Path) -> Result<(), String> {
+    storage::backup_config(path)?;
+    println!(
+        "rustnake backed up {} to {}",
+        storage::config_path_for_current_user().display(),
+        path.display()
+    );
+    Ok(())
+}
+
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    let _ = std::io::Write::flush(&mut stdout());
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer).is_ok()
+        && matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
+fn run_restore_config(path: &Path) -> Result<(), String> {
+    let config = storage::read_backup(path)?;
+    let config_path = storage::config_path_for_current_user();
+    if storage::config_is_newer_than(path)
+        && !confirm(&format!(
+            "{} was modified after {} was written; overwrite it?",
+            config_path.display(),
+            path.display()
+        ))
+    {
+        println!("rustnake restore cancelled");
         return Ok(());
     }
+    storage::save_config(&config)?;
+    println!(
+        "rustnake restored {} from {}",
+        config_path.display(),
+        path.display()
+    );
+    Ok(())
+}
+
+/// How often the spectator view checks for new frames from the host.
+const SPECTATE_POLL_INTERVAL: Duration = Duration::from_millis(30);
+
+/// `spectate <addr>`: read-only view of a game started with `play --broadcast <addr>`.
+fn run_spectate(addr: &str) -> Result<(), String> {
+    let events = spectate::connect(addr)?;
+    let settings = storage::load_config().settings;
+    let language = settings.language;
+
+    let mut stdout = stdout();
+    execute!(stdout, EnterAlternateScreen, Hide).map_err(|err| err.to_string())?;
+    enable_raw_mode().map_err(|err| err.to_string())?;
+    let _terminal_guard = TerminalGuard::new();
+    let renderer = &mut render::Renderer::new();
+    renderer.set_theme(render::Theme::from_settings(&settings));
+    set_render_backend(renderer, &settings);
+    render::set_wide_ambiguous(settings.wide_ambiguous);
+    renderer.set_right_to_left(i18n::is_right_to_left(settings.language));
+
+    let rx = input::setup_input_handler(
+        storage::config_path_for_current_user(),
+        input::Keymap::from_settings(&settings),
+    );
+    let mut term_size = renderer.size();
+    let mut game: Option<Game> = None;
+    let mut active_layout: Option<layout::Layout> = None;
+    let mut needs_redraw = true;
+
+    loop {
+        loop {
+            match events.try_recv() {
+                Ok(spectate::StreamEvent::Frame(frame)) => {
+                    match game.as_mut() {
+                        Some(game) if frame.fits(game) => frame.apply(game),
+                        _ => {
+                            game = Some(frame.to_game());
+                            active_layout = None;
+                        }
+                    }
+                    needs_redraw = true;
+                }
+                Ok(spectate::StreamEvent::Ended(None)) => return Ok(()),
+                Ok(spectate::StreamEvent::Ended(Some(err))) => {
+                    return Err(format!("spectator stream from {addr} ended: {err}"));
+                }
+                Err(_) => break,
+            }
+        }
+
+        if needs_redraw {
+            needs_redraw = false;
+            match game.as_mut() {
+                None => {
+                    render::draw_spectator_waiting(renderer, term_size.0, term_size.1, language)
+                }
+                Some(game) => {
+                    match layout::compute_layout(
+                        term_size.0,
+                        term_size.1,
+                        game.width,
+                        game.height,
+                        settings.square_cells,
+                        layout::Hud::from_settings(&settings),
+                        language,
+                    ) {
+                        Ok(layout) => {
+                            let layout =
+                                layout.following(game.snake.head_position(), active_layout);
+                            if active_layout != Some(layout) {
+                                render::draw_frame(renderer, active_layout, &layout);
+                                active_layout = Some(layout);
+                            }
+                            render::draw(renderer, game, &layout, language);
+                        }
+                        Err(size_check) => {
+                            render::draw_size_warning(renderer, size_check, language);
+                            active_layout = None;
+                        }
+                    }
+                }
+            }
+        }
+
+        match input::wait_for_input(&rx, Some(Instant::now() + SPECTATE_POLL_INTERVAL)) {
+            InputWait::Input(GameInput::Resize(width, height)) => {
+                term_size = (width, height);
+                active_layout = None;
+                needs_redraw = true;
+            }
+            InputWait::Input(GameInput::Quit) | InputWait::Disconnected => return Ok(()),
+            InputWait::Input(_) | InputWait::TimedOut => {}
+        }
+    }
+}
+
+/// Restores the terminal after `text-mode`, which never leaves the main screen.
+struct RawModeGuard;
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+    }
+}
+
+/// Shortest gap between two `text-mode` announcements, whatever the config says.
+const TEXT_MODE_MIN_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Prints one line of `text-mode` output. Raw mode needs the explicit `\r`.
+fn announce(line: &str) {
+    let mut stdout = stdout();
+    let _ = write!(stdout, "{line}\r\n");
+    let _ = stdout.flush();
+}
+
+/// `text-mode`: one run at the default difficulty that prints short status
+/// lines at the configured cadence instead of drawing the board, for playing
+/// with a screen reader.
+fn run_text_mode() -> Result<(), String> {
+    let config = storage::load_config();
+    let mut high_scores = config.high_scores;
+    let settings = config.settings;
+    let language = settings.language;
+    let interval =
+        Duration::from_millis(settings.text_mode_interval_ms).max(TEXT_MODE_MIN_INTERVAL);
+    let difficulty = settings.default_difficulty;
+    let profile = DifficultyProfile::for_difficulty(difficulty, settings.custom_difficulty);
+    let high_score = high_scores.best(ScoreKey::new(
+        difficulty,
+        profile.mode(),
+        utils::WIDTH,
+        utils::HEIGHT,
+    ));
+    let mut game = Game::with_profile(difficulty, profile, utils::WIDTH, utils::HEIGHT, high_score);
+    game.muted = !settings.sound_on;
+    game.reduced_motion = settings.reduced_motion;
+    game.speed_cap = settings.speed_cap();
+    game.set_disabled_power_ups(settings.disabled_power_ups);
+
+    enable_raw_mode().map_err(|err| err.to_string())?;
+    let raw_mode_guard = RawModeGuard;
+    // Only used for the bell; nothing is ever presented.
+    let renderer = &mut render::Renderer::new();
+    let rx = input::setup_input_handler(
+        storage::config_path_for_current_user(),
+        input::Keymap::from_settings(&settings),
+    );
+
+    announce(i18n::controls_text(language));
+    announce(&render::describe_state(&game, language));
+    let mut clock = timing::FixedTimestep::new(Instant::now());
+    let mut next_announcement = Instant::now() + interval;
+    let mut direction_queue: VecDeque<utils::Direction> = VecDeque::with_capacity(2);
+    let mut pending_input: Option<GameInput> = None;
+    'run: loop {
+        let now = Instant::now();
+        if game.is_paused() {
+            clock.hold(now);
+        } else {
+            clock.advance(now);
+        }
+
+        for input_cmd in pending_input.take().into_iter().chain(rx.try_iter()) {
+            match input_cmd {
+                GameInput::Quit | GameInput::MenuConfirm => break 'run,
+                GameInput::Pause => {
+                    game.toggle_pause();
+                    if game.is_paused() {
+                        announce(i18n::status_paused(language));
+                    } else {
+                        announce(&render::describe_state(&game, language));
+                        next_announcement = now + interval;
+                    }
+                }
+                GameInput::ToggleMute => game.toggle_mute(),
+                GameInput::Boost(on) => game.set_boost(on),
+                GameInput::Direction(direction) => {
+                    input::queue_direction(&mut direction_queue, game.snake.direction, direction);
+                }
+                _ => {}
+            }
+        }
+        let mut step = game.tick_interval(game.snake.direction);
+        while !game.is_paused() && !game.game_over {
+            step = game.tick_interval(
+                direction_queue
+                    .front()
+                    .copied()
+                    .unwrap_or(game.snake.direction),
+            );
+            if !clock.try_step(step) {
+                break;
+            }
+            if let Some(direction) = direction_queue.pop_front() {
+                game.update_snake_direction(direction);
+            }
+            game.tick();
+            play_game_sounds(renderer, &mut game, &settings);
+            game.stats.play_time += step;
+        }
+        if game.game_over {
+            announce(&render::describe_game_over(&game, language));
+            break;
+        }
+        if !game.is_paused() && now >= next_announcement {
+            announce(&render::describe_state(&game, language));
+            next_announcement = now + interval;
+        }
+
+        let deadline = (!game.is_paused()).then(|| clock.next_step_at(step).min(next_announcement));
+        match input::wait_for_input(&rx, deadline) {
+            InputWait::Input(input_cmd) => pending_input = Some(input_cmd),
+            InputWait::TimedOut => {}
+            InputWait::Disconnected => break,
+        }
+    }
+    drop(raw_mode_guard);
+
+    record_run_history(&game);
+    if high_score_rank(&game, &high_scores).is_some() {
+        high_scores.insert(
+            game.score_key(),
+            HighScoreEntry::new(
+                game.score,
+                settings.last_initials,
+                storage::current_utc_date(),
+            ),
+        );
+        persist_high_scores(&high_scores);
+    }
+    Ok(())
+}
+
+/// Stops `autoplay` where a recording ends without the snake dying.
+struct RecordingOver;
+
+/// `replay <file> [--headless]`: replays a run saved by `play --record-input`.
+/// Headless playback checks that the replay ends exactly like the recording.
+fn run_replay(path: &Path, headless: bool) -> Result<(), String> {
+    let recording = input::InputRecording::load(path)?;
+    if headless {
+        let game = recording.play_headless();
+        println!(
+            "rustnake input replay {}: score {}, length {}, {} ticks",
+            path.display(),
+            game.score,
+            game.snake.body().len(),
+            game.stats.ticks
+        );
+        return recording.verify(&game);
+    }
+
+    let settings = storage::load_config().settings;
+    let mut game = recording.new_game();
+    game.muted = !settings.sound_on;
+    game.reduced_motion = settings.reduced_motion;
+    game.speed_cap = settings.speed_cap();
+    let mut playback = input::Playback::new(&recording);
+
+    let mut stdout = stdout();
+    execute!(stdout, EnterAlternateScreen, Hide).map_err(|err| err.to_string())?;
+    enable_raw_mode().map_err(|err| err.to_string())?;
+    let _terminal_guard = TerminalGuard::new();
+    let renderer = &mut render::Renderer::new();
+    renderer.set_theme(render::Theme::from_settings(&settings));
+    set_render_backend(renderer, &settings);
+    render::set_wide_ambiguous(settings.wide_ambiguous);
+    renderer.set_right_to_left(i18n::is_right_to_left(settings.language));
+
+    let rx = input::setup_input_handler(
+        storage::config_path_for_current_user(),
+        input::Keymap::from_settings(&settings),
+    );
+    let mut term_size = renderer.size();
+    loop {
+        let end = autoplay(
+            renderer,
+            &rx,
+            &mut term_size,
+            &settings,
+            &mut game,
+            |game| playback.next_tick(game).ok_or(RecordingOver),
+        );
+        match end {
+            Ok(AutoplayEnd::GameOver) => match input::wait_for_input(&rx, None) {
+                InputWait::Input(GameInput::Resize(width, height)) => term_size = (width, height),
+                InputWait::Input(GameInput::Quit) | InputWait::Disconnected => return Ok(()),
+                InputWait::Input(_) | InputWait::TimedOut => {}
+            },
+            // A run that was quit before the snake died holds its last frame.
+            Err(RecordingOver) => {
+                if !game.is_paused() {
+                    game.toggle_pause();
+                }
+            }
+            Ok(AutoplayEnd::Input(GameInput::Pause)) => game.toggle_pause(),
+            Ok(AutoplayEnd::Input(GameInput::Quit) | AutoplayEnd::Disconnected) => return Ok(()),
+            Ok(AutoplayEnd::Input(_)) => {}
+        }
+    }
+}
+
+/// Headless bot runs stop here if the snake never dies.
+#[cfg(feature = "bot")]
+const BOT_MAX_TICKS: u32 = 100_000;
+
+/// `bot <file> [--headless]`: a script plays the default difficulty. Bot runs
+/// never reach the high-score tables or the run history.
+#[cfg(feature = "bot")]
+fn run_bot(path: &Path, headless: bool) -> Result<(), String> {
+    let mut bot = bot::Bot::load(path)?;
+    let settings = storage::load_config().settings;
+    let difficulty = settings.default_difficulty;
+    let mut game = Game::with_profile(
+        difficulty,
+        paced_profile(
+            DifficultyProfile::for_difficulty(difficulty, settings.custom_difficulty),
+            &settings,
+        ),
+        utils::WIDTH,
+        utils::HEIGHT,
+        0,
+    );
+    game.muted = !settings.sound_on;
+    game.reduced_motion = settings.reduced_motion;
+    game.speed_cap = settings.speed_cap();
+    game.set_disabled_power_ups(settings.disabled_power_ups);
+
+    if headless {
+        while !game.game_over && game.stats.ticks < BOT_MAX_TICKS {
+            game.stats.play_time += game.tick_interval(game.snake.direction);
+            bot.step(&mut game)?;
+            game.sound_events.clear();
+        }
+        println!(
+            "rustnake bot {}: score {}, length {}, {} ticks",
+            path.display(),
+            game.score,
+            game.snake.body().len(),
+            game.stats.ticks
+        );
+        return Ok(());
+    }
+
+    let mut stdout = stdout();
+    execute!(stdout, EnterAlternateScreen, Hide).map_err(|err| err.to_string())?;
+    enable_raw_mode().map_err(|err| err.to_string())?;
+    let _terminal_guard = TerminalGuard::new();
+    let renderer = &mut render::Renderer::new();
+    renderer.set_theme(render::Theme::from_settings(&settings));
+    set_render_backend(renderer, &settings);
+    render::set_wide_ambiguous(settings.wide_ambiguous);
+    renderer.set_right_to_left(i18n::is_right_to_left(settings.language));
+
+    let rx = input::setup_input_handler(
+        storage::config_path_for_current_user(),
+        input::Keymap::from_settings(&settings),
+    );
+    let mut term_size = renderer.size();
+    loop {
+        match autoplay(
+            renderer,
+            &rx,
+            &mut term_size,
+            &settings,
+            &mut game,
+            |game| bot.choose(game),
+        )? {
+            AutoplayEnd::GameOver => match input::wait_for_input(&rx, None) {
+                InputWait::Input(GameInput::Resize(width, height)) => term_size = (width, height),
+                InputWait::Input(GameInput::Quit) | InputWait::Disconnected => return Ok(()),
+                InputWait::Input(_) | InputWait::TimedOut => {}
+            },
+            AutoplayEnd::Input(GameInput::Pause) => game.toggle_pause(),
+            AutoplayEnd::Input(GameInput::ToggleMute) => game.toggle_mute(),
+            AutoplayEnd::Input(GameInput::Quit) | AutoplayEnd::Disconnected => return Ok(()),
+            AutoplayEnd::Input(_) => {}
+        }
+    }
+}
+
+/// Runs a subcommand other than `play`, which needs the terminal UI below.
+fn run_command(command: cli::Command) -> Result<(), String> {
+    match command {
+        cli::Command::Play(_) => unreachable!("play runs the game loop"),
+        cli::Command::Scores => run_scores(),
+        cli::Command::Config { command } => match command {
+            cli::ConfigCommand::Path => run_config_path(),
+            cli::ConfigCommand::Check => run_check_config(),
+            cli::ConfigCommand::Backup { file } => run_backup_config(&file),
+            cli::ConfigCommand::Restore { file } => run_restore_config(&file),
+        },
+        cli::Command::CheckConfig => run_check_config(),
+        cli::Command::BackupConfig { file } => run_backup_config(&file),
+        cli::Command::RestoreConfig { file } => run_restore_config(&file),
+        cli::Command::SmokeCheck => run_smoke_check(),
+        cli::Command::BenchRender { frames } => run_bench_render(frames),
+        cli::Command::Screenshot {
+            file,
+            seed,
+            ticks,
+            difficulty,
+            ansi,
+            card,
+        } => run_screenshot(&file, seed, ticks, difficulty, ansi, card),
+        cli::Command::ExportScores { file } => run_export_scores(&file),
+        cli::Command::Spectate { addr } => run_spectate(&addr),
+        cli::Command::TextMode => run_text_mode(),
+        cli::Command::Replay { file, headless } => run_replay(&file, headless),
+        #[cfg(feature = "sync")]
+        cli::Command::SyncScores => run_sync_scores(),
+        #[cfg(feature = "bot")]
+        cli::Command::Bot { script, headless } => run_bot(&script, headless),
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = cli::Cli::parse();
+    if let Some(warning) = cli::deprecation_warning(std::env::args().skip(1)) {
+        eprintln!("{warning}");
+    }
+    let log_level = cli.log_level();
+    install_panic_hook(cli.crash_log);
+    if let Some(level) = log_level {
+        logging::init(level, &storage::log_path_for_current_user())
+            .map_err(std::io::Error::other)?;
+    }
+    // Before anything reads the config, which may name a pack's language.
+    for problem in i18n::load_packs(&storage::translation_pack_paths()) {
+        notices::report(format!("Translation pack skipped: {problem}"));
+    }
+    let play = match cli.command {
+        None => cli::PlayArgs::default(),
+        Some(cli::Command::Play(play)) => play,
+        Some(command) => {
+            return run_command(command).map_err(|err| std::io::Error::other(err).into());
+        }
+    };
+
+    let broadcaster = match &play.broadcast {
+        Some(addr) => Some(spectate::Broadcaster::bind(addr).map_err(std::io::Error::other)?),
+        None => None,
+    };
+
+    #[cfg(feature = "sync")]
+    sync_scores_or_warn(true);
+    #[cfg(feature = "leaderboard")]
+    let leaderboard = leaderboard_client_or_warn();
 
     // Setup terminal
     let mut stdout = stdout();
     execute!(stdout, EnterAlternateScreen, Hide, EnableFocusChange)?;
+    // Saved for `restore_terminal`, since the title cannot be read back.
+    execute!(stdout, Print(render::SAVE_TITLE))?;
+    TITLE_SAVED.store(true, Ordering::Relaxed);
+    // Key releases end a boost. Terminals without the kitty keyboard protocol
+    // ignore this, and the boost then ends when the key stops repeating.
+    let _ = execute!(
+        stdout,
+        PushKeyboardEnhancementFlags(
+            KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+                | KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES
+        )
+    );
     enable_raw_mode()?;
-    let _terminal_guard = TerminalGuard;
+    let terminal_guard = TerminalGuard::new();
+    let renderer = &mut render::Renderer::new();
+    if play.debug_overlay {
+        renderer.show_latency_overlay();
+    }
+    if play.practice {
+        renderer.toggle_practice_overlay();
+    }
 
-    // Input handling channel
-    let rx = input::setup_input_handler();
-    let config = storage::load_config();
+    let storage::CheckedConfig { config, problems } = storage::load_config_checked();
     let mut high_scores: HighScores = config.high_scores;
     let mut settings: Settings = config.settings;
-    let mut selected_difficulty = settings.default_difficulty;
-    let mut term_size = layout::terminal_size();
+    // Input handling channel
+    let keymap = input::Keymap::from_settings(&settings);
+    let rx = input::setup_input_handler(storage::config_path_for_current_user(), keymap.clone());
+    renderer.set_theme(render::Theme::from_settings(&settings));
+    set_render_backend(renderer, &settings);
+    render::set_wide_ambiguous(settings.wide_ambiguous);
+    renderer.set_right_to_left(i18n::is_right_to_left(settings.language));
+    let mut term_size = renderer.size();
+
+    if !problems.is_empty()
+        && !menu::show_config_notice(renderer, &rx, &mut term_size, &settings, &problems)
+    {
+        return Ok(());
+    }
+
+    // A run set up on the command line starts without the menu.
+    let single_run = play.is_single_run();
+    let mut direct_start =
+        single_run.then(|| play.difficulty.unwrap_or(settings.default_difficulty));
+
+    // An autosave left behind means the last run never ended normally.
+    let mut recovered = None;
+    if !single_run {
+        if let Some(game) = storage::load_autosave() {
+            match menu::show_recovery_prompt(renderer, &rx, &mut term_size, &settings, &game) {
+                None => return Ok(()),
+                Some(true) => {
+                    direct_start = Some(game.difficulty);
+                    recovered = Some(game);
+                }
+                Some(false) => discard_autosave(),
+            }
+        }
+    }
 
     // Main game loop with restart capability
-    'game_loop: loop {
+    loop {
         // Show difficulty selection menu
-        let Some(difficulty) = show_menu(
-            &rx,
-            &mut term_size,
-            &mut settings,
-            &mut selected_difficulty,
-            &mut high_scores,
-        ) else {
-            break;
+        let difficulty = match direct_start.take() {
+            Some(difficulty) => difficulty,
+            None => {
+                let Some(difficulty) = menu::show_menu(
+                    renderer,
+                    &rx,
+                    &keymap,
+                    &mut term_size,
+                    &mut settings,
+                    &mut high_scores,
+                    #[cfg(feature = "leaderboard")]
+                    leaderboard.as_ref(),
+                ) else {
+                    break;
+                };
+                difficulty
+            }
         };
 
         // Create new game instance with selected difficulty
-        let mut game = Game::new(
+        let mut profile = DifficultyProfile::for_difficulty(difficulty, settings.custom_difficulty);
+        if let Some(mode) = play.mode {
+            profile.wrap_edges = mode == cli::EdgeMode::Wrap;
+        }
+        let profile = paced_profile(profile, &settings);
+        let high_score = high_scores.best(ScoreKey::new(
             difficulty,
+            profile.mode(),
             utils::WIDTH,
             utils::HEIGHT,
-            high_scores.get(difficulty),
-        );
+        ));
+        let custom_seed = settings
+            .custom_difficulty
+            .seed
+            .filter(|_| matches!(difficulty, Difficulty::Custom));
+        let resumed = recovered.is_some();
+        let mut game = match (recovered.take(), play.seed.or(custom_seed)) {
+            (Some(mut game), _) => {
+                // Picks up paused, to give the player a moment to find the snake.
+                if !game.is_paused() {
+                    game.toggle_pause();
+                }
+                game
+            }
+            (None, Some(seed)) => Game::with_seed(
+                difficulty,
+                profile,
+                utils::WIDTH,
+                utils::HEIGHT,
+                high_score,
+                seed,
+            ),
+            (None, None) => {
+                Game::with_profile(difficulty, profile, utils::WIDTH, utils::HEIGHT, high_score)
+            }
+        };
         game.muted = !settings.sound_on;
+        game.reduced_motion = settings.reduced_motion;
+        game.speed_cap = settings.speed_cap();
+        game.set_disabled_power_ups(settings.disabled_power_ups);
         let mut active_layout: Option<layout::Layout> = None;
-        let mut last_tick = Instant::now();
+        let mut clock = timing::FixedTimestep::new(Instant::now());
+        let mut frames = timing::FramePacer::default();
+        // Something changed that a frame has to show; while the run is live
+        // frames are drawn anyway, for the clock and the animations.
+        let mut needs_redraw = true;
+        let mut died_at: Option<Instant> = None;
+        let mut shown_death_frame: Option<u32> = None;
         let mut direction_queue: VecDeque<utils::Direction> = VecDeque::with_capacity(2);
+        let mut pending_input: Option<GameInput> = None;
+        let mut config_changed = false;
+        let mut quit_prompt: Option<QuitPrompt> = None;
+        // A recording plays from the start of a run, so a resumed one has none.
+        let mut recording = play
+            .record_input
+            .as_ref()
+            .filter(|_| !resumed)
+            .map(|_| input::InputRecording::start(&game));
+        let mut next_autosave = Instant::now() + AUTOSAVE_INTERVAL;
+        let mut autosaved = false;
+        let idle_pause = (settings.idle_pause_secs > 0)
+            .then(|| Duration::from_secs(u64::from(settings.idle_pause_secs)));
+        let mut last_input = Instant::now();
+        // Paused by losing the focus, and when to resume now that it is back.
+        let mut focus_paused = false;
+        let mut resume_at: Option<Instant> = None;
+        // A run at another speed than its difficulty's has no ghost to race.
+        let mut ghost = (settings.ghost && game.is_ranked())
+            .then(|| storage::load_ghost(game.score_key()))
+            .flatten()
+            .map(core::Ghost::new);
+        let mut rewind = core::RewindBuffer::default();
+        // Where a retry puts a lost run back to, and the run as it was lost,
+        // which is the one that counts whatever the retries score.
+        let mut retry_point: Option<core::Game> = None;
+        let mut lost_run: Option<core::Game> = None;
+        renderer.set_retry_offered(recording.is_none());
+        // Practice stepping: the run stays paused and plays one tick per `N`.
+        let mut stepping = false;
+        let mut step_requested = false;
+        log::info!(
+            "run started: {difficulty:?}, seed {}, profile {:?}",
+            game.replay.seed,
+            game.profile
+        );
 
-        // Get tick rates based on difficulty
-        let (horizontal_tick_rate, vertical_tick_rate) = game.get_tick_rates();
-
-        loop {
-            let mut return_to_menu = false;
+        let run_end = 'run: loop {
+            let now = Instant::now();
+            let elapsed = if game.game_over || game.is_paused() {
+                clock.hold(now);
+                Duration::ZERO
+            } else {
+                clock.advance(now)
+            };
 
-            // Handle inputs during normal gameplay (only when not game over)
-            if !game.game_over {
-                while let Ok(input_cmd) = rx.try_recv() {
-                    // Process MenuConfirm immediately, otherwise respect cooldown
-                    match input_cmd {
+            // Handle the input that woke us up, then drain anything else queued behind it.
+            for input_cmd in pending_input.take().into_iter().chain(rx.try_iter()) {
+                needs_redraw = true;
+                if !matches!(
+                    input_cmd,
+                    GameInput::Resize(..)
+                        | GameInput::ConfigChanged
+                        | GameInput::FocusLost
+                        | GameInput::FocusGained
+                ) {
+                    last_input = now;
+                }
+                if matches!(input_cmd, GameInput::ToggleDebugOverlay) {
+                    if !renderer.toggle_debug_overlay() {
+                        // Redraw the board over the panel.
+                        active_layout = None;
+                    }
+                    continue;
+                }
+                if matches!(input_cmd, GameInput::TogglePracticeOverlay) {
+                    if !renderer.toggle_practice_overlay() {
+                        active_layout = None;
+                    }
+                    continue;
+                }
+                if let Some(prompt) = &mut quit_prompt {
+                    // The dialog takes every key until it closes; only a
+                    // confirmed quit reaches the recording.
+                    let decision = match input_cmd {
                         GameInput::Resize(width, height) => {
                             term_size = (width, height);
+                            None
+                        }
+                        GameInput::ConfigChanged => {
+                            config_changed = true;
+                            None
                         }
                         GameInput::MenuConfirm => {
-                            return_to_menu = true;
-                            break;
+                            play_ui_sound(renderer, &settings);
+                            prompt.answer(&input_cmd)
+                        }
+                        _ => prompt.answer(&input_cmd),
+                    };
+                    let resume = prompt.resume;
+                    match decision {
+                        Some(true) => {
+                            if let Some(recording) = &mut recording {
+                                recording.record(game.stats.ticks, &GameInput::Quit);
+                            }
+                            break 'run prompt.leave;
                         }
-                        GameInput::Quit => break 'game_loop,
-                        GameInput::Pause => game.toggle_pause(), // Pause/unpause the game
-                        GameInput::ToggleMute => game.toggle_mute(), // Toggle mute
-                        GameInput::FocusLost => {
-                            if settings.pause_on_focus_loss && !game.is_paused() {
+                        Some(false) => {
+                            if resume {
                                 game.toggle_pause();
                             }
+                            quit_prompt = None;
+                            // The board under the dialog is stale.
+                            active_layout = None;
                         }
-                        GameInput::Direction(direction) => {
-                            let reference_direction = direction_queue
-                                .back()
-                                .copied()
-                                .unwrap_or(game.snake.direction);
-                            let is_same_direction = direction == reference_direction;
-                            if !is_same_direction
-                                && !is_reverse_direction(reference_direction, direction)
-                            {
-                                if direction_queue.len() >= 2 {
-                                    direction_queue.pop_back();
-                                }
-                                direction_queue.push_back(direction);
+                        None => {}
+                    }
+                    continue;
+                }
+                match leave_run(&game, &input_cmd) {
+                    Some((leave, true)) => {
+                        quit_prompt = Some(QuitPrompt::open(&mut game, leave));
+                        continue;
+                    }
+                    Some((leave, false)) => {
+                        if let Some(recording) = &mut recording {
+                            recording.record(game.stats.ticks, &input_cmd);
+                        }
+                        break 'run leave;
+                    }
+                    None => {}
+                }
+                if let Some(recording) = &mut recording {
+                    recording.record(game.stats.ticks, &input_cmd);
+                }
+                match input_cmd {
+                    GameInput::Resize(width, height) => {
+                        term_size = (width, height);
+                    }
+                    GameInput::ConfigChanged => config_changed = true, // Applied back in the menus
+                    GameInput::Pause => game.toggle_pause(),           // Pause/unpause the game
+                    GameInput::Back if game.game_over => break 'run RunEnd::Menu,
+                    GameInput::Back => game.toggle_pause(),
+                    GameInput::ToggleMute if !game.game_over => game.toggle_mute(), // Toggle mute
+                    GameInput::Boost(on) if !game.game_over => game.set_boost(on),
+                    GameInput::Copy if game.game_over => {
+                        render::copy_result(renderer, &game, settings.language);
+                    }
+                    GameInput::SaveCard if game.game_over => {
+                        save_result_card(renderer, &game, settings.language);
+                    }
+                    // Recordings only play forward, so a recorded run cannot rewind.
+                    GameInput::Undo if play.practice && recording.is_none() => {
+                        if rewind.rewind(&mut game) {
+                            log::debug!("rewound to tick {}", game.stats.ticks);
+                            direction_queue.clear();
+                            died_at = None;
+                            shown_death_frame = None;
+                            // A death frame may cover the board.
+                            active_layout = None;
+                        }
+                    }
+                    GameInput::Reset if game.game_over && recording.is_none() => {
+                        if lost_run.is_none() {
+                            retry_point = rewind.retry_point(&game);
+                            lost_run = retry_point.is_some().then(|| game.clone());
+                        }
+                        if let Some(point) = &retry_point {
+                            rewind.retry(&mut game, point);
+                            log::debug!("retrying from tick {}", game.stats.ticks);
+                            direction_queue.clear();
+                            died_at = None;
+                            shown_death_frame = None;
+                            active_layout = None;
+                        }
+                    }
+                    GameInput::SlowMotion if play.practice && !game.game_over => {
+                        game.toggle_slow_motion();
+                    }
+                    GameInput::Step if play.practice && !game.game_over => {
+                        if stepping {
+                            step_requested = true;
+                        } else {
+                            stepping = true;
+                            if !game.is_paused() {
+                                game.toggle_pause();
                             }
                         }
-                        _ => {}
                     }
+                    GameInput::CycleLanguage => {
+                        menu::cycle_language(renderer, &mut settings, true);
+                        persist_settings(&settings);
+                        // The frame and HUD are laid out for the old language.
+                        active_layout = None;
+                    }
+                    GameInput::FocusLost => {
+                        if settings.pause_on_focus_loss && !game.is_paused() {
+                            game.toggle_pause();
+                            focus_paused = true;
+                        }
+                        resume_at = None;
+                    }
+                    GameInput::FocusGained => {
+                        if settings.resume_on_focus_gain && focus_paused && !game.game_over {
+                            resume_at = Some(now + FOCUS_RESUME_COUNTDOWN);
+                        }
+                    }
+                    GameInput::Direction(direction) if !game.game_over => {
+                        input::queue_direction(
+                            &mut direction_queue,
+                            game.snake.direction,
+                            direction,
+                        );
+                    }
+                    _ => {}
                 }
+            }
 
-                if return_to_menu {
-                    continue 'game_loop;
+            // Unpausing, by any key that does it, goes back to real time.
+            stepping &= game.is_paused();
+            step_requested &= stepping;
+            // So does the countdown, unless a key got there first.
+            focus_paused &= game.is_paused() && quit_prompt.is_none();
+            if !focus_paused {
+                resume_at = None;
+            } else if resume_at.is_some_and(|resume_at| now >= resume_at) {
+                game.toggle_pause();
+                focus_paused = false;
+                resume_at = None;
+            }
+            let countdown = resume_at.map(|resume_at| {
+                let left = resume_at.saturating_duration_since(now);
+                u64::try_from(left.as_millis().div_ceil(1000)).unwrap_or(u64::MAX)
+            });
+            if countdown != renderer.resume_countdown() {
+                renderer.set_resume_countdown(countdown);
+                needs_redraw = true;
+            }
+
+            // A live run wakes every frame, so the idle time is checked often
+            // enough without a deadline of its own.
+            if let Some(idle_pause) = idle_pause {
+                if !game.game_over
+                    && !game.is_paused()
+                    && now.saturating_duration_since(last_input) >= idle_pause
+                {
+                    game.toggle_pause();
+                    renderer.show_toast(i18n::toast_idle_paused(
+                        settings.language,
+                        settings.idle_pause_secs,
+                    ));
+                    needs_redraw = true;
                 }
+            }
 
-                let layout = match layout::compute_layout(
-                    term_size.0,
-                    term_size.1,
-                    game.width,
-                    game.height,
-                    settings.language,
-                ) {
-                    Ok(layout) => layout,
-                    Err(size_check) => {
-                        render::draw_size_warning(size_check, settings.language);
-                        active_layout = None;
-                        thread::sleep(Duration::from_millis(25));
-                        continue;
+            let mut next_deadline = None;
+            match layout::compute_layout(
+                term_size.0,
+                term_size.1,
+                game.width,
+                game.height,
+                settings.square_cells,
+                layout::Hud::from_settings(&settings),
+                settings.language,
+            ) {
+                Ok(layout) => {
+                    // The HUD clock runs on real time, so it moves between ticks.
+                    game.stats.play_time += elapsed;
+                    let layout = layout.following(game.snake.head_position(), active_layout);
+                    if active_layout != Some(layout) {
+                        render::draw_frame(renderer, active_layout, &layout);
+                        active_layout = Some(layout);
+                        needs_redraw = true;
                     }
-                };
-                if active_layout != Some(layout) {
-                    render::draw_static_frame(&layout);
-                    active_layout = Some(layout);
-                }
 
-                // Determine the tick rate based on the current direction and power-ups
-                let progression_multiplier = game.difficulty_speed_multiplier_percent();
-                let power_up_multiplier = game.speed_multiplier_percent();
-                let speed_multiplier = progression_multiplier * power_up_multiplier / 100;
-                let effective_horizontal_rate = Duration::from_millis(
-                    (horizontal_tick_rate.as_millis() as u64 * speed_multiplier / 100).max(20),
-                );
-                let effective_vertical_rate = Duration::from_millis(
-                    (vertical_tick_rate.as_millis() as u64 * speed_multiplier / 100).max(20),
-                );
-
-                let direction_for_tick_rate = direction_queue
-                    .front()
-                    .copied()
-                    .unwrap_or(game.snake.direction);
-                let tick_rate = match direction_for_tick_rate {
-                    utils::Direction::Up | utils::Direction::Down => effective_vertical_rate,
-                    utils::Direction::Left | utils::Direction::Right => effective_horizontal_rate,
-                };
+                    if !game.game_over && (!game.is_paused() || step_requested) {
+                        loop {
+                            // The pending turn sets the pace so it applies its own
+                            // horizontal/vertical rate.
+                            let step = game.tick_interval(
+                                direction_queue
+                                    .front()
+                                    .copied()
+                                    .unwrap_or(game.snake.direction),
+                            );
+                            let due = if stepping {
+                                std::mem::take(&mut step_requested)
+                            } else {
+                                clock.try_step(step)
+                            };
+                            if game.game_over || !due {
+                                // Sleep until the next tick is due unless input arrives first.
+                                if !game.game_over && !stepping {
+                                    next_deadline = Some(clock.next_step_at(step));
+                                }
+                                break;
+                            }
+                            if let Some(direction) = direction_queue.pop_front() {
+                                game.update_snake_direction(direction);
+                            }
+                            // Every tick for rewinding; a retry needs far less.
+                            if play.practice {
+                                rewind.record(&game);
+                            } else {
+                                rewind.checkpoint(&game);
+                            }
+                            let tick_started = Instant::now();
+                            game.tick();
+                            log::trace!(
+                                "tick {} took {:?} (step {step:?})",
+                                game.stats.ticks,
+                                tick_started.elapsed()
+                            );
+                            play_game_sounds(renderer, &mut game, &settings);
+                            if stepping {
+                                game.stats.play_time += step;
+                                game.stats.stepped_ticks += 1;
+                            }
+                            needs_redraw = true;
+                        }
+                    }
 
-                // Update game state
-                if !game.game_over && !game.is_paused() && last_tick.elapsed() >= tick_rate {
-                    if let Some(direction) = direction_queue.pop_front() {
-                        game.update_snake_direction(direction);
+                    // A recorded run has to start from the beginning, so it is
+                    // not worth saving midway.
+                    if recording.is_none() && !game.game_over && now >= next_autosave {
+                        autosave_run(&game);
+                        autosaved = true;
+                        next_autosave = now + AUTOSAVE_INTERVAL;
                     }
-                    game.tick();
-                    if game.high_score > high_scores.get(difficulty) {
-                        high_scores.set(difficulty, game.high_score);
-                        persist_config(&high_scores, settings);
+                    // A lost run is not offered back, even if the game stops
+                    // before the player leaves the game-over panel.
+                    if game.game_over && std::mem::take(&mut autosaved) {
+                        discard_autosave();
                     }
-                    last_tick = Instant::now();
-                }
 
-                // Draw everything
-                render::draw(&mut game, &layout, settings.language);
-            } else {
-                while let Ok(input_cmd) = rx.try_recv() {
-                    match input_cmd {
-                        GameInput::Resize(width, height) => {
-                            term_size = (width, height);
+                    // The death animation runs on its own clock once the snake dies.
+                    let death_frame = game.game_over.then(|| {
+                        let died_at = *died_at.get_or_insert(now);
+                        let frame = if game.reduced_motion {
+                            render::DEATH_ANIMATION_FRAMES
+                        } else {
+                            render::death_frame(now.saturating_duration_since(died_at))
+                        };
+                        if frame < render::DEATH_ANIMATION_FRAMES {
+                            next_deadline =
+                                Some(died_at + render::DEATH_FRAME_INTERVAL * (frame + 1));
                         }
-                        GameInput::MenuConfirm => {
-                            // Space bar to go back to menu
-                            continue 'game_loop;
+                        frame
+                    });
+                    needs_redraw |= death_frame != shown_death_frame;
+                    // Toasts go away on time even when nothing else changes.
+                    if let Some(expiry) = renderer.next_toast_expiry() {
+                        if now >= expiry {
+                            needs_redraw = true;
+                        } else {
+                            next_deadline =
+                                Some(next_deadline.map_or(expiry, |deadline| deadline.min(expiry)));
                         }
-                        GameInput::Quit => {
-                            break 'game_loop; // Quit the game
+                    }
+
+                    // Draw the latest state, at most once per frame interval.
+                    let live = !game.game_over && !game.is_paused();
+                    if needs_redraw || live {
+                        if frames.is_due(now) {
+                            renderer.set_ghost(
+                                ghost
+                                    .as_mut()
+                                    .and_then(|ghost| ghost.head_at(game.stats.ticks)),
+                            );
+                            renderer.set_debug_run(Some(render::DebugRun {
+                                ticks: game.stats.ticks,
+                                queue_depth: direction_queue.len(),
+                                seed: game.replay.seed,
+                            }));
+                            update_window_title(renderer, &settings, Some(game.score));
+                            match death_frame {
+                                Some(frame) => render::draw_death_frame(
+                                    renderer,
+                                    &mut game,
+                                    &layout,
+                                    settings.language,
+                                    frame,
+                                ),
+                                None => match &quit_prompt {
+                                    Some(prompt) => render::draw_quit_confirm(
+                                        renderer,
+                                        &mut game,
+                                        &layout,
+                                        settings.language,
+                                        prompt.selected,
+                                        prompt.leave == RunEnd::Menu,
+                                    ),
+                                    None => render::draw(
+                                        renderer,
+                                        &mut game,
+                                        &layout,
+                                        settings.language,
+                                    ),
+                                },
+                            }
+                            shown_death_frame = death_frame;
+                            // Spectators only need the frames that changed something.
+                            if let Some(broadcaster) = broadcaster.as_ref().filter(|_| needs_redraw)
+                            {
+                                broadcaster.send(&game);
+                            }
+                            frames.drawn(now);
+                            needs_redraw = false;
+                        }
+                        if needs_redraw || live {
+                            let frame_due = frames.next_frame_at(now);
+                            next_deadline = Some(
+                                next_deadline.map_or(frame_due, |deadline| deadline.min(frame_due)),
+                            );
                         }
-                        _ => {}
                     }
                 }
+                Err(size_check) => {
+                    // The run stands still while the board does not fit.
+                    render::draw_size_warning(renderer, size_check, settings.language);
+                    active_layout = None;
+                    clock.hold(now);
+                }
+            }
 
-                let layout = match layout::compute_layout(
-                    term_size.0,
-                    term_size.1,
-                    game.width,
-                    game.height,
-                    settings.language,
-                ) {
-                    Ok(layout) => layout,
-                    Err(size_check) => {
-                        render::draw_size_warning(size_check, settings.language);
-                        active_layout = None;
-                        thread::sleep(Duration::from_millis(25));
-                        continue;
-                    }
-                };
-                if active_layout != Some(layout) {
-                    render::draw_static_frame(&layout);
-                    active_layout = Some(layout);
+            // The countdown changes every second.
+            if let (Some(resume_at), Some(seconds)) = (resume_at, countdown) {
+                let change = resume_at - Duration::from_secs(seconds - 1);
+                next_deadline = Some(next_deadline.map_or(change, |deadline| deadline.min(change)));
+            }
+
+            // Paused, game-over, and undersized screens only change on input, so
+            // they block without a deadline instead of polling.
+            match input::wait_for_input(&rx, next_deadline) {
+                InputWait::Input(input_cmd) => {
+                    renderer.note_input(Instant::now());
+                    pending_input = Some(input_cmd);
                 }
-                render::draw(&mut game, &layout, settings.language);
+                InputWait::TimedOut => {}
+                InputWait::Disconnected => break 'run RunEnd::Quit,
             }
+        };
 
-            // Check for game over and handle input differently
-            if game.game_over {
-                // During game over, we handle input from the channel
-                if let Ok(input_cmd) = rx.recv_timeout(Duration::from_millis(100)) {
-                    match input_cmd {
-                        GameInput::Resize(width, height) => {
-                            term_size = (width, height);
-                        }
-                        GameInput::MenuConfirm => {
-                            // Space bar to go back to menu
-                            continue 'game_loop;
-                        }
-                        GameInput::Quit => {
-                            // 'q' key to quit
-                            break 'game_loop; // Quit the game
-                        }
-                        _ => {} // Ignore other inputs during game over
-                    }
+        // The run ended normally; there is nothing left to recover.
+        discard_autosave();
+        renderer.set_debug_run(None);
+        renderer.set_ghost(None);
+        renderer.set_resume_countdown(None);
+        renderer.set_retry_offered(false);
+        let game = lost_run.unwrap_or(game);
+        log::info!(
+            "run ended: score {}, {} ticks, game over: {}",
+            game.score,
+            game.stats.ticks,
+            game.game_over
+        );
+        record_run_history(&game);
+        record_ghost_run(&game);
+        if let (Some(recording), Some(path)) = (&mut recording, &play.record_input) {
+            // Each run overwrites the file, which ends up with the last one played.
+            recording.finish(&game);
+            if let Err(err) = recording.save(path) {
+                if !REPORTED_RECORDING_SAVE_ERROR.swap(true, Ordering::Relaxed) {
+                    notices::report(format!("Failed to save input recording: {err}"));
                 }
+            }
+        }
+        if config_changed {
+            reload_settings(renderer, &keymap, &mut settings);
+        }
+
+        // Leaving the run records a qualifying score; quitting skips the prompt
+        // and reuses the last initials.
+        let mut quit = matches!(run_end, RunEnd::Quit);
+        if let Some(rank) = high_score_rank(&game, &high_scores) {
+            let initials = if quit {
+                None
             } else {
-                // Small delay to prevent excessive CPU usage (only when not game over)
-                thread::sleep(Duration::from_millis(10));
+                menu::prompt_initials(
+                    renderer,
+                    &rx,
+                    &keymap,
+                    &mut term_size,
+                    &settings,
+                    game.score,
+                    rank,
+                )
+            };
+            quit |= initials.is_none();
+            settings.last_initials = initials.unwrap_or(settings.last_initials);
+            high_scores.insert(
+                game.score_key(),
+                HighScoreEntry::new(
+                    game.score,
+                    settings.last_initials,
+                    storage::current_utc_date(),
+                ),
+            );
+            persist_high_scores(&high_scores);
+            persist_settings(&settings);
+        }
+        #[cfg(feature = "leaderboard")]
+        if let Some(client) = &leaderboard {
+            if game.score > 0 && game.is_ranked() {
+                client.submit(leaderboard::Submission {
+                    initials: settings.last_initials,
+                    difficulty,
+                    score: game.score,
+                    replay_hash: game.replay_hash(),
+                });
             }
         }
-        // When we break from the inner loop (either game over or back to menu),
-        // we continue to the next iteration of the outer loop which shows the menu again
+        if quit || single_run {
+            break;
+        }
     }
 
+    drop(terminal_guard);
+    #[cfg(feature = "leaderboard")]
+    if let Some(client) = leaderboard {
+        client.shutdown();
+    }
+    #[cfg(feature = "sync")]
+    sync_scores_or_warn(false);
     Ok(())
 }
 
@@ -657,12 +1661,49 @@ mod tests {
     use super::*;
 
     #[test]
-    fn menu_required_min_size_applies_menu_safety_floor() {
-        for language in Language::ALL {
-            let gameplay_min = layout::min_terminal_size(utils::WIDTH, utils::HEIGHT, language);
-            let menu_min = menu_required_min_size(language);
-            assert_eq!(menu_min.width, gameplay_min.width.max(34));
-            assert_eq!(menu_min.height, gameplay_min.height.max(23));
-        }
+    fn leaving_a_live_run_asks_first() {
+        let mut game = Game::new_seeded(Difficulty::Medium, 20, 12, 0, 1);
+        assert_eq!(
+            leave_run(&game, &GameInput::MenuConfirm),
+            Some((RunEnd::Menu, true))
+        );
+        assert_eq!(
+            leave_run(&game, &GameInput::Quit),
+            Some((RunEnd::Quit, true))
+        );
+        assert_eq!(leave_run(&game, &GameInput::Pause), None);
+
+        // A stray Space opens the dialog on No, and Space again keeps the run.
+        let mut prompt = QuitPrompt::open(&mut game, RunEnd::Menu);
+        assert!(game.is_paused());
+        assert_eq!(prompt.answer(&GameInput::MenuConfirm), Some(false));
+        prompt.answer(&GameInput::Direction(utils::Direction::Up));
+        assert_eq!(prompt.answer(&GameInput::MenuConfirm), Some(true));
+        assert_eq!(prompt.leave, RunEnd::Menu);
+
+        // Paused or over, Space goes straight back to the menu.
+        assert_eq!(
+            leave_run(&game, &GameInput::MenuConfirm),
+            Some((RunEnd::Menu, false))
+        );
+        game.game_over = true;
+        assert_eq!(
+            leave_run(&game, &GameInput::Quit),
+            Some((RunEnd::Quit, false))
+        );
+    }
+
+    #[test]
+    fn only_ranked_runs_that_started_reach_the_score_tables() {
+        let high_scores = HighScores::default();
+        let mut game = Game::new_seeded(Difficulty::Medium, 20, 12, 0, 1);
+        game.score = 50;
+        assert_eq!(high_score_rank(&game, &high_scores), None);
+
+        game.tick();
+        assert_eq!(high_score_rank(&game, &high_scores), Some(0));
+
+        game.stats.rewinds = 1;
+        assert_eq!(high_score_rank(&game, &high_scores), None);
     }
 }
@@ -0,0 +1,1194 @@
+//! The menus: the main menu and every screen under it, Settings included,
+//! shown between runs until one starts or the player quits.
+
+mod prompts;
+mod settings;
+
+use std::{
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use crate::app::{
+    persist_high_scores, persist_settings, play_ui_sound, reload_settings, update_window_title,
+};
+use crate::demo::{DEMO_IDLE_TIMEOUT, run_demo};
+use crate::input::{self, GameInput, InputWait, TextField};
+use crate::storage::{HighScores, ScoreKey, Settings};
+use crate::utils::{self, CustomDifficulty, Difficulty, Language, PowerUpType, SoundEvent};
+use crate::{i18n, layout, notices, render};
+#[cfg(feature = "leaderboard")]
+use crate::{leaderboard, storage};
+pub(crate) use prompts::{prompt_initials, show_config_notice, show_recovery_prompt};
+use settings::SettingsRow;
+pub(crate) use settings::cycle_language;
+
+#[derive(Clone, Copy)]
+enum MenuScreen {
+    Main,
+    Difficulty,
+    CustomDifficulty,
+    HighScores,
+    #[cfg(feature = "leaderboard")]
+    GlobalLeaderboard,
+    Settings,
+    Language,
+    PowerUps,
+    Sound,
+    ResetScores,
+    ResetScoresConfirm,
+    ResetTableConfirm,
+}
+
+impl MenuScreen {
+    /// Where the screen's Back entry (or Esc) leads; the main menu has none.
+    fn parent(self) -> Option<Self> {
+        match self {
+            Self::Main => None,
+            Self::Difficulty | Self::CustomDifficulty | Self::HighScores | Self::Settings => {
+                Some(Self::Main)
+            }
+            #[cfg(feature = "leaderboard")]
+            Self::GlobalLeaderboard => Some(Self::Main),
+            Self::Language | Self::PowerUps | Self::Sound | Self::ResetScores => {
+                Some(Self::Settings)
+            }
+            Self::ResetScoresConfirm => Some(Self::ResetScores),
+            Self::ResetTableConfirm => Some(Self::HighScores),
+        }
+    }
+
+    /// The smallest terminal the screen is drawn in; a score table needs
+    /// room for every rank.
+    fn min_size(self, compact: bool, language: Language) -> layout::MinSize {
+        let menu = menu_required_min_size(compact, language);
+        let table = layout::MinSize {
+            height: render::high_scores_min_height(),
+            ..menu
+        };
+        match self {
+            Self::HighScores => table,
+            #[cfg(feature = "leaderboard")]
+            Self::GlobalLeaderboard => table,
+            _ => menu,
+        }
+    }
+}
+
+/// Main menu rows; the global leaderboard row only appears once it is set up.
+#[derive(Clone, Copy)]
+enum MainEntry {
+    Play,
+    Difficulty,
+    HighScores,
+    #[cfg(feature = "leaderboard")]
+    GlobalLeaderboard,
+    Settings,
+    Quit,
+}
+
+fn difficulty_to_index(difficulty: Difficulty) -> usize {
+    match difficulty {
+        Difficulty::Easy => 0,
+        Difficulty::Medium => 1,
+        Difficulty::Hard => 2,
+        Difficulty::Extreme => 3,
+        Difficulty::Custom => 4,
+    }
+}
+
+fn difficulty_from_index(index: usize) -> Difficulty {
+    match index {
+        0 => Difficulty::Easy,
+        1 => Difficulty::Medium,
+        2 => Difficulty::Hard,
+        3 => Difficulty::Extreme,
+        4 => Difficulty::Custom,
+        _ => Difficulty::Medium,
+    }
+}
+
+/// Seeds are typed as digits only, short enough to round-trip through the config.
+fn seed_digit(ch: char) -> Option<char> {
+    ch.is_ascii_digit().then_some(ch)
+}
+
+/// The custom screen's rows: the `adjust` fields, the seed (`seed` is the text
+/// shown for it), and Back.
+fn custom_difficulty_options(
+    custom: CustomDifficulty,
+    seed: &str,
+    language: Language,
+) -> Vec<String> {
+    vec![
+        format!(
+            "{}: < {} ms >",
+            i18n::custom_tick_rate_label(language),
+            custom.tick_ms
+        ),
+        format!(
+            "{}: < {} >",
+            i18n::custom_progression_label(language),
+            custom.progression_steps
+        ),
+        format!(
+            "{}: < {}% >",
+            i18n::custom_power_up_chance_label(language),
+            custom.power_up_chance_percent
+        ),
+        format!(
+            "{}: < {} >",
+            i18n::custom_wrap_label(language),
+            if custom.wrap_edges {
+                i18n::setting_on(language)
+            } else {
+                i18n::setting_off(language)
+            }
+        ),
+        format!("{}: {}", i18n::custom_seed_label(language), seed),
+        i18n::menu_back(language).to_string(),
+    ]
+}
+
+/// The Sound screen's rows: the master switch, one per sound event, and Back.
+fn sound_options(settings: &Settings, language: Language) -> Vec<String> {
+    let on_off = |on: bool| {
+        if on {
+            i18n::setting_on(language)
+        } else {
+            i18n::setting_off(language)
+        }
+    };
+    let mut options = vec![format!(
+        "{}: {}",
+        i18n::settings_sound_label(language),
+        on_off(settings.sound_on)
+    )];
+    options.extend(SoundEvent::ALL.iter().map(|event| {
+        format!(
+            "{}: {}",
+            i18n::sound_event_name(language, *event),
+            on_off(settings.sounds.enabled(*event))
+        )
+    }));
+    options.push(i18n::menu_back(language).to_string());
+    options
+}
+
+/// The Power-ups screen's rows: one per power-up type, and Back.
+fn power_up_options(settings: &Settings, language: Language) -> Vec<String> {
+    let mut options: Vec<String> = PowerUpType::ALL
+        .iter()
+        .map(|power_up_type| {
+            format!(
+                "{}: {}",
+                i18n::power_up_name(language, *power_up_type),
+                if settings.disabled_power_ups.contains(*power_up_type) {
+                    i18n::setting_off(language)
+                } else {
+                    i18n::setting_on(language)
+                }
+            )
+        })
+        .collect();
+    options.push(i18n::menu_back(language).to_string());
+    options
+}
+
+/// The smallest terminal a menu is drawn in. Menus page their options, so
+/// they only need their own text to fit and stay usable in a terminal too
+/// small for the board.
+pub(crate) fn menu_required_min_size(compact: bool, language: Language) -> layout::MinSize {
+    layout::MinSize {
+        width: i18n::minimum_ui_width(language).max(34),
+        height: render::menu_min_height(compact),
+    }
+}
+
+/// Says in the banner why Play does nothing: the menus fit the terminal,
+/// but the board does not.
+fn report_board_too_big(minimum: layout::MinSize, language: Language) {
+    notices::report(format!(
+        "{}  {}: {}x{}",
+        i18n::small_window_title(language),
+        i18n::small_window_minimum_label(language),
+        minimum.width,
+        minimum.height
+    ));
+}
+
+/// How often the global leaderboard screen checks for a pending fetch.
+const LEADERBOARD_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// When a menu next has to redraw without input: while a global board loads,
+/// when the demo is due, or when a banner expires. `None` means nothing is
+/// pending, so the menu blocks until the next key instead of polling.
+fn menu_deadline(
+    now: Instant,
+    board_loading: bool,
+    demo_due: Option<Instant>,
+    banner_until: Option<Instant>,
+) -> Option<Instant> {
+    let board_poll = board_loading.then(|| now + LEADERBOARD_POLL_INTERVAL);
+    [board_poll, demo_due, banner_until]
+        .into_iter()
+        .flatten()
+        .min()
+}
+
+#[cfg(feature = "leaderboard")]
+fn draw_global_leaderboard(
+    renderer: &mut render::Renderer,
+    boards: &leaderboard::GlobalBoards,
+    difficulty: Difficulty,
+    page: usize,
+    term_size: (u16, u16),
+    settings: &Settings,
+) {
+    let language = settings.language;
+    let first_rank = page * storage::HIGH_SCORE_TABLE_SIZE;
+    let (entries, status) = match boards.state(difficulty) {
+        leaderboard::BoardState::Loading => {
+            (&[][..], Some(i18n::global_leaderboard_loading(language)))
+        }
+        leaderboard::BoardState::Failed => (
+            &[][..],
+            Some(i18n::global_leaderboard_unavailable(language)),
+        ),
+        leaderboard::BoardState::Ready(entries) => {
+            let page_entries = entries.get(first_rank..).unwrap_or_default();
+            (
+                &page_entries[..page_entries.len().min(storage::HIGH_SCORE_TABLE_SIZE)],
+                None,
+            )
+        }
+    };
+    let title = format!(
+        "{}  {}-{}",
+        i18n::global_leaderboard_title(language),
+        first_rank + 1,
+        first_rank + storage::HIGH_SCORE_TABLE_SIZE
+    );
+    render::draw_high_scores_menu(
+        renderer,
+        render::HighScoresRenderRequest {
+            title: &title,
+            entries,
+            first_rank,
+            status,
+            switch_hint: i18n::global_leaderboard_hint(language),
+            actions_hint: None,
+            by_date: false,
+            difficulty,
+            variant: None,
+            page: (difficulty_to_index(difficulty) + 1, Difficulty::ALL.len()),
+            term_width: term_size.0,
+            term_height: term_size.1,
+            language,
+            compact: settings.ui_compact,
+        },
+    );
+}
+
+/// The table one mode or board size before or after `selected` in
+/// `HighScores::listed_keys` order, at the same difficulty when there is one.
+fn neighbouring_score_table(tables: &[ScoreKey], selected: usize, forward: bool) -> usize {
+    let group = |key: &ScoreKey| (key.mode, key.width, key.height);
+    let current = tables[selected];
+    let target = if forward {
+        tables[selected..]
+            .iter()
+            .map(group)
+            .find(|other| *other != group(&current))
+    } else {
+        tables[..selected]
+            .iter()
+            .rev()
+            .map(group)
+            .find(|other| *other != group(&current))
+    };
+    let Some(target) = target else {
+        return selected;
+    };
+    tables
+        .iter()
+        .position(|key| group(key) == target && key.difficulty == current.difficulty)
+        .or_else(|| tables.iter().position(|key| group(key) == target))
+        .unwrap_or(selected)
+}
+
+pub(crate) fn show_menu(
+    renderer: &mut render::Renderer,
+    rx: &mpsc::Receiver<GameInput>,
+    keymap: &input::Keymap,
+    term_size: &mut (u16, u16),
+    settings: &mut Settings,
+    high_scores: &mut HighScores,
+    #[cfg(feature = "leaderboard")] leaderboard: Option<&leaderboard::LeaderboardClient>,
+) -> Option<Difficulty> {
+    render::clear_for_menu_entry(renderer);
+
+    let mut main_entries = vec![
+        MainEntry::Play,
+        MainEntry::Difficulty,
+        MainEntry::HighScores,
+    ];
+    #[cfg(feature = "leaderboard")]
+    if leaderboard.is_some() {
+        main_entries.push(MainEntry::GlobalLeaderboard);
+    }
+    main_entries.extend([MainEntry::Settings, MainEntry::Quit]);
+
+    let mut screen = MenuScreen::Main;
+    let mut main_selected = 0usize;
+    let mut difficulty_selected = difficulty_to_index(settings.default_difficulty);
+    let mut settings_selected = 0usize;
+    let mut language_selected = settings.language.to_index();
+    let mut power_ups_selected = 0usize;
+    let mut sound_selected = 0usize;
+    let mut custom_selected = 0usize;
+    let mut seed_field = TextField::new("", CustomDifficulty::SEED_DIGITS, seed_digit);
+    #[cfg(feature = "leaderboard")]
+    let mut leaderboard_selected = difficulty_to_index(settings.default_difficulty);
+    let mut high_scores_selected = 0usize;
+    let mut scores_by_date = false;
+    let mut reset_scores_selected = 0usize;
+    // The difficulty whose scores a confirmed reset clears; `None` is all.
+    let mut reset_target: Option<Difficulty> = None;
+    let mut reset_selected = 1usize; // Default to "No"
+    #[cfg(feature = "leaderboard")]
+    let mut global_boards = leaderboard::GlobalBoards::default();
+    #[cfg(feature = "leaderboard")]
+    let mut global_page = 0usize;
+    let mut idle_since = Instant::now();
+    let mut rainbow_just_unlocked = false;
+
+    loop {
+        let ui_language = settings.language;
+        update_window_title(renderer, settings, None);
+        let seed_focused = matches!(screen, MenuScreen::CustomDifficulty)
+            && custom_selected == CustomDifficulty::FIELD_COUNT;
+        keymap.set_text_entry(seed_focused);
+        let gameplay_min = layout::min_play_size(
+            utils::WIDTH,
+            utils::HEIGHT,
+            settings.square_cells,
+            layout::Hud::from_settings(settings),
+            ui_language,
+        );
+        let required_min = screen.min_size(settings.ui_compact, ui_language);
+        let can_start_game =
+            term_size.0 >= gameplay_min.width && term_size.1 >= gameplay_min.height;
+        let can_render_menu =
+            term_size.0 >= required_min.width && term_size.1 >= required_min.height;
+        let banner = notices::current(Instant::now());
+
+        #[cfg(feature = "leaderboard")]
+        if let (MenuScreen::GlobalLeaderboard, Some(client)) = (&screen, leaderboard) {
+            global_boards.refresh(client, Difficulty::ALL[leaderboard_selected]);
+        }
+
+        if can_render_menu {
+            match screen {
+                MenuScreen::HighScores => {
+                    let tables = high_scores.listed_keys();
+                    let key = tables[high_scores_selected.min(tables.len() - 1)];
+                    let variant = i18n::score_table_variant(ui_language, key);
+                    render::draw_high_scores_menu(
+                        renderer,
+                        render::HighScoresRenderRequest {
+                            title: i18n::high_scores_menu_title(ui_language),
+                            entries: high_scores.entries(key),
+                            first_rank: 0,
+                            status: None,
+                            switch_hint: i18n::leaderboard_switch_hint(ui_language),
+                            actions_hint: Some(i18n::high_scores_actions_hint(ui_language)),
+                            by_date: scores_by_date,
+                            difficulty: key.difficulty,
+                            variant: Some(&variant),
+                            page: (high_scores_selected + 1, tables.len()),
+                            term_width: term_size.0,
+                            term_height: term_size.1,
+                            language: ui_language,
+                            compact: settings.ui_compact,
+                        },
+                    );
+                }
+                #[cfg(feature = "leaderboard")]
+                MenuScreen::GlobalLeaderboard => draw_global_leaderboard(
+                    renderer,
+                    &global_boards,
+                    Difficulty::ALL[leaderboard_selected],
+                    global_page,
+                    *term_size,
+                    settings,
+                ),
+                _ => {
+                    let mut values = Vec::new();
+                    let reset_title = reset_target
+                        .map(|difficulty| i18n::reset_difficulty_title(ui_language, difficulty));
+                    let (screen_tag, title, subtitle, options, selected, danger_option) =
+                        match screen {
+                            MenuScreen::Main => (
+                                "MENU",
+                                i18n::menu_title(ui_language),
+                                Some(if rainbow_just_unlocked {
+                                    i18n::menu_rainbow_unlocked(ui_language).to_string()
+                                } else {
+                                    format!(
+                                        "{}: {}",
+                                        i18n::menu_difficulty(ui_language),
+                                        i18n::difficulty_label(
+                                            ui_language,
+                                            settings.default_difficulty
+                                        )
+                                    )
+                                }),
+                                main_entries
+                                    .iter()
+                                    .map(|entry| match entry {
+                                        MainEntry::Play => i18n::menu_play(ui_language).to_string(),
+                                        MainEntry::Difficulty => format!(
+                                            "{}: {}",
+                                            i18n::menu_difficulty(ui_language),
+                                            i18n::difficulty_label(
+                                                ui_language,
+                                                settings.default_difficulty
+                                            )
+                                        ),
+                                        MainEntry::HighScores => {
+                                            i18n::menu_high_scores(ui_language).to_string()
+                                        }
+                                        #[cfg(feature = "leaderboard")]
+                                        MainEntry::GlobalLeaderboard => {
+                                            i18n::menu_global_leaderboard(ui_language).to_string()
+                                        }
+                                        MainEntry::Settings => {
+                                            i18n::menu_settings(ui_language).to_string()
+                                        }
+                                        MainEntry::Quit => i18n::menu_quit(ui_language).to_string(),
+                                    })
+                                    .collect(),
+                                main_selected,
+                                None,
+                            ),
+                            MenuScreen::Difficulty => (
+                                "DIFFICULTY",
+                                i18n::difficulty_menu_title(ui_language),
+                                Some(format!(
+                                    "{}: {}",
+                                    i18n::menu_difficulty(ui_language),
+                                    i18n::difficulty_label(
+                                        ui_language,
+                                        difficulty_from_index(
+                                            difficulty_selected.min(Difficulty::ALL.len() - 1),
+                                        )
+                                    )
+                                )),
+                                vec![
+                                    i18n::difficulty_label(ui_language, Difficulty::Easy)
+                                        .to_string(),
+                                    i18n::difficulty_label(ui_language, Difficulty::Medium)
+                                        .to_string(),
+                                    i18n::difficulty_label(ui_language, Difficulty::Hard)
+                                        .to_string(),
+                                    i18n::difficulty_label(ui_language, Difficulty::Extreme)
+                                        .to_string(),
+                                    i18n::difficulty_label(ui_language, Difficulty::Custom)
+                                        .to_string(),
+                                    i18n::menu_back(ui_language).to_string(),
+                                ],
+                                difficulty_selected,
+                                None,
+                            ),
+                            MenuScreen::CustomDifficulty => (
+                                "CUSTOM",
+                                i18n::custom_difficulty_title(ui_language),
+                                Some(i18n::custom_adjust_hint(ui_language).to_string()),
+                                custom_difficulty_options(
+                                    settings.custom_difficulty,
+                                    &if seed_focused || !seed_field.text().is_empty() {
+                                        seed_field.text()
+                                    } else {
+                                        i18n::custom_seed_random(ui_language).to_string()
+                                    },
+                                    ui_language,
+                                ),
+                                custom_selected,
+                                None,
+                            ),
+                            MenuScreen::Settings => {
+                                let (labels, row_values) =
+                                    settings::settings_rows(settings, ui_language);
+                                values = row_values;
+                                (
+                                    "SETTINGS",
+                                    i18n::menu_settings(ui_language),
+                                    Some(i18n::custom_adjust_hint(ui_language).to_string()),
+                                    labels,
+                                    settings_selected,
+                                    Some(SettingsRow::ResetHighScores.index()),
+                                )
+                            }
+                            MenuScreen::Sound => (
+                                "SOUND",
+                                i18n::sound_menu_title(ui_language),
+                                None,
+                                sound_options(settings, ui_language),
+                                sound_selected,
+                                None,
+                            ),
+                            MenuScreen::PowerUps => (
+                                "POWERUPS",
+                                i18n::power_ups_menu_title(ui_language),
+                                Some(i18n::settings_power_ups_label(ui_language).to_string()),
+                                power_up_options(settings, ui_language),
+                                power_ups_selected,
+                                None,
+                            ),
+                            MenuScreen::Language => {
+                                let mut options: Vec<String> = Language::available()
+                                    .into_iter()
+                                    .map(|language| i18n::language_name(language).to_string())
+                                    .collect();
+                                options.push(i18n::menu_back(ui_language).to_string());
+                                (
+                                    "LANGUAGE",
+                                    i18n::language_popup_title(ui_language),
+                                    Some(format!(
+                                        "{}: {}",
+                                        i18n::language_label(ui_language),
+                                        i18n::language_name(settings.language)
+                                    )),
+                                    options,
+                                    language_selected,
+                                    None,
+                                )
+                            }
+                            MenuScreen::ResetScores => {
+                                let mut options: Vec<String> = Difficulty::ALL
+                                    .iter()
+                                    .map(|difficulty| {
+                                        i18n::difficulty_label(ui_language, *difficulty).to_string()
+                                    })
+                                    .collect();
+                                options.push(i18n::reset_scores_all(ui_language).to_string());
+                                options.push(i18n::menu_back(ui_language).to_string());
+                                // How many scores each row would clear.
+                                let counts = Difficulty::ALL
+                                    .map(|difficulty| high_scores.count_for(difficulty));
+                                values = counts
+                                    .iter()
+                                    .chain([&counts.iter().sum()])
+                                    .map(usize::to_string)
+                                    .chain([String::new()])
+                                    .collect();
+                                (
+                                    "RESET",
+                                    i18n::settings_reset_high_scores_label(ui_language),
+                                    None,
+                                    options,
+                                    reset_scores_selected,
+                                    Some(Difficulty::ALL.len()),
+                                )
+                            }
+                            MenuScreen::ResetScoresConfirm => (
+                                "RESET",
+                                reset_title
+                                    .as_deref()
+                                    .unwrap_or(i18n::reset_high_scores_title(ui_language)),
+                                Some(
+                                    i18n::settings_reset_high_scores_label(ui_language).to_string(),
+                                ),
+                                vec![
+                                    i18n::confirm_yes(ui_language).to_string(),
+                                    i18n::confirm_no(ui_language).to_string(),
+                                ],
+                                reset_selected,
+                                Some(0),
+                            ),
+                            MenuScreen::ResetTableConfirm => {
+                                let tables = high_scores.listed_keys();
+                                let key = tables[high_scores_selected.min(tables.len() - 1)];
+                                (
+                                    "RESET",
+                                    i18n::reset_score_table_title(ui_language),
+                                    Some(format!(
+                                        "{} · {}",
+                                        i18n::difficulty_label(ui_language, key.difficulty),
+                                        i18n::score_table_variant(ui_language, key)
+                                    )),
+                                    vec![
+                                        i18n::confirm_yes(ui_language).to_string(),
+                                        i18n::confirm_no(ui_language).to_string(),
+                                    ],
+                                    reset_selected,
+                                    Some(0),
+                                )
+                            }
+                            MenuScreen::HighScores => unreachable!(),
+                            #[cfg(feature = "leaderboard")]
+                            MenuScreen::GlobalLeaderboard => unreachable!(),
+                        };
+                    render::draw_menu(
+                        renderer,
+                        render::MenuRenderRequest {
+                            screen_tag,
+                            title,
+                            subtitle: subtitle.as_deref(),
+                            options: &options,
+                            values: &values,
+                            selected_option: selected,
+                            danger_option,
+                            text_cursor: seed_focused.then(|| {
+                                (
+                                    CustomDifficulty::FIELD_COUNT,
+                                    i18n::custom_seed_label(ui_language).chars().count()
+                                        + 2
+                                        + seed_field.cursor(),
+                                )
+                            }),
+                            banner: banner.as_ref().map(|(text, _)| text.as_str()),
+                            term_width: term_size.0,
+                            term_height: term_size.1,
+                            language: ui_language,
+                            compact: settings.ui_compact,
+                        },
+                    );
+                }
+            }
+        } else {
+            render::draw_size_warning(
+                renderer,
+                layout::SizeCheck {
+                    current_width: term_size.0,
+                    current_height: term_size.1,
+                    minimum: required_min,
+                },
+                ui_language,
+            );
+        }
+
+        // Keep redrawing while a global board is still on its way.
+        #[cfg(feature = "leaderboard")]
+        let board_loading = matches!(screen, MenuScreen::GlobalLeaderboard)
+            && matches!(
+                global_boards.state(Difficulty::ALL[leaderboard_selected]),
+                leaderboard::BoardState::Loading
+            );
+        #[cfg(not(feature = "leaderboard"))]
+        let board_loading = false;
+        let demo_due = (matches!(screen, MenuScreen::Main) && can_start_game)
+            .then(|| idle_since + DEMO_IDLE_TIMEOUT);
+        let deadline = menu_deadline(
+            Instant::now(),
+            board_loading,
+            demo_due,
+            banner.map(|(_, until)| until),
+        );
+        let input_cmd = match input::wait_for_input(rx, deadline) {
+            InputWait::Input(input_cmd) => input_cmd,
+            InputWait::TimedOut => {
+                if demo_due.is_some_and(|demo_due| Instant::now() >= demo_due) {
+                    if !run_demo(renderer, rx, term_size, settings) {
+                        return None;
+                    }
+                    idle_since = Instant::now();
+                }
+                continue;
+            }
+            InputWait::Disconnected => return None,
+        };
+        idle_since = Instant::now();
+        // The notice stays up through the config reload its own save triggers.
+        rainbow_just_unlocked &= !matches!(
+            input_cmd,
+            GameInput::Direction(_) | GameInput::MenuSelect(_) | GameInput::MenuConfirm
+        );
+        let max_index = match screen {
+            MenuScreen::Main => main_entries.len() - 1,
+            MenuScreen::Difficulty => Difficulty::ALL.len(),
+            MenuScreen::CustomDifficulty => CustomDifficulty::FIELD_COUNT + 1,
+            MenuScreen::Settings => SettingsRow::ALL.len() - 1,
+            MenuScreen::Language => Language::available().len(),
+            MenuScreen::PowerUps => PowerUpType::ALL.len(),
+            MenuScreen::Sound => SoundEvent::ALL.len() + 1,
+            MenuScreen::ResetScores => Difficulty::ALL.len() + 1,
+            MenuScreen::ResetScoresConfirm | MenuScreen::ResetTableConfirm => 1,
+            MenuScreen::HighScores => 0,
+            #[cfg(feature = "leaderboard")]
+            MenuScreen::GlobalLeaderboard => 0,
+        };
+        if seed_focused && seed_field.handle(&input_cmd) {
+            settings.custom_difficulty.seed = seed_field.text().parse().ok();
+            persist_settings(settings);
+            continue;
+        }
+        if matches!(input_cmd, GameInput::MenuConfirm) {
+            play_ui_sound(renderer, settings);
+        }
+        match input_cmd {
+            GameInput::Resize(width, height) => {
+                *term_size = (width, height);
+                // Every cell is painted again, so nothing stale comes back
+                // when terminals restore previously hidden columns.
+                render::resize_for_menu(renderer, width, height);
+            }
+            GameInput::ToggleDebugOverlay => {
+                if !renderer.toggle_debug_overlay() {
+                    render::clear_for_menu_entry(renderer);
+                }
+            }
+            GameInput::ConfigChanged => {
+                if reload_settings(renderer, keymap, settings) {
+                    language_selected = settings.language.to_index();
+                    render::clear_for_menu_entry(renderer);
+                }
+            }
+            GameInput::MenuSelect(option) => {
+                let selection = option.min(max_index);
+                match screen {
+                    MenuScreen::Main => main_selected = selection,
+                    MenuScreen::Difficulty => difficulty_selected = selection,
+                    MenuScreen::CustomDifficulty => custom_selected = selection,
+                    MenuScreen::Settings => settings_selected = selection,
+                    MenuScreen::Language => language_selected = selection,
+                    MenuScreen::PowerUps => power_ups_selected = selection,
+                    MenuScreen::Sound => sound_selected = selection,
+                    MenuScreen::ResetScores => reset_scores_selected = selection,
+                    MenuScreen::ResetScoresConfirm | MenuScreen::ResetTableConfirm => {
+                        reset_selected = selection
+                    }
+                    MenuScreen::HighScores => {
+                        high_scores_selected = option.min(high_scores.listed_keys().len() - 1)
+                    }
+                    #[cfg(feature = "leaderboard")]
+                    MenuScreen::GlobalLeaderboard => {
+                        leaderboard_selected = option.min(Difficulty::ALL.len() - 1)
+                    }
+                }
+            }
+            GameInput::Direction(utils::Direction::Up) => match screen {
+                MenuScreen::Main => main_selected = main_selected.saturating_sub(1),
+                MenuScreen::Difficulty => {
+                    difficulty_selected = difficulty_selected.saturating_sub(1)
+                }
+                MenuScreen::CustomDifficulty => custom_selected = custom_selected.saturating_sub(1),
+                MenuScreen::Settings => settings_selected = settings_selected.saturating_sub(1),
+                MenuScreen::Language => language_selected = language_selected.saturating_sub(1),
+                MenuScreen::PowerUps => power_ups_selected = power_ups_selected.saturating_sub(1),
+                MenuScreen::Sound => sound_selected = sound_selected.saturating_sub(1),
+                MenuScreen::ResetScores => {
+                    reset_scores_selected = reset_scores_selected.saturating_sub(1)
+                }
+                MenuScreen::ResetScoresConfirm | MenuScreen::ResetTableConfirm => {
+                    reset_selected = reset_selected.saturating_sub(1)
+                }
+                MenuScreen::HighScores => {
+                    high_scores_selected = neighbouring_score_table(
+                        &high_scores.listed_keys(),
+                        high_scores_selected,
+                        false,
+                    )
+                }
+                #[cfg(feature = "leaderboard")]
+                MenuScreen::GlobalLeaderboard => global_page = global_page.saturating_sub(1),
+            },
+            GameInput::Direction(utils::Direction::Down) => match screen {
+                MenuScreen::Main => main_selected = (main_selected + 1).min(max_index),
+                MenuScreen::Difficulty => {
+                    difficulty_selected = (difficulty_selected + 1).min(max_index)
+                }
+                MenuScreen::CustomDifficulty => {
+                    custom_selected = (custom_selected + 1).min(max_index)
+                }
+                MenuScreen::Settings => settings_selected = (settings_selected + 1).min(max_index),
+                MenuScreen::Language => {
+                    language_selected = (language_selected + 1).min(Language::available().len())
+                }
+                MenuScreen::PowerUps => {
+                    power_ups_selected = (power_ups_selected + 1).min(PowerUpType::ALL.len())
+                }
+                MenuScreen::Sound => sound_selected = (sound_selected + 1).min(max_index),
+                MenuScreen::ResetScores => {
+                    reset_scores_selected = (reset_scores_selected + 1).min(max_index)
+                }
+                MenuScreen::ResetScoresConfirm | MenuScreen::ResetTableConfirm => {
+                    reset_selected = (reset_selected + 1).min(1)
+                }
+                MenuScreen::HighScores => {
+                    high_scores_selected = neighbouring_score_table(
+                        &high_scores.listed_keys(),
+                        high_scores_selected,
+                        true,
+                    )
+                }
+                #[cfg(feature = "leaderboard")]
+                MenuScreen::GlobalLeaderboard => {
+                    global_page = (global_page + 1)
+                        .min(leaderboard::GLOBAL_TOP_SIZE / storage::HIGH_SCORE_TABLE_SIZE - 1)
+                }
+            },
+            GameInput::Page(down) => {
+                let selected = match screen {
+                    MenuScreen::Main => &mut main_selected,
+                    MenuScreen::Difficulty => &mut difficulty_selected,
+                    MenuScreen::CustomDifficulty => &mut custom_selected,
+                    MenuScreen::Settings => &mut settings_selected,
+                    MenuScreen::Language => &mut language_selected,
+                    MenuScreen::PowerUps => &mut power_ups_selected,
+                    MenuScreen::Sound => &mut sound_selected,
+                    MenuScreen::ResetScores => &mut reset_scores_selected,
+                    MenuScreen::ResetScoresConfirm | MenuScreen::ResetTableConfirm => {
+                        &mut reset_selected
+                    }
+                    MenuScreen::HighScores => continue,
+                    #[cfg(feature = "leaderboard")]
+                    MenuScreen::GlobalLeaderboard => continue,
+                };
+                let page = render::menu_page_size(term_size.1, settings.ui_compact);
+                *selected = if down {
+                    (*selected + page).min(max_index)
+                } else {
+                    selected.saturating_sub(page)
+                };
+            }
+            GameInput::Direction(
+                direction @ (utils::Direction::Left | utils::Direction::Right),
+            ) => {
+                let forward = matches!(direction, utils::Direction::Right);
+                match screen {
+                    MenuScreen::CustomDifficulty
+                        if custom_selected < CustomDifficulty::FIELD_COUNT =>
+                    {
+                        settings.custom_difficulty.adjust(custom_selected, forward);
+                        persist_settings(settings);
+                    }
+                    MenuScreen::Settings => SettingsRow::at(settings_selected)
+                        .adjust(renderer, keymap, settings, forward),
+                    #[cfg(feature = "leaderboard")]
+                    MenuScreen::GlobalLeaderboard => {
+                        let count = Difficulty::ALL.len();
+                        leaderboard_selected = if forward {
+                            (leaderboard_selected + 1) % count
+                        } else {
+                            (leaderboard_selected + count - 1) % count
+                        };
+                    }
+                    MenuScreen::HighScores => {
+                        let count = high_scores.listed_keys().len();
+                        high_scores_selected = if forward {
+                            (high_scores_selected + 1) % count
+                        } else {
+                            (high_scores_selected + count - 1) % count
+                        };
+                    }
+                    _ => {}
+                }
+            }
+            GameInput::MenuConfirm => match screen {
+                MenuScreen::Main => match main_entries.get(main_selected) {
+                    Some(MainEntry::Play) => {
+                        if can_start_game {
+                            return Some(settings.default_difficulty);
+                        }
+                        report_board_too_big(gameplay_min, ui_language);
+                    }
+                    Some(MainEntry::Difficulty) => {
+                        difficulty_selected = difficulty_to_index(settings.default_difficulty);
+                        screen = MenuScreen::Difficulty;
+                    }
+                    Some(MainEntry::HighScores) => {
+                        let default_table = ScoreKey::classic(settings.default_difficulty);
+                        high_scores_selected = high_scores
+                            .listed_keys()
+                            .iter()
+                            .position(|key| *key == default_table)
+                            .unwrap_or(0);
+                        screen = MenuScreen::HighScores;
+                    }
+                    #[cfg(feature = "leaderboard")]
+                    Some(MainEntry::GlobalLeaderboard) => {
+                        // Fetch fresh boards on every visit.
+                        global_boards = leaderboard::GlobalBoards::default();
+                        global_page = 0;
+                        leaderboard_selected = difficulty_to_index(settings.default_difficulty);
+                        screen = MenuScreen::GlobalLeaderboard;
+                    }
+                    Some(MainEntry::Settings) => screen = MenuScreen::Settings,
+                    Some(MainEntry::Quit) => return None,
+                    None => {}
+                },
+                MenuScreen::Difficulty => {
+                    let chosen = Difficulty::ALL.get(difficulty_selected).copied();
+                    if let Some(difficulty) = chosen {
+                        settings.default_difficulty = difficulty;
+                        persist_settings(settings);
+                    }
+                    if chosen == Some(Difficulty::Custom) {
+                        custom_selected = 0;
+                        seed_field = TextField::new(
+                            &settings
+                                .custom_difficulty
+                                .seed
+                                .map_or_else(String::new, |seed| seed.to_string()),
+                            CustomDifficulty::SEED_DIGITS,
+                            seed_digit,
+                        );
+                        seed_field.move_to_end();
+                        screen = MenuScreen::CustomDifficulty;
+                    } else {
+                        screen = MenuScreen::Main;
+                    }
+                }
+                MenuScreen::CustomDifficulty => {
+                    if custom_selected == CustomDifficulty::FIELD_COUNT + 1 {
+                        screen = MenuScreen::Main;
+                    } else if custom_selected == CustomDifficulty::FIELD_COUNT {
+                        custom_selected += 1;
+                    } else if custom_selected == CustomDifficulty::FIELD_COUNT - 1 {
+                        // Confirm flips the on/off field like the other toggles.
+                        settings.custom_difficulty.adjust(custom_selected, true);
+                        persist_settings(settings);
+                    }
+                }
+                MenuScreen::Settings => match SettingsRow::at(settings_selected) {
+                    // Rows with a submenu open it; Enter on the others steps
+                    // the value like →.
+                    SettingsRow::Language => {
+                        language_selected = settings.language.to_index();
+                        screen = MenuScreen::Language;
+                    }
+                    SettingsRow::Sound => {
+                        sound_selected = 0;
+                        screen = MenuScreen::Sound;
+                    }
+                    SettingsRow::PowerUps => {
+                        power_ups_selected = 0;
+                        screen = MenuScreen::PowerUps;
+                    }
+                    SettingsRow::ResetHighScores => {
+                        reset_scores_selected = 0;
+                        screen = MenuScreen::ResetScores;
+                    }
+                    SettingsRow::Back => screen = MenuScreen::Main,
+                    row => row.adjust(renderer, keymap, settings, true),
+                },
+                MenuScreen::Language => {
+                    if let Some(&language) = Language::available().get(language_selected) {
+                        settings.language = language;
+                        renderer.set_right_to_left(i18n::is_right_to_left(settings.language));
+                        persist_settings(settings);
+                    }
+                    screen = MenuScreen::Settings;
+                }
+                MenuScreen::Sound => match sound_selected {
+                    0 => {
+                        settings.sound_on = !settings.sound_on;
+                        persist_settings(settings);
+                    }
+                    index if index <= SoundEvent::ALL.len() => {
+                        settings.sounds.toggle(SoundEvent::ALL[index - 1]);
+                        persist_settings(settings);
+                    }
+                    _ => screen = MenuScreen::Settings,
+                },
+                MenuScreen::PowerUps => {
+                    if let Some(power_up_type) = PowerUpType::ALL.get(power_ups_selected) {
+                        settings.disabled_power_ups.toggle(*power_up_type);
+                        persist_settings(settings);
+                    } else {
+                        screen = MenuScreen::Settings;
+                    }
+                }
+                MenuScreen::ResetScores => match Difficulty::ALL.get(reset_scores_selected) {
+                    // A difficulty without scores has nothing to reset.
+                    Some(difficulty) if high_scores.count_for(*difficulty) == 0 => {}
+                    Some(difficulty) => {
+                        reset_target = Some(*difficulty);
+                        reset_selected = 1;
+                        screen = MenuScreen::ResetScoresConfirm;
+                    }
+                    None if reset_scores_selected == Difficulty::ALL.len() => {
+                        reset_target = None;
+                        reset_selected = 1;
+                        screen = MenuScreen::ResetScoresConfirm;
+                    }
+                    None => screen = MenuScreen::Settings,
+                },
+                MenuScreen::ResetScoresConfirm => {
+                    if reset_selected == 0 {
+                        match reset_target {
+                            Some(difficulty) => high_scores.clear_difficulty(difficulty),
+                            None => *high_scores = HighScores::default(),
+                        }
+                        persist_high_scores(high_scores);
+                    }
+                    screen = MenuScreen::ResetScores;
+                }
+                MenuScreen::ResetTableConfirm => {
+                    if reset_selected == 0 {
+                        let tables = high_scores.listed_keys();
+                        let key = tables[high_scores_selected.min(tables.len() - 1)];
+                        high_scores.clear_table(key);
+                        persist_high_scores(high_scores);
+                        // A table outside the classic ones is no longer listed.
+                        high_scores_selected =
+                            high_scores_selected.min(high_scores.listed_keys().len() - 1);
+                    }
+                    screen = MenuScreen::HighScores;
+                }
+                MenuScreen::HighScores => {
+                    screen = MenuScreen::Main;
+                }
+                #[cfg(feature = "leaderboard")]
+                MenuScreen::GlobalLeaderboard => {
+                    screen = MenuScreen::Main;
+                }
+            },
+            GameInput::QuickStart(difficulty) => {
+                if matches!(screen, MenuScreen::Main) {
+                    if can_start_game {
+                        play_ui_sound(renderer, settings);
+                        return Some(difficulty);
+                    }
+                    report_board_too_big(gameplay_min, ui_language);
+                }
+            }
+            GameInput::SortOrder if matches!(screen, MenuScreen::HighScores) => {
+                scores_by_date = !scores_by_date;
+            }
+            GameInput::Reset if matches!(screen, MenuScreen::HighScores) => {
+                let tables = high_scores.listed_keys();
+                // An empty table has nothing to reset.
+                if !high_scores
+                    .entries(tables[high_scores_selected.min(tables.len() - 1)])
+                    .is_empty()
+                {
+                    play_ui_sound(renderer, settings);
+                    reset_selected = 1;
+                    screen = MenuScreen::ResetTableConfirm;
+                }
+            }
+            GameInput::Back => {
+                if let Some(parent) = screen.parent() {
+                    play_ui_sound(renderer, settings);
+                    screen = parent;
+                }
+            }
+            GameInput::Quit => {
+                return None;
+            }
+            GameInput::SecretCode
+                if matches!(screen, MenuScreen::Main) && !settings.rainbow_unlocked =>
+            {
+                settings.rainbow_unlocked = true;
+                settings.theme = "rainbow".to_string();
+                renderer.set_theme(render::Theme::from_settings(settings));
+                play_ui_sound(renderer, settings);
+                persist_settings(settings);
+                rainbow_just_unlocked = true;
+            }
+            _ => {} // Ignore other inputs
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::HighScoreEntry;
+
+    #[test]
+    fn menus_and_scores_fit_a_terminal_too_small_for_the_board() {
+        for language in Language::ALL {
+            let gameplay_min = layout::min_terminal_size(
+                utils::WIDTH,
+                utils::HEIGHT,
+                false,
+                layout::Hud::default(),
+                language,
+            );
+            for compact in [false, true] {
+                for screen in [MenuScreen::Settings, MenuScreen::HighScores] {
+                    let min = screen.min_size(compact, language);
+                    assert!(min.width >= 34 && min.width <= 60, "{language:?}");
+                    assert!(min.height <= 16 && min.height < gameplay_min.height);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn an_idle_menu_waits_for_input_without_a_deadline() {
+        let now = Instant::now();
+        assert_eq!(menu_deadline(now, false, None, None), None);
+
+        let demo_due = now + DEMO_IDLE_TIMEOUT;
+        let banner_until = now + Duration::from_secs(2);
+        assert_eq!(
+            menu_deadline(now, false, Some(demo_due), None),
+            Some(demo_due)
+        );
+        assert_eq!(
+            menu_deadline(now, false, Some(demo_due), Some(banner_until)),
+            Some(banner_until)
+        );
+        assert_eq!(
+            menu_deadline(now, true, Some(demo_due), Some(banner_until)),
+            Some(now + LEADERBOARD_POLL_INTERVAL)
+        );
+    }
+
+    #[test]
+    fn esc_walks_back_up_the_menu_tree() {
+        let mut screen = MenuScreen::Sound;
+        let mut path = Vec::new();
+        while let Some(parent) = screen.parent() {
+            path.push(parent);
+            screen = parent;
+        }
+        assert!(matches!(path[..], [MenuScreen::Settings, MenuScreen::Main]));
+        assert!(matches!(
+            MenuScreen::ResetScoresConfirm.parent(),
+            Some(MenuScreen::ResetScores)
+        ));
+        assert!(matches!(
+            MenuScreen::ResetTableConfirm.parent(),
+            Some(MenuScreen::HighScores)
+        ));
+    }
+
+    #[test]
+    fn up_and_down_move_between_score_tables_of_other_modes() {
+        let mut high_scores = HighScores::default();
+        let entry = || vec![HighScoreEntry::new(10, Default::default(), "")];
+        let walls = |difficulty| ScoreKey::new(difficulty, utils::GameMode::Walls, 40, 20);
+        high_scores.set_entries(walls(Difficulty::Hard), entry());
+        high_scores.set_entries(walls(Difficulty::Easy), entry());
+        let tables = high_scores.listed_keys();
+        let at = |key: ScoreKey| tables.iter().position(|other| *other == key).unwrap();
+
+        let classic_hard = at(ScoreKey::classic(Difficulty::Hard));
+        assert_eq!(
+            neighbouring_score_table(&tables, classic_hard, true),
+            at(walls(Difficulty::Hard))
+        );
+        // Without the same difficulty the group's first table is picked.
+        assert_eq!(
+            neighbouring_score_table(&tables, at(ScoreKey::classic(Difficulty::Medium)), true),
+            at(walls(Difficulty::Easy))
+        );
+        assert_eq!(
+            neighbouring_score_table(&tables, at(walls(Difficulty::Hard)), false),
+            classic_hard
+        );
+        // There is nothing past the first and last groups.
+        assert_eq!(
+            neighbouring_score_table(&tables, classic_hard, false),
+            classic_hard
+        );
+        let last = tables.len() - 1;
+        assert_eq!(neighbouring_score_table(&tables, last, true), last);
+    }
+}
@@ -0,0 +1,245 @@
+//! The one-question screens drawn like menus: a new record's initials, the
+//! notice about a config that failed to load, and the offer to resume an
+//! unfinished run.
+
+use std::sync::mpsc;
+
+use super::menu_required_min_size;
+use crate::core::Game;
+use crate::input::{self, GameInput, InputWait, TextField};
+use crate::utils::{self, Initials};
+use crate::{i18n, layout, render, storage, storage::Settings};
+
+/// Initials entry for a new leaderboard record: type the letters, then save.
+/// Esc keeps the last initials. Returns `None` if the input thread is gone.
+pub(crate) fn prompt_initials(
+    renderer: &mut render::Renderer,
+    rx: &mpsc::Receiver<GameInput>,
+    keymap: &input::Keymap,
+    term_size: &mut (u16, u16),
+    settings: &Settings,
+    score: u32,
+    rank: usize,
+) -> Option<Initials> {
+    render::clear_for_menu_entry(renderer);
+
+    let language = settings.language;
+    let mut field = TextField::new(
+        &settings.last_initials.to_string(),
+        Initials::LEN,
+        Initials::typed,
+    );
+    let mut selected = 0usize;
+    let save_index = 1;
+    let label = format!("{}: ", i18n::leaderboard_initials_label(language));
+
+    let initials = loop {
+        keymap.set_text_entry(selected == 0);
+        let required_min = menu_required_min_size(settings.ui_compact, language);
+        if term_size.0 >= required_min.width && term_size.1 >= required_min.height {
+            let options = vec![
+                format!("{label}{}", field.text()),
+                i18n::initials_save_label(language).to_string(),
+            ];
+            let subtitle = i18n::new_record_subtitle(language, score, rank + 1);
+            render::draw_menu(
+                renderer,
+                render::MenuRenderRequest {
+                    screen_tag: "INITIALS",
+                    title: i18n::new_record_title(language),
+                    subtitle: Some(&subtitle),
+                    options: &options,
+                    values: &[],
+                    selected_option: selected,
+                    danger_option: None,
+                    text_cursor: (selected == 0)
+                        .then(|| (0, label.chars().count() + field.cursor())),
+                    banner: None,
+                    term_width: term_size.0,
+                    term_height: term_size.1,
+                    language,
+                    compact: settings.ui_compact,
+                },
+            );
+        } else {
+            render::draw_size_warning(
+                renderer,
+                layout::SizeCheck {
+                    current_width: term_size.0,
+                    current_height: term_size.1,
+                    minimum: required_min,
+                },
+                language,
+            );
+        }
+
+        let input_cmd = match input::wait_for_input(rx, None) {
+            InputWait::Input(input_cmd) => input_cmd,
+            InputWait::TimedOut => continue,
+            InputWait::Disconnected => break None,
+        };
+        if selected == 0 && field.handle(&input_cmd) {
+            continue;
+        }
+        match input_cmd {
+            GameInput::Resize(width, height) => {
+                *term_size = (width, height);
+                render::resize_for_menu(renderer, width, height);
+            }
+            GameInput::MenuSelect(option) => selected = option.min(save_index),
+            GameInput::Direction(utils::Direction::Up) => selected = 0,
+            GameInput::Direction(utils::Direction::Down) => selected = save_index,
+            // Enter on the name moves on to Save, like an arcade cabinet.
+            GameInput::MenuConfirm if selected < save_index => selected = save_index,
+            GameInput::MenuConfirm => break Some(Initials::from(field.text())),
+            GameInput::Back => break Some(settings.last_initials),
+            GameInput::Quit => break None,
+            _ => {}
+        }
+    };
+    keymap.set_text_entry(false);
+    initials
+}
+
+/// Startup notice for a config that failed validation. Returns `false` if the
+/// player quits instead of continuing.
+pub(crate) fn show_config_notice(
+    renderer: &mut render::Renderer,
+    rx: &mpsc::Receiver<GameInput>,
+    term_size: &mut (u16, u16),
+    settings: &Settings,
+    problems: &[storage::ConfigError],
+) -> bool {
+    render::clear_for_menu_entry(renderer);
+
+    let language = settings.language;
+    let mut subtitle = problems
+        .first()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    if problems.len() > 1 {
+        subtitle.push_str(&format!("  (+{})", problems.len() - 1));
+    }
+    let options = [i18n::config_notice_continue(language).to_string()];
+
+    loop {
+        let required_min = menu_required_min_size(settings.ui_compact, language);
+        if term_size.0 >= required_min.width && term_size.1 >= required_min.height {
+            render::draw_menu(
+                renderer,
+                render::MenuRenderRequest {
+                    screen_tag: "CONFIG",
+                    title: i18n::config_notice_title(language),
+                    subtitle: Some(&subtitle),
+                    options: &options,
+                    values: &[],
+                    selected_option: 0,
+                    danger_option: None,
+                    text_cursor: None,
+                    banner: None,
+                    term_width: term_size.0,
+                    term_height: term_size.1,
+                    language,
+                    compact: settings.ui_compact,
+                },
+            );
+        } else {
+            render::draw_size_warning(
+                renderer,
+                layout::SizeCheck {
+                    current_width: term_size.0,
+                    current_height: term_size.1,
+                    minimum: required_min,
+                },
+                language,
+            );
+        }
+
+        match input::wait_for_input(rx, None) {
+            InputWait::Input(GameInput::Resize(width, height)) => {
+                *term_size = (width, height);
+                render::resize_for_menu(renderer, width, height);
+            }
+            InputWait::Input(GameInput::MenuConfirm | GameInput::MenuSelect(0)) => return true,
+            InputWait::Input(GameInput::Quit) | InputWait::Disconnected => return false,
+            InputWait::Input(_) | InputWait::TimedOut => {}
+        }
+    }
+}
+
+/// Offers back the run an earlier launch left unfinished. Returns whether to
+/// resume it, or `None` to quit.
+pub(crate) fn show_recovery_prompt(
+    renderer: &mut render::Renderer,
+    rx: &mpsc::Receiver<GameInput>,
+    term_size: &mut (u16, u16),
+    settings: &Settings,
+    game: &Game,
+) -> Option<bool> {
+    render::clear_for_menu_entry(renderer);
+
+    let language = settings.language;
+    let subtitle = format!(
+        "{}  {}",
+        i18n::difficulty_label(language, game.difficulty),
+        i18n::summary_score(language, game.score)
+    );
+    let options = [
+        i18n::recover_resume(language).to_string(),
+        i18n::recover_discard(language).to_string(),
+    ];
+    let mut selected = 0;
+
+    loop {
+        let required_min = menu_required_min_size(settings.ui_compact, language);
+        if term_size.0 >= required_min.width && term_size.1 >= required_min.height {
+            render::draw_menu(
+                renderer,
+                render::MenuRenderRequest {
+                    screen_tag: "RECOVER",
+                    title: i18n::recover_title(language),
+                    subtitle: Some(&subtitle),
+                    options: &options,
+                    values: &[],
+                    selected_option: selected,
+                    danger_option: Some(1),
+                    text_cursor: None,
+                    banner: None,
+                    term_width: term_size.0,
+                    term_height: term_size.1,
+                    language,
+                    compact: settings.ui_compact,
+                },
+            );
+        } else {
+            render::draw_size_warning(
+                renderer,
+                layout::SizeCheck {
+                    current_width: term_size.0,
+                    current_height: term_size.1,
+                    minimum: required_min,
+                },
+                language,
+            );
+        }
+
+        match input::wait_for_input(rx, None) {
+            InputWait::Input(GameInput::Resize(width, height)) => {
+                *term_size = (width, height);
+                render::resize_for_menu(renderer, width, height);
+            }
+            InputWait::Input(GameInput::Direction(direction)) => {
+                selected = match direction {
+                    utils::Direction::Up | utils::Direction::Left => 0,
+                    utils::Direction::Down | utils::Direction::Right => 1,
+                };
+            }
+            InputWait::Input(GameInput::MenuSelect(option)) if option < options.len() => {
+                return Some(option == 0);
+            }
+            InputWait::Input(GameInput::MenuConfirm) => return Some(selected == 0),
+            InputWait::Input(GameInput::Quit) | InputWait::Disconnected => return None,
+            InputWait::Input(_) | InputWait::TimedOut => {}
+        }
+    }
+}
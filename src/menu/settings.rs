@@ -0,0 +1,385 @@
+//! The Settings screen: its rows, what each shows, and how ←/→ steps the
+//! values that change in place.
+
+use crate::app::{persist_settings, update_window_title};
+use crate::utils::{AxisPace, Language, PowerUpType};
+use crate::{i18n, input, render, storage::Settings};
+
+/// The Settings screen's rows, in the order they are listed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(super) enum SettingsRow {
+    Language,
+    PauseOnFocusLoss,
+    ResumeOnFocusGain,
+    IdlePause,
+    Sound,
+    UiCompact,
+    Theme,
+    Colorblind,
+    HighContrast,
+    ReducedMotion,
+    Speed,
+    SpeedCap,
+    SquareCells,
+    AxisPace,
+    Symbols,
+    BoardPattern,
+    HudPosition,
+    HudCompact,
+    Sidebar,
+    Ghost,
+    WindowTitle,
+    Controls,
+    PowerUps,
+    ResetHighScores,
+    Back,
+}
+
+impl SettingsRow {
+    pub(super) const ALL: [SettingsRow; 25] = [
+        SettingsRow::Language,
+        SettingsRow::PauseOnFocusLoss,
+        SettingsRow::ResumeOnFocusGain,
+        SettingsRow::IdlePause,
+        SettingsRow::Sound,
+        SettingsRow::UiCompact,
+        SettingsRow::Theme,
+        SettingsRow::Colorblind,
+        SettingsRow::HighContrast,
+        SettingsRow::ReducedMotion,
+        SettingsRow::Speed,
+        SettingsRow::SpeedCap,
+        SettingsRow::SquareCells,
+        SettingsRow::AxisPace,
+        SettingsRow::Symbols,
+        SettingsRow::BoardPattern,
+        SettingsRow::HudPosition,
+        SettingsRow::HudCompact,
+        SettingsRow::Sidebar,
+        SettingsRow::Ghost,
+        SettingsRow::WindowTitle,
+        SettingsRow::Controls,
+        SettingsRow::PowerUps,
+        SettingsRow::ResetHighScores,
+        SettingsRow::Back,
+    ];
+
+    /// The row listed at `index`; past the end is Back.
+    pub(super) fn at(index: usize) -> Self {
+        Self::ALL.get(index).copied().unwrap_or(SettingsRow::Back)
+    }
+
+    /// Where the row is listed on the screen.
+    pub(super) fn index(self) -> usize {
+        Self::ALL.iter().position(|row| *row == self).unwrap_or(0)
+    }
+
+    fn label(self, language: Language) -> &'static str {
+        match self {
+            SettingsRow::Language => i18n::language_label(language),
+            SettingsRow::PauseOnFocusLoss => i18n::settings_pause_on_focus_loss_label(language),
+            SettingsRow::ResumeOnFocusGain => i18n::settings_resume_on_focus_gain_label(language),
+            SettingsRow::IdlePause => i18n::settings_idle_pause_label(language),
+            SettingsRow::Sound => i18n::settings_sound_label(language),
+            SettingsRow::UiCompact => i18n::settings_ui_compact_label(language),
+            SettingsRow::Theme => i18n::settings_theme_label(language),
+            SettingsRow::Colorblind => i18n::settings_colorblind_label(language),
+            SettingsRow::HighContrast => i18n::settings_high_contrast_label(language),
+            SettingsRow::ReducedMotion => i18n::settings_reduced_motion_label(language),
+            SettingsRow::Speed => i18n::settings_speed_label(language),
+            SettingsRow::SpeedCap => i18n::settings_speed_cap_label(language),
+            SettingsRow::SquareCells => i18n::settings_square_cells_label(language),
+            SettingsRow::AxisPace => i18n::settings_axis_pace_label(language),
+            SettingsRow::Symbols => i18n::settings_symbols_label(language),
+            SettingsRow::BoardPattern => i18n::settings_board_pattern_label(language),
+            SettingsRow::HudPosition => i18n::settings_hud_position_label(language),
+            SettingsRow::HudCompact => i18n::settings_hud_compact_label(language),
+            SettingsRow::Sidebar => i18n::settings_sidebar_label(language),
+            SettingsRow::Ghost => i18n::settings_ghost_label(language),
+            SettingsRow::WindowTitle => i18n::settings_window_title_label(language),
+            SettingsRow::Controls => i18n::settings_controls_label(language),
+            SettingsRow::PowerUps => i18n::settings_power_ups_label(language),
+            SettingsRow::ResetHighScores => i18n::settings_reset_high_scores_label(language),
+            SettingsRow::Back => i18n::menu_back(language),
+        }
+    }
+
+    /// The row's value cell. Values in `< >` change in place with ←/→ (see
+    /// `adjust`); the rows without one open their own screen.
+    fn value(self, settings: &Settings, language: Language) -> String {
+        let on_off = |on: bool| {
+            if on {
+                i18n::setting_on(language)
+            } else {
+                i18n::setting_off(language)
+            }
+        };
+        let value = match self {
+            SettingsRow::Language => i18n::language_name(settings.language).to_string(),
+            SettingsRow::PauseOnFocusLoss => on_off(settings.pause_on_focus_loss).to_string(),
+            SettingsRow::ResumeOnFocusGain => on_off(settings.resume_on_focus_gain).to_string(),
+            SettingsRow::IdlePause => idle_pause_text(settings.idle_pause_secs, language),
+            SettingsRow::Sound => on_off(settings.sound_on).to_string(),
+            SettingsRow::UiCompact => on_off(settings.ui_compact).to_string(),
+            SettingsRow::Theme => settings.theme.clone(),
+            SettingsRow::Colorblind => on_off(settings.colorblind).to_string(),
+            SettingsRow::HighContrast => on_off(settings.high_contrast).to_string(),
+            SettingsRow::ReducedMotion => on_off(settings.reduced_motion).to_string(),
+            SettingsRow::Speed => i18n::game_speed_name(language, settings.speed).to_string(),
+            SettingsRow::SpeedCap => speed_cap_text(settings.speed_cap_ms, language),
+            SettingsRow::SquareCells => on_off(settings.square_cells).to_string(),
+            SettingsRow::AxisPace => axis_pace_text(settings, language),
+            SettingsRow::Symbols => i18n::symbol_set_name(language, settings.symbols).to_string(),
+            SettingsRow::BoardPattern => {
+                i18n::board_pattern_name(language, settings.board_pattern).to_string()
+            }
+            SettingsRow::HudPosition => {
+                i18n::hud_position_name(language, settings.hud_position).to_string()
+            }
+            SettingsRow::HudCompact => on_off(settings.hud_compact).to_string(),
+            SettingsRow::Sidebar => on_off(settings.sidebar).to_string(),
+            SettingsRow::Ghost => on_off(settings.ghost).to_string(),
+            SettingsRow::WindowTitle => on_off(settings.window_title).to_string(),
+            SettingsRow::Controls => {
+                i18n::control_scheme_name(language, settings.control_scheme).to_string()
+            }
+            SettingsRow::PowerUps => {
+                let enabled_power_ups = PowerUpType::ALL
+                    .iter()
+                    .filter(|power_up_type| !settings.disabled_power_ups.contains(**power_up_type))
+                    .count();
+                return format!("{enabled_power_ups}/{}", PowerUpType::ALL.len());
+            }
+            SettingsRow::ResetHighScores | SettingsRow::Back => return String::new(),
+        };
+        format!("< {value} >")
+    }
+
+    /// Steps the row's value and saves it. Rows without a value to change in
+    /// place are left alone.
+    pub(super) fn adjust(
+        self,
+        renderer: &mut render::Renderer,
+        keymap: &input::Keymap,
+        settings: &mut Settings,
+        forward: bool,
+    ) {
+        match self {
+            SettingsRow::Language => cycle_language(renderer, settings, forward),
+            SettingsRow::PauseOnFocusLoss => {
+                settings.pause_on_focus_loss = !settings.pause_on_focus_loss
+            }
+            SettingsRow::ResumeOnFocusGain => {
+                settings.resume_on_focus_gain = !settings.resume_on_focus_gain
+            }
+            SettingsRow::IdlePause => {
+                settings.idle_pause_secs = cycle_idle_pause(settings.idle_pause_secs, forward)
+            }
+            SettingsRow::Sound => settings.sound_on = !settings.sound_on,
+            SettingsRow::UiCompact => settings.ui_compact = !settings.ui_compact,
+            SettingsRow::Theme => {
+                cycle_theme(settings, forward);
+                renderer.set_theme(render::Theme::from_settings(settings));
+                render::clear_for_menu_entry(renderer);
+            }
+            SettingsRow::Colorblind => {
+                settings.colorblind = !settings.colorblind;
+                renderer.set_theme(render::Theme::from_settings(settings));
+            }
+            SettingsRow::HighContrast => {
+                settings.high_contrast = !settings.high_contrast;
+                renderer.set_theme(render::Theme::from_settings(settings));
+            }
+            SettingsRow::ReducedMotion => settings.reduced_motion = !settings.reduced_motion,
+            SettingsRow::Speed => settings.speed = settings.speed.cycle(forward),
+            SettingsRow::SpeedCap => {
+                settings.speed_cap_ms = cycle_speed_cap(settings.speed_cap_ms, forward)
+            }
+            SettingsRow::SquareCells => settings.square_cells = !settings.square_cells,
+            SettingsRow::AxisPace => cycle_axis_pace(settings, forward),
+            SettingsRow::Symbols => {
+                settings.symbols = settings.symbols.toggled();
+                renderer.set_theme(render::Theme::from_settings(settings));
+            }
+            SettingsRow::BoardPattern => {
+                settings.board_pattern = settings.board_pattern.cycle(forward);
+                renderer.set_theme(render::Theme::from_settings(settings));
+            }
+            SettingsRow::HudPosition => {
+                settings.hud_position = settings.hud_position.cycle(forward)
+            }
+            SettingsRow::HudCompact => settings.hud_compact = !settings.hud_compact,
+            SettingsRow::Sidebar => settings.sidebar = !settings.sidebar,
+            SettingsRow::Ghost => settings.ghost = !settings.ghost,
+            SettingsRow::WindowTitle => {
+                settings.window_title = !settings.window_title;
+                update_window_title(renderer, settings, None);
+            }
+            SettingsRow::Controls => {
+                settings.control_scheme = settings.control_scheme.cycle(forward);
+                keymap.set(settings);
+            }
+            SettingsRow::PowerUps | SettingsRow::ResetHighScores | SettingsRow::Back => return,
+        }
+        persist_settings(settings);
+    }
+}
+
+/// Labels and value cells of the Settings screen, one per `SettingsRow`.
+pub(super) fn settings_rows(settings: &Settings, language: Language) -> (Vec<String>, Vec<String>) {
+    SettingsRow::ALL
+        .iter()
+        .map(|row| {
+            (
+                row.label(language).to_string(),
+                row.value(settings, language),
+            )
+        })
+        .unzip()
+}
+
+/// Moves `settings.theme` to the next (or previous) built-in or custom theme.
+fn cycle_theme(settings: &mut Settings, forward: bool) {
+    let names = render::Theme::names(settings);
+    let current = names
+        .iter()
+        .position(|name| *name == settings.theme)
+        .unwrap_or(0);
+    let next = if forward {
+        (current + 1) % names.len()
+    } else {
+        (current + names.len() - 1) % names.len()
+    };
+    settings.theme = names[next].clone();
+}
+
+/// Idle times Settings steps through before a run pauses itself, in
+/// seconds; 0 is never.
+const IDLE_PAUSE_STEPS_SECS: [u16; 6] = [0, 15, 30, 60, 120, 300];
+
+fn cycle_idle_pause(current: u16, forward: bool) -> u16 {
+    cycle_step(IDLE_PAUSE_STEPS_SECS, current, forward)
+}
+
+fn idle_pause_text(idle_pause_secs: u16, language: Language) -> String {
+    if idle_pause_secs == 0 {
+        i18n::setting_off(language).to_string()
+    } else {
+        format!("{idle_pause_secs} s")
+    }
+}
+
+/// Speed caps Settings steps through, as the shortest tick in milliseconds;
+/// 0 is no cap.
+const SPEED_CAP_STEPS_MS: [u16; 6] = [0, 60, 80, 100, 130, 160];
+
+fn cycle_speed_cap(current: u16, forward: bool) -> u16 {
+    cycle_step(SPEED_CAP_STEPS_MS, current, forward)
+}
+
+/// The next (or previous) of `steps` after `current`, which may be a value
+/// typed into the config between two steps. The first step follows the last.
+fn cycle_step<const N: usize>(steps: [u16; N], current: u16, forward: bool) -> u16 {
+    if forward {
+        steps.into_iter().find(|step| *step > current).unwrap_or(0)
+    } else {
+        steps
+            .into_iter()
+            .rev()
+            .find(|step| *step < current)
+            .unwrap_or(steps[N - 1])
+    }
+}
+
+fn speed_cap_text(speed_cap_ms: u16, language: Language) -> String {
+    if speed_cap_ms == 0 {
+        i18n::setting_off(language).to_string()
+    } else {
+        format!("{speed_cap_ms} ms")
+    }
+}
+
+/// Cell aspects Settings steps through after Auto and Off, in percent.
+const CELL_ASPECT_STEPS_PERCENT: [u16; 11] =
+    [100, 120, 140, 160, 180, 200, 220, 240, 260, 280, 300];
+
+/// Steps Settings -> Axis pacing through Auto, Off, and then each of
+/// `CELL_ASPECT_STEPS_PERCENT` as a configured ratio.
+fn cycle_axis_pace(settings: &mut Settings, forward: bool) {
+    let choices: Vec<(AxisPace, u16)> = [
+        (AxisPace::Auto, settings.cell_aspect_percent),
+        (AxisPace::Off, settings.cell_aspect_percent),
+    ]
+    .into_iter()
+    .chain(CELL_ASPECT_STEPS_PERCENT.map(|percent| (AxisPace::Ratio, percent)))
+    .collect();
+    // A ratio typed into the config counts as the step at or above it.
+    let current = choices
+        .iter()
+        .position(|&(pace, percent)| {
+            pace == settings.axis_pace
+                && (pace != AxisPace::Ratio || percent >= settings.cell_aspect_percent)
+        })
+        .unwrap_or(0);
+    let next = if forward {
+        (current + 1) % choices.len()
+    } else {
+        (current + choices.len() - 1) % choices.len()
+    };
+    (settings.axis_pace, settings.cell_aspect_percent) = choices[next];
+}
+
+fn axis_pace_text(settings: &Settings, language: Language) -> String {
+    match settings.axis_pace {
+        AxisPace::Auto => i18n::axis_pace_auto(language).to_string(),
+        AxisPace::Off => i18n::setting_off(language).to_string(),
+        AxisPace::Ratio => format!(
+            "{}.{}:1",
+            settings.cell_aspect_percent / 100,
+            settings.cell_aspect_percent % 100 / 10
+        ),
+    }
+}
+
+/// Moves `settings.language` to the next (or previous) available language
+/// and lays the screen out in its direction.
+pub(crate) fn cycle_language(
+    renderer: &mut render::Renderer,
+    settings: &mut Settings,
+    forward: bool,
+) {
+    let languages = Language::available();
+    let count = languages.len();
+    let current = settings.language.to_index();
+    settings.language = languages[if forward {
+        (current + 1) % count
+    } else {
+        (current + count - 1) % count
+    }];
+    renderer.set_right_to_left(i18n::is_right_to_left(settings.language));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_rows_list_every_row_in_order() {
+        for (index, row) in SettingsRow::ALL.into_iter().enumerate() {
+            assert_eq!(row.index(), index);
+            assert_eq!(SettingsRow::at(index), row);
+        }
+        assert_eq!(SettingsRow::at(SettingsRow::ALL.len()), SettingsRow::Back);
+
+        let settings = Settings::default();
+        let (labels, values) = settings_rows(&settings, Language::En);
+        assert_eq!(labels.len(), SettingsRow::ALL.len());
+        assert_eq!(labels[SettingsRow::Sound.index()], "Sound");
+        assert_eq!(values[SettingsRow::Sound.index()], "< On >");
+        assert_eq!(
+            values[SettingsRow::PowerUps.index()],
+            format!("{}/{}", PowerUpType::ALL.len(), PowerUpType::ALL.len())
+        );
+        assert_eq!(values[SettingsRow::Back.index()], "");
+    }
+}
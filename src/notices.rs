@@ -0,0 +1,80 @@
+//! Non-fatal errors raised while the terminal UI is up, such as a settings
+//! save that failed. `eprintln!` would print them onto the alternate screen,
+//! where the next frame wipes them, so they queue here instead and the menu
+//! shows each in turn in a banner across the top of the screen.
+
+use std::{
+    collections::VecDeque,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// How long each notice stays up.
+const BANNER_DURATION: Duration = Duration::from_secs(4);
+
+struct Notices {
+    pending: VecDeque<String>,
+    /// When the front notice first went up; `None` until it has been shown.
+    shown_since: Option<Instant>,
+}
+
+static NOTICES: Mutex<Notices> = Mutex::new(Notices {
+    pending: VecDeque::new(),
+    shown_since: None,
+});
+
+/// Queues `message` for the banner. A repeat of the last queued message is
+/// dropped rather than shown twice in a row.
+pub fn report(message: String) {
+    log::warn!("{message}");
+    let mut notices = NOTICES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if notices.pending.back() != Some(&message) {
+        notices.pending.push_back(message);
+    }
+}
+
+/// The notice to show at `now` and when it comes down, retiring any whose
+/// time is up. The clock for a notice starts the first time it is asked for,
+/// so one raised during a run still gets its full time on the menu.
+pub fn current(now: Instant) -> Option<(String, Instant)> {
+    let mut notices = NOTICES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if notices
+        .shown_since
+        .is_some_and(|since| now >= since + BANNER_DURATION)
+    {
+        notices.pending.pop_front();
+        notices.shown_since = None;
+    }
+    let message = notices.pending.front()?.clone();
+    let since = *notices.shown_since.get_or_insert(now);
+    Some((message, since + BANNER_DURATION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notices_are_shown_one_at_a_time_for_the_banner_duration() {
+        let start = Instant::now();
+        assert!(current(start).is_none());
+
+        report("config save failed".to_string());
+        report("config save failed".to_string());
+        report("sync failed".to_string());
+        let later = start + Duration::from_secs(60);
+        assert_eq!(
+            current(later),
+            Some(("config save failed".to_string(), later + BANNER_DURATION))
+        );
+        assert_eq!(
+            current(later + BANNER_DURATION).map(|(message, _)| message),
+            Some("sync failed".to_string())
+        );
+        assert!(current(later + BANNER_DURATION * 2).is_none());
+    }
+}
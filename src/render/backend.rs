@@ -0,0 +1,371 @@
+//! Where presented frames go. `Renderer::present` works out which cells
+//! changed and sends just those through a `Backend`: the terminal itself, a
+//! screen kept in memory for tests and screenshots, or ratatui.
+
+use std::cell::RefCell;
+use std::io::{self, BufWriter, Stdout, Write};
+use std::rc::Rc;
+
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Print, ResetColor},
+    terminal::{self, Clear, ClearType, SetTitle},
+};
+
+use super::screen::{Cell, put_text, rows_text};
+use super::shared::base64;
+use super::style::{Attrs, Color, Style};
+
+/// Saves the window title on xterm's title stack. Terminals without the stack
+/// ignore this and `RESTORE_TITLE`.
+pub const SAVE_TITLE: &str = "\x1b[22;0t";
+/// Puts back the title `SAVE_TITLE` saved.
+pub const RESTORE_TITLE: &str = "\x1b[23;0t";
+
+/// The SGR sequence that sets `style` on top of a reset; empty for the
+/// default style.
+pub(super) fn sgr(style: Style) -> String {
+    const ATTRS: [(Attrs, &str); 4] = [
+        (Attrs::BOLD, "1"),
+        (Attrs::DIM, "2"),
+        (Attrs::UNDERLINE, "4"),
+        (Attrs::REVERSE, "7"),
+    ];
+    let mut params: Vec<String> = ATTRS
+        .iter()
+        .filter(|(attrs, _)| style.attrs.contains(*attrs))
+        .map(|(_, code)| (*code).to_string())
+        .collect();
+    let color = |color: Color, base: u8, extended: u8| match color {
+        Color::Named(index @ 0..8) => (base + index).to_string(),
+        Color::Named(index) => (base + 60 + index % 8).to_string(),
+        Color::Rgb(r, g, b) => format!("{extended};2;{r};{g};{b}"),
+    };
+    params.extend(style.fg.map(|fg| color(fg, 30, 38)));
+    params.extend(style.bg.map(|bg| color(bg, 40, 48)));
+    if params.is_empty() {
+        String::new()
+    } else {
+        format!("\x1b[{}m", params.join(";"))
+    }
+}
+
+/// The OSC 52 sequence that puts `text` on the clipboard.
+pub(super) fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+pub trait Backend {
+    /// Moves the cursor to 0-based column `x` of row `y`.
+    fn move_to(&mut self, x: u16, y: u16);
+    /// Applies `style` to what is printed next.
+    fn set_style(&mut self, style: Style);
+    /// Prints `text` at the cursor and moves the cursor past it.
+    fn print(&mut self, text: &str);
+    /// Blanks the whole screen.
+    fn clear(&mut self);
+    /// The terminal is now `width` columns by `height` rows; the frame that
+    /// follows rewrites every cell of it.
+    fn resized(&mut self, _width: u16, _height: u16) {}
+    /// Takes a finished frame whole, for backends that work out what changed
+    /// themselves. The default returns false, and the cells that changed come
+    /// through `move_to`, `set_style`, and `print` instead.
+    fn present_frame(&mut self, _rows: &[Vec<Cell>]) -> bool {
+        false
+    }
+    /// Ends the frame.
+    fn flush(&mut self);
+    /// Columns and rows.
+    fn size(&self) -> (u16, u16);
+    /// The terminal bell, which stands in for every sound effect.
+    fn bell(&mut self) {}
+    /// Sets the window title, or with `None` puts back the one saved with
+    /// `SAVE_TITLE` and saves it again.
+    fn set_title(&mut self, _title: Option<&str>) {}
+    /// Sends `sequence`, an image in a terminal graphics protocol, with the
+    /// cursor at 0-based column `x` of row `y`. Backends that cannot show
+    /// images ignore it.
+    fn graphics(&mut self, _x: u16, _y: u16, _sequence: &str) {}
+    /// Puts `text` on the clipboard with OSC 52, which also works over SSH.
+    /// Terminals that do not allow it ignore the sequence.
+    fn copy_to_clipboard(&mut self, _text: &str) {}
+}
+
+/// Writes escape sequences to `out`, normally the terminal.
+pub struct CrosstermBackend<W: Write> {
+    out: W,
+}
+
+impl CrosstermBackend<BufWriter<Stdout>> {
+    pub fn stdout() -> Self {
+        Self::new(BufWriter::new(io::stdout()))
+    }
+}
+
+impl<W: Write> CrosstermBackend<W> {
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+}
+
+impl<W: Write> Backend for CrosstermBackend<W> {
+    fn move_to(&mut self, x: u16, y: u16) {
+        let _ = queue!(self.out, MoveTo(x, y));
+    }
+
+    fn set_style(&mut self, style: Style) {
+        let _ = queue!(self.out, ResetColor, Print(sgr(style)));
+    }
+
+    fn print(&mut self, text: &str) {
+        let _ = queue!(self.out, Print(text));
+    }
+
+    fn clear(&mut self) {
+        let _ = queue!(self.out, ResetColor, Clear(ClearType::All));
+    }
+
+    fn flush(&mut self) {
+        let _ = self.out.flush();
+    }
+
+    fn size(&self) -> (u16, u16) {
+        terminal::size().unwrap_or((80, 24))
+    }
+
+    fn bell(&mut self) {
+        let _ = queue!(self.out, Print('\x07'));
+        let _ = self.out.flush();
+    }
+
+    fn set_title(&mut self, title: Option<&str>) {
+        let _ = match title {
+            Some(title) => queue!(self.out, SetTitle(title)),
+            None => queue!(self.out, Print(RESTORE_TITLE), Print(SAVE_TITLE)),
+        };
+    }
+
+    fn graphics(&mut self, x: u16, y: u16, sequence: &str) {
+        let _ = queue!(self.out, MoveTo(x, y), Print(sequence));
+    }
+
+    fn copy_to_clipboard(&mut self, text: &str) {
+        let _ = queue!(self.out, Print(osc52(text)));
+        let _ = self.out.flush();
+    }
+}
+
+/// What the sequences sent so far would show on a terminal of `size`.
+pub(super) struct Grid {
+    size: (u16, u16),
+    rows: Vec<Vec<Cell>>,
+    cursor: (u16, u16),
+    style: Style,
+}
+
+impl Grid {
+    pub(super) fn new(size: (u16, u16)) -> Self {
+        Self {
+            size,
+            rows: Vec::new(),
+            cursor: (0, 0),
+            style: Style::PLAIN,
+        }
+    }
+
+    pub(super) fn rows(&self) -> &[Vec<Cell>] {
+        &self.rows
+    }
+
+    pub(super) fn move_to(&mut self, x: u16, y: u16) {
+        self.cursor = (x, y);
+    }
+
+    pub(super) fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Text past the right edge or below the bottom is cut off.
+    pub(super) fn print(&mut self, text: &str) {
+        let (x, y) = self.cursor;
+        let (width, height) = self.size;
+        let written = if y < height {
+            let y = usize::from(y);
+            if self.rows.len() <= y {
+                self.rows.resize_with(y + 1, Vec::new);
+            }
+            let row = &mut self.rows[y];
+            let written = put_text(row, usize::from(x), text, self.style);
+            row.truncate(usize::from(width));
+            written
+        } else {
+            put_text(&mut Vec::new(), usize::from(x), text, self.style)
+        };
+        self.cursor.0 = x.saturating_add(u16::try_from(written).unwrap_or(u16::MAX));
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.rows.clear();
+    }
+
+    /// Takes the new size; what no longer fits is gone.
+    pub(super) fn resize(&mut self, size: (u16, u16)) {
+        self.size = size;
+        self.rows.truncate(usize::from(size.1));
+        for row in &mut self.rows {
+            row.truncate(usize::from(size.0));
+        }
+    }
+}
+
+struct MemoryScreen {
+    grid: Grid,
+    /// Everything sent, as `CrosstermBackend` would have written it.
+    transcript: CrosstermBackend<Vec<u8>>,
+}
+
+/// A screen kept in memory, for tests and screenshots. Clones share the
+/// screen, so one can go into a `Renderer` while another reads it back.
+#[derive(Clone)]
+pub struct MemoryBackend(Rc<RefCell<MemoryScreen>>);
+
+impl MemoryBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self(Rc::new(RefCell::new(MemoryScreen {
+            grid: Grid::new((width, height)),
+            transcript: CrosstermBackend::new(Vec::new()),
+        })))
+    }
+
+    /// The screen, one line per row with trailing blanks dropped, and with
+    /// each style as its SGR sequence when `ansi` is set.
+    pub fn contents(&self, ansi: bool) -> String {
+        rows_text(self.0.borrow().grid.rows(), ansi)
+    }
+
+    /// The escape sequences sent since the last call.
+    #[cfg(test)]
+    pub fn take_transcript(&self) -> String {
+        let bytes = std::mem::take(&mut self.0.borrow_mut().transcript.out);
+        String::from_utf8(bytes).expect("the renderer only sends UTF-8")
+    }
+}
+
+impl Backend for MemoryBackend {
+    fn move_to(&mut self, x: u16, y: u16) {
+        let mut screen = self.0.borrow_mut();
+        screen.grid.move_to(x, y);
+        screen.transcript.move_to(x, y);
+    }
+
+    fn set_style(&mut self, style: Style) {
+        let mut screen = self.0.borrow_mut();
+        screen.grid.set_style(style);
+        screen.transcript.set_style(style);
+    }
+
+    fn print(&mut self, text: &str) {
+        let mut screen = self.0.borrow_mut();
+        screen.grid.print(text);
+        screen.transcript.print(text);
+    }
+
+    fn clear(&mut self) {
+        let mut screen = self.0.borrow_mut();
+        screen.grid.clear();
+        screen.transcript.clear();
+    }
+
+    fn resized(&mut self, width: u16, height: u16) {
+        self.0.borrow_mut().grid.resize((width, height));
+    }
+
+    fn flush(&mut self) {}
+
+    fn size(&self) -> (u16, u16) {
+        self.0.borrow().grid.size
+    }
+
+    fn bell(&mut self) {
+        self.0.borrow_mut().transcript.bell();
+    }
+
+    fn set_title(&mut self, title: Option<&str>) {
+        self.0.borrow_mut().transcript.set_title(title);
+    }
+
+    fn graphics(&mut self, x: u16, y: u16, sequence: &str) {
+        self.0.borrow_mut().transcript.graphics(x, y, sequence);
+    }
+
+    fn copy_to_clipboard(&mut self, text: &str) {
+        self.0.borrow_mut().transcript.copy_to_clipboard(text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_backend_shows_what_a_terminal_would() {
+        let memory = MemoryBackend::new(8, 3);
+        let mut backend = memory.clone();
+        backend.move_to(1, 0);
+        backend.set_style(Style::fg(Color::BRIGHT_GREEN));
+        backend.print("日本");
+        backend.set_style(Style::PLAIN);
+        backend.print("x ");
+        backend.move_to(0, 1);
+        backend.set_style(Style::PLAIN.on(Color::RED));
+        backend.print("  ");
+        backend.move_to(6, 2);
+        backend.set_style(Style::PLAIN);
+        backend.print("abcd");
+        backend.move_to(0, 3);
+        backend.print("off screen");
+
+        assert_eq!(memory.contents(false), " 日本x\n\n      ab\n");
+        assert_eq!(
+            memory.contents(true),
+            " \x1b[0m\x1b[92m日本\x1b[0mx\n\x1b[0m\x1b[41m  \x1b[0m\n      ab\n"
+        );
+        assert!(
+            memory
+                .take_transcript()
+                .starts_with("\x1b[1;2H\x1b[0m\x1b[92m日本\x1b[0mx ")
+        );
+
+        backend.clear();
+        assert_eq!(memory.contents(false), "");
+        assert_eq!(memory.take_transcript(), "\x1b[0m\x1b[2J");
+
+        backend.copy_to_clipboard("hi");
+        assert_eq!(memory.take_transcript(), "\x1b]52;c;aGk=\x07");
+    }
+
+    #[test]
+    fn styles_become_one_sgr_sequence() {
+        assert_eq!(sgr(Style::PLAIN), "");
+        assert_eq!(sgr(Style::fg(Color::WHITE).dim()), "\x1b[2;37m");
+        assert_eq!(
+            sgr(Style::fg(Color::BRIGHT_WHITE).on(Color::RED).bold()),
+            "\x1b[1;97;41m"
+        );
+        assert_eq!(
+            sgr(Style::fg(Color::Rgb(255, 255, 255))
+                .on(Color::Rgb(89, 138, 207))
+                .bold()),
+            "\x1b[1;38;2;255;255;255;48;2;89;138;207m"
+        );
+        assert_eq!(
+            sgr(Style::PLAIN.bold().underlined().reversed()),
+            "\x1b[1;4;7m"
+        );
+        assert_eq!(
+            sgr(Style::fg(Color::BLACK).on(Color::BRIGHT_WHITE)),
+            "\x1b[30;107m"
+        );
+    }
+}
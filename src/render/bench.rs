@@ -0,0 +1,146 @@
+//! `bench-render`: draws synthetic frames into a byte-counting sink, so
+//! rendering speed and output size can be tracked without a terminal.
+//!
+//! The workload is a worst case on purpose. A rainbow snake fills two thirds
+//! of the board in a serpentine and slides one cell per frame, which moves
+//! every body color along and changes nearly every board cell each frame.
+
+use std::cell::Cell;
+use std::io::{self, Write};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use super::{CrosstermBackend, Renderer, Theme, gameplay};
+use crate::core::Game;
+use crate::layout::{self, Hud};
+use crate::utils::{self, Difficulty, Direction, Language, Position};
+
+/// Terminal size the frames are laid out for.
+const BENCH_TERM_SIZE: (u16, u16) = (120, 40);
+
+pub struct BenchReport {
+    pub frames: u32,
+    pub elapsed: Duration,
+    pub bytes: u64,
+}
+
+impl BenchReport {
+    pub fn frames_per_sec(&self) -> f64 {
+        f64::from(self.frames) / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    pub fn bytes_per_frame(&self) -> u64 {
+        self.bytes / u64::from(self.frames.max(1))
+    }
+}
+
+/// Throws output away, counting the bytes.
+#[derive(Clone, Default)]
+struct ByteCount(Rc<Cell<u64>>);
+
+impl Write for ByteCount {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.set(self.0.get() + buf.len() as u64);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Every interior cell of the board, row by row, alternating direction so
+/// consecutive cells are always neighbors.
+fn serpentine(width: u16, height: u16) -> Vec<Position> {
+    (1..height.saturating_sub(1))
+        .flat_map(|y| {
+            let row: Vec<u16> = (1..width.saturating_sub(1)).collect();
+            let row: Vec<u16> = if y % 2 == 1 {
+                row
+            } else {
+                row.into_iter().rev().collect()
+            };
+            row.into_iter().map(move |x| Position { x, y })
+        })
+        .collect()
+}
+
+fn heading(from: Position, to: Position) -> Direction {
+    if to.x > from.x {
+        Direction::Right
+    } else if to.x < from.x {
+        Direction::Left
+    } else if to.y > from.y {
+        Direction::Down
+    } else {
+        Direction::Up
+    }
+}
+
+/// Renders `frames` frames and measures the time and output they took.
+pub fn bench_render(frames: u32) -> BenchReport {
+    let (term_width, term_height) = BENCH_TERM_SIZE;
+    let layout = layout::compute_layout(
+        term_width,
+        term_height,
+        utils::WIDTH,
+        utils::HEIGHT,
+        false,
+        Hud::default(),
+        Language::En,
+    )
+    .expect("the bench terminal fits the board");
+    let mut game = Game::new_seeded(Difficulty::Medium, utils::WIDTH, utils::HEIGHT, 0, 1);
+    game.power_up = None;
+    let path = serpentine(game.width, game.height);
+    let length = path.len() * 2 / 3;
+    let positions = path.len() - length;
+
+    let bytes = ByteCount::default();
+    let mut renderer = Renderer::with_backend(Box::new(CrosstermBackend::new(bytes.clone())));
+    renderer.set_theme(Theme::RAINBOW);
+    let started = Instant::now();
+    for frame in 0..frames {
+        let offset = frame as usize % positions;
+        let body: Vec<Position> = path[offset..offset + length]
+            .iter()
+            .rev()
+            .copied()
+            .collect();
+        game.dirty_positions
+            .extend(game.snake.body().iter().copied());
+        game.snake.set_body(body);
+        game.snake.direction = heading(game.snake.body()[1], game.snake.body()[0]);
+        game.food = path[(offset + length) % path.len()];
+        gameplay::draw(&mut renderer, &mut game, &layout, Language::En);
+    }
+    BenchReport {
+        frames,
+        elapsed: started.elapsed(),
+        bytes: bytes.0.get(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bench_frames_write_most_of_the_board() {
+        let path = serpentine(6, 5);
+        assert_eq!(path.len(), 12);
+        assert!(
+            path.windows(2)
+                .all(|pair| pair[0].x.abs_diff(pair[1].x) + pair[0].y.abs_diff(pair[1].y) == 1)
+        );
+
+        let _guard = super::super::render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let report = bench_render(3);
+        assert_eq!(report.frames, 3);
+        // Each frame redraws hundreds of snake cells, each several bytes long.
+        let board_cells = u64::from(utils::WIDTH - 2) * u64::from(utils::HEIGHT - 2);
+        assert!(report.bytes_per_frame() > board_cells);
+    }
+}
@@ -0,0 +1,148 @@
+//! Result cards: a finished run as a few lines of text and an emoji map of
+//! the final board, to paste into a chat the way Wordle results are shared.
+
+use crate::core::Game;
+use crate::i18n;
+use crate::utils::{Language, Position};
+
+use super::backend::sgr;
+use super::hud::format_play_time;
+use super::style::{Color, Style};
+
+/// Widest the emoji map gets, in emoji. Larger boards are scaled down, each
+/// emoji standing for a square block of cells.
+const MAP_COLUMNS: u16 = 12;
+
+const TITLE_STYLE: Style = Style::fg(Color::BRIGHT_GREEN).bold();
+const SCORE_STYLE: Style = Style::fg(Color::BRIGHT_YELLOW).bold();
+const RESET: &str = "\x1b[0m";
+
+/// What one emoji of the map shows, most important first.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Mark {
+    Head,
+    Food,
+    PowerUp,
+    Body,
+    Empty,
+}
+
+impl Mark {
+    fn emoji(self) -> &'static str {
+        match self {
+            Mark::Head => "🟨",
+            Mark::Food => "🟥",
+            Mark::PowerUp => "🟦",
+            Mark::Body => "🟩",
+            Mark::Empty => "⬛",
+        }
+    }
+}
+
+/// The result card for `game` on `date`, with the title and score in color
+/// when `ansi` is set.
+pub fn result_card(game: &Game, language: Language, date: &str, ansi: bool) -> String {
+    let styled = |text: String, style: Style| {
+        if ansi {
+            format!("{}{text}{RESET}", sgr(style))
+        } else {
+            text
+        }
+    };
+    let mut card = vec![
+        styled(
+            format!(
+                "🐍 RUSTNAKE · {}",
+                i18n::difficulty_label(language, game.difficulty)
+            ),
+            TITLE_STYLE,
+        ),
+        format!(
+            "{} {} · {} {} · {} {}",
+            i18n::status_score_label(language),
+            styled(game.score.to_string(), SCORE_STYLE),
+            i18n::text_length_label(language),
+            game.snake.body().len(),
+            i18n::summary_time_label(language),
+            format_play_time(game.stats.play_time)
+        ),
+        date.to_string(),
+        String::new(),
+    ];
+    card.extend(board_map(game));
+    card.push(String::new());
+    card.join("\n")
+}
+
+/// The final board as rows of emoji.
+fn board_map(game: &Game) -> Vec<String> {
+    let columns = game.width.saturating_sub(2);
+    let rows = game.height.saturating_sub(2);
+    let block = columns.div_ceil(MAP_COLUMNS).max(1);
+    let mark_at = |pos: Position| {
+        if pos == game.snake.head_position() {
+            Mark::Head
+        } else if pos == game.food {
+            Mark::Food
+        } else if game
+            .power_up
+            .as_ref()
+            .is_some_and(|power_up| power_up.position == pos)
+        {
+            Mark::PowerUp
+        } else if game.snake.overlaps_with(pos) {
+            Mark::Body
+        } else {
+            Mark::Empty
+        }
+    };
+    (0..rows.div_ceil(block))
+        .map(|map_y| {
+            (0..columns.div_ceil(block))
+                .map(|map_x| {
+                    let cells = (0..block * block).map(|index| Position {
+                        x: 2 + map_x * block + index % block,
+                        y: 2 + map_y * block + index / block,
+                    });
+                    cells
+                        .filter(|pos| pos.x < game.width && pos.y < game.height)
+                        .map(mark_at)
+                        .min()
+                        .unwrap_or(Mark::Empty)
+                        .emoji()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Difficulty;
+    use std::time::Duration;
+
+    #[test]
+    fn card_sums_up_the_run_over_a_scaled_down_map() {
+        let mut game = Game::new_seeded(Difficulty::Hard, 40, 20, 0, 7);
+        game.score = 430;
+        game.stats.play_time = Duration::from_secs(192);
+        game.snake
+            .set_body(vec![Position { x: 2, y: 2 }, Position { x: 6, y: 2 }]);
+        game.food = Position { x: 39, y: 19 };
+        game.power_up = None;
+
+        let card = result_card(&game, Language::En, "2026-10-16", false);
+        let lines: Vec<&str> = card.lines().collect();
+        assert_eq!(lines[0], "🐍 RUSTNAKE · Hard");
+        assert_eq!(lines[1], "Score 430 · Length 2 · Time 3:12");
+        assert_eq!(lines[2], "2026-10-16");
+        // 38x18 cells in blocks of 4.
+        assert_eq!(lines.len(), 4 + 5);
+        assert_eq!(lines[4], format!("🟨🟩{}", "⬛".repeat(8)));
+        assert_eq!(lines[8], format!("{}🟥", "⬛".repeat(9)));
+
+        let ansi = result_card(&game, Language::En, "2026-10-16", true);
+        assert!(ansi.contains("\x1b[1;93m430\x1b[0m"));
+    }
+}
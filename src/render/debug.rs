@@ -0,0 +1,152 @@
+//! F3 debug overlay: frame and tick rates, how long the last frame took to
+//! present and how many cells it changed, and the state of the run, in a
+//! panel in the top-left corner drawn over everything else.
+
+use std::time::{Duration, Instant};
+
+/// How long frames and ticks are counted before the rates are updated.
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// What the game loop knows that the renderer does not.
+#[derive(Clone, Copy)]
+pub struct DebugRun {
+    pub ticks: u32,
+    /// Turns queued for the coming ticks.
+    pub queue_depth: usize,
+    pub seed: u64,
+}
+
+pub(crate) struct DebugOverlay {
+    window_start: Instant,
+    frames_in_window: u32,
+    /// Tick count when the window started, once a run has been seen.
+    ticks_at_window_start: Option<u32>,
+    fps: f64,
+    tick_rate: f64,
+    frame_time: Duration,
+    dirty_cells: usize,
+    run: Option<DebugRun>,
+}
+
+impl DebugOverlay {
+    pub(crate) fn new(now: Instant) -> Self {
+        Self {
+            window_start: now,
+            frames_in_window: 0,
+            ticks_at_window_start: None,
+            fps: 0.0,
+            tick_rate: 0.0,
+            frame_time: Duration::ZERO,
+            dirty_cells: 0,
+            run: None,
+        }
+    }
+
+    pub(crate) fn set_run(&mut self, run: Option<DebugRun>) {
+        if run.is_none() {
+            self.ticks_at_window_start = None;
+            self.tick_rate = 0.0;
+        }
+        self.run = run;
+    }
+
+    /// Counts a presented frame that took `frame_time` and changed
+    /// `dirty_cells` cells, and updates the rates once a window is full.
+    pub(crate) fn frame_presented(
+        &mut self,
+        now: Instant,
+        frame_time: Duration,
+        dirty_cells: usize,
+    ) {
+        self.frame_time = frame_time;
+        self.dirty_cells = dirty_cells;
+        self.frames_in_window += 1;
+        let ticks = self.run.map(|run| run.ticks);
+        let elapsed = now.saturating_duration_since(self.window_start);
+        if elapsed < RATE_WINDOW {
+            self.ticks_at_window_start = self.ticks_at_window_start.or(ticks);
+            return;
+        }
+        let seconds = elapsed.as_secs_f64();
+        self.fps = f64::from(self.frames_in_window) / seconds;
+        self.tick_rate = match (self.ticks_at_window_start, ticks) {
+            // A new run starts its count over.
+            (Some(start), Some(ticks)) => f64::from(ticks.saturating_sub(start)) / seconds,
+            _ => 0.0,
+        };
+        self.window_start = now;
+        self.frames_in_window = 0;
+        self.ticks_at_window_start = ticks;
+    }
+
+    pub(crate) fn lines(&self) -> Vec<String> {
+        let (ticks, queue, seed) = match self.run {
+            Some(run) => (
+                format!("{:.1}/s", self.tick_rate),
+                run.queue_depth.to_string(),
+                run.seed.to_string(),
+            ),
+            None => ("-".to_string(), "-".to_string(), "-".to_string()),
+        };
+        [
+            ("fps", format!("{:.1}", self.fps)),
+            ("ticks", ticks),
+            (
+                "frame",
+                format!("{:.2}ms", self.frame_time.as_secs_f64() * 1000.0),
+            ),
+            ("dirty", self.dirty_cells.to_string()),
+            ("queue", queue),
+            ("seed", seed),
+        ]
+        .into_iter()
+        .map(|(label, value)| format!(" {label:<6}{value:>20} "))
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rates_are_measured_over_a_window() {
+        let start = Instant::now();
+        let mut overlay = DebugOverlay::new(start);
+        let run = |ticks| {
+            Some(DebugRun {
+                ticks,
+                queue_depth: 1,
+                seed: 42,
+            })
+        };
+        overlay.set_run(run(0));
+        for frame in 1..=30 {
+            overlay.set_run(run(frame / 3));
+            overlay.frame_presented(
+                start + Duration::from_millis(u64::from(frame) * 20),
+                Duration::from_micros(250),
+                7,
+            );
+        }
+        overlay.set_run(run(20));
+        overlay.frame_presented(start + RATE_WINDOW, Duration::from_micros(250), 7);
+
+        assert_eq!(overlay.fps, 31.0);
+        assert_eq!(overlay.tick_rate, 20.0);
+        assert_eq!(
+            overlay.lines(),
+            [
+                " fps                   31.0 ",
+                " ticks               20.0/s ",
+                " frame               0.25ms ",
+                " dirty                    7 ",
+                " queue                    1 ",
+                " seed                    42 ",
+            ]
+        );
+
+        overlay.set_run(None);
+        assert_eq!(overlay.lines()[1], " ticks                    - ");
+    }
+}
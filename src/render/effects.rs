@@ -0,0 +1,196 @@
+//! Short-lived decorations drawn on top of the board: a sparkle around food
+//! that was just eaten and a "+10" floater for every score gain. They play
+//! out on the clock, not per frame, so drawing more often does not speed
+//! them up.
+
+use crate::core::Game;
+use crate::utils::Position;
+use std::time::{Duration, Instant};
+
+use super::theme::Glyphs;
+
+const ORTHOGONAL: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const DIAGONAL: [(i32, i32); 4] = [(-1, -1), (1, -1), (1, 1), (-1, 1)];
+
+/// Surrounding cells for each frame of a sparkle, first to last; the glyphs
+/// are `Glyphs::sparkles`.
+const SPARKLE_STAGES: [[(i32, i32); 4]; 3] = [ORTHOGONAL, DIAGONAL, ORTHOGONAL];
+
+/// Stages a score floater goes through, rising one row per stage.
+pub(crate) const FLOATER_FRAMES: usize = 4;
+/// How long each stage of a sparkle or floater stays on screen.
+const STAGE_INTERVAL: Duration = Duration::from_millis(90);
+
+struct Sparkle {
+    center: Position,
+    started: Instant,
+}
+
+struct Floater {
+    origin: Position,
+    text: String,
+    started: Instant,
+}
+
+/// The stage an effect that `started` is at by `now`.
+fn stage_at(started: Instant, now: Instant) -> usize {
+    let elapsed = now.saturating_duration_since(started);
+    usize::try_from(elapsed.as_millis() / STAGE_INTERVAL.as_millis()).unwrap_or(usize::MAX)
+}
+
+/// What the previous frame showed, to spot what changed since.
+#[derive(Clone, Copy)]
+struct Seen {
+    food: Position,
+    food_eaten: u32,
+    score: u32,
+    ticks: u32,
+}
+
+#[derive(Default)]
+pub(crate) struct Effects {
+    sparkles: Vec<Sparkle>,
+    floaters: Vec<Floater>,
+    last_seen: Option<Seen>,
+}
+
+/// One frame of a score floater.
+pub(crate) struct FloaterFrame {
+    /// Board cell the text is centered on.
+    pub(crate) at: Position,
+    pub(crate) text: String,
+    /// 0 at first, up to `FLOATER_FRAMES - 1`.
+    pub(crate) stage: usize,
+}
+
+impl Effects {
+    /// Starts a sparkle where the food was whenever the eaten count goes up,
+    /// and a floater for every score gain.
+    pub(crate) fn track(&mut self, game: &Game, now: Instant) {
+        let seen = Seen {
+            food: game.food,
+            food_eaten: game.stats.food_eaten,
+            score: game.score,
+            ticks: game.stats.ticks,
+        };
+        let Some(last) = self.last_seen.replace(seen) else {
+            return;
+        };
+        // Lower counts mean a new run; its predecessor's effects are stale.
+        // The score alone can drop within a run, since boosting costs points.
+        if seen.food_eaten < last.food_eaten || seen.ticks < last.ticks {
+            self.sparkles.clear();
+            self.floaters.clear();
+            return;
+        }
+
+        let eaten_at = (seen.food_eaten > last.food_eaten).then_some(last.food);
+        if let Some(center) = eaten_at {
+            self.sparkles.push(Sparkle {
+                center,
+                started: now,
+            });
+        }
+        if seen.score > last.score {
+            self.floaters.push(Floater {
+                // Points without food come from a power-up under the head.
+                origin: eaten_at.unwrap_or_else(|| game.snake.head_position()),
+                text: format!("+{}", seen.score - last.score),
+                started: now,
+            });
+        }
+    }
+
+    /// Cells and glyphs of the sparkles in a frame drawn at `now`. Finished
+    /// sparkles are dropped.
+    pub(crate) fn sparkle_frame(
+        &mut self,
+        glyphs: &Glyphs,
+        now: Instant,
+    ) -> Vec<(Position, &'static str)> {
+        self.sparkles
+            .retain(|sparkle| stage_at(sparkle.started, now) < SPARKLE_STAGES.len());
+        let mut cells = Vec::new();
+        for sparkle in &self.sparkles {
+            let stage = stage_at(sparkle.started, now);
+            let glyph = glyphs.sparkles[stage];
+            cells.extend(SPARKLE_STAGES[stage].iter().filter_map(|(dx, dy)| {
+                let x = u16::try_from(i32::from(sparkle.center.x) + dx).ok()?;
+                let y = u16::try_from(i32::from(sparkle.center.y) + dy).ok()?;
+                Some((Position { x, y }, glyph))
+            }));
+        }
+        cells
+    }
+
+    /// The score floaters in a frame drawn at `now`, each a row higher per
+    /// stage. Finished floaters are dropped.
+    pub(crate) fn floater_frame(&mut self, now: Instant) -> Vec<FloaterFrame> {
+        self.floaters
+            .retain(|floater| stage_at(floater.started, now) < FLOATER_FRAMES);
+        self.floaters
+            .iter()
+            .filter_map(|floater| {
+                let stage = stage_at(floater.started, now);
+                let y = floater.origin.y.checked_sub(1 + stage as u16)?;
+                Some(FloaterFrame {
+                    at: Position {
+                        x: floater.origin.x,
+                        y,
+                    },
+                    text: floater.text.clone(),
+                    stage,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Difficulty;
+
+    #[test]
+    fn eating_food_sparkles_and_floats_the_points() {
+        let mut game = Game::new_seeded(Difficulty::Easy, 20, 12, 0, 3);
+        let eaten_at = Position { x: 5, y: 5 };
+        game.food = eaten_at;
+        let start = Instant::now();
+        let stage = |index: u32| start + STAGE_INTERVAL * index + Duration::from_millis(5);
+        let mut effects = Effects::default();
+        effects.track(&game, start);
+        assert!(effects.sparkle_frame(&Glyphs::FANCY, start).is_empty());
+        assert!(effects.floater_frame(start).is_empty());
+
+        game.food = Position { x: 9, y: 3 };
+        game.stats.food_eaten = 1;
+        game.score = 10;
+        effects.track(&game, start);
+        // Frames drawn without anything happening in between repeat the stage.
+        assert_eq!(
+            effects.sparkle_frame(&Glyphs::FANCY, start),
+            effects.sparkle_frame(&Glyphs::FANCY, stage(0))
+        );
+        let sparkles: Vec<_> = (0..4)
+            .map(|index| effects.sparkle_frame(&Glyphs::FANCY, stage(index)))
+            .collect();
+        assert!(sparkles[0].contains(&(Position { x: 5, y: 4 }, "✦")));
+        assert!(sparkles[1].contains(&(Position { x: 4, y: 4 }, "✧")));
+        assert!(sparkles[2].contains(&(Position { x: 6, y: 5 }, "·")));
+        assert!(sparkles[3].is_empty());
+
+        let rows: Vec<Vec<(u16, String)>> = (0..=FLOATER_FRAMES as u32)
+            .map(|index| {
+                effects
+                    .floater_frame(stage(index))
+                    .into_iter()
+                    .map(|frame| (frame.at.y, frame.text))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(rows[0], [(4, "+10".to_string())]);
+        assert_eq!(rows[3], [(1, "+10".to_string())]);
+        assert!(rows[FLOATER_FRAMES].is_empty());
+    }
+}
@@ -1,70 +1,328 @@
 use crate::core::Game;
 use crate::i18n;
 use crate::layout::{Layout, SizeCheck};
-use crate::utils::Language;
-use std::io::Write;
+use crate::utils::{BoardPattern, Direction, Language, Position, PowerUpType};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 
+use super::Renderer;
 use super::hud;
 use super::menu;
-use super::shared::{ANSI_RESET, STYLE_MENU_BORDER, center_start, draw_centered_line};
+use super::minimap;
+use super::pixels::{BoardImage, Protocol, Sprite};
+use super::shared::{center_start, clip_by_display_width, display_width, draw_centered_line};
+use super::sidebar;
+use super::style::Style;
+use super::text::share_result;
+use super::theme::{RAINBOW_BODY, Theme};
 
-fn draw_border(layout: &Layout) {
-    let inner_width = layout.map_width.saturating_sub(2) as usize;
-    let top = format!("┌{}┐", "─".repeat(inner_width));
-    let bottom = format!("└{}┘", "─".repeat(inner_width));
-
-    print!(
-        "{}\x1b[{};{}H{}{}",
-        STYLE_MENU_BORDER, layout.origin_y, layout.origin_x, top, ANSI_RESET
+fn draw_border(renderer: &mut Renderer, layout: &Layout) {
+    let theme = renderer.theme();
+    let horizontal = "─".repeat(layout.map_width.saturating_sub(2) as usize);
+    renderer.put(
+        layout.origin_y,
+        layout.origin_x,
+        &format!("┌{horizontal}┐"),
+        theme.border,
     );
-    print!(
-        "{}\x1b[{};{}H{}{}",
-        STYLE_MENU_BORDER,
+    renderer.put(
         layout.map_bottom(),
         layout.origin_x,
-        bottom,
-        ANSI_RESET
+        &format!("└{horizontal}┘"),
+        theme.border,
     );
 
     for y in (layout.origin_y + 1)..layout.map_bottom() {
-        print!(
-            "{}\x1b[{};{}H│{}",
-            STYLE_MENU_BORDER, y, layout.origin_x, ANSI_RESET
-        );
-        print!(
-            "{}\x1b[{};{}H│{}",
-            STYLE_MENU_BORDER,
-            y,
-            layout.map_right(),
-            ANSI_RESET
-        );
+        renderer.put(y, layout.origin_x, "│", theme.border);
+        renderer.put(y, layout.map_right(), "│", theme.border);
+    }
+}
+
+/// Puts the border back beside each of `positions` that lies along the edge
+/// of the board, where a glyph wider than its cell may have spilled onto it.
+/// The rest of the border stays as `draw_static_frame` left it.
+fn repair_border<'a>(
+    renderer: &mut Renderer,
+    game: &Game,
+    layout: &Layout,
+    positions: impl IntoIterator<Item = &'a Position>,
+) {
+    let theme = renderer.theme();
+    let horizontal = "─".repeat(usize::from(layout.cell_width));
+    let inside = |pos: &&Position| {
+        (2..game.width).contains(&pos.x) && (2..game.height).contains(&pos.y) && layout.shows(**pos)
+    };
+    // The cells along the border, which a camera may have scrolled away
+    // from the edge of the board.
+    let (left, top) = (2 + layout.scroll_x, 2 + layout.scroll_y);
+    let right = (left + layout.view_columns()).min(game.width) - 1;
+    let bottom = (top + layout.view_rows()).min(game.height) - 1;
+    for pos in positions.into_iter().filter(inside) {
+        let (x, y) = layout.board_to_screen(pos.x, pos.y);
+        if pos.x <= left {
+            renderer.put(y, layout.origin_x, "│", theme.border);
+        }
+        if pos.x >= right {
+            renderer.put(y, layout.map_right(), "│", theme.border);
+        }
+        if pos.y <= top {
+            renderer.put(layout.origin_y, x, &horizontal, theme.border);
+        }
+        if pos.y >= bottom {
+            renderer.put(layout.map_bottom(), x, &horizontal, theme.border);
+        }
+    }
+}
+
+/// `symbol` widened to a whole board cell by repeating `filler`.
+fn fill_cell(symbol: &str, filler: char, layout: &Layout) -> String {
+    let mut cell = symbol.to_string();
+    cell.extend(std::iter::repeat_n(
+        filler,
+        usize::from(layout.cell_width.saturating_sub(1)),
+    ));
+    cell
+}
+
+/// The letter a power-up of `kind` is drawn with on the board.
+pub(super) fn power_up_symbol(kind: PowerUpType) -> &'static str {
+    match kind {
+        PowerUpType::SpeedBoost => ">",
+        PowerUpType::SlowDown => "<",
+        PowerUpType::ExtraPoints => "$",
+        PowerUpType::Grow => "+",
+        PowerUpType::Shrink => "-",
+        PowerUpType::Shield => "#",
+        PowerUpType::ScoreMultiplier => "x",
+    }
+}
+
+/// What an empty board cell at `pos` shows: blank, or the theme's board
+/// pattern.
+fn empty_cell(theme: Theme, pos: Position, layout: &Layout) -> String {
+    match theme.board_pattern {
+        BoardPattern::Plain => fill_cell(" ", ' ', layout),
+        BoardPattern::Dots => fill_cell(theme.glyphs.board_dot, ' ', layout),
+        BoardPattern::Checkerboard if (pos.x + pos.y) % 2 == 0 => theme
+            .glyphs
+            .board_shade
+            .repeat(usize::from(layout.cell_width)),
+        BoardPattern::Checkerboard => fill_cell(" ", ' ', layout),
+    }
+}
+
+/// Draws the board pattern over the whole interior.
+fn draw_board_pattern(renderer: &mut Renderer, layout: &Layout) {
+    let theme = renderer.theme();
+    if theme.board_pattern == BoardPattern::Plain {
+        return;
+    }
+    let (left, top) = (2 + layout.scroll_x, 2 + layout.scroll_y);
+    for y in top..top + layout.view_rows() {
+        for x in left..left + layout.view_columns() {
+            let (screen_x, screen_y) = layout.board_to_screen(x, y);
+            let cell = empty_cell(theme, Position { x, y }, layout);
+            renderer.put(screen_y, screen_x, &cell, theme.board);
+        }
+    }
+}
+
+/// Cells between the coordinates of the practice overlay.
+const PRACTICE_MARK_SPACING: u16 = 5;
+
+/// Practice overlay: board coordinates from 0 on the top border and beside
+/// the left one every `PRACTICE_MARK_SPACING` cells, and how far the food is
+/// from the snake's head on the bottom border.
+fn draw_practice_overlay(
+    renderer: &mut Renderer,
+    game: &Game,
+    layout: &Layout,
+    language: Language,
+) {
+    let style = renderer.theme().hint;
+    let marks = |first: u16, count: u16| {
+        (first.next_multiple_of(PRACTICE_MARK_SPACING)..first + count)
+            .step_by(PRACTICE_MARK_SPACING.into())
+    };
+    let columns = layout.view_columns().min(game.width.saturating_sub(2));
+    for column in marks(layout.scroll_x, columns) {
+        let label = column.to_string();
+        let (x, _) = layout.board_to_screen(column + 2, 2 + layout.scroll_y);
+        if x + display_width(&label) <= layout.map_right() {
+            renderer.put(layout.origin_y, x, &label, style);
+        }
+    }
+    let rows = layout.view_rows().min(game.height.saturating_sub(2));
+    for row in marks(layout.scroll_y, rows) {
+        let label = row.to_string();
+        let (_, y) = layout.board_to_screen(2 + layout.scroll_x, row + 2);
+        // Right-aligned so the last digit takes the place of the border.
+        let width = display_width(&label);
+        if layout.origin_x >= width {
+            renderer.put(y, layout.origin_x + 1 - width, &label, style);
+        }
+    }
+
+    let head = game.snake.head_position();
+    let offset = i18n::practice_food_offset(
+        language,
+        i32::from(game.food.x) - i32::from(head.x),
+        i32::from(game.food.y) - i32::from(head.y),
+    );
+    let interior_width = layout.map_width.saturating_sub(2);
+    let label = clip_by_display_width(&format!(" {offset} "), interior_width);
+    let x = layout.origin_x + center_start(interior_width, display_width(&label));
+    renderer.put(layout.map_bottom(), x, &label, style);
+}
+
+/// Draws a snake segment, food, or power-up at board cell `pos` and adds it to
+/// `sprites` for the board image. With `image_only`, as for a kitty image
+/// that text would show over, the cell is left blank.
+fn draw_sprite(
+    renderer: &mut Renderer,
+    layout: &Layout,
+    sprites: &mut Vec<Sprite>,
+    image_only: bool,
+    pos: Position,
+    glyph: &str,
+    style: Style,
+) {
+    if !layout.shows(pos) {
+        return;
+    }
+    let (x, y) = layout.board_to_screen(pos.x, pos.y);
+    if image_only {
+        renderer.put(y, x, &fill_cell(" ", ' ', layout), Style::PLAIN);
+    } else {
+        renderer.put(y, x, glyph, style);
+    }
+    sprites.push(Sprite {
+        x: pos.x.saturating_sub(2),
+        y: pos.y.saturating_sub(2),
+        style,
+    });
+}
+
+/// An interior cell with nothing on it.
+fn cell_is_free(game: &Game, pos: Position) -> bool {
+    (2..game.width).contains(&pos.x)
+        && (2..game.height).contains(&pos.y)
+        && pos != game.food
+        && game
+            .power_up
+            .is_none_or(|power_up| power_up.position != pos)
+        && !game.snake.overlaps_with(pos)
+}
+
+/// Which way a neighboring segment lies from `from`. Neighbors more than one
+/// cell apart met across a wrapped edge, so the direction flips.
+fn neighbor_direction(from: Position, to: Position) -> Option<Direction> {
+    let wrapped = from.x.abs_diff(to.x) > 1 || from.y.abs_diff(to.y) > 1;
+    let direction = match (to.x.cmp(&from.x), to.y.cmp(&from.y)) {
+        (Ordering::Less, Ordering::Equal) => Direction::Left,
+        (Ordering::Greater, Ordering::Equal) => Direction::Right,
+        (Ordering::Equal, Ordering::Less) => Direction::Up,
+        (Ordering::Equal, Ordering::Greater) => Direction::Down,
+        _ => return None,
+    };
+    Some(if wrapped {
+        direction.opposite()
+    } else {
+        direction
+    })
+}
+
+/// Directions toward the segment in front of and behind segment `index`,
+/// looking past copies stacked on the same cell (right after growing).
+fn segment_links(body: &VecDeque<Position>, index: usize) -> [Option<Direction>; 2] {
+    let cell = body[index];
+    let toward = |neighbor: Option<&Position>| {
+        neighbor.and_then(|neighbor| neighbor_direction(cell, *neighbor))
+    };
+    [
+        toward(body.range(..index).rev().find(|segment| **segment != cell)),
+        toward(body.range(index + 1..).find(|segment| **segment != cell)),
+    ]
+}
+
+/// Box-drawing piece that joins a body segment to its neighbors, or
+/// `lone` when it has none to join.
+fn segment_glyph(links: [Option<Direction>; 2], lone: &'static str) -> &'static str {
+    let has = |direction| links.contains(&Some(direction));
+    match (
+        has(Direction::Up),
+        has(Direction::Down),
+        has(Direction::Left),
+        has(Direction::Right),
+    ) {
+        (true, false, true, false) => "┘",
+        (true, false, false, true) => "└",
+        (false, true, true, false) => "┐",
+        (false, true, false, true) => "┌",
+        (true, _, false, false) | (_, true, false, false) => "│",
+        (false, false, true, _) | (false, false, _, true) => "─",
+        _ => lone,
     }
 }
 
-pub fn draw_static_frame(layout: &Layout) {
+pub fn draw_static_frame(renderer: &mut Renderer, layout: &Layout) {
     menu::invalidate_menu_render_caches();
-    print!("\x1b[2J\x1b[H");
-    draw_border(layout);
+    renderer.clear();
+    draw_border(renderer, layout);
+    draw_board_pattern(renderer, layout);
+
+    renderer.present();
+}
+
+/// `draw_static_frame` for `layout` after `previous`. When only the camera
+/// moved, the board is drawn again in place rather than on a cleared
+/// terminal, which would flicker with every scroll; the frame after it
+/// presents the lot.
+pub fn draw_frame(renderer: &mut Renderer, previous: Option<Layout>, layout: &Layout) {
+    let scrolled = previous.is_some_and(|previous| {
+        Layout {
+            scroll_x: layout.scroll_x,
+            scroll_y: layout.scroll_y,
+            ..previous
+        } == *layout
+    });
+    if !scrolled {
+        draw_static_frame(renderer, layout);
+        return;
+    }
+    menu::invalidate_menu_render_caches();
+    renderer.redraw();
+    draw_border(renderer, layout);
+    draw_board_pattern(renderer, layout);
+}
 
-    let _ = std::io::stdout().flush();
+pub fn clear_for_menu_entry(renderer: &mut Renderer) {
+    menu::invalidate_menu_render_caches();
+    renderer.clear();
+    renderer.present();
 }
 
-pub fn clear_for_menu_entry() {
+/// Starts the menus over on a terminal now `width` by `height`: the panel
+/// is laid out again for the new size, and the next menu frame paints over
+/// the old one without a blank screen in between.
+pub fn resize_for_menu(renderer: &mut Renderer, width: u16, height: u16) {
     menu::invalidate_menu_render_caches();
-    print!("\x1b[2J\x1b[H");
-    let _ = std::io::stdout().flush();
+    renderer.resized(width, height);
 }
 
-pub fn draw_size_warning(size_check: SizeCheck, language: Language) {
+pub fn draw_size_warning(renderer: &mut Renderer, size_check: SizeCheck, language: Language) {
     menu::invalidate_menu_render_caches();
-    print!("\x1b[2J\x1b[H");
+    renderer.clear();
     let start_y = center_start(size_check.current_height, 5);
     draw_centered_line(
+        renderer,
         start_y,
         size_check.current_width,
         i18n::small_window_title(language),
     );
     draw_centered_line(
+        renderer,
         start_y + 1,
         size_check.current_width,
         &format!(
@@ -78,74 +336,337 @@ pub fn draw_size_warning(size_check: SizeCheck, language: Language) {
         ),
     );
     draw_centered_line(
+        renderer,
         start_y + 3,
         size_check.current_width,
         i18n::small_window_hint(language),
     );
 
-    let _ = std::io::stdout().flush();
+    renderer.present();
+}
+
+pub fn ring_bell(renderer: &mut Renderer) {
+    renderer.bell();
+}
+
+/// Puts the result of a finished run on the clipboard and says so with a
+/// toast on the next frame.
+pub fn copy_result(renderer: &mut Renderer, game: &Game, language: Language) {
+    renderer.copy_to_clipboard(&share_result(game, language));
+    renderer.toasts().push(
+        i18n::toast_result_copied(language).to_string(),
+        Instant::now(),
+    );
+}
+
+/// Shown by `spectate` until the host starts a run.
+pub fn draw_spectator_waiting(
+    renderer: &mut Renderer,
+    term_width: u16,
+    term_height: u16,
+    language: Language,
+) {
+    menu::invalidate_menu_render_caches();
+    renderer.clear();
+    let start_y = center_start(term_height, 3);
+    draw_centered_line(
+        renderer,
+        start_y,
+        term_width,
+        i18n::spectate_waiting_title(language),
+    );
+    draw_centered_line(
+        renderer,
+        start_y + 2,
+        term_width,
+        i18n::spectate_quit_hint(language),
+    );
+
+    renderer.present();
 }
 
-pub fn draw(game: &mut Game, layout: &Layout, language: Language) {
+/// Frames in which the dead snake flashes, alternating with its normal colors.
+const DEATH_FLASH_FRAMES: u32 = 6;
+/// Frames over which it then dissolves, tail first.
+const DEATH_DISSOLVE_FRAMES: u32 = 12;
+/// `draw_death_frame` shows the game-over panel from this frame on.
+pub const DEATH_ANIMATION_FRAMES: u32 = DEATH_FLASH_FRAMES + DEATH_DISSOLVE_FRAMES;
+pub const DEATH_FRAME_INTERVAL: Duration = Duration::from_millis(60);
+
+/// The death animation frame `elapsed` after the snake died.
+pub fn death_frame(elapsed: Duration) -> u32 {
+    let frame = elapsed.as_millis() / DEATH_FRAME_INTERVAL.as_millis();
+    u32::try_from(frame).map_or(DEATH_ANIMATION_FRAMES, |frame| {
+        frame.min(DEATH_ANIMATION_FRAMES)
+    })
+}
+
+pub fn draw(renderer: &mut Renderer, game: &mut Game, layout: &Layout, language: Language) {
+    draw_board(renderer, game, layout, language, None, None);
+}
+
+/// Like `draw`, for a finished run: the snake flashes and dissolves over
+/// `DEATH_ANIMATION_FRAMES` frames, then the game-over panel appears over the
+/// empty board.
+pub fn draw_death_frame(
+    renderer: &mut Renderer,
+    game: &mut Game,
+    layout: &Layout,
+    language: Language,
+    frame: u32,
+) {
+    draw_board(renderer, game, layout, language, Some(frame), None);
+}
+
+/// Like `draw`, with the quit confirmation over the board; `selected` is 0
+/// for Yes and 1 for No, and `to_menu` asks about leaving for the menu
+/// instead of quitting.
+pub fn draw_quit_confirm(
+    renderer: &mut Renderer,
+    game: &mut Game,
+    layout: &Layout,
+    language: Language,
+    selected: usize,
+    to_menu: bool,
+) {
+    draw_board(
+        renderer,
+        game,
+        layout,
+        language,
+        None,
+        Some((selected, to_menu)),
+    );
+}
+
+fn draw_board(
+    renderer: &mut Renderer,
+    game: &mut Game,
+    layout: &Layout,
+    language: Language,
+    death_frame: Option<u32>,
+    quit_confirm: Option<(usize, bool)>,
+) {
+    let theme = renderer.theme();
     menu::invalidate_menu_render_caches();
-    for pos in &game.dirty_positions {
+    let snake_len = game.snake.body().len();
+    let dissolved = match death_frame {
+        Some(frame) if frame >= DEATH_FLASH_FRAMES => {
+            let progress = (frame - DEATH_FLASH_FRAMES + 1).min(DEATH_DISSOLVE_FRAMES) as usize;
+            (snake_len * progress).div_ceil(DEATH_DISSOLVE_FRAMES as usize)
+        }
+        _ => 0,
+    };
+    let visible = snake_len - dissolved;
+    for pos in game
+        .dirty_positions
+        .iter()
+        .chain(game.snake.body().range(visible..))
+        .filter(|pos| layout.shows(**pos))
+    {
         let (x, y) = layout.board_to_screen(pos.x, pos.y);
-        print!("\x1b[{};{}H ", y, x);
+        renderer.put(y, x, &empty_cell(theme, *pos, layout), theme.board);
+    }
+
+    if renderer.practice_overlay() {
+        // The labels on the border move with the snake and the food.
+        draw_border(renderer, layout);
+        draw_practice_overlay(renderer, game, layout, language);
+    } else {
+        let blanked = game
+            .dirty_positions
+            .iter()
+            .chain(game.snake.body().range(visible..));
+        repair_border(renderer, game, layout, blanked);
     }
 
-    draw_border(layout);
+    // A panel over the board hides the board image, and the sprites are
+    // drawn as glyphs again.
+    let show_game_over_panel =
+        game.game_over && death_frame.is_none_or(|frame| frame >= DEATH_ANIMATION_FRAMES);
+    // The image is of the whole board, which a camera does not show.
+    let board_image = !show_game_over_panel && quit_confirm.is_none() && !layout.scrolls();
+    if !board_image {
+        renderer.set_board_image(None);
+    }
+    let mut sprites = Vec::new();
+    let image_only = board_image && renderer.pixel_protocol() == Some(Protocol::Kitty);
 
-    for (i, pos) in game.snake.body.iter().enumerate() {
+    let dead = death_frame.is_some_and(|frame| frame >= DEATH_FLASH_FRAMES || frame % 2 == 0);
+    for (i, pos) in game.snake.body().iter().enumerate().take(visible) {
         // Head is bright green, body segments get darker toward the tail.
-        let color = if i == 0 {
-            "\x1b[92m"
-        } else if i < game.snake.body.len() / 3 {
-            "\x1b[32m"
-        } else if i < game.snake.body.len() * 2 / 3 {
-            "\x1b[33m"
+        let color = if dead {
+            theme.snake_dead
+        } else if i == 0 {
+            theme.snake_head
+        } else if theme.rainbow_snake {
+            RAINBOW_BODY[(i - 1) % RAINBOW_BODY.len()]
+        } else if i < game.snake.body().len() / 3 {
+            theme.snake_body
+        } else if i < game.snake.body().len() * 2 / 3 {
+            theme.snake_mid
         } else {
-            "\x1b[90m"
+            theme.snake_tail
         };
 
-        let (x, y) = layout.board_to_screen(pos.x, pos.y);
-        print!("\x1b[{};{}H{}", y, x, color);
-
-        if i == 0 {
-            let head_symbol = match game.snake.direction {
-                crate::utils::Direction::Up | crate::utils::Direction::Down => "█",
-                crate::utils::Direction::Left | crate::utils::Direction::Right => "█",
-            };
-            print!("{}", head_symbol);
+        let links = segment_links(game.snake.body(), i);
+        let symbol = if i == 0 {
+            let [up, down, left, right] = theme.glyphs.head;
+            match game.snake.direction {
+                Direction::Up => up,
+                Direction::Down => down,
+                Direction::Left => left,
+                Direction::Right => right,
+            }
         } else {
-            print!("■");
-        }
+            segment_glyph(links, theme.glyphs.lone_segment)
+        };
+        // In square-cell mode the right half continues the line toward a
+        // neighbor on the right and is blank otherwise.
+        let filler = if links.contains(&Some(Direction::Right)) {
+            '─'
+        } else {
+            ' '
+        };
+        draw_sprite(
+            renderer,
+            layout,
+            &mut sprites,
+            image_only,
+            *pos,
+            &fill_cell(symbol, filler, layout),
+            color,
+        );
     }
 
     let food_symbol = if game.score % 50 == 0 && game.score != 0 {
-        "★"
+        theme.glyphs.bonus_food
     } else {
-        "●"
+        theme.glyphs.food
     };
-    let (food_x, food_y) = layout.board_to_screen(game.food.x, game.food.y);
-    print!("\x1b[{};{}H\x1b[91m{}", food_y, food_x, food_symbol);
-
-    if let Some(power_up) = game.power_up {
-        let (symbol, color) = match power_up.power_up_type {
-            crate::utils::PowerUpType::SpeedBoost => (">", "\x1b[94m"),
-            crate::utils::PowerUpType::SlowDown => ("<", "\x1b[96m"),
-            crate::utils::PowerUpType::ExtraPoints => ("$", "\x1b[93m"),
-            crate::utils::PowerUpType::Grow => ("+", "\x1b[92m"),
-            crate::utils::PowerUpType::Shrink => ("-", "\x1b[95m"),
-        };
-        let (power_up_x, power_up_y) =
-            layout.board_to_screen(power_up.position.x, power_up.position.y);
-        print!("\x1b[{};{}H{}{}", power_up_y, power_up_x, color, symbol);
+    draw_sprite(
+        renderer,
+        layout,
+        &mut sprites,
+        image_only,
+        game.food,
+        &fill_cell(food_symbol, ' ', layout),
+        theme.food,
+    );
+
+    let now = Instant::now();
+    let blink_clock = renderer.running_time(now);
+    if let Some(power_up) = game
+        .power_up
+        .filter(|_| game.power_up_blink_visible(blink_clock))
+    {
+        let symbol = power_up_symbol(power_up.power_up_type);
+        draw_sprite(
+            renderer,
+            layout,
+            &mut sprites,
+            image_only,
+            power_up.position,
+            &fill_cell(symbol, ' ', layout),
+            theme.power_up(power_up.power_up_type),
+        );
+    }
+
+    // Effects only go on empty cells, which are blanked again next frame.
+    renderer.effects().track(game, now);
+    let sparkles = renderer.effects().sparkle_frame(theme.glyphs, now);
+    let floaters = renderer.effects().floater_frame(now);
+    let mut decorated: Vec<Position> = Vec::new();
+    // The ghost is only a marker: it gives way to anything on the board.
+    if let Some(ghost) = renderer
+        .ghost()
+        .filter(|pos| death_frame.is_none() && cell_is_free(game, *pos) && layout.shows(*pos))
+    {
+        let (x, y) = layout.board_to_screen(ghost.x, ghost.y);
+        renderer.put(
+            y,
+            x,
+            &fill_cell(theme.glyphs.ghost, ' ', layout),
+            theme.hint,
+        );
+        decorated.push(ghost);
+    }
+    if !game.reduced_motion {
+        for (pos, glyph) in sparkles {
+            if cell_is_free(game, pos) && layout.shows(pos) {
+                let (x, y) = layout.board_to_screen(pos.x, pos.y);
+                renderer.put(y, x, &fill_cell(glyph, ' ', layout), theme.food);
+                decorated.push(pos);
+            }
+        }
+        let fade = [theme.title, theme.option, theme.subtitle, theme.hint];
+        for floater in floaters {
+            let width = u16::try_from(floater.text.chars().count()).unwrap_or(u16::MAX);
+            let cells = width.div_ceil(layout.cell_width);
+            let Some(first_x) = floater.at.x.checked_sub((cells - 1) / 2) else {
+                continue;
+            };
+            let covered: Vec<Position> = (first_x..first_x.saturating_add(cells))
+                .map(|x| Position { x, y: floater.at.y })
+                .collect();
+            if !covered
+                .iter()
+                .all(|pos| cell_is_free(game, *pos) && layout.shows(*pos))
+            {
+                continue;
+            }
+            let (x, y) = layout.board_to_screen(first_x, floater.at.y);
+            let padded = format!(
+                "{:<width$}",
+                floater.text,
+                width = usize::from(cells * layout.cell_width)
+            );
+            renderer.put(y, x, &padded, fade[floater.stage.min(fade.len() - 1)]);
+            decorated.extend(covered);
+        }
     }
 
-    print!("\x1b[0m");
+    decorated.extend(hud::draw_toasts(renderer, game, layout, language));
+    decorated.extend(minimap::draw_minimap(renderer, game, layout));
 
-    hud::draw_gameplay_hud(game, layout, language);
+    hud::draw_gameplay_hud(renderer, game, layout, language, show_game_over_panel);
+    sidebar::draw_sidebar(renderer, game, layout, language);
+    if let Some((selected, to_menu)) = quit_confirm {
+        hud::draw_quit_confirm_panel(renderer, layout, language, selected, to_menu);
+    }
 
-    let _ = std::io::stdout().flush();
+    if board_image {
+        renderer.set_board_image(Some(BoardImage {
+            origin: layout.board_to_screen(2, 2),
+            cells: (game.width - 2, game.height - 2),
+            cell_width: layout.cell_width,
+            sprites,
+        }));
+    }
+    renderer.present();
     game.dirty_positions.clear();
+    game.dirty_positions.extend(decorated);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(cells: &[(u16, u16)]) -> VecDeque<Position> {
+        cells.iter().map(|&(x, y)| Position { x, y }).collect()
+    }
+
+    #[test]
+    fn segment_glyphs_follow_neighbors_across_wrapped_edges() {
+        // Moving left through the left wall, then turning down into a corner.
+        let snake = body(&[(39, 5), (2, 5), (3, 5), (3, 4), (3, 4)]);
+        let glyphs: Vec<&str> = (1..snake.len())
+            .map(|index| segment_glyph(segment_links(&snake, index), "■"))
+            .collect();
+        assert_eq!(glyphs, ["─", "┘", "│", "│"]);
+        assert_eq!(
+            segment_links(&snake, 1),
+            [Some(Direction::Left), Some(Direction::Right)]
+        );
+    }
 }
@@ -1,129 +1,766 @@
-use crate::core::Game;
+use crate::core::{Game, RETRY_TICKS};
 use crate::i18n;
-use crate::layout::Layout;
-use crate::utils::Language;
+use crate::layout::{Layout, SIDE_PANEL_WIDTH};
+use crate::utils::{HudPosition, Language, Position, PowerUpType};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
 
+use super::Renderer;
 use super::shared::{
-    STYLE_MENU_HINT, STYLE_MENU_OPTION, STYLE_MENU_SUBTITLE, STYLE_MENU_TITLE, display_width,
-    draw_box_line_styled, draw_centered_line_styled, draw_panel_frame,
+    center_start, clip_by_display_width, display_width, draw_box_line_styled,
+    draw_centered_line_styled, draw_panel_frame, pad_to_display_width,
 };
+use super::style::Style;
+use super::theme::Glyphs;
 
-pub(crate) fn draw_gameplay_hud(game: &Game, layout: &Layout, language: Language) {
-    let score_y = layout.hud_score_y();
-    let info_y = layout.hud_info_y();
-    let controls_y = layout.hud_controls_y();
-
-    let difficulty_short = i18n::difficulty_label(language, game.difficulty);
-    let mut status_text = format!(
-        "{}:{}  {}:{}",
-        i18n::status_score_label(language),
-        game.score,
-        i18n::status_difficulty_label(language),
-        difficulty_short
-    );
+/// How long a toast stays up.
+const TOAST_DURATION: Duration = Duration::from_secs(2);
+/// Toasts shown at once; a new one pushes out the oldest.
+const MAX_TOASTS: usize = 3;
+
+struct Toast {
+    text: String,
+    expires_at: Instant,
+}
+
+/// What the previous frame showed, to spot the events worth a toast.
+#[derive(Clone, Copy)]
+struct ToastSeen {
+    ticks: u32,
+    food_eaten: u32,
+    power_ups: [u32; PowerUpType::ALL.len()],
+}
+
+/// Short messages stacked at the top of the board, such as a beaten high
+/// score or a collected power-up.
+#[derive(Default)]
+pub(crate) struct Toasts {
+    toasts: VecDeque<Toast>,
+    last_seen: Option<ToastSeen>,
+    /// Best score when the run started.
+    run_best: u32,
+    high_score_announced: bool,
+}
+
+impl Toasts {
+    pub(crate) fn push(&mut self, text: String, now: Instant) {
+        if self.toasts.len() == MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+        self.toasts.push_back(Toast {
+            text,
+            expires_at: now + TOAST_DURATION,
+        });
+    }
+
+    /// Queues a toast for every power-up picked up since the last call, and
+    /// one the first time a run beats a previous best.
+    pub(crate) fn track(&mut self, game: &Game, language: Language, now: Instant) {
+        let seen = ToastSeen {
+            ticks: game.stats.ticks,
+            food_eaten: game.stats.food_eaten,
+            power_ups: PowerUpType::ALL.map(|kind| game.stats.power_ups_collected(kind)),
+        };
+        let last = self.last_seen.replace(seen);
+        // Lower counts mean a new run; its predecessor's toasts are stale.
+        let Some(last) =
+            last.filter(|last| seen.ticks >= last.ticks && seen.food_eaten >= last.food_eaten)
+        else {
+            self.toasts.clear();
+            self.run_best = game.high_score;
+            self.high_score_announced = false;
+            return;
+        };
+
+        for (index, kind) in PowerUpType::ALL.iter().enumerate() {
+            if seen.power_ups[index] > last.power_ups[index] {
+                self.push(i18n::power_up_name(language, *kind).to_string(), now);
+            }
+        }
+        if !self.high_score_announced && self.run_best > 0 && game.score > self.run_best {
+            self.high_score_announced = true;
+            self.push(i18n::toast_new_high_score(language).to_string(), now);
+        }
+    }
+
+    /// Drops expired toasts and returns the rest, oldest first.
+    fn visible(&mut self, now: Instant) -> Vec<String> {
+        self.toasts.retain(|toast| toast.expires_at > now);
+        self.toasts.iter().map(|toast| toast.text.clone()).collect()
+    }
+
+    pub(crate) fn next_expiry(&self) -> Option<Instant> {
+        self.toasts.front().map(|toast| toast.expires_at)
+    }
+}
+
+/// Draws the live toasts one per row from the top of the board and returns
+/// the board cells they cover, which the next frame has to blank again.
+pub(crate) fn draw_toasts(
+    renderer: &mut Renderer,
+    game: &Game,
+    layout: &Layout,
+    language: Language,
+) -> Vec<Position> {
+    let now = Instant::now();
+    renderer.toasts().track(game, language, now);
+    let texts = renderer.toasts().visible(now);
+    let style = renderer.theme().option_selected;
+    let interior_width = layout.map_width.saturating_sub(2);
+    let mut covered = Vec::new();
+    for (row, text) in (0..layout.view_rows().min(game.height.saturating_sub(2))).zip(texts) {
+        let label = clip_by_display_width(&format!(" {text} "), interior_width);
+        let width = display_width(&label);
+        let start = layout.origin_x + center_start(interior_width, width);
+        let y = 2 + layout.scroll_y + row;
+        renderer.put(layout.origin_y + 1 + row, start, &label, style);
+        covered.extend((2..game.width).map(|x| Position { x, y }).filter(|pos| {
+            let (x, _) = layout.board_to_screen(pos.x, pos.y);
+            layout.shows(*pos) && x < start + width && x + layout.cell_width > start
+        }));
+    }
+    covered
+}
+
+/// The HUD lines drawn last, by screen row. A line that reads the same as
+/// in the last frame is left alone on screen rather than blanked and put
+/// again; a full redraw forgets them all.
+#[derive(Default)]
+pub(crate) struct HudLines {
+    rows: HashMap<u16, String>,
+}
+
+impl HudLines {
+    /// Notes that row `y` now reads `line`, and returns whether it read
+    /// anything else before.
+    fn update(&mut self, y: u16, line: &str) -> bool {
+        if self.rows.get(&y).is_some_and(|last| last == line) {
+            return false;
+        }
+        self.rows.insert(y, line.to_string());
+        true
+    }
+
+    pub(crate) fn forget(&mut self) {
+        self.rows.clear();
+    }
+}
+
+/// Separator between status bar slots.
+const SLOT_GAP: u16 = 2;
+const SCORE_WIDTH: u16 = 5;
+/// Room for a boost marker after the percentage.
+const PACE_WIDTH: u16 = 5;
+const TIME_WIDTH: u16 = 5;
+const LENGTH_WIDTH: u16 = 3;
+const EFFECT_WIDTH: u16 = 20;
+const EFFECT_MIN_WIDTH: u16 = 8;
+const SPEED_UP_WIDTH: u16 = 5;
+
+/// One labeled field of the status bar. Values are padded to a fixed width so
+/// the bar keeps its shape while numbers grow and effects come and go.
+struct Slot {
+    label: &'static str,
+    value: String,
+    width: u16,
+    /// Narrowest the value may be cut to before the slot is dropped instead.
+    min_width: u16,
+    /// Lower numbers are kept longest on narrow terminals.
+    priority: u8,
+}
+
+impl Slot {
+    /// A number right-aligned in `width` columns.
+    fn number(label: &'static str, value: String, width: u16, priority: u8) -> Self {
+        Self {
+            label,
+            value,
+            width,
+            min_width: width,
+            priority,
+        }
+    }
+
+    fn total_width(&self) -> u16 {
+        display_width(self.label) + 1 + self.width
+    }
+
+    fn padded_value(&self) -> String {
+        let value = clip_by_display_width(&self.value, self.width);
+        let padding = " ".repeat(usize::from(self.width - display_width(&value)));
+        if self.min_width == self.width {
+            format!("{padding}{value}")
+        } else {
+            format!("{value}{padding}")
+        }
+    }
+}
+
+fn status_slots(game: &Game, glyphs: &Glyphs, language: Language) -> Vec<Slot> {
+    let effect_entries: Vec<String> = game
+        .active_effects
+        .iter()
+        .filter_map(|effect| {
+            let short_effect = i18n::effect_short(language, effect.kind);
+            if short_effect.is_empty() {
+                return None;
+            }
+            Some(match effect.score_multiplier() {
+                Some(multiplier) => {
+                    format!("{}x{}({})", short_effect, multiplier, effect.ticks_left)
+                }
+                None => format!("{}({})", short_effect, effect.ticks_left),
+            })
+        })
+        .collect();
+    let effects = if effect_entries.is_empty() {
+        "-".to_string()
+    } else {
+        effect_entries.join(" ")
+    };
+
+    vec![
+        Slot::number(
+            i18n::status_score_label(language),
+            game.score.to_string(),
+            SCORE_WIDTH,
+            0,
+        ),
+        Slot::number(
+            i18n::info_best_label(language),
+            game.high_score.to_string(),
+            SCORE_WIDTH,
+            3,
+        ),
+        Slot::number(
+            i18n::info_pace_label(language),
+            format!(
+                "{}%{}",
+                game.pace_percent(),
+                // Keeps the percentage in place when the marker appears.
+                if game.boosting { "»" } else { " " }
+            ),
+            PACE_WIDTH,
+            2,
+        ),
+        Slot::number(
+            i18n::info_speed_up_label(language),
+            speed_up_bar(game, glyphs),
+            SPEED_UP_WIDTH,
+            6,
+        ),
+        Slot::number(
+            i18n::summary_time_label(language),
+            format_play_time(game.stats.play_time),
+            TIME_WIDTH,
+            4,
+        ),
+        Slot::number(
+            i18n::text_length_label(language),
+            game.snake.body().len().to_string(),
+            LENGTH_WIDTH,
+            5,
+        ),
+        Slot {
+            label: i18n::info_effect_label(language),
+            value: effects,
+            width: EFFECT_WIDTH,
+            min_width: EFFECT_MIN_WIDTH,
+            priority: 1,
+        },
+    ]
+}
+
+/// The points toward the next progression speed-up as a bar, or `-` once
+/// the pace has stopped speeding up.
+fn speed_up_bar(game: &Game, glyphs: &Glyphs) -> String {
+    let Some((points, step)) = game.progression_progress() else {
+        return "-".to_string();
+    };
+    let width = u32::from(SPEED_UP_WIDTH);
+    let filled = (points * width / step.max(1)).min(width) as usize;
+    format!(
+        "{}{}",
+        glyphs.bar_fill.repeat(filled),
+        glyphs
+            .bar_track
+            .repeat(usize::from(SPEED_UP_WIDTH) - filled)
+    )
+}
+
+fn bar_width(slots: &[Slot]) -> u16 {
+    let gaps = u16::try_from(slots.len().saturating_sub(1)).unwrap_or(0) * SLOT_GAP;
+    slots.iter().map(Slot::total_width).sum::<u16>() + gaps
+}
+
+/// Fits the bar into `max_width`: flexible slots are cut down first, and when
+/// that is not enough the lowest-priority slot is dropped and the cut retried.
+fn fit_slots(mut slots: Vec<Slot>, max_width: u16) -> Vec<Slot> {
+    loop {
+        let mut excess = bar_width(&slots).saturating_sub(max_width);
+        let slack: u16 = slots.iter().map(|slot| slot.width - slot.min_width).sum();
+        if excess <= slack {
+            for slot in &mut slots {
+                let cut = excess.min(slot.width - slot.min_width);
+                slot.width -= cut;
+                excess -= cut;
+            }
+            return slots;
+        }
+        let Some(lowest) = (0..slots.len()).max_by_key(|&index| slots[index].priority) else {
+            return slots;
+        };
+        slots.remove(lowest);
+    }
+}
+
+pub(crate) fn draw_gameplay_hud(
+    renderer: &mut Renderer,
+    game: &Game,
+    layout: &Layout,
+    language: Language,
+    show_game_over_panel: bool,
+) {
+    let theme = renderer.theme();
+    match (layout.hud.position, layout.hud.compact) {
+        (HudPosition::Right, _) => draw_side_panel(renderer, game, layout, language),
+        (_, true) => {
+            let mode = mode_value(game, renderer.resume_countdown(), language);
+            let mut slots = status_slots(game, theme.glyphs, language);
+            // Before the effects, whose padding would push it off to the side.
+            slots.insert(
+                slots.len() - 1,
+                Slot::number(
+                    i18n::status_difficulty_label(language),
+                    mode.clone(),
+                    display_width(&mode),
+                    0,
+                ),
+            );
+            draw_status_bar(renderer, layout.hud_score_y(), layout.term_width, slots);
+        }
+        (_, false) => {
+            draw_status_bar(
+                renderer,
+                layout.hud_score_y(),
+                layout.term_width,
+                status_slots(game, theme.glyphs, language),
+            );
+            let info = format!(
+                "{}:{}",
+                i18n::status_difficulty_label(language),
+                mode_value(game, renderer.resume_countdown(), language)
+            );
+            let controls = i18n::controls_text(language);
+            for (y, text, style) in [
+                (layout.hud_info_y(), info.as_str(), theme.subtitle),
+                (layout.hud_controls_y(), controls, theme.hint),
+            ] {
+                if renderer.hud_lines().update(y, text) {
+                    draw_centered_line_styled(renderer, y, layout.term_width, text, style);
+                }
+            }
+        }
+    }
+
+    if show_game_over_panel {
+        draw_game_over_panel(renderer, game, layout, language);
+    }
+}
+
+/// The paused status, or the countdown to resuming when there is one.
+fn paused_status(resume_countdown: Option<u64>, language: Language) -> String {
+    match resume_countdown {
+        Some(seconds) => i18n::status_resuming(language, seconds),
+        None => i18n::status_paused(language).to_string(),
+    }
+}
+
+/// The difficulty, followed by whether the game is paused, muted, or in slow
+/// motion.
+fn mode_value(game: &Game, resume_countdown: Option<u64>, language: Language) -> String {
+    let mut value = i18n::difficulty_label(language, game.difficulty).to_string();
     if game.is_paused() {
-        status_text.push_str(&format!("  {}", i18n::status_paused(language)));
+        value.push_str(&format!("  {}", paused_status(resume_countdown, language)));
     }
     if game.muted {
-        status_text.push_str(&format!("  {}", i18n::status_muted(language)));
-    }
-    draw_centered_line_styled(score_y, layout.term_width, &status_text, STYLE_MENU_TITLE);
-
-    // Draw progression/speed telemetry.
-    let progression_multiplier = game.difficulty_speed_multiplier_percent();
-    let power_up_multiplier = game.speed_multiplier_percent();
-    let combined_multiplier = progression_multiplier * power_up_multiplier / 100;
-    let mut info_text = format!(
-        "{}:{}  {}:{}%",
-        i18n::info_best_label(language),
-        game.high_score,
-        i18n::info_pace_label(language),
-        combined_multiplier
+        value.push_str(&format!("  {}", i18n::status_muted(language)));
+    }
+    if game.is_slow_motion() {
+        value.push_str(&format!("  {}", i18n::status_slow_motion(language)));
+    }
+    value
+}
+
+/// `slots` on row `y`, cut to fit and centered.
+fn draw_status_bar(renderer: &mut Renderer, y: u16, term_width: u16, slots: Vec<Slot>) {
+    let theme = renderer.theme();
+    let slots = fit_slots(slots, term_width);
+    let line = slots
+        .iter()
+        .map(|slot| format!("{}:{}", slot.label, slot.padded_value()))
+        .collect::<Vec<_>>()
+        .join("  ");
+    if !renderer.hud_lines().update(y, &line) {
+        return;
+    }
+    renderer.clear_row(y);
+    let width = bar_width(&slots);
+    let start = center_start(term_width, width);
+    let right_to_left = renderer.is_right_to_left();
+    let mut offset = 0;
+    for slot in &slots {
+        let label = format!("{}:", slot.label);
+        let label_width = display_width(&label);
+        // Right to left, the slots run from the right edge of the bar and
+        // each label stands to the right of its value.
+        let (label_x, value_x) = if right_to_left {
+            let end = start + width - offset;
+            (end - label_width, end - slot.total_width())
+        } else {
+            (start + offset, start + offset + label_width)
+        };
+        renderer.put(y, label_x, &label, theme.hint);
+        renderer.put(y, value_x, &slot.padded_value(), theme.title);
+        offset += slot.total_width() + SLOT_GAP;
+    }
+}
+
+/// The HUD as a panel beside the board: one status field per line, then
+/// the mode and the controls. Every line is padded to the panel width so it
+/// covers what an earlier frame drew there.
+fn draw_side_panel(renderer: &mut Renderer, game: &Game, layout: &Layout, language: Language) {
+    let theme = renderer.theme();
+    let mut lines: Vec<(String, Style)> = status_slots(game, theme.glyphs, language)
+        .into_iter()
+        .map(|slot| (format!("{}: {}", slot.label, slot.value), theme.title))
+        .collect();
+    lines.push((String::new(), Style::PLAIN));
+    lines.push((
+        format!(
+            "{}: {}",
+            i18n::status_difficulty_label(language),
+            i18n::difficulty_label(language, game.difficulty)
+        ),
+        theme.subtitle,
+    ));
+    let paused = paused_status(renderer.resume_countdown(), language);
+    for (shown, status) in [
+        (game.is_paused(), paused.as_str()),
+        (game.muted, i18n::status_muted(language)),
+        (game.is_slow_motion(), i18n::status_slow_motion(language)),
+    ] {
+        let text = if shown { status } else { "" };
+        lines.push((text.to_string(), theme.subtitle));
+    }
+    lines.push((String::new(), Style::PLAIN));
+    let controls: Vec<String> = i18n::controls_text(language)
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+    lines.extend(
+        pack_entries(&controls, SIDE_PANEL_WIDTH)
+            .into_iter()
+            .map(|line| (line, theme.hint)),
     );
-    if let (Some(effect_kind), Some(_)) = (game.active_speed_effect, game.power_up_timer) {
-        let short_effect = i18n::speed_effect_short(language, effect_kind);
-        if !short_effect.is_empty() {
-            info_text.push_str(&format!(
-                "  {}:{}({})",
-                i18n::info_effect_label(language),
-                short_effect,
-                game.speed_effect_ticks_left()
-            ));
+
+    let x = layout.hud_panel_x();
+    for (y, (text, style)) in (layout.origin_y..=layout.map_bottom()).zip(lines) {
+        let clipped = clip_by_display_width(&text, SIDE_PANEL_WIDTH);
+        let line = pad_to_display_width(&clipped, SIDE_PANEL_WIDTH);
+        if renderer.hud_lines().update(y, &line) {
+            renderer.put(y, x, &line, style);
         }
     }
-    draw_centered_line_styled(info_y, layout.term_width, &info_text, STYLE_MENU_SUBTITLE);
+}
 
-    draw_centered_line_styled(
-        controls_y,
-        layout.term_width,
-        i18n::controls_text(language),
-        STYLE_MENU_HINT,
-    );
+pub(super) fn format_play_time(play_time: Duration) -> String {
+    let seconds = play_time.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
 
-    if game.game_over {
-        draw_game_over_panel(game, layout, language);
+/// Packs `entries` into as few lines as fit within `max_width`.
+fn pack_entries(entries: &[String], max_width: u16) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for entry in entries {
+        match lines.last_mut() {
+            Some(line) if display_width(line) + 2 + display_width(entry) <= max_width => {
+                line.push_str("  ");
+                line.push_str(entry);
+            }
+            _ => lines.push(entry.clone()),
+        }
     }
+    lines
 }
 
-fn draw_game_over_panel(game: &Game, layout: &Layout, language: Language) {
-    let score_line = format!("{}: {}", i18n::status_score_label(language), game.score);
-    let text_lines = [
-        i18n::game_over_title(language),
-        score_line.as_str(),
-        "",
-        i18n::game_over_menu_hint(language),
-        i18n::game_over_quit_hint(language),
+fn draw_game_over_panel(renderer: &mut Renderer, game: &Game, layout: &Layout, language: Language) {
+    let theme = renderer.theme();
+    let interior_width = layout.map_width.saturating_sub(2);
+    let interior_height = layout.map_height.saturating_sub(2);
+    let stats = &game.stats;
+
+    let score_line = i18n::summary_score(language, game.score);
+    let time_line = i18n::summary_time(language, &format_play_time(stats.play_time));
+    let food_line = i18n::summary_food(language, stats.food_eaten);
+    let power_ups_line = i18n::summary_power_ups(language, stats.total_power_ups());
+    let pace_line = i18n::summary_fastest_pace(language, stats.fastest_pace_percent.unwrap_or(100));
+    let combo_line = i18n::summary_best_combo(language, stats.max_combo);
+    let length_line = i18n::summary_max_length(language, stats.max_length);
+    let power_up_entries: Vec<String> = PowerUpType::ALL
+        .iter()
+        .filter_map(|power_up_type| {
+            let count = stats.power_ups_collected(*power_up_type);
+            (count > 0).then(|| {
+                format!(
+                    "{} x{}",
+                    i18n::power_up_name(language, *power_up_type),
+                    count
+                )
+            })
+        })
+        .collect();
+    let power_up_lines = pack_entries(&power_up_entries, interior_width.saturating_sub(4));
+
+    let mut lines: Vec<(&str, Style)> = vec![
+        (i18n::game_over_title(language), theme.title),
+        (score_line.as_str(), theme.option),
+        ("", Style::PLAIN),
+        (time_line.as_str(), theme.subtitle),
+        (food_line.as_str(), theme.subtitle),
+        (pace_line.as_str(), theme.subtitle),
+        (combo_line.as_str(), theme.subtitle),
+        (length_line.as_str(), theme.subtitle),
+        (power_ups_line.as_str(), theme.subtitle),
     ];
+    lines.extend(
+        power_up_lines
+            .iter()
+            .map(|line| (line.as_str(), theme.hint)),
+    );
+    let retry_hint = renderer
+        .retry_offered()
+        .then(|| i18n::game_over_retry_hint(language, RETRY_TICKS));
+    let hints: Vec<&str> = [
+        Some(i18n::game_over_menu_hint(language)),
+        Some(i18n::game_over_quit_hint(language)),
+        Some(i18n::game_over_share_hint(language)),
+        retry_hint.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    lines.push(("", Style::PLAIN));
+    lines.extend(hints.iter().map(|hint| (*hint, theme.hint)));
+    // Small maps drop summary rows from the middle rather than the hints.
+    let max_lines = usize::from(interior_height.saturating_sub(2)).max(hints.len() + 3);
+    while lines.len() > max_lines {
+        lines.remove(lines.len() - hints.len() - 2);
+    }
 
-    let max_line_width = text_lines
+    let max_line_width = lines
         .iter()
-        .map(|line| display_width(line))
+        .map(|(line, _)| display_width(line))
         .max()
         .unwrap_or(0);
-    let interior_width = layout.map_width.saturating_sub(2);
-    let interior_height = layout.map_height.saturating_sub(2);
-
     let desired_box_width = max_line_width.saturating_add(4); // text + side padding + borders
     let box_width = desired_box_width.min(interior_width).max(10);
     let box_inner_width = box_width - 2;
-    let box_height: u16 = 7;
+    let box_height = lines.len() as u16 + 2;
     let box_start_x: u16 = layout.origin_x + 1 + (interior_width.saturating_sub(box_width)) / 2;
     let box_top_y: u16 = layout.origin_y + 1 + (interior_height.saturating_sub(box_height)) / 2;
 
     draw_panel_frame(
+        renderer,
         box_top_y,
         box_start_x,
         box_inner_width,
         box_height.saturating_sub(2),
-        super::shared::STYLE_MENU_BORDER,
-    );
-    draw_box_line_styled(
-        box_top_y + 1,
-        box_start_x,
-        box_inner_width,
-        i18n::game_over_title(language),
-        STYLE_MENU_TITLE,
+        theme.border,
     );
-    draw_box_line_styled(
-        box_top_y + 2,
-        box_start_x,
-        box_inner_width,
-        &score_line,
-        STYLE_MENU_OPTION,
-    );
-    draw_box_line_styled(box_top_y + 3, box_start_x, box_inner_width, "", "");
-    draw_box_line_styled(
-        box_top_y + 4,
-        box_start_x,
-        box_inner_width,
-        i18n::game_over_menu_hint(language),
-        STYLE_MENU_HINT,
+    for (offset, (text, style)) in lines.iter().enumerate() {
+        draw_box_line_styled(
+            renderer,
+            box_top_y + 1 + offset as u16,
+            box_start_x,
+            box_inner_width,
+            text,
+            *style,
+        );
+    }
+}
+
+/// Yes/No dialog centered on the board, asking before a run in progress is
+/// thrown away. `selected` is 0 for Yes and 1 for No; `to_menu` asks about
+/// going back to the menu rather than quitting.
+pub(crate) fn draw_quit_confirm_panel(
+    renderer: &mut Renderer,
+    layout: &Layout,
+    language: Language,
+    selected: usize,
+    to_menu: bool,
+) {
+    let theme = renderer.theme();
+    let interior_width = layout.map_width.saturating_sub(2);
+    let interior_height = layout.map_height.saturating_sub(2);
+    let choices = [i18n::confirm_yes(language), i18n::confirm_no(language)];
+    let choice_width = choices
+        .iter()
+        .copied()
+        .map(display_width)
+        .max()
+        .unwrap_or(0)
+        + 4;
+    let choice_lines: Vec<(String, Style)> = choices
+        .iter()
+        .enumerate()
+        .map(|(index, choice)| {
+            let marker = if index == selected { ">" } else { " " };
+            let line = format!("{marker} {choice}");
+            let padding = usize::from(choice_width.saturating_sub(display_width(&line)));
+            let style = if index != selected {
+                theme.option
+            } else if index == 0 {
+                theme.option_selected_danger
+            } else {
+                theme.option_selected
+            };
+            (format!("{line}{}", " ".repeat(padding)), style)
+        })
+        .collect();
+    let mut lines: Vec<(&str, Style)> = vec![
+        (
+            if to_menu {
+                i18n::leave_confirm_title(language)
+            } else {
+                i18n::quit_confirm_title(language)
+            },
+            theme.title,
+        ),
+        (i18n::quit_confirm_warning(language), theme.subtitle),
+        ("", Style::PLAIN),
+    ];
+    lines.extend(
+        choice_lines
+            .iter()
+            .map(|(line, style)| (line.as_str(), *style)),
     );
-    draw_box_line_styled(
-        box_top_y + 5,
+
+    let max_line_width = lines
+        .iter()
+        .map(|(line, _)| display_width(line))
+        .max()
+        .unwrap_or(0);
+    let box_width = max_line_width.saturating_add(4).min(interior_width).max(10);
+    let box_inner_width = box_width - 2;
+    let box_height = lines.len() as u16 + 2;
+    let box_start_x = layout.origin_x + 1 + (interior_width.saturating_sub(box_width)) / 2;
+    let box_top_y = layout.origin_y + 1 + (interior_height.saturating_sub(box_height)) / 2;
+
+    draw_panel_frame(
+        renderer,
+        box_top_y,
         box_start_x,
         box_inner_width,
-        i18n::game_over_quit_hint(language),
-        STYLE_MENU_HINT,
+        box_height - 2,
+        theme.border,
     );
+    for (offset, (text, style)) in lines.iter().enumerate() {
+        draw_box_line_styled(
+            renderer,
+            box_top_y + 1 + offset as u16,
+            box_start_x,
+            box_inner_width,
+            text,
+            *style,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ActiveEffect;
+    use crate::utils::Difficulty;
+
+    #[test]
+    fn status_bar_cuts_effects_then_drops_low_priority_slots() {
+        let game = Game::new_seeded(Difficulty::Easy, 20, 12, 300, 3);
+        let slots = || status_slots(&game, &Glyphs::FANCY, Language::En);
+        let labels = |max_width| -> Vec<(&str, u16)> {
+            fit_slots(slots(), max_width)
+                .iter()
+                .map(|slot| (slot.label, slot.width))
+                .collect()
+        };
+        let full = bar_width(&slots());
+        assert_eq!(labels(full).len(), 7);
+        assert_eq!(labels(full - 4)[6], ("Effect", EFFECT_WIDTH - 4));
+        // Dropping the speed-up bar frees 16 columns, length and time 12 each.
+        assert_eq!(
+            labels(full - EFFECT_WIDTH - 16 - 12),
+            [("Score", 5), ("Best", 5), ("Pace", 5), ("Effect", 12)]
+        );
+        assert_eq!(labels(20), [("Score", 5)]);
+
+        let slot = Slot::number("Score", "120".to_string(), SCORE_WIDTH, 0);
+        assert_eq!(slot.padded_value(), "  120");
+    }
+
+    #[test]
+    fn score_multiplier_effect_shows_what_points_are_multiplied_by() {
+        let mut game = Game::new_seeded(Difficulty::Easy, 20, 12, 300, 3);
+        let effect = |game: &Game| {
+            status_slots(game, &Glyphs::FANCY, Language::En)
+                .into_iter()
+                .find(|slot| slot.label == "Effect")
+                .expect("the status bar has an effect slot")
+                .value
+        };
+        game.active_effects.push(ActiveEffect {
+            kind: PowerUpType::ScoreMultiplier,
+            ticks_left: 40,
+            stacks: 1,
+        });
+        assert_eq!(game.score_multiplier(), 2);
+        assert_eq!(effect(&game), "Bonusx2(40)");
+        game.active_effects[0].stacks = 2;
+        assert_eq!(game.score_multiplier(), 3);
+        assert_eq!(effect(&game), "Bonusx3(40)");
+    }
+
+    #[test]
+    fn hud_lines_only_report_rows_whose_text_changed() {
+        let mut lines = HudLines::default();
+        assert!(lines.update(3, "Score: 10"));
+        assert!(!lines.update(3, "Score: 10"));
+        assert!(lines.update(4, "Score: 10"));
+        assert!(lines.update(3, "Score: 20"));
+        lines.forget();
+        assert!(lines.update(3, "Score: 20"));
+    }
+
+    #[test]
+    fn toasts_announce_power_ups_and_a_beaten_best_then_expire() {
+        let start = Instant::now();
+        let mut game = Game::new_seeded(Difficulty::Easy, 20, 12, 30, 3);
+        let mut toasts = Toasts::default();
+        toasts.track(&game, Language::En, start);
+        assert!(toasts.visible(start).is_empty());
+
+        game.stats.record_power_up(PowerUpType::Shield);
+        game.score = 40;
+        toasts.track(&game, Language::En, start);
+        game.score = 50;
+        toasts.track(&game, Language::En, start);
+        assert_eq!(toasts.visible(start), ["Shield", "New high score!"]);
+
+        for _ in 0..3 {
+            game.stats.record_power_up(PowerUpType::Grow);
+            toasts.track(&game, Language::En, start + Duration::from_secs(1));
+        }
+        assert_eq!(toasts.visible(start).len(), MAX_TOASTS);
+        assert_eq!(toasts.next_expiry(), Some(start + Duration::from_secs(3)));
+        assert!(toasts.visible(start + Duration::from_secs(3)).is_empty());
+    }
 }
@@ -2,8 +2,10 @@ mod menu_cache;
 mod menu_high_scores;
 mod menu_main;
 
-pub use menu_high_scores::{HighScoresRenderRequest, draw_high_scores_menu};
-pub use menu_main::{MenuRenderRequest, draw_menu};
+pub use menu_high_scores::{
+    HighScoresRenderRequest, draw_high_scores_menu, high_scores_min_height,
+};
+pub use menu_main::{MenuRenderRequest, draw_menu, menu_min_height, menu_page_size};
 
 pub(crate) use menu_cache::invalidate_menu_render_caches;
 
@@ -11,8 +13,20 @@ pub(crate) use menu_cache::invalidate_menu_render_caches;
 mod tests {
     use super::*;
 
-    use crate::storage::HighScores;
-    use crate::utils::Language;
+    use super::super::style::Style;
+    use super::super::{MemoryBackend, Renderer};
+    use crate::i18n;
+    use crate::storage::{HighScoreEntry, HighScores, ScoreKey};
+    use crate::utils::{Difficulty, GameMode, Initials, Language};
+
+    fn highlight_row_ansi(y: u16, x: u16, width: u16, style: Style, line: &str) -> String {
+        let memory = MemoryBackend::new(120, 40);
+        let mut renderer = Renderer::with_backend(Box::new(memory.clone()));
+        renderer.clear();
+        menu_main::draw_highlight_row(&mut renderer, y, x, width, style, line);
+        renderer.present();
+        memory.take_transcript()
+    }
 
     #[test]
     fn menu_option_line_text_snapshot() {
@@ -23,21 +37,32 @@ mod tests {
         assert_eq!(line, "> [1] Play      ");
     }
 
+    #[test]
+    fn option_values_line_up_in_a_second_column() {
+        let options = ["Language", "Sound", "Back"].map(str::to_string);
+        let values = ["< English >", "< On >", ""].map(str::to_string);
+        assert_eq!(
+            menu_main::option_rows(&options, &values),
+            ["Language  < English >", "Sound     < On >", "Back"]
+        );
+        assert_eq!(menu_main::option_rows(&options, &[]), options);
+    }
+
     #[test]
     fn selected_row_ansi_snapshot() {
         let _guard = super::super::render_test_lock()
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
-        let ansi = menu_main::build_highlight_row_ansi(
+        let ansi = highlight_row_ansi(
             7,
             12,
             16,
-            menu_main::selected_option_style(false),
+            menu_main::selected_option_style(super::super::Theme::CLASSIC, false),
             "> [1] Play",
         );
         assert_eq!(
             ansi,
-            "\x1b[1;38;2;255;255;255;48;2;89;138;207m\x1b[7;12H                \x1b[0m\x1b[1;38;2;255;255;255;48;2;89;138;207m\x1b[7;12H> [1] Play\x1b[0m"
+            "\x1b[0m\x1b[2J\x1b[7;12H\x1b[0m\x1b[1;38;2;255;255;255;48;2;89;138;207m> [1] Play      \x1b[0m"
         );
     }
 
@@ -46,16 +71,16 @@ mod tests {
         let _guard = super::super::render_test_lock()
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
-        let ansi = menu_main::build_highlight_row_ansi(
+        let ansi = highlight_row_ansi(
             5,
             3,
             14,
-            menu_main::selected_option_style(true),
+            menu_main::selected_option_style(super::super::Theme::CLASSIC, true),
             "> [5] Reset",
         );
         assert_eq!(
             ansi,
-            "\x1b[1;97;41m\x1b[5;3H              \x1b[0m\x1b[1;97;41m\x1b[5;3H> [5] Reset\x1b[0m"
+            "\x1b[0m\x1b[2J\x1b[5;3H\x1b[0m\x1b[1;97;41m> [5] Reset   \x1b[0m"
         );
     }
 
@@ -103,6 +128,7 @@ mod tests {
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
         invalidate_menu_render_caches();
+        let renderer = &mut Renderer::with_backend(Box::new(MemoryBackend::new(120, 40)));
 
         let options = vec![
             "Play".to_string(),
@@ -116,39 +142,58 @@ mod tests {
             title: "SNAKE GAME",
             subtitle: Some("Difficulty: Extreme"),
             options: &options,
+            values: &[],
             selected_option: 0,
             danger_option: None,
+            text_cursor: None,
+            banner: None,
             term_width: 120,
             term_height: 40,
             language: Language::En,
             compact: false,
         };
 
-        draw_menu(make_menu_request());
+        draw_menu(renderer, make_menu_request());
         let menu_region =
             menu_cache::cached_region().expect("menu should populate a redraw region");
 
-        let high_scores = HighScores {
-            easy: 50,
-            medium: 80,
-            hard: 120,
-            extreme: 460,
-        };
-        draw_high_scores_menu(HighScoresRenderRequest {
-            high_scores: &high_scores,
-            term_width: 120,
-            term_height: 40,
-            language: Language::En,
-            compact: false,
-        });
+        let medium = ScoreKey::classic(Difficulty::Medium);
+        let mut high_scores = HighScores::default();
+        high_scores.insert(
+            medium,
+            HighScoreEntry {
+                score: 80,
+                initials: Initials::default(),
+                date: "2026-01-02".to_string(),
+            },
+        );
+        draw_high_scores_menu(
+            renderer,
+            HighScoresRenderRequest {
+                title: i18n::high_scores_menu_title(Language::En),
+                entries: high_scores.entries(medium),
+                first_rank: 0,
+                status: None,
+                switch_hint: i18n::leaderboard_switch_hint(Language::En),
+                actions_hint: None,
+                by_date: false,
+                difficulty: Difficulty::Medium,
+                variant: Some(i18n::game_mode_name(Language::En, GameMode::Classic)),
+                page: (2, 5),
+                term_width: 120,
+                term_height: 40,
+                language: Language::En,
+                compact: false,
+            },
+        );
         let high_scores_region =
             menu_cache::cached_region().expect("high-scores should populate a redraw region");
 
         assert_ne!(menu_region, high_scores_region);
-        assert!(high_scores_region.start_x <= menu_region.start_x);
-        assert!(high_scores_region.end_x >= menu_region.end_x);
+        assert!(high_scores_region.start_y <= menu_region.start_y);
+        assert!(high_scores_region.end_y >= menu_region.end_y);
 
-        draw_menu(make_menu_request());
+        draw_menu(renderer, make_menu_request());
         let menu_region_after_return =
             menu_cache::cached_region().expect("menu should restore its own cached redraw region");
         assert_eq!(menu_region_after_return, menu_region);
@@ -171,9 +216,155 @@ mod tests {
         };
         menu_cache::set_cached_region(Some(previous));
 
-        super::super::clear_for_menu_entry();
+        super::super::clear_for_menu_entry(&mut Renderer::with_backend(Box::new(
+            MemoryBackend::new(120, 40),
+        )));
 
         let cached_region = menu_cache::cached_region();
         assert_eq!(cached_region, None);
     }
+
+    #[test]
+    fn a_resized_menu_is_painted_over_without_clearing_the_terminal() {
+        let _guard = super::super::render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let options = ["Play".to_string(), "Quit".to_string()];
+        let request = |term_width, term_height| MenuRenderRequest {
+            screen_tag: "MAIN",
+            title: "Rustnake",
+            subtitle: None,
+            options: &options,
+            values: &[],
+            selected_option: 1,
+            danger_option: None,
+            text_cursor: None,
+            banner: None,
+            term_width,
+            term_height,
+            language: Language::En,
+            compact: false,
+        };
+        invalidate_menu_render_caches();
+        let fresh = MemoryBackend::new(70, 22);
+        draw_menu(
+            &mut Renderer::with_backend(Box::new(fresh.clone())),
+            request(70, 22),
+        );
+
+        invalidate_menu_render_caches();
+        let memory = MemoryBackend::new(120, 40);
+        let renderer = &mut Renderer::with_backend(Box::new(memory.clone()));
+        draw_menu(renderer, request(120, 40));
+        memory.take_transcript();
+        super::super::resize_for_menu(renderer, 70, 22);
+        draw_menu(renderer, request(70, 22));
+
+        assert!(!memory.take_transcript().contains("\x1b[2J"));
+        // The old frame is gone; the rows below the panel are blank.
+        assert_eq!(
+            memory.contents(true).trim_end(),
+            fresh.contents(true).trim_end()
+        );
+
+        invalidate_menu_render_caches();
+    }
+
+    #[test]
+    fn long_menus_show_the_page_holding_the_selection() {
+        let _guard = super::super::render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        invalidate_menu_render_caches();
+        let memory = MemoryBackend::new(60, 23);
+        let renderer = &mut Renderer::with_backend(Box::new(memory.clone()));
+        let options: Vec<String> = (b'a'..=b't')
+            .map(|letter| format!("Row {}", char::from(letter)))
+            .collect();
+        assert_eq!(menu_page_size(23, false), 12);
+
+        draw_menu(
+            renderer,
+            MenuRenderRequest {
+                screen_tag: "LONG",
+                title: "Settings",
+                subtitle: None,
+                options: &options,
+                values: &[],
+                selected_option: 13,
+                danger_option: None,
+                text_cursor: None,
+                banner: None,
+                term_width: 60,
+                term_height: 23,
+                language: Language::En,
+                compact: false,
+            },
+        );
+        let ansi = memory.take_transcript();
+        assert!(ansi.contains("Row m") && ansi.contains("> [ ] Row n") && ansi.contains("Row t"));
+        assert!(!ansi.contains("Row l"));
+        assert!(ansi.contains('█') && ansi.contains('░'));
+
+        invalidate_menu_render_caches();
+    }
+
+    #[test]
+    fn a_table_ordered_by_date_keeps_each_score_at_its_rank() {
+        let _guard = super::super::render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let entry = |score: u32, initials: &str, date: &str| HighScoreEntry {
+            score,
+            initials: Initials::from(initials.to_string()),
+            date: date.to_string(),
+        };
+        let entries = [
+            entry(460, "ABC", "2026-01-02"),
+            entry(230, "ZED", "2026-03-14"),
+            entry(90, "OLD", ""),
+            entry(40, "NEW", "2026-03-14"),
+        ];
+        let rows = |by_date: bool| {
+            invalidate_menu_render_caches();
+            let memory = MemoryBackend::new(100, 30);
+            draw_high_scores_menu(
+                &mut Renderer::with_backend(Box::new(memory.clone())),
+                HighScoresRenderRequest {
+                    title: i18n::high_scores_menu_title(Language::En),
+                    entries: &entries,
+                    first_rank: 0,
+                    status: None,
+                    switch_hint: i18n::leaderboard_switch_hint(Language::En),
+                    actions_hint: Some(i18n::high_scores_actions_hint(Language::En)),
+                    by_date,
+                    difficulty: Difficulty::Hard,
+                    variant: None,
+                    page: (3, 5),
+                    term_width: 100,
+                    term_height: 30,
+                    language: Language::En,
+                    compact: false,
+                },
+            );
+            memory
+                .contents(false)
+                .lines()
+                .filter_map(|line| {
+                    let words: Vec<&str> = line.split_whitespace().collect();
+                    let rank = words.iter().position(|word| {
+                        word.strip_suffix('.')
+                            .is_some_and(|number| number.parse::<usize>().is_ok())
+                    })?;
+                    Some(format!("{} {}", words[rank], words.get(rank + 1)?))
+                })
+                .take(entries.len())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(rows(false), ["1. ABC", "2. ZED", "3. OLD", "4. NEW"]);
+        assert_eq!(rows(true), ["2. ZED", "4. NEW", "1. ABC", "3. OLD"]);
+
+        invalidate_menu_render_caches();
+    }
 }
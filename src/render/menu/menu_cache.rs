@@ -1,8 +1,9 @@
-use crate::storage::HighScores;
-use crate::utils::Language;
+use crate::storage::HighScoreEntry;
+use crate::utils::{Difficulty, Language};
 use std::sync::{Mutex, OnceLock};
 
 use super::super::shared::Rect;
+use super::menu_high_scores::HighScoresRenderRequest;
 
 #[derive(Clone, PartialEq, Eq)]
 struct MenuStaticKey {
@@ -10,7 +11,9 @@ struct MenuStaticKey {
     title: String,
     subtitle: Option<String>,
     options: Vec<String>,
+    first_visible: usize,
     danger_option: Option<usize>,
+    banner: Option<String>,
     term_width: u16,
     term_height: u16,
     language: Language,
@@ -22,7 +25,10 @@ pub(super) struct MenuStaticView<'a> {
     pub(super) title: &'a str,
     pub(super) subtitle: Option<&'a str>,
     pub(super) options: &'a [String],
+    /// Index of the top option row on screen; moving to another page redraws everything.
+    pub(super) first_visible: usize,
     pub(super) danger_option: Option<usize>,
+    pub(super) banner: Option<&'a str>,
     pub(super) term_width: u16,
     pub(super) term_height: u16,
     pub(super) language: Language,
@@ -35,9 +41,18 @@ struct MenuRenderCache {
     selected_option: Option<usize>,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 struct HighScoresStaticKey {
-    high_scores: HighScores,
+    title: String,
+    entries: Vec<HighScoreEntry>,
+    first_rank: usize,
+    status: Option<String>,
+    switch_hint: String,
+    actions_hint: Option<String>,
+    by_date: bool,
+    difficulty: Difficulty,
+    variant: Option<String>,
+    page: (usize, usize),
     term_width: u16,
     term_height: u16,
     language: Language,
@@ -91,7 +106,9 @@ fn menu_static_key_matches_view(key: &MenuStaticKey, view: &MenuStaticView<'_>)
         && key.title == view.title
         && key.subtitle.as_deref() == view.subtitle
         && key.options.as_slice() == view.options
+        && key.first_visible == view.first_visible
         && key.danger_option == view.danger_option
+        && key.banner.as_deref() == view.banner
         && key.term_width == view.term_width
         && key.term_height == view.term_height
         && key.language == view.language
@@ -104,7 +121,9 @@ fn menu_static_key_from_view(view: &MenuStaticView<'_>) -> MenuStaticKey {
         title: view.title.to_string(),
         subtitle: view.subtitle.map(str::to_string),
         options: view.options.to_vec(),
+        first_visible: view.first_visible,
         danger_option: view.danger_option,
+        banner: view.banner.map(str::to_string),
         term_width: view.term_width,
         term_height: view.term_height,
         language: view.language,
@@ -129,6 +148,7 @@ pub(super) fn menu_redraw_state(
         cache.selected_option
     };
     if key_changed {
+        log::trace!("menu cache miss, redrawing {}", view.screen_tag);
         cache.key = Some(menu_static_key_from_view(view));
     }
     cache.selected_option = Some(selected_option);
@@ -142,25 +162,28 @@ pub(super) fn mark_menu_draw() {
     cache.key = None;
 }
 
-pub(super) fn begin_high_scores_draw(
-    high_scores: &HighScores,
-    term_width: u16,
-    term_height: u16,
-    language: Language,
-    compact: bool,
-) -> bool {
+pub(super) fn begin_high_scores_draw(request: &HighScoresRenderRequest<'_>) -> bool {
     let static_key = HighScoresStaticKey {
-        high_scores: *high_scores,
-        term_width,
-        term_height,
-        language,
-        compact,
+        title: request.title.to_string(),
+        entries: request.entries.to_vec(),
+        first_rank: request.first_rank,
+        status: request.status.map(str::to_string),
+        switch_hint: request.switch_hint.to_string(),
+        actions_hint: request.actions_hint.map(str::to_string),
+        by_date: request.by_date,
+        difficulty: request.difficulty,
+        variant: request.variant.map(str::to_string),
+        page: request.page,
+        term_width: request.term_width,
+        term_height: request.term_height,
+        language: request.language,
+        compact: request.compact,
     };
     {
         let mut cache = high_scores_render_cache()
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
-        if cache.key == Some(static_key) {
+        if cache.key.as_ref() == Some(&static_key) {
             return true;
         }
         cache.key = Some(static_key);
@@ -177,6 +200,7 @@ pub(super) fn begin_high_scores_draw(
 }
 
 pub(crate) fn invalidate_menu_render_caches() {
+    log::trace!("menu render caches invalidated");
     {
         let mut cache = menu_render_cache()
             .lock()
@@ -1,111 +1,230 @@
 use crate::i18n;
-use crate::storage::HighScores;
+use crate::storage::{HIGH_SCORE_TABLE_SIZE, HighScoreEntry};
 use crate::utils::{Difficulty, Language};
-use std::io::Write;
 
+use super::super::Renderer;
 use super::super::shared::{
-    ANSI_RESET, MENU_LOGO, Rect, STYLE_MENU_BORDER, STYLE_MENU_HINT, STYLE_MENU_LOGO,
-    STYLE_MENU_OPTION, STYLE_MENU_SUBTITLE, STYLE_MENU_TITLE, TextureContext, center_start,
-    clear_rect_clipped, clip_by_display_width, display_width, draw_menu_texture_region,
-    draw_panel_frame, draw_panel_separator, pad_to_display_width, print_clipped,
+    MENU_LOGO, Rect, TextureContext, center_start, clear_rect_clipped, clip_by_display_width,
+    display_width, draw_menu_texture_region, draw_panel_frame, draw_panel_separator,
+    pad_to_display_width, put_clipped,
 };
+use super::super::style::{Color, Style};
 use super::menu_cache;
-use super::menu_main::selected_option_style;
+use super::menu_main::{draw_highlight_row, selected_option_style};
 
 pub struct HighScoresRenderRequest<'a> {
-    pub high_scores: &'a HighScores,
+    pub title: &'a str,
+    /// One page of up to `HIGH_SCORE_TABLE_SIZE` rows.
+    pub entries: &'a [HighScoreEntry],
+    /// Rank shown on the first row, for pages after the first.
+    pub first_rank: usize,
+    /// Shown in place of the rows, e.g. while a remote board is loading.
+    pub status: Option<&'a str>,
+    pub switch_hint: &'a str,
+    /// Keys for what else the screen offers, shown under `switch_hint`.
+    pub actions_hint: Option<&'a str>,
+    /// Newest scores first instead of best first; ranks stay by score.
+    pub by_date: bool,
+    pub difficulty: Difficulty,
+    /// Mode and board size shown after the difficulty, for tables kept apart
+    /// by more than difficulty.
+    pub variant: Option<&'a str>,
+    /// One-based position of this table and the number of tables to page through.
+    pub page: (usize, usize),
     pub term_width: u16,
     pub term_height: u16,
     pub language: Language,
     pub compact: bool,
 }
 
-pub fn draw_high_scores_menu(request: HighScoresRenderRequest<'_>) {
-    let high_scores = request.high_scores;
+fn difficulty_badge(difficulty: Difficulty) -> (&'static str, Style) {
+    match difficulty {
+        Difficulty::Easy => ("I", Style::fg(Color::Rgb(89, 138, 207))),
+        Difficulty::Medium => ("II", Style::fg(Color::GREEN)),
+        Difficulty::Hard => ("III", Style::fg(Color::YELLOW)),
+        Difficulty::Extreme => ("IV", Style::fg(Color::RED)),
+        Difficulty::Custom => ("V", Style::fg(Color::MAGENTA)),
+    }
+}
+
+fn pad_start_to_display_width(text: &str, target_width: u16) -> String {
+    let padding = target_width.saturating_sub(display_width(text));
+    format!("{}{}", " ".repeat(padding as usize), text)
+}
+
+struct LeaderboardColumns {
+    initials: u16,
+    score: u16,
+    date: u16,
+}
+
+impl LeaderboardColumns {
+    const RANK: u16 = 3;
+
+    fn row(&self, rank: &str, initials: &str, score: &str, date: &str) -> String {
+        format!(
+            "{}  {}  {}  {}",
+            pad_start_to_display_width(rank, Self::RANK),
+            pad_to_display_width(initials, self.initials),
+            pad_start_to_display_width(score, self.score),
+            pad_to_display_width(date, self.date)
+        )
+    }
+
+    fn width(&self) -> u16 {
+        Self::RANK + self.initials + self.score + self.date + 6
+    }
+}
+
+/// Rows inside the panel border around a table `table_height` rows tall,
+/// header included, with `hints` rows of key hints. `compact` drops the
+/// logo and the blank rows; `tight` also drops the separators and the key
+/// hints, leaving the title, the table tabs, the table, and Back.
+fn panel_inner_height(table_height: u16, hints: u16, compact: bool, tight: bool) -> u16 {
+    if tight {
+        return 1 + 1 + table_height + 1;
+    }
+    let spacer = u16::from(!compact);
+    spacer + 1 + 1 + spacer + 1 + spacer + table_height + spacer + 1 + 1 + hints
+}
+
+/// The fewest rows the high-scores screen is drawn in: the tight panel
+/// with a full table.
+pub fn high_scores_min_height() -> u16 {
+    panel_inner_height(1 + HIGH_SCORE_TABLE_SIZE as u16, 0, true, true) + 2
+}
+
+/// Rows of `entries` in the order they are listed, each with its rank.
+fn listed_order(entries: &[HighScoreEntry], by_date: bool) -> Vec<(usize, &HighScoreEntry)> {
+    let mut listed: Vec<(usize, &HighScoreEntry)> = entries.iter().enumerate().collect();
+    if by_date {
+        // Stable, so scores from the same day keep their rank order; the
+        // undated ones from older configs go last.
+        listed.sort_by(|(_, a), (_, b)| b.date.cmp(&a.date));
+    }
+    listed
+}
+
+pub fn draw_high_scores_menu(renderer: &mut Renderer, request: HighScoresRenderRequest<'_>) {
+    let theme = renderer.theme();
+    if menu_cache::begin_high_scores_draw(&request) {
+        return;
+    }
+
+    let entries = request.entries;
+    let difficulty = request.difficulty;
     let term_width = request.term_width;
     let term_height = request.term_height;
     let language = request.language;
-    let compact = request.compact;
 
-    if menu_cache::begin_high_scores_draw(high_scores, term_width, term_height, language, compact) {
-        return;
+    let (badge, color) = difficulty_badge(difficulty);
+    let mut table_name = i18n::difficulty_label(language, difficulty).to_string();
+    if let Some(variant) = request.variant {
+        table_name.push_str(" · ");
+        table_name.push_str(variant);
     }
+    let tab_line = format!(
+        "<  {} {}  ({}/{})  >",
+        badge, table_name, request.page.0, request.page.1
+    );
 
+    let initials_label = i18n::leaderboard_initials_label(language);
+    // The column the table is ordered by is marked.
+    let (score_label, date_label) = {
+        let score = i18n::status_score_label(language);
+        let date = i18n::leaderboard_date_label(language);
+        if request.by_date {
+            (score.to_string(), format!("{date} ▼"))
+        } else {
+            (format!("{score} ▼"), date.to_string())
+        }
+    };
+    let (score_label, date_label) = (score_label.as_str(), date_label.as_str());
+    let columns = LeaderboardColumns {
+        initials: display_width(initials_label).max(3),
+        score: entries
+            .iter()
+            .map(|entry| display_width(&entry.score.to_string()))
+            .max()
+            .unwrap_or(0)
+            .max(display_width(score_label))
+            .max(5),
+        date: display_width(date_label).max(10),
+    };
+    let header_line = columns.row("#", initials_label, score_label, date_label);
+    let status_row = HIGH_SCORE_TABLE_SIZE / 2 - 1;
+    let listed = listed_order(entries, request.by_date);
+    let rows: Vec<(String, Style)> = (0..HIGH_SCORE_TABLE_SIZE)
+        .map(|index| {
+            if let Some(status) = request.status {
+                let line = if index == status_row {
+                    let padding = columns.width().saturating_sub(display_width(status)) / 2;
+                    format!("{}{}", " ".repeat(padding as usize), status)
+                } else {
+                    String::new()
+                };
+                return (line, theme.hint);
+            }
+            match listed.get(index) {
+                Some(&(ranked, entry)) => {
+                    let rank = format!("{}.", request.first_rank + ranked + 1);
+                    let date = if entry.date.is_empty() {
+                        "-"
+                    } else {
+                        entry.date.as_str()
+                    };
+                    let style = if request.first_rank + ranked == 0 {
+                        color
+                    } else {
+                        theme.option
+                    };
+                    (
+                        columns.row(
+                            &rank,
+                            &entry.initials.to_string(),
+                            &entry.score.to_string(),
+                            date,
+                        ),
+                        style,
+                    )
+                }
+                None => {
+                    let rank = format!("{}.", request.first_rank + index + 1);
+                    (columns.row(&rank, "---", "-", "-"), theme.hint)
+                }
+            }
+        })
+        .collect();
+    let table_height = 1 + rows.len() as u16;
+    let hints = 2 + u16::from(request.actions_hint.is_some());
+    // A terminal too short for the panel gets a smaller one.
+    let compact =
+        request.compact || panel_inner_height(table_height, hints, false, false) + 2 > term_height;
+    let tight = panel_inner_height(table_height, hints, true, false) + 2 > term_height;
     let show_logo = !compact;
     let pre_options_blank = if compact { 0u16 } else { 1u16 };
     let pre_footer_blank = if compact { 0u16 } else { 1u16 };
 
-    let entries = [
-        (
-            Difficulty::Easy,
-            high_scores.easy,
-            "I",
-            "\x1b[38;2;89;138;207m",
-        ),
-        (Difficulty::Medium, high_scores.medium, "II", "\x1b[32m"),
-        (Difficulty::Hard, high_scores.hard, "III", "\x1b[33m"),
-        (Difficulty::Extreme, high_scores.extreme, "IV", "\x1b[31m"),
-    ];
-    let max_score = entries
-        .iter()
-        .map(|(_, score, _, _)| *score)
-        .max()
-        .unwrap_or(0);
-
-    let best_label = i18n::info_best_label(language);
-    let max_label_width = entries
-        .iter()
-        .map(|(difficulty, _, _, _)| display_width(i18n::difficulty_label(language, *difficulty)))
-        .max()
-        .unwrap_or(1);
-    let max_score_width = entries
-        .iter()
-        .map(|(_, score, _, _)| display_width(&score.to_string()))
-        .max()
-        .unwrap_or(1);
-    let max_badge_width = entries
-        .iter()
-        .map(|(_, _, badge, _)| display_width(badge))
-        .max()
-        .unwrap_or(1);
-    let min_bar_width = 8u16;
-
-    let card_inner_width = (max_label_width
-        .max(max_score_width)
-        .max(max_badge_width)
-        .max(display_width(best_label))
-        .max(min_bar_width.saturating_add(2))
-        + 2)
-    .clamp(12, 20);
-    let card_inner_height = 5u16;
-    let card_width = card_inner_width + 2;
-    let card_height = card_inner_height + 2;
-    let gap = 2u16;
-    let row_gap = 1u16;
-
-    let total_horizontal_width = 4 * card_width + 3 * gap;
-    let use_two_rows = total_horizontal_width > term_width.saturating_sub(2);
-    let rows = if use_two_rows { 2u16 } else { 1u16 };
-    let columns = if use_two_rows { 2u16 } else { 4u16 };
-    let cards_block_height = rows * card_height + (rows - 1) * row_gap;
-    let cards_row_width = columns * card_width + (columns - 1) * gap;
-
-    let title = i18n::high_scores_menu_title(language);
+    let title = request.title;
     let back_line = format!("> {}", i18n::menu_back(language));
+    let switch_hint = request.switch_hint;
     let back_hint = i18n::high_scores_back_hint(language);
     let logo_width = display_width(MENU_LOGO);
     let max_inner_width = term_width.saturating_sub(2).max(1);
-    let desired_inner_width = cards_row_width
-        .saturating_add(2)
+    let desired_inner_width = columns
+        .width()
+        .saturating_add(4)
         .max(logo_width)
         .max(display_width(title))
+        .max(display_width(&tab_line))
         .max(display_width(&back_line))
+        .max(display_width(switch_hint))
+        .max(request.actions_hint.map_or(0, display_width))
         .max(display_width(back_hint))
+        .max(request.status.map_or(0, display_width))
         .max(32);
     let panel_inner_width = desired_inner_width.min(max_inner_width);
-    let header_lines = u16::from(show_logo) + 1;
-    let panel_inner_height =
-        header_lines + 1 + pre_options_blank + cards_block_height + pre_footer_blank + 1 + 2;
+    let panel_inner_height = panel_inner_height(table_height, hints, compact, tight);
     let panel_width = panel_inner_width + 2;
     let panel_height = panel_inner_height + 2;
     let panel_start_x = center_start(term_width, panel_width);
@@ -128,8 +247,9 @@ pub fn draw_high_scores_menu(request: HighScoresRenderRequest<'_>) {
     };
 
     let redraw_region = menu_cache::claim_redraw_region(current_clear_region);
-    clear_rect_clipped(redraw_region, term_width, term_height);
+    clear_rect_clipped(renderer, redraw_region, term_width, term_height);
     draw_menu_texture_region(
+        renderer,
         TextureContext {
             term_width,
             term_height,
@@ -141,141 +261,95 @@ pub fn draw_high_scores_menu(request: HighScoresRenderRequest<'_>) {
         redraw_region,
     );
     draw_panel_frame(
+        renderer,
         panel_start_y,
         panel_start_x,
         panel_inner_width,
         panel_inner_height,
-        STYLE_MENU_BORDER,
+        theme.border,
     );
 
+    let draw_centered = |renderer: &mut Renderer, y: u16, text: &str, style: Style| {
+        let draw_width = display_width(text).min(panel_inner_width);
+        let x = panel_start_x + 1 + (panel_inner_width.saturating_sub(draw_width) / 2);
+        put_clipped(renderer, y, x, text, panel_inner_width, style);
+    };
+
     let mut row_y = panel_start_y + 1;
     if show_logo {
-        let logo_draw_width = logo_width.min(panel_inner_width);
-        let logo_x = panel_start_x + 1 + (panel_inner_width.saturating_sub(logo_draw_width) / 2);
-        print!("{}", STYLE_MENU_LOGO);
-        print_clipped(row_y, logo_x, MENU_LOGO, panel_inner_width);
-        print!("{}", ANSI_RESET);
+        draw_centered(renderer, row_y, MENU_LOGO, theme.logo);
         row_y += 1;
     }
 
-    let title_draw_width = display_width(title).min(panel_inner_width);
-    let title_x = panel_start_x + 1 + (panel_inner_width.saturating_sub(title_draw_width) / 2);
-    print!("{}", STYLE_MENU_TITLE);
-    print_clipped(row_y, title_x, title, panel_inner_width);
-    print!("{}", ANSI_RESET);
+    draw_centered(renderer, row_y, title, theme.title);
     row_y += 1;
 
-    draw_panel_separator(row_y, panel_start_x, panel_inner_width, STYLE_MENU_BORDER);
-    row_y += 1 + pre_options_blank;
-    let cards_y = row_y;
-
-    let draw_card =
-        |x: u16, y: u16, difficulty: Difficulty, score: u32, badge: &str, color: &str| {
-            let label = i18n::difficulty_label(language, difficulty);
-            let score_text = score.to_string();
-            let bar_width = card_inner_width.saturating_sub(2).max(4);
-            let filled_width = if max_score == 0 {
-                0
-            } else {
-                ((score as u64 * bar_width as u64).div_ceil(max_score as u64) as u16).min(bar_width)
-            };
-            let empty_width = bar_width.saturating_sub(filled_width);
-            let bar_line = format!(
-                "{}{}",
-                "█".repeat(filled_width as usize),
-                "░".repeat(empty_width as usize)
-            );
-
-            print!(
-                "{}\x1b[{};{}H┌{}┐{}",
-                color,
-                y,
-                x,
-                "─".repeat(card_inner_width as usize),
-                ANSI_RESET
-            );
-            for line_y in (y + 1)..=(y + card_inner_height) {
-                print!(
-                    "{}\x1b[{};{}H│{}│{}",
-                    color,
-                    line_y,
-                    x,
-                    " ".repeat(card_inner_width as usize),
-                    ANSI_RESET
-                );
-            }
-            print!(
-                "{}\x1b[{};{}H└{}┘{}",
-                color,
-                y + card_inner_height + 1,
-                x,
-                "─".repeat(card_inner_width as usize),
-                ANSI_RESET
-            );
-
-            let badge_x = x + 1 + (card_inner_width.saturating_sub(display_width(badge)) / 2);
-            print!("\x1b[{};{}H{}", y + 1, badge_x, color);
-            print_clipped(y + 1, badge_x, badge, card_inner_width);
-            print!("{}", ANSI_RESET);
-
-            let label_x = x + 1 + (card_inner_width.saturating_sub(display_width(label)) / 2);
-            print!("{}", STYLE_MENU_OPTION);
-            print_clipped(y + 2, label_x, label, card_inner_width);
-            print!("{}", ANSI_RESET);
-
-            let best_x = x + 1 + (card_inner_width.saturating_sub(display_width(best_label)) / 2);
-            print!("{}", STYLE_MENU_SUBTITLE);
-            print_clipped(y + 3, best_x, best_label, card_inner_width);
-            print!("{}", ANSI_RESET);
-
-            let score_x = x + 1 + (card_inner_width.saturating_sub(display_width(&score_text)) / 2);
-            print!("{}", STYLE_MENU_TITLE);
-            print_clipped(y + 4, score_x, &score_text, card_inner_width);
-            print!("{}", ANSI_RESET);
+    if !tight {
+        draw_panel_separator(
+            renderer,
+            row_y,
+            panel_start_x,
+            panel_inner_width,
+            theme.border,
+        );
+        row_y += 1 + pre_options_blank;
+    }
 
-            let bar_x = x + 1 + (card_inner_width.saturating_sub(bar_width) / 2);
-            print!("{}", color);
-            print_clipped(y + 5, bar_x, &bar_line, bar_width);
-            print!("{}", ANSI_RESET);
-        };
+    draw_centered(renderer, row_y, &tab_line, color);
+    row_y += 1 + pre_options_blank;
 
-    let row_start_x = panel_start_x + 1 + (panel_inner_width.saturating_sub(cards_row_width) / 2);
-    for (index, (difficulty, score, badge, color)) in entries.iter().enumerate() {
-        let row = (index as u16) / columns;
-        let col = (index as u16) % columns;
-        let x = row_start_x + col * (card_width + gap);
-        let y = cards_y + row * (card_height + row_gap);
-        draw_card(x, y, *difficulty, *score, badge, color);
+    // Rows share one left edge so the columns line up under the header.
+    let table_width = columns.width().min(panel_inner_width);
+    let table_x = panel_start_x + 1 + (panel_inner_width.saturating_sub(table_width) / 2);
+    put_clipped(
+        renderer,
+        row_y,
+        table_x,
+        &header_line,
+        table_width,
+        theme.subtitle,
+    );
+    row_y += 1;
+    for (line, style) in &rows {
+        put_clipped(renderer, row_y, table_x, line, table_width, *style);
+        row_y += 1;
     }
 
-    row_y = cards_y + cards_block_height;
-    row_y += pre_footer_blank;
-    draw_panel_separator(row_y, panel_start_x, panel_inner_width, STYLE_MENU_BORDER);
-    row_y += 1;
+    if !tight {
+        row_y += pre_footer_blank;
+        draw_panel_separator(
+            renderer,
+            row_y,
+            panel_start_x,
+            panel_inner_width,
+            theme.border,
+        );
+        row_y += 1;
+    }
 
     let back_row_width = panel_inner_width.saturating_sub(2).max(1);
     let back_x = panel_start_x + 1 + (panel_inner_width.saturating_sub(back_row_width) / 2);
     let clipped_back_line = clip_by_display_width(&back_line, back_row_width);
     let padded_back_line = pad_to_display_width(&clipped_back_line, back_row_width);
-    let selected_style = selected_option_style(false);
-    print!(
-        "{}\x1b[{};{}H{}{}",
-        selected_style,
+    draw_highlight_row(
+        renderer,
         row_y,
         back_x,
-        " ".repeat(back_row_width as usize),
-        ANSI_RESET
+        back_row_width,
+        selected_option_style(theme, false),
+        &padded_back_line,
     );
-    print!("{}", selected_style);
-    print_clipped(row_y, back_x, &padded_back_line, back_row_width);
-    print!("{}", ANSI_RESET);
-    row_y += 1;
 
-    let back_hint_width = display_width(back_hint).min(panel_inner_width);
-    let back_hint_x = panel_start_x + 1 + (panel_inner_width.saturating_sub(back_hint_width) / 2);
-    print!("{}", STYLE_MENU_HINT);
-    print_clipped(row_y, back_hint_x, back_hint, panel_inner_width);
-    print!("{}", ANSI_RESET);
+    if !tight {
+        row_y += 1;
+        draw_centered(renderer, row_y, switch_hint, theme.hint);
+        row_y += 1;
+        if let Some(actions_hint) = request.actions_hint {
+            draw_centered(renderer, row_y, actions_hint, theme.hint);
+            row_y += 1;
+        }
+        draw_centered(renderer, row_y, back_hint, theme.hint);
+    }
 
-    let _ = std::io::stdout().flush();
+    renderer.present();
 }
@@ -1,15 +1,13 @@
 use crate::i18n;
 use crate::utils::Language;
-use std::io::Write;
 
 use super::super::shared::{
-    ANSI_RESET, MENU_LOGO, Rect, STYLE_MENU_BORDER, STYLE_MENU_HINT, STYLE_MENU_LOGO,
-    STYLE_MENU_OPTION, STYLE_MENU_OPTION_DANGER, STYLE_MENU_OPTION_SELECTED_DANGER,
-    STYLE_MENU_OPTION_SELECTED_MID, STYLE_MENU_SUBTITLE, STYLE_MENU_TITLE, TextureContext,
-    center_start, clear_rect_clipped, clip_by_display_width, display_width,
-    draw_menu_texture_region, draw_panel_frame, draw_panel_separator, pad_to_display_width,
-    print_clipped,
+    MENU_LOGO, Rect, TextureContext, center_start, clear_rect_clipped, clip_by_display_width,
+    display_width, draw_menu_texture_region, draw_panel_frame, draw_panel_separator,
+    pad_to_display_width, put_clipped,
 };
+use super::super::style::Style;
+use super::super::{Renderer, Theme};
 use super::menu_cache::{self, MenuStaticView};
 
 struct MenuOptionRowContext {
@@ -25,41 +23,109 @@ pub struct MenuRenderRequest<'a> {
     pub title: &'a str,
     pub subtitle: Option<&'a str>,
     pub options: &'a [String],
+    /// Value cell of each option, drawn in a second column after the labels;
+    /// missing or empty values leave the row a plain label.
+    pub values: &'a [String],
     pub selected_option: usize,
     pub danger_option: Option<usize>,
+    /// Option row holding a text field, and how many characters of its
+    /// label come before the caret.
+    pub text_cursor: Option<(usize, usize)>,
+    /// Notice shown across the top row of the screen, over the panel if the
+    /// panel reaches that far up.
+    pub banner: Option<&'a str>,
     pub term_width: u16,
     pub term_height: u16,
     pub language: Language,
     pub compact: bool,
 }
 
-pub(super) fn selected_option_style(is_danger: bool) -> &'static str {
+/// Joins each option with its value cell, lining the values up after the
+/// longest label that has one.
+pub(super) fn option_rows(options: &[String], values: &[String]) -> Vec<String> {
+    let label_width = options
+        .iter()
+        .zip(values)
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(label, _)| display_width(label))
+        .max()
+        .unwrap_or(0);
+    options
+        .iter()
+        .enumerate()
+        .map(
+            |(index, option)| match values.get(index).filter(|value| !value.is_empty()) {
+                Some(value) => format!("{}  {value}", pad_to_display_width(option, label_width)),
+                None => option.clone(),
+            },
+        )
+        .collect()
+}
+
+/// Rows of the panel that are not options: the borders, logo, title, a
+/// subtitle, both separators, the hints, and the blank spacer rows.
+fn menu_chrome_rows(compact: bool) -> u16 {
+    if compact { 8 } else { 11 }
+}
+
+/// The fewest options a menu page holds before the terminal counts as too
+/// short for the menus.
+const MIN_PAGE_ROWS: u16 = 3;
+
+/// Rows a menu needs to show a page of at least `MIN_PAGE_ROWS` options.
+pub fn menu_min_height(compact: bool) -> u16 {
+    menu_chrome_rows(compact) + MIN_PAGE_ROWS
+}
+
+/// How many options a menu shows at once in a terminal `term_height` rows
+/// tall. Longer menus are split into pages of this size, and PageUp/PageDown
+/// move the selection by as much.
+pub fn menu_page_size(term_height: u16, compact: bool) -> usize {
+    usize::from(term_height.saturating_sub(menu_chrome_rows(compact)).max(1))
+}
+
+/// Track and thumb of the scrollbar beside a paged menu's options.
+fn draw_menu_scrollbar(
+    renderer: &mut Renderer,
+    x: u16,
+    top_y: u16,
+    page_size: usize,
+    first_visible: usize,
+    option_count: usize,
+) {
+    let theme = renderer.theme();
+    let thumb_len = (page_size * page_size / option_count).max(1);
+    let thumb_start = (first_visible * page_size / option_count).min(page_size - thumb_len);
+    for row in 0..page_size {
+        let glyph = if (thumb_start..thumb_start + thumb_len).contains(&row) {
+            theme.glyphs.bar_fill
+        } else {
+            theme.glyphs.bar_track
+        };
+        renderer.put(top_y + row as u16, x, glyph, theme.hint);
+    }
+}
+
+pub(super) fn selected_option_style(theme: Theme, is_danger: bool) -> Style {
     if is_danger {
-        return STYLE_MENU_OPTION_SELECTED_DANGER;
+        return theme.option_selected_danger;
     }
-    STYLE_MENU_OPTION_SELECTED_MID
+    theme.option_selected
 }
 
-pub(super) fn build_highlight_row_ansi(
+/// Fills a menu row with `row_style` and draws `line` over it.
+pub(super) fn draw_highlight_row(
+    renderer: &mut Renderer,
     y: u16,
     x: u16,
     row_width: u16,
-    row_style: &str,
+    row_style: Style,
     line: &str,
-) -> String {
-    format!(
-        "{}\x1b[{};{}H{}{}{}\x1b[{};{}H{}{}",
-        row_style,
-        y,
-        x,
-        " ".repeat(row_width as usize),
-        ANSI_RESET,
-        row_style,
-        y,
-        x,
-        clip_by_display_width(line, row_width),
-        ANSI_RESET
-    )
+) {
+    renderer.put(y, x, &" ".repeat(row_width as usize), row_style);
+    // Padded to the full row, so right-to-left lines end at its right edge.
+    let padded = pad_to_display_width(line, row_width);
+    put_clipped(renderer, y, x, &padded, row_width, row_style);
 }
 
 pub(super) fn menu_option_line_text(
@@ -73,7 +139,7 @@ pub(super) fn menu_option_line_text(
     } else {
         " "
     };
-    let shortcut = if option_index < 6 {
+    let shortcut = if option_index < 9 {
         format!("[{}]", option_index + 1)
     } else {
         "[ ]".to_string()
@@ -84,11 +150,13 @@ pub(super) fn menu_option_line_text(
 }
 
 fn draw_menu_option_row(
+    renderer: &mut Renderer,
     row_y: u16,
     option_index: usize,
     option: &str,
     context: &MenuOptionRowContext,
 ) {
+    let theme = renderer.theme();
     let is_selected = context.selected_option == option_index;
     let is_danger = matches!(context.danger_option, Some(index) if index == option_index);
     let line = menu_option_line_text(
@@ -98,26 +166,27 @@ fn draw_menu_option_row(
         context.row_label_width,
     );
     let row_style = if is_selected {
-        selected_option_style(is_danger)
+        selected_option_style(theme, is_danger)
     } else if is_danger {
-        STYLE_MENU_OPTION_DANGER
+        theme.option_danger
     } else {
-        STYLE_MENU_OPTION
+        theme.option
     };
 
-    print!(
-        "{}",
-        build_highlight_row_ansi(
-            row_y,
-            context.options_start_x,
-            context.row_width,
-            row_style,
-            &line
-        )
+    draw_highlight_row(
+        renderer,
+        row_y,
+        context.options_start_x,
+        context.row_width,
+        row_style,
+        &line,
     );
 }
 
-pub fn draw_menu(request: MenuRenderRequest<'_>) {
+pub fn draw_menu(renderer: &mut Renderer, request: MenuRenderRequest<'_>) {
+    let rows = option_rows(request.options, request.values);
+    let options = rows.as_slice();
+    let theme = renderer.theme();
     let compact = request.compact;
     let subtitle = request.subtitle.filter(|text| !text.is_empty());
     let nav_hint = i18n::menu_navigation_hint(request.language);
@@ -125,11 +194,21 @@ pub fn draw_menu(request: MenuRenderRequest<'_>) {
     let show_logo = !compact;
     let pre_options_blank = if compact { 0u16 } else { 1u16 };
     let pre_footer_blank = if compact { 0u16 } else { 1u16 };
+    let page_size = menu_page_size(request.term_height, compact);
+    let paged = options.len() > page_size;
+    // Whole pages rather than a sliding window, so where the list is cut
+    // depends only on the selection.
+    let first_visible = if paged {
+        request.selected_option.min(options.len() - 1) / page_size * page_size
+    } else {
+        0
+    };
+    let shown_rows = if paged { page_size } else { options.len() };
+    let visible = first_visible..(first_visible + shown_rows).min(options.len());
 
     let max_inner_width = request.term_width.saturating_sub(2).max(1);
     let option_overhead = 6u16; // marker + shortcut token + spacing
-    let option_label_width = request
-        .options
+    let option_label_width = options
         .iter()
         .map(|option| display_width(option))
         .max()
@@ -151,13 +230,8 @@ pub fn draw_menu(request: MenuRenderRequest<'_>) {
     let row_width = panel_inner_width.saturating_sub(2).max(1);
     let row_label_width = row_width.saturating_sub(option_overhead).max(1);
     let header_lines = u16::from(show_logo) + 1 + u16::from(subtitle.is_some());
-    let panel_inner_height = header_lines
-        + 1
-        + pre_options_blank
-        + request.options.len() as u16
-        + pre_footer_blank
-        + 1
-        + 2;
+    let panel_inner_height =
+        header_lines + 1 + pre_options_blank + shown_rows as u16 + pre_footer_blank + 1 + 2;
     let panel_width = panel_inner_width + 2;
     let panel_height = panel_inner_height + 2;
     let panel_start_y = center_start(request.term_height, panel_height);
@@ -173,19 +247,34 @@ pub fn draw_menu(request: MenuRenderRequest<'_>) {
         .saturating_add(panel_height)
         .saturating_add(1)
         .min(request.term_height.max(1));
-    let current_clear_region = Rect {
+    let panel_region = Rect {
         start_x: clear_start_x,
         end_x: clear_end_x,
         start_y: clear_start_y,
         end_y: clear_end_y,
     };
+    // Claiming the banner row makes the redraw after it comes down clear it.
+    let current_clear_region = match request.banner {
+        Some(_) => menu_cache::rect_union(
+            panel_region,
+            Rect {
+                start_x: 1,
+                end_x: request.term_width.max(1),
+                start_y: 1,
+                end_y: 1,
+            },
+        ),
+        None => panel_region,
+    };
 
     let static_view = MenuStaticView {
         screen_tag: request.screen_tag,
         title: request.title,
         subtitle,
-        options: request.options,
+        options,
+        first_visible,
         danger_option: request.danger_option,
+        banner: request.banner,
         term_width: request.term_width,
         term_height: request.term_height,
         language: request.language,
@@ -215,8 +304,14 @@ pub fn draw_menu(request: MenuRenderRequest<'_>) {
 
     if full_redraw {
         let redraw_region = menu_cache::claim_redraw_region(current_clear_region);
-        clear_rect_clipped(redraw_region, request.term_width, request.term_height);
+        clear_rect_clipped(
+            renderer,
+            redraw_region,
+            request.term_width,
+            request.term_height,
+        );
         draw_menu_texture_region(
+            renderer,
             TextureContext {
                 term_width: request.term_width,
                 term_height: request.term_height,
@@ -228,11 +323,12 @@ pub fn draw_menu(request: MenuRenderRequest<'_>) {
             redraw_region,
         );
         draw_panel_frame(
+            renderer,
             panel_start_y,
             panel_start_x,
             panel_inner_width,
             panel_inner_height,
-            STYLE_MENU_BORDER,
+            theme.border,
         );
 
         let mut row_y = panel_start_y + 1;
@@ -240,73 +336,159 @@ pub fn draw_menu(request: MenuRenderRequest<'_>) {
             let logo_draw_width = logo_width.min(panel_inner_width);
             let logo_x =
                 panel_start_x + 1 + (panel_inner_width.saturating_sub(logo_draw_width) / 2);
-            print!("{}", STYLE_MENU_LOGO);
-            print_clipped(row_y, logo_x, MENU_LOGO, panel_inner_width);
-            print!("{}", ANSI_RESET);
+            put_clipped(
+                renderer,
+                row_y,
+                logo_x,
+                MENU_LOGO,
+                panel_inner_width,
+                theme.logo,
+            );
             row_y += 1;
         }
 
         let draw_title_width = title_width.min(panel_inner_width);
         let title_x = panel_start_x + 1 + (panel_inner_width.saturating_sub(draw_title_width) / 2);
-        print!("{}", STYLE_MENU_TITLE);
-        print_clipped(row_y, title_x, request.title, panel_inner_width);
-        print!("{}", ANSI_RESET);
+        put_clipped(
+            renderer,
+            row_y,
+            title_x,
+            request.title,
+            panel_inner_width,
+            theme.title,
+        );
         row_y += 1;
 
         if let Some(subtitle_text) = subtitle {
             let subtitle_draw_width = display_width(subtitle_text).min(panel_inner_width);
             let subtitle_x =
                 panel_start_x + 1 + (panel_inner_width.saturating_sub(subtitle_draw_width) / 2);
-            print!("{}", STYLE_MENU_SUBTITLE);
-            print_clipped(row_y, subtitle_x, subtitle_text, panel_inner_width);
-            print!("{}", ANSI_RESET);
+            put_clipped(
+                renderer,
+                row_y,
+                subtitle_x,
+                subtitle_text,
+                panel_inner_width,
+                theme.subtitle,
+            );
             row_y += 1;
         }
 
-        draw_panel_separator(row_y, panel_start_x, panel_inner_width, STYLE_MENU_BORDER);
+        draw_panel_separator(
+            renderer,
+            row_y,
+            panel_start_x,
+            panel_inner_width,
+            theme.border,
+        );
         row_y += 1 + pre_options_blank;
-        for (i, option) in request.options.iter().enumerate() {
-            draw_menu_option_row(row_y, i, option, &row_context);
+        for i in visible.clone() {
+            draw_menu_option_row(renderer, row_y, i, &options[i], &row_context);
             row_y += 1;
         }
+        if paged {
+            draw_menu_scrollbar(
+                renderer,
+                panel_start_x + panel_inner_width,
+                options_start_y,
+                page_size,
+                first_visible,
+                options.len(),
+            );
+        }
+        row_y = options_start_y + shown_rows as u16;
 
         row_y += pre_footer_blank;
-        draw_panel_separator(row_y, panel_start_x, panel_inner_width, STYLE_MENU_BORDER);
+        draw_panel_separator(
+            renderer,
+            row_y,
+            panel_start_x,
+            panel_inner_width,
+            theme.border,
+        );
         row_y += 1;
 
         let nav_hint_width = display_width(nav_hint).min(panel_inner_width);
         let nav_hint_x = panel_start_x + 1 + (panel_inner_width.saturating_sub(nav_hint_width) / 2);
-        print!("{}", STYLE_MENU_HINT);
-        print_clipped(row_y, nav_hint_x, nav_hint, panel_inner_width);
-        print!("{}", ANSI_RESET);
+        put_clipped(
+            renderer,
+            row_y,
+            nav_hint_x,
+            nav_hint,
+            panel_inner_width,
+            theme.hint,
+        );
         row_y += 1;
 
         let confirm_hint_width = display_width(confirm_hint).min(panel_inner_width);
         let confirm_hint_x =
             panel_start_x + 1 + (panel_inner_width.saturating_sub(confirm_hint_width) / 2);
-        print!("{}", STYLE_MENU_HINT);
-        print_clipped(row_y, confirm_hint_x, confirm_hint, panel_inner_width);
-        print!("{}", ANSI_RESET);
+        put_clipped(
+            renderer,
+            row_y,
+            confirm_hint_x,
+            confirm_hint,
+            panel_inner_width,
+            theme.hint,
+        );
+
+        if let Some(banner) = request.banner {
+            let width = request.term_width.max(1);
+            let text = clip_by_display_width(banner, width.saturating_sub(2));
+            let padding = width.saturating_sub(display_width(&text));
+            let left = " ".repeat(usize::from(padding / 2));
+            let right = " ".repeat(usize::from(padding - padding / 2));
+            renderer.put(
+                1,
+                1,
+                &format!("{left}{text}{right}"),
+                theme.option_selected_danger,
+            );
+        }
     } else {
-        if let Some(previous) = previous_selected.filter(|index| *index < request.options.len()) {
+        if let Some(previous) = previous_selected.filter(|index| visible.contains(index)) {
             draw_menu_option_row(
-                options_start_y + previous as u16,
+                renderer,
+                options_start_y + (previous - first_visible) as u16,
                 previous,
-                &request.options[previous],
+                &options[previous],
                 &row_context,
             );
         }
-        if request.selected_option < request.options.len()
+        if visible.contains(&request.selected_option)
             && previous_selected != Some(request.selected_option)
         {
             draw_menu_option_row(
-                options_start_y + request.selected_option as u16,
+                renderer,
+                options_start_y + (request.selected_option - first_visible) as u16,
                 request.selected_option,
-                &request.options[request.selected_option],
+                &options[request.selected_option],
                 &row_context,
             );
         }
     }
 
-    let _ = std::io::stdout().flush();
+    if let Some((index, chars_before)) = request
+        .text_cursor
+        .filter(|(index, _)| visible.contains(index))
+    {
+        // The caret can move without the text changing, so its row is
+        // always redrawn rather than only when the cache says so.
+        let row_y = options_start_y + (index - first_visible) as u16;
+        let option = &options[index];
+        draw_menu_option_row(renderer, row_y, index, option, &row_context);
+        let before: String = option.chars().take(chars_before).collect();
+        let column = display_width(&before);
+        if column < row_label_width {
+            let under = option.chars().nth(chars_before).unwrap_or(' ');
+            renderer.put(
+                row_y,
+                options_start_x + option_overhead + column,
+                &under.to_string(),
+                theme.text_cursor,
+            );
+        }
+    }
+
+    renderer.present();
 }
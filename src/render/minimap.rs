@@ -0,0 +1,137 @@
+//! The minimap: while a camera shows only part of the board, a small map of
+//! all of it in a top corner of the view, with the snake's head, the food,
+//! any power-up, and the part the camera shows.
+
+use crate::core::Game;
+use crate::layout::Layout;
+use crate::utils::{Direction, Position};
+
+use super::Renderer;
+use super::gameplay::power_up_symbol;
+use super::style::Style;
+
+/// Columns and rows inside the minimap's frame.
+const MINIMAP_COLUMNS: u16 = 10;
+const MINIMAP_ROWS: u16 = 5;
+
+/// Which minimap cell board cell `pos` falls in, or `None` off the board.
+fn minimap_cell(layout: &Layout, pos: Position) -> Option<(usize, usize)> {
+    let column = pos.x.checked_sub(2).filter(|x| *x < layout.board_columns)?;
+    let row = pos.y.checked_sub(2).filter(|y| *y < layout.board_rows)?;
+    Some((
+        usize::from(column * MINIMAP_COLUMNS / layout.board_columns),
+        usize::from(row * MINIMAP_ROWS / layout.board_rows),
+    ))
+}
+
+/// Whether the board cells a minimap cell `index` of `count` stands for,
+/// along a side `board` cells long, overlap the `view` cells from `scroll`.
+fn in_view(index: u16, count: u16, board: u16, scroll: u16, view: u16) -> bool {
+    let first = (index * board).div_ceil(count);
+    let end = ((index + 1) * board).div_ceil(count);
+    first < scroll + view && end > scroll
+}
+
+/// Draws the minimap when the layout has a camera and returns the board
+/// cells it covers, which the next frame has to blank again. It sits in the
+/// top right corner, or the top left while the head is under that one.
+pub(super) fn draw_minimap(renderer: &mut Renderer, game: &Game, layout: &Layout) -> Vec<Position> {
+    if !layout.scrolls() || layout.board_columns == 0 || layout.board_rows == 0 {
+        return Vec::new();
+    }
+    let theme = renderer.theme();
+    let (width, height) = (MINIMAP_COLUMNS + 2, MINIMAP_ROWS + 2);
+    let top = layout.origin_y + 1;
+    let right = layout.map_right().saturating_sub(width);
+    let head = game.snake.head_position();
+    let (head_x, head_y) = layout.board_to_screen(head.x, head.y);
+    let head_under =
+        layout.shows(head) && head_x + layout.cell_width >= right && head_y <= top + height;
+    let left = if head_under {
+        layout.origin_x + 1
+    } else {
+        right
+    };
+
+    let mut cells =
+        vec![vec![(" ", Style::PLAIN); usize::from(MINIMAP_COLUMNS)]; usize::from(MINIMAP_ROWS)];
+    for (row, line) in (0..MINIMAP_ROWS).zip(cells.iter_mut()) {
+        let row_in_view = in_view(
+            row,
+            MINIMAP_ROWS,
+            layout.board_rows,
+            layout.scroll_y,
+            layout.view_rows(),
+        );
+        for (column, cell) in (0..MINIMAP_COLUMNS).zip(line.iter_mut()) {
+            if row_in_view
+                && in_view(
+                    column,
+                    MINIMAP_COLUMNS,
+                    layout.board_columns,
+                    layout.scroll_x,
+                    layout.view_columns(),
+                )
+            {
+                *cell = (theme.glyphs.board_dot, theme.hint);
+            }
+        }
+    }
+    let [up, down, left_head, right_head] = theme.glyphs.head;
+    let head_glyph = match game.snake.direction {
+        Direction::Up => up,
+        Direction::Down => down,
+        Direction::Left => left_head,
+        Direction::Right => right_head,
+    };
+    let marks = game
+        .power_up
+        .map(|power_up| {
+            (
+                power_up.position,
+                power_up_symbol(power_up.power_up_type),
+                theme.power_up(power_up.power_up_type),
+            )
+        })
+        .into_iter()
+        .chain([
+            (game.food, theme.glyphs.food, theme.food),
+            (head, head_glyph, theme.snake_head),
+        ]);
+    // Later marks win a shared cell, so the head always shows.
+    for (pos, glyph, style) in marks {
+        if let Some((column, row)) = minimap_cell(layout, pos) {
+            cells[row][column] = (glyph, style);
+        }
+    }
+
+    let horizontal = "─".repeat(usize::from(MINIMAP_COLUMNS));
+    renderer.put(top, left, &format!("┌{horizontal}┐"), theme.border);
+    for (y, line) in (top + 1..).zip(&cells) {
+        renderer.put(y, left, "│", theme.border);
+        for (x, (glyph, style)) in (left + 1..).zip(line) {
+            renderer.put(y, x, glyph, *style);
+        }
+        renderer.put(y, left + width - 1, "│", theme.border);
+    }
+    renderer.put(
+        top + height - 1,
+        left,
+        &format!("└{horizontal}┘"),
+        theme.border,
+    );
+
+    let mut covered = Vec::new();
+    for y in (2 + layout.scroll_y)..(2 + layout.scroll_y + layout.view_rows()) {
+        for x in (2 + layout.scroll_x)..(2 + layout.scroll_x + layout.view_columns()) {
+            let (screen_x, screen_y) = layout.board_to_screen(x, y);
+            if (top..top + height).contains(&screen_y)
+                && screen_x < left + width
+                && screen_x + layout.cell_width > left
+            {
+                covered.push(Position { x, y });
+            }
+        }
+    }
+    covered
+}
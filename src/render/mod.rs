@@ -1,84 +1,114 @@
 //! UI and rendering module for the Snake game.
 //! Handles all terminal-based graphics and user interface elements.
 
-use std::cell::RefCell;
-use std::fmt::{self, Write as _};
-#[cfg(not(test))]
-use std::io::Write as _;
 #[cfg(test)]
 use std::sync::{Mutex, OnceLock};
 
-thread_local! {
-    static RENDER_CAPTURE: RefCell<Option<String>> = const { RefCell::new(None) };
-}
-
-pub(crate) fn emit(args: fmt::Arguments<'_>) {
-    RENDER_CAPTURE.with(|slot| {
-        let mut slot = slot.borrow_mut();
-        if let Some(buffer) = slot.as_mut() {
-            let _ = buffer.write_fmt(args);
-            return;
-        }
-
-        #[cfg(test)]
-        {
-            let _ = args;
-        }
-
-        #[cfg(not(test))]
-        {
-            let mut stdout = std::io::stdout();
-            let _ = stdout.write_fmt(args);
-        }
-    });
-}
-
-#[cfg(test)]
-pub(crate) fn begin_capture() {
-    RENDER_CAPTURE.with(|slot| {
-        *slot.borrow_mut() = Some(String::new());
-    });
-}
-
-#[cfg(test)]
-pub(crate) fn end_capture() -> String {
-    RENDER_CAPTURE.with(|slot| slot.borrow_mut().take().unwrap_or_default())
-}
-
 #[cfg(test)]
 pub(crate) fn render_test_lock() -> &'static Mutex<()> {
     static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
     LOCK.get_or_init(|| Mutex::new(()))
 }
 
-macro_rules! print {
-    ($($arg:tt)*) => {{
-        $crate::render::emit(format_args!($($arg)*));
-    }};
-}
-
+mod backend;
+mod bench;
+mod card;
+mod debug;
+mod effects;
 mod gameplay;
 mod hud;
 mod menu;
+mod minimap;
+mod pixels;
+mod screen;
+mod screenshot;
 mod shared;
+mod sidebar;
+mod style;
+mod text;
+mod theme;
+#[cfg(feature = "ratatui")]
+mod tui;
 
-pub use gameplay::{clear_for_menu_entry, draw, draw_size_warning, draw_static_frame};
-pub use menu::{HighScoresRenderRequest, MenuRenderRequest, draw_high_scores_menu, draw_menu};
+pub use backend::{CrosstermBackend, MemoryBackend, RESTORE_TITLE, SAVE_TITLE};
+pub use bench::bench_render;
+pub use card::result_card;
+pub use debug::DebugRun;
+pub use gameplay::{
+    DEATH_ANIMATION_FRAMES, DEATH_FRAME_INTERVAL, clear_for_menu_entry, copy_result, death_frame,
+    draw, draw_death_frame, draw_frame, draw_quit_confirm, draw_size_warning,
+    draw_spectator_waiting, resize_for_menu, ring_bell,
+};
+pub use menu::{
+    HighScoresRenderRequest, MenuRenderRequest, draw_high_scores_menu, draw_menu,
+    high_scores_min_height, menu_min_height, menu_page_size,
+};
+pub use screen::Renderer;
+pub use screenshot::screenshot;
+pub(crate) use shared::display_width;
+pub use shared::set_wide_ambiguous;
+pub use text::{describe_game_over, describe_state};
+pub use theme::Theme;
 
 #[cfg(test)]
 mod tests {
+    use super::gameplay::draw_static_frame;
+    use super::style::Style;
     use super::*;
-    use crate::core::Game;
-    use crate::layout;
-    use crate::storage::HighScores;
-    use crate::utils::{Difficulty, Direction, Language, Position, PowerUp, PowerUpType};
+    use crate::core::{ActiveEffect, Game};
+    use crate::i18n;
+    use crate::layout::{self, Hud};
+    use crate::storage::{HighScoreEntry, HighScores, ScoreKey, Settings};
+    use crate::utils::{
+        BoardPattern, Difficulty, Direction, GameMode, HudPosition, Initials, Language, Position,
+        PowerUp, PowerUpType,
+    };
     use std::fs;
     use std::path::PathBuf;
+    use std::time::Duration;
 
-    fn capture_render_output<F: FnOnce()>(render_fn: F) -> String {
-        begin_capture();
-        render_fn();
-        end_capture()
+    /// Runs `render_fn` against a 120x40 screen kept in memory.
+    fn render_to_memory<F: FnOnce(&mut Renderer)>(render_fn: F) -> MemoryBackend {
+        let memory = MemoryBackend::new(120, 40);
+        render_fn(&mut Renderer::with_backend(Box::new(memory.clone())));
+        memory
+    }
+
+    fn capture_render_output<F: FnOnce(&mut Renderer)>(render_fn: F) -> String {
+        render_to_memory(render_fn).take_transcript()
+    }
+
+    /// What ends up on screen, as plain text.
+    fn capture_screen<F: FnOnce(&mut Renderer)>(render_fn: F) -> String {
+        render_to_memory(render_fn).contents(false)
+    }
+
+    /// A run in progress with the snake, food, and a power-up on the board.
+    fn running_game() -> Game {
+        let mut game = Game::new_seeded(
+            Difficulty::Medium,
+            crate::utils::WIDTH,
+            crate::utils::HEIGHT,
+            200,
+            1,
+        );
+        game.snake.set_body(vec![
+            Position { x: 10, y: 6 },
+            Position { x: 9, y: 6 },
+            Position { x: 8, y: 6 },
+            Position { x: 8, y: 7 },
+        ]);
+        game.snake.direction = Direction::Right;
+        game.food = Position { x: 18, y: 6 };
+        game.power_up = Some(PowerUp {
+            position: Position { x: 4, y: 12 },
+            power_up_type: PowerUpType::Shield,
+            active: false,
+            ticks_left: 0,
+        });
+        game.score = 40;
+        game.dirty_positions.clear();
+        game
     }
 
     fn snapshot_path(name: &str) -> PathBuf {
@@ -120,20 +150,26 @@ mod tests {
             "Quit".to_string(),
         ];
 
-        let ansi = capture_render_output(|| {
-            clear_for_menu_entry();
-            draw_menu(MenuRenderRequest {
-                screen_tag: "MENU",
-                title: "SNAKE GAME",
-                subtitle: Some("Difficulty: Extreme"),
-                options: &options,
-                selected_option: 0,
-                danger_option: None,
-                term_width: 120,
-                term_height: 40,
-                language: Language::En,
-                compact: false,
-            });
+        let ansi = capture_render_output(|renderer| {
+            clear_for_menu_entry(renderer);
+            draw_menu(
+                renderer,
+                MenuRenderRequest {
+                    screen_tag: "MENU",
+                    title: "SNAKE GAME",
+                    subtitle: Some("Difficulty: Extreme"),
+                    options: &options,
+                    values: &[],
+                    selected_option: 0,
+                    danger_option: None,
+                    text_cursor: None,
+                    banner: None,
+                    term_width: 120,
+                    term_height: 40,
+                    language: Language::En,
+                    compact: false,
+                },
+            );
         });
 
         assert_snapshot("main_menu.ansi", &ansi);
@@ -145,22 +181,44 @@ mod tests {
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
 
-        let high_scores = HighScores {
-            easy: 50,
-            medium: 80,
-            hard: 120,
-            extreme: 460,
+        let entry = |score: u32, initials: &str, date: &str| HighScoreEntry {
+            score,
+            initials: Initials::from(initials.to_string()),
+            date: date.to_string(),
         };
+        let medium = ScoreKey::new(Difficulty::Medium, GameMode::Walls, 30, 15);
+        let mut high_scores = HighScores::default();
+        high_scores.set_entries(
+            medium,
+            vec![
+                entry(460, "ABC", "2026-03-14"),
+                entry(230, "ZED", "2026-02-01"),
+                entry(90, "---", ""),
+            ],
+        );
+        let variant = i18n::score_table_variant(Language::En, medium);
 
-        let ansi = capture_render_output(|| {
-            clear_for_menu_entry();
-            draw_high_scores_menu(HighScoresRenderRequest {
-                high_scores: &high_scores,
-                term_width: 120,
-                term_height: 40,
-                language: Language::En,
-                compact: false,
-            });
+        let ansi = capture_render_output(|renderer| {
+            clear_for_menu_entry(renderer);
+            draw_high_scores_menu(
+                renderer,
+                HighScoresRenderRequest {
+                    title: i18n::high_scores_menu_title(Language::En),
+                    entries: high_scores.entries(medium),
+                    first_rank: 0,
+                    status: None,
+                    switch_hint: i18n::leaderboard_switch_hint(Language::En),
+                    actions_hint: None,
+                    by_date: false,
+                    difficulty: Difficulty::Medium,
+                    variant: Some(&variant),
+                    page: (6, 6),
+                    term_width: 120,
+                    term_height: 40,
+                    language: Language::En,
+                    compact: false,
+                },
+            );
         });
 
         assert_snapshot("high_scores.ansi", &ansi);
@@ -178,33 +236,529 @@ mod tests {
             crate::utils::HEIGHT,
             460,
         );
-        game.snake.body = vec![
+        game.snake.set_body(vec![
             Position { x: 8, y: 8 },
             Position { x: 7, y: 8 },
             Position { x: 6, y: 8 },
-        ];
+        ]);
         game.snake.direction = Direction::Right;
         game.food = Position { x: 20, y: 10 };
         game.power_up = Some(PowerUp {
             position: Position { x: 15, y: 6 },
             power_up_type: PowerUpType::SpeedBoost,
             active: true,
+            ticks_left: 100,
         });
         game.score = 123;
         game.high_score = 460;
+        game.stats.play_time = Duration::from_secs(95);
+        game.stats.food_eaten = 9;
+        game.stats.fastest_pace_percent = Some(70);
+        game.stats.max_combo = 3;
+        game.stats.max_length = 12;
+        game.stats.record_power_up(PowerUpType::SpeedBoost);
+        game.stats.record_power_up(PowerUpType::SpeedBoost);
+        game.stats.record_power_up(PowerUpType::Shield);
         game.game_over = true;
         game.paused = false;
         game.muted = false;
         game.dirty_positions.clear();
 
-        let layout = layout::compute_layout(120, 40, game.width, game.height, Language::En)
-            .expect("layout should fit snapshot terminal");
+        let layout = layout::compute_layout(
+            120,
+            40,
+            game.width,
+            game.height,
+            false,
+            Hud::default(),
+            Language::En,
+        )
+        .expect("layout should fit snapshot terminal");
 
-        let ansi = capture_render_output(|| {
-            draw_static_frame(&layout);
-            draw(&mut game, &layout, Language::En);
+        let ansi = capture_render_output(|renderer| {
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
         });
 
         assert_snapshot("game_over_panel.ansi", &ansi);
     }
+
+    #[test]
+    fn ansi_snapshot_square_cells_board() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let mut game = Game::new(
+            Difficulty::Easy,
+            crate::utils::WIDTH,
+            crate::utils::HEIGHT,
+            0,
+        );
+        game.snake.set_body(vec![
+            Position { x: 8, y: 8 },
+            Position { x: 7, y: 8 },
+            Position { x: 7, y: 9 },
+        ]);
+        game.snake.direction = Direction::Right;
+        game.food = Position { x: 12, y: 8 };
+        game.power_up = None;
+        game.dirty_positions.clear();
+
+        let layout = layout::compute_layout(
+            120,
+            40,
+            game.width,
+            game.height,
+            true,
+            Hud::default(),
+            Language::En,
+        )
+        .expect("layout should fit snapshot terminal");
+
+        let ansi = capture_render_output(|renderer| {
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+
+        assert!(ansi.contains("┌─"));
+        assert_snapshot("square_cells_board.ansi", &ansi);
+    }
+
+    #[test]
+    fn board_pattern_fills_the_cells_the_snake_leaves() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let mut game = running_game();
+        let mut body = Vec::from(game.snake.body().clone());
+        body.push(Position { x: 8, y: 8 });
+        game.snake.set_body(body);
+        let layout = layout::compute_layout(
+            120,
+            40,
+            game.width,
+            game.height,
+            false,
+            Hud::default(),
+            Language::En,
+        )
+        .expect("layout should fit test terminal");
+        let screen = capture_screen(|renderer| {
+            renderer.set_theme(Theme::from_settings(&Settings {
+                board_pattern: BoardPattern::Checkerboard,
+                ..Settings::default()
+            }));
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+            let tail = game.snake.pop_tail().expect("the snake has a tail");
+            game.dirty_positions.insert(tail);
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+        // Screen coordinates count from 1.
+        let (x, y) = layout.board_to_screen(8, 8);
+        let (x, y) = (usize::from(x) - 1, usize::from(y) - 1);
+        let row: Vec<char> = screen.lines().nth(y).unwrap().chars().collect();
+        // (8, 8) is a shaded cell between two plain ones.
+        assert_eq!(row[x - 1..=x + 1], [' ', '░', ' ']);
+    }
+
+    #[test]
+    fn border_is_repaired_only_beside_edge_cells_that_changed() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let mut game = running_game();
+        let layout = layout::compute_layout(
+            120,
+            40,
+            game.width,
+            game.height,
+            false,
+            Hud::default(),
+            Language::En,
+        )
+        .expect("layout should fit test terminal");
+        let (_, changed_y) = layout.board_to_screen(2, 4);
+        let (_, still_y) = layout.board_to_screen(2, 9);
+        let screen = capture_screen(|renderer| {
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+            renderer.put(changed_y, layout.origin_x, "x", Style::PLAIN);
+            renderer.put(still_y, layout.origin_x, "x", Style::PLAIN);
+            game.dirty_positions.insert(Position { x: 2, y: 4 });
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+        let left_border = |y: u16| {
+            let line = screen.lines().nth(usize::from(y) - 1).unwrap();
+            line.chars().nth(usize::from(layout.origin_x) - 1).unwrap()
+        };
+        assert_eq!(left_border(changed_y), '│');
+        assert_eq!(left_border(still_y), 'x');
+    }
+
+    #[test]
+    fn practice_overlay_marks_coordinates_and_the_food_offset() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let mut game = running_game();
+        let layout = layout::compute_layout(
+            120,
+            40,
+            game.width,
+            game.height,
+            false,
+            Hud::default(),
+            Language::En,
+        )
+        .expect("layout should fit test terminal");
+        let screen = capture_screen(|renderer| {
+            assert!(renderer.toggle_practice_overlay());
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+        let lines: Vec<&str> = screen.lines().collect();
+        assert!(lines[usize::from(layout.origin_y) - 1].contains("┌0────5────10───15"));
+        assert!(lines[usize::from(layout.map_bottom()) - 1].contains("─ Food x+8 y+0 ─"));
+        let (_, row_5) = layout.board_to_screen(2, 7);
+        assert!(lines[usize::from(row_5) - 1].trim_start().starts_with("5 "));
+    }
+
+    #[test]
+    fn a_camera_view_of_the_board_comes_with_a_minimap() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let mut game = running_game();
+        let layout = layout::compute_layout(
+            80,
+            20,
+            game.width,
+            game.height,
+            false,
+            Hud::default(),
+            Language::En,
+        )
+        .expect("a camera fits the board in 80x20")
+        .following(game.snake.head_position(), None);
+        assert!(layout.scrolls());
+        let screen = capture_screen(|renderer| {
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+        let lines: Vec<&str> = screen.lines().collect();
+        let row = |y: u16| lines[usize::from(y) - 1].trim_end();
+        let (head_x, head_y) = layout.board_to_screen(10, 6);
+        assert_eq!(row(head_y).chars().nth(usize::from(head_x) - 1), Some('▶'));
+
+        // Tucked into the top right corner, a tenth of the board per column.
+        let top = layout.origin_y + 1;
+        assert!(row(top).ends_with("┌──────────┐│"));
+        assert!(row(top + 1).ends_with("│··········││"));
+        assert!(row(top + 2).ends_with("│··▶·●·····││"));
+        assert!(row(top + 3).ends_with("│#·········││"));
+        // The camera does not reach the bottom of the board.
+        assert!(row(top + 5).ends_with("│          ││"));
+        assert!(row(top + 6).ends_with("└──────────┘│"));
+    }
+
+    #[test]
+    fn ghost_marks_free_cells_and_gives_way_to_the_board() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let mut game = running_game();
+        let layout = layout::compute_layout(
+            120,
+            40,
+            game.width,
+            game.height,
+            false,
+            Hud::default(),
+            Language::En,
+        )
+        .expect("layout should fit test terminal");
+        let cell = |screen: &str, pos: Position| {
+            let (x, y) = layout.board_to_screen(pos.x, pos.y);
+            screen
+                .lines()
+                .nth(usize::from(y) - 1)
+                .and_then(|line| line.chars().nth(usize::from(x) - 1))
+        };
+        let ghost = Position { x: 14, y: 9 };
+        let screen = capture_screen(|renderer| {
+            renderer.set_ghost(Some(ghost));
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+        assert_eq!(cell(&screen, ghost), Some('○'));
+
+        let screen = capture_screen(|renderer| {
+            renderer.set_ghost(Some(game.food));
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+        assert_eq!(cell(&screen, game.food), Some('●'));
+        assert_eq!(cell(&screen, ghost), Some(' '));
+    }
+
+    #[test]
+    fn side_panel_hud_lists_the_status_beside_the_board() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let mut game = running_game();
+        game.toggle_slow_motion();
+        let hud = Hud {
+            position: HudPosition::Right,
+            ..Hud::default()
+        };
+        let layout =
+            layout::compute_layout(120, 40, game.width, game.height, false, hud, Language::En)
+                .expect("layout should fit test terminal");
+        let screen = capture_screen(|renderer| {
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+        let lines: Vec<&str> = screen.lines().collect();
+        let panel = |row: u16| {
+            lines[usize::from(row) - 1]
+                .chars()
+                .skip(usize::from(layout.hud_panel_x()) - 1)
+                .collect::<String>()
+        };
+        assert_eq!(panel(layout.origin_y), "Score: 40");
+        assert_eq!(panel(layout.origin_y + 3), "Speed-up: ████░");
+        assert_eq!(panel(layout.origin_y + 8), "Diff: Medium");
+        assert_eq!(panel(layout.origin_y + 11), "SLOW");
+        assert!(panel(layout.origin_y + 13).starts_with("WASD/Arrows:Move"));
+        // Nothing is drawn below the board.
+        assert!(lines.len() <= usize::from(layout.map_bottom()));
+    }
+
+    #[test]
+    fn sidebar_times_effects_and_lists_enabled_power_ups() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let mut game = running_game();
+        game.active_effects.push(ActiveEffect {
+            kind: PowerUpType::ScoreMultiplier,
+            ticks_left: game.effect_duration_ticks() / 2,
+            stacks: 2,
+        });
+        game.disabled_power_ups.toggle(PowerUpType::Grow);
+        let hud = Hud {
+            sidebar: true,
+            ..Hud::default()
+        };
+        let layout =
+            layout::compute_layout(120, 40, game.width, game.height, false, hud, Language::En)
+                .expect("layout should fit test terminal");
+        let sidebar_x = layout
+            .sidebar_x
+            .expect("120 columns leave room for the sidebar");
+        let screen = capture_screen(|renderer| {
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+        let lines: Vec<&str> = screen.lines().collect();
+        let sidebar = |row: u16| {
+            lines[usize::from(row) - 1]
+                .chars()
+                .skip(usize::from(sidebar_x) - 1)
+                .collect::<String>()
+        };
+        assert_eq!(sidebar(layout.origin_y), "Effects");
+        assert_eq!(sidebar(layout.origin_y + 1), "Score Multiplier x3");
+        assert_eq!(
+            sidebar(layout.origin_y + 2),
+            format!("{}{}", "█".repeat(11), "░".repeat(11))
+        );
+        assert_eq!(sidebar(layout.origin_y + 4), "Legend");
+        assert_eq!(sidebar(layout.origin_y + 5), "> Speed Boost");
+        assert_eq!(sidebar(layout.origin_y + 8), "- Shrink");
+    }
+
+    #[test]
+    fn death_animation_dissolves_the_snake_before_the_panel() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let mut game = Game::new(
+            Difficulty::Easy,
+            crate::utils::WIDTH,
+            crate::utils::HEIGHT,
+            0,
+        );
+        game.snake.set_body(vec![
+            Position { x: 8, y: 8 },
+            Position { x: 7, y: 8 },
+            Position { x: 6, y: 8 },
+        ]);
+        game.snake.direction = Direction::Right;
+        game.food = Position { x: 20, y: 10 };
+        game.power_up = None;
+        game.game_over = true;
+        game.dirty_positions.clear();
+        let layout = layout::compute_layout(
+            120,
+            40,
+            game.width,
+            game.height,
+            false,
+            Hud::default(),
+            Language::En,
+        )
+        .expect("layout should fit test terminal");
+
+        let mut draw_frame = |frame| {
+            capture_render_output(|renderer| {
+                draw_static_frame(renderer, &layout);
+                draw_death_frame(renderer, &mut game, &layout, Language::En, frame);
+            })
+        };
+        let flashing = draw_frame(0);
+        assert!(flashing.contains(&backend::sgr(Theme::CLASSIC.snake_dead)));
+        assert!(!flashing.contains(i18n::game_over_title(Language::En)));
+
+        let finished = draw_frame(DEATH_ANIMATION_FRAMES);
+        assert!(finished.contains(i18n::game_over_title(Language::En)));
+        assert!(!finished.contains('▶'));
+        assert_eq!(death_frame(Duration::from_secs(60)), DEATH_ANIMATION_FRAMES);
+    }
+
+    #[test]
+    fn golden_frame_settings_menu() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let options = ["Language", "Theme", "Sound", "Wrap edges", "Back"].map(str::to_string);
+        let values = ["< English >", "< Classic >", "< On >", "< Off >", ""].map(str::to_string);
+        let screen = capture_screen(|renderer| {
+            clear_for_menu_entry(renderer);
+            draw_menu(
+                renderer,
+                MenuRenderRequest {
+                    screen_tag: "SETTINGS",
+                    title: "Settings",
+                    subtitle: None,
+                    options: &options,
+                    values: &values,
+                    selected_option: 2,
+                    danger_option: None,
+                    text_cursor: None,
+                    banner: None,
+                    term_width: 120,
+                    term_height: 40,
+                    language: Language::En,
+                    compact: false,
+                },
+            );
+        });
+
+        assert_snapshot("settings_menu.txt", &screen);
+    }
+
+    #[test]
+    fn golden_frames_running_and_paused_board() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let layout = layout::compute_layout(
+            120,
+            40,
+            crate::utils::WIDTH,
+            crate::utils::HEIGHT,
+            false,
+            Hud::default(),
+            Language::En,
+        )
+        .expect("layout should fit snapshot terminal");
+
+        let mut game = running_game();
+        let running = capture_screen(|renderer| {
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+        assert_snapshot("running_board.txt", &running);
+
+        let mut game = running_game();
+        game.paused = true;
+        let paused = capture_screen(|renderer| {
+            draw_static_frame(renderer, &layout);
+            draw(renderer, &mut game, &layout, Language::En);
+        });
+        assert_snapshot("paused_board.txt", &paused);
+    }
+
+    #[test]
+    fn golden_frame_quit_confirm() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let layout = layout::compute_layout(
+            120,
+            40,
+            crate::utils::WIDTH,
+            crate::utils::HEIGHT,
+            false,
+            Hud::default(),
+            Language::En,
+        )
+        .expect("layout should fit snapshot terminal");
+
+        let mut game = running_game();
+        let screen = capture_screen(|renderer| {
+            draw_static_frame(renderer, &layout);
+            draw_quit_confirm(renderer, &mut game, &layout, Language::En, 1, false);
+        });
+
+        assert_snapshot("quit_confirm.txt", &screen);
+
+        // Space mid-run asks the same question about the menu.
+        let screen = capture_screen(|renderer| {
+            draw_static_frame(renderer, &layout);
+            draw_quit_confirm(renderer, &mut game, &layout, Language::En, 1, true);
+        });
+        assert!(screen.contains("Back to the menu?"));
+        assert!(!screen.contains("Quit the game?"));
+    }
+
+    #[test]
+    fn golden_frames_size_warning_and_spectator_waiting() {
+        let _guard = render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let size_check = layout::compute_layout(
+            40,
+            12,
+            crate::utils::WIDTH,
+            crate::utils::HEIGHT,
+            false,
+            Hud::default(),
+            Language::En,
+        )
+        .expect_err("a 40x12 terminal is too small for the board");
+        let warning = capture_screen(|renderer| {
+            draw_size_warning(renderer, size_check, Language::En);
+        });
+        assert_snapshot("size_warning.txt", &warning);
+
+        let waiting = capture_screen(|renderer| {
+            draw_spectator_waiting(renderer, 120, 40, Language::En);
+        });
+        assert_snapshot("spectator_waiting.txt", &waiting);
+    }
 }
@@ -0,0 +1,394 @@
+//! Experimental pixel renderer (`renderer = "pixels"`): the snake, food, and
+//! power-ups are drawn as an image over the board with the kitty graphics
+//! protocol or sixel, whichever the terminal speaks. The border, HUD, menus,
+//! and any panel over the board stay character cells.
+
+use std::env;
+
+use crossterm::terminal;
+
+use super::backend::Backend;
+use super::shared::base64;
+use super::style::{Color, Style};
+
+/// Pixels per terminal cell in kitty images; the terminal scales them to fit.
+const KITTY_CELL_PX: (usize, usize) = (4, 8);
+/// Cell size assumed for sixel when the terminal does not report its own.
+const DEFAULT_CELL_PX: (usize, usize) = (10, 20);
+/// The one kitty image the board uses, replaced on every change.
+const KITTY_IMAGE_ID: u32 = 1;
+/// Longest base64 payload the kitty protocol takes in one escape sequence.
+const KITTY_CHUNK: usize = 4096;
+const KITTY_DELETE: &str = "\x1b_Ga=d,d=I,i=1,q=2\x1b\\";
+
+/// The 16 palette colors, as xterm shows them by default.
+const NAMED_COLORS: [[u8; 3]; 16] = [
+    [0, 0, 0],
+    [205, 0, 0],
+    [0, 205, 0],
+    [205, 205, 0],
+    [0, 0, 238],
+    [205, 0, 205],
+    [0, 205, 205],
+    [229, 229, 229],
+    [127, 127, 127],
+    [255, 0, 0],
+    [0, 255, 0],
+    [255, 255, 0],
+    [92, 92, 255],
+    [255, 0, 255],
+    [0, 255, 255],
+    [255, 255, 255],
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Protocol {
+    Kitty,
+    Sixel,
+}
+
+impl Protocol {
+    /// The graphics protocol the terminal is known to support, going by the
+    /// environment: once the input thread owns stdin there is no reading a
+    /// reply to a query.
+    pub(crate) fn detect() -> Option<Self> {
+        Self::from_env(|name| env::var(name).ok())
+    }
+
+    fn from_env(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        // tmux and screen only pass graphics through when each sequence is
+        // wrapped for them.
+        if var("TMUX").is_some() || var("STY").is_some() {
+            return None;
+        }
+        let term = var("TERM").unwrap_or_default();
+        let program = var("TERM_PROGRAM").unwrap_or_default();
+        if var("KITTY_WINDOW_ID").is_some()
+            || term == "xterm-kitty"
+            || matches!(program.as_str(), "WezTerm" | "ghostty")
+        {
+            Some(Self::Kitty)
+        } else if term.contains("sixel")
+            || term.starts_with("foot")
+            || term.starts_with("mlterm")
+            || program == "iTerm.app"
+        {
+            Some(Self::Sixel)
+        } else {
+            None
+        }
+    }
+}
+
+/// A snake segment, food, or power-up, at a board cell counted from the
+/// top-left playable cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Sprite {
+    pub(crate) x: u16,
+    pub(crate) y: u16,
+    /// The style the cell would be drawn in; its foreground is the color.
+    pub(crate) style: Style,
+}
+
+/// What the board image shows and where.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct BoardImage {
+    /// 1-based column and row of the top-left playable cell.
+    pub(crate) origin: (u16, u16),
+    /// Playable cells across and down.
+    pub(crate) cells: (u16, u16),
+    /// Terminal columns per board cell.
+    pub(crate) cell_width: u16,
+    pub(crate) sprites: Vec<Sprite>,
+}
+
+pub(super) struct PixelBoard {
+    protocol: Protocol,
+    /// Pixels per terminal cell in the images sent.
+    cell_px: (usize, usize),
+    /// What the image on screen shows, if there is one.
+    shown: Option<BoardImage>,
+    /// What it should show after the next frame.
+    next: Option<BoardImage>,
+}
+
+impl PixelBoard {
+    pub(super) fn new(protocol: Protocol) -> Self {
+        let cell_px = match protocol {
+            Protocol::Kitty => KITTY_CELL_PX,
+            Protocol::Sixel => terminal::window_size()
+                .ok()
+                .filter(|size| size.width > 0 && size.columns > 0 && size.rows > 0)
+                .map_or(DEFAULT_CELL_PX, |size| {
+                    (
+                        usize::from(size.width / size.columns),
+                        usize::from(size.height / size.rows),
+                    )
+                }),
+        };
+        Self {
+            protocol,
+            cell_px,
+            shown: None,
+            next: None,
+        }
+    }
+
+    pub(super) fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    pub(super) fn set(&mut self, image: Option<BoardImage>) {
+        self.next = image;
+    }
+
+    /// The screen is being cleared: a sixel image goes with it, a kitty image
+    /// has to be deleted.
+    pub(super) fn cleared(&mut self, out: &mut dyn Backend) {
+        if self.shown.is_some() && self.protocol == Protocol::Kitty {
+            out.graphics(0, 0, KITTY_DELETE);
+        }
+        self.shown = None;
+        self.next = None;
+    }
+
+    /// Sends the image after the cells of a frame went out. Text written over
+    /// a sixel image erases it under the text, so with sixel any changed cell
+    /// sends it again. Returns whether a sixel image has to be wiped by
+    /// redrawing the whole screen.
+    pub(super) fn present(&mut self, out: &mut dyn Backend, cells_changed: bool) -> bool {
+        let resend = cells_changed && self.protocol == Protocol::Sixel;
+        if self.next == self.shown && !resend {
+            return false;
+        }
+        let mut wipe = false;
+        match &self.next {
+            Some(image) => {
+                let canvas = Canvas::paint(image, self.cell_px);
+                let sequence = match self.protocol {
+                    Protocol::Kitty => kitty_sequence(&canvas, image),
+                    Protocol::Sixel => sixel_sequence(&canvas),
+                };
+                out.graphics(image.origin.0 - 1, image.origin.1 - 1, &sequence);
+            }
+            None if self.shown.is_some() => match self.protocol {
+                Protocol::Kitty => out.graphics(0, 0, KITTY_DELETE),
+                Protocol::Sixel => wipe = true,
+            },
+            None => {}
+        }
+        self.shown.clone_from(&self.next);
+        wipe
+    }
+}
+
+/// Image pixels row by row; `None` is transparent.
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<Option<[u8; 3]>>,
+}
+
+impl Canvas {
+    fn paint(image: &BoardImage, (px_width, px_height): (usize, usize)) -> Self {
+        let sprite_width = usize::from(image.cell_width) * px_width;
+        let width = usize::from(image.cells.0) * sprite_width;
+        let height = usize::from(image.cells.1) * px_height;
+        let mut canvas = Self {
+            width,
+            height,
+            pixels: vec![None; width * height],
+        };
+        // A margin keeps neighboring sprites apart.
+        let margin = (sprite_width.min(px_height) / 6).max(1);
+        for sprite in &image.sprites {
+            let Some(color) = foreground(sprite.style) else {
+                continue;
+            };
+            let left = usize::from(sprite.x) * sprite_width;
+            let top = usize::from(sprite.y) * px_height;
+            for y in top + margin..(top + px_height - margin).min(height) {
+                for x in left + margin..(left + sprite_width - margin).min(width) {
+                    canvas.pixels[y * width + x] = Some(color);
+                }
+            }
+        }
+        canvas
+    }
+}
+
+/// The foreground color of `style`, if it sets one.
+fn foreground(style: Style) -> Option<[u8; 3]> {
+    match style.fg? {
+        Color::Named(index) => NAMED_COLORS.get(usize::from(index)).copied(),
+        Color::Rgb(r, g, b) => Some([r, g, b]),
+    }
+}
+
+/// Transmits and places the image in one go, replacing the previous one. It
+/// sits below the text, so panels and floaters still show over the board.
+fn kitty_sequence(canvas: &Canvas, image: &BoardImage) -> String {
+    let rgba: Vec<u8> = canvas
+        .pixels
+        .iter()
+        .flat_map(|pixel| match pixel {
+            Some([r, g, b]) => [*r, *g, *b, 255],
+            None => [0; 4],
+        })
+        .collect();
+    let payload = base64(&rgba);
+    let chunks: Vec<&str> = payload
+        .as_bytes()
+        .chunks(KITTY_CHUNK)
+        .map(|chunk| std::str::from_utf8(chunk).expect("base64 is ASCII"))
+        .collect();
+    let mut out = String::with_capacity(payload.len() + chunks.len() * 16);
+    for (index, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(index + 1 < chunks.len());
+        if index == 0 {
+            out.push_str(&format!(
+                "\x1b_Ga=T,f=32,s={},v={},c={},r={},i={KITTY_IMAGE_ID},z=-1,C=1,q=2,m={more};{chunk}\x1b\\",
+                canvas.width,
+                canvas.height,
+                image.cells.0 * image.cell_width,
+                image.cells.1,
+            ));
+        } else {
+            out.push_str(&format!("\x1b_Gm={more};{chunk}\x1b\\"));
+        }
+    }
+    out
+}
+
+/// The image as sixel, with transparent pixels left as they are.
+fn sixel_sequence(canvas: &Canvas) -> String {
+    let mut palette: Vec<[u8; 3]> = Vec::new();
+    for color in canvas.pixels.iter().flatten() {
+        if !palette.contains(color) {
+            palette.push(*color);
+        }
+    }
+    let mut out = format!("\x1bP0;1;0q\"1;1;{};{}", canvas.width, canvas.height);
+    for (index, [r, g, b]) in palette.iter().enumerate() {
+        let percent = |channel: &u8| u32::from(*channel) * 100 / 255;
+        out.push_str(&format!(
+            "#{index};2;{};{};{}",
+            percent(r),
+            percent(g),
+            percent(b)
+        ));
+    }
+    for band in (0..canvas.height).step_by(6) {
+        for (index, color) in palette.iter().enumerate() {
+            let sixels: Vec<u8> = (0..canvas.width)
+                .map(|x| {
+                    (0..6)
+                        .filter(|dy| band + dy < canvas.height)
+                        .filter(|dy| canvas.pixels[(band + dy) * canvas.width + x] == Some(*color))
+                        .fold(0, |bits, dy| bits | 1 << dy)
+                })
+                .collect();
+            let Some(end) = sixels.iter().rposition(|bits| *bits != 0) else {
+                continue;
+            };
+            out.push_str(&format!("#{index}"));
+            push_sixel_runs(&mut out, &sixels[..=end]);
+            out.push('$');
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out
+}
+
+/// Sixel characters, with runs of four or more as `!<count><char>`.
+fn push_sixel_runs(out: &mut String, sixels: &[u8]) {
+    let mut rest = sixels;
+    while let Some(&bits) = rest.first() {
+        let run = rest.iter().take_while(|other| **other == bits).count();
+        let ch = char::from(63 + bits);
+        if run >= 4 {
+            out.push_str(&format!("!{run}{ch}"));
+        } else {
+            out.extend(std::iter::repeat_n(ch, run));
+        }
+        rest = &rest[run..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::MemoryBackend;
+
+    #[test]
+    fn detects_the_protocol_from_the_environment() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            Protocol::from_env(env(&[("TERM", "xterm-kitty")])),
+            Some(Protocol::Kitty)
+        );
+        assert_eq!(
+            Protocol::from_env(env(&[("TERM", "foot"), ("TERM_PROGRAM", "")])),
+            Some(Protocol::Sixel)
+        );
+        assert_eq!(
+            Protocol::from_env(env(&[("TERM", "xterm-kitty"), ("TMUX", "/tmp/tmux")])),
+            None
+        );
+        assert_eq!(Protocol::from_env(env(&[("TERM", "xterm-256color")])), None);
+    }
+
+    #[test]
+    fn board_images_encode_for_both_protocols() {
+        assert_eq!(base64(b"Man"), "TWFu");
+        assert_eq!(base64(b"Ma"), "TWE=");
+        assert_eq!(base64(b"M"), "TQ==");
+        assert_eq!(
+            foreground(Style::fg(Color::Rgb(0, 200, 10)).bold()),
+            Some([0, 200, 10])
+        );
+        assert_eq!(
+            foreground(Style::fg(Color::BRIGHT_GREEN)),
+            Some([0, 255, 0])
+        );
+        assert_eq!(foreground(Style::PLAIN.bold()), None);
+
+        let image = BoardImage {
+            origin: (5, 3),
+            cells: (2, 1),
+            cell_width: 1,
+            sprites: vec![Sprite {
+                x: 1,
+                y: 0,
+                style: Style::fg(Color::BRIGHT_RED),
+            }],
+        };
+        let canvas = Canvas::paint(&image, (6, 6));
+        assert_eq!((canvas.width, canvas.height), (12, 6));
+        assert_eq!(canvas.pixels[6], None);
+        assert_eq!(canvas.pixels[7 + 12], Some([255, 0, 0]));
+        assert_eq!(
+            sixel_sequence(&canvas),
+            "\x1bP0;1;0q\"1;1;12;6#0;2;100;0;0#0!7?!4]$-\x1b\\"
+        );
+
+        let memory = MemoryBackend::new(20, 10);
+        let mut board = PixelBoard::new(Protocol::Kitty);
+        board.set(Some(image.clone()));
+        assert!(!board.present(&mut memory.clone(), true));
+        let sent = memory.take_transcript();
+        assert!(sent.starts_with("\x1b[3;5H\x1b_Ga=T,f=32,s=8,v=8,c=2,r=1,i=1,"));
+        assert!(!board.present(&mut memory.clone(), true));
+        assert_eq!(memory.take_transcript(), "");
+        board.set(None);
+        board.present(&mut memory.clone(), false);
+        assert!(memory.take_transcript().ends_with(KITTY_DELETE));
+    }
+}
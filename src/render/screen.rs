@@ -0,0 +1,761 @@
+//! Cell-based back buffer for everything the renderer draws.
+//!
+//! Draw calls only write characters and styles into cells. `present` compares
+//! the buffer with what the backend already shows and sends just the cells
+//! that changed, so a frame never flickers through a half-drawn state and an
+//! unchanged screen costs no output at all.
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::Theme;
+use super::backend::{Backend, CrosstermBackend, sgr};
+use super::debug::{DebugOverlay, DebugRun};
+use super::effects::Effects;
+use super::hud::{HudLines, Toasts};
+use super::pixels::{BoardImage, PixelBoard, Protocol};
+use super::shared::{cluster_width, visual_order};
+use super::style::Style;
+#[cfg(feature = "ratatui")]
+use super::tui::TuiBackend;
+use crate::utils::{Position, RenderBackend};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Cell {
+    pub(super) ch: char,
+    /// Marks, joiners, and selectors that follow `ch` in its grapheme cluster.
+    pub(super) extra: &'static str,
+    pub(super) style: Style,
+}
+
+const BLANK: Cell = Cell {
+    ch: ' ',
+    extra: "",
+    style: Style::PLAIN,
+};
+/// Placeholder for the right half of a double-width character.
+pub(super) const CONTINUATION: char = '\0';
+/// A cell of `front` whose contents on the terminal are not known, which
+/// differs from anything drawn.
+const UNKNOWN: Cell = Cell {
+    ch: '\u{ffff}',
+    extra: "",
+    style: Style::PLAIN,
+};
+
+#[derive(Default)]
+struct ScreenBuffer {
+    /// Rows of cells being drawn, top to bottom. Missing cells are blank.
+    back: Vec<Vec<Cell>>,
+    /// What the terminal showed after the last `present`.
+    front: Vec<Vec<Cell>>,
+    /// The terminal contents are unknown (startup, new view) and must be
+    /// cleared.
+    clear_pending: bool,
+    /// Cells the last `diff` wrote.
+    changed_cells: usize,
+}
+
+fn cell_at(rows: &[Vec<Cell>], y: usize, x: usize) -> Cell {
+    rows.get(y)
+        .and_then(|row| row.get(x))
+        .copied()
+        .unwrap_or(BLANK)
+}
+
+fn set_cell(row: &mut Vec<Cell>, x: usize, cell: Cell) {
+    if row.len() <= x + 1 {
+        row.resize(x + 2, BLANK);
+    }
+    // Overwriting either half of a wide character blanks the other half.
+    if cell.ch != CONTINUATION && row[x].ch == CONTINUATION && x > 0 {
+        row[x - 1] = BLANK;
+    }
+    if row[x + 1].ch == CONTINUATION {
+        row[x + 1] = BLANK;
+    }
+    row[x] = cell;
+}
+
+/// `text` with a `'static` lifetime. Each distinct string is leaked once;
+/// clusters longer than a char are rare enough that this stays small.
+fn intern(text: &str) -> &'static str {
+    static INTERNED: Mutex<Option<HashSet<&'static str>>> = Mutex::new(None);
+    if text.is_empty() {
+        return "";
+    }
+    let mut interned = INTERNED.lock().unwrap_or_else(|err| err.into_inner());
+    let interned = interned.get_or_insert_with(HashSet::new);
+    if let Some(&known) = interned.get(text) {
+        return known;
+    }
+    let leaked: &'static str = Box::leak(text.to_string().into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+/// Writes `text` into `row` from column `x` and returns the columns it took.
+pub(super) fn put_text(row: &mut Vec<Cell>, mut x: usize, text: &str, style: Style) -> usize {
+    let start = x;
+    for cluster in text.graphemes(true) {
+        let width = cluster_width(cluster);
+        let mut chars = cluster.chars();
+        let Some(ch) = chars.next() else {
+            continue;
+        };
+        if width == 0 {
+            continue;
+        }
+        let extra = intern(chars.as_str());
+        set_cell(row, x, Cell { ch, extra, style });
+        if width == 2 {
+            set_cell(
+                row,
+                x + 1,
+                Cell {
+                    ch: CONTINUATION,
+                    extra: "",
+                    style,
+                },
+            );
+        }
+        x += width;
+    }
+    x - start
+}
+
+/// `rows` as text, one line per row with trailing blanks dropped, and with
+/// each style as its SGR sequence when `ansi` is set.
+pub(super) fn rows_text(rows: &[Vec<Cell>], ansi: bool) -> String {
+    let mut out = String::new();
+    for row in rows {
+        // Styled blanks still show in color, but not as plain text.
+        let end = row
+            .iter()
+            .rposition(|cell| *cell != BLANK && (ansi || cell.ch != ' '))
+            .map_or(0, |x| x + 1);
+        let mut current_style = Style::PLAIN;
+        for cell in row[..end].iter().filter(|cell| cell.ch != CONTINUATION) {
+            if ansi && cell.style != current_style {
+                out.push_str("\x1b[0m");
+                out.push_str(&sgr(cell.style));
+                current_style = cell.style;
+            }
+            out.push(cell.ch);
+            out.push_str(cell.extra);
+        }
+        if current_style != Style::PLAIN {
+            out.push_str("\x1b[0m");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+impl ScreenBuffer {
+    fn new() -> Self {
+        Self {
+            clear_pending: true,
+            ..Self::default()
+        }
+    }
+
+    fn put(&mut self, y: u16, x: u16, text: &str, style: Style) {
+        if y == 0 || x == 0 {
+            return;
+        }
+        let y = usize::from(y - 1);
+        if self.back.len() <= y {
+            self.back.resize_with(y + 1, Vec::new);
+        }
+        put_text(&mut self.back[y], usize::from(x - 1), text, style);
+    }
+
+    fn clear(&mut self) {
+        self.back.clear();
+        self.clear_pending = true;
+    }
+
+    /// Starts over on a terminal resized to `width` by `height`. Whatever it
+    /// shows now, the next `diff` writes every cell in place, blanks
+    /// included, so the old frame goes straight to the new one rather than
+    /// through an empty screen.
+    fn repaint(&mut self, width: u16, height: u16) {
+        self.back.clear();
+        self.front = vec![vec![UNKNOWN; usize::from(width)]; usize::from(height)];
+    }
+
+    /// Starts the next frame blank but keeps track of what the terminal
+    /// shows, so `diff` only sends the cells that come out different.
+    fn redraw(&mut self) {
+        self.back.clear();
+    }
+
+    fn clear_row(&mut self, y: u16) {
+        if let Some(row) = y
+            .checked_sub(1)
+            .and_then(|y| self.back.get_mut(usize::from(y)))
+        {
+            row.clear();
+        }
+    }
+
+    /// Sends what brings the screen from `front` to `back` to `out`, or the
+    /// whole of `back` to a backend that takes frames whole.
+    fn diff(&mut self, out: &mut dyn Backend) {
+        if self.clear_pending {
+            out.clear();
+            self.front.clear();
+            self.clear_pending = false;
+        }
+        let whole_frame = out.present_frame(&self.back);
+
+        let mut cursor: Option<(usize, usize)> = None;
+        let mut current_style: Option<Style> = None;
+        self.changed_cells = 0;
+        for y in 0..self.back.len().max(self.front.len()) {
+            let row_width = [&self.back, &self.front]
+                .iter()
+                .filter_map(|rows| rows.get(y))
+                .map(Vec::len)
+                .max()
+                .unwrap_or(0);
+            let mut x = 0;
+            while x < row_width {
+                let cell = cell_at(&self.back, y, x);
+                if cell.ch == CONTINUATION {
+                    x += 1;
+                    continue;
+                }
+                let width = if cell_at(&self.back, y, x + 1).ch == CONTINUATION {
+                    2
+                } else {
+                    1
+                };
+                let changed =
+                    (x..x + width).any(|x| cell_at(&self.back, y, x) != cell_at(&self.front, y, x));
+                if changed && !whole_frame {
+                    if cursor != Some((y, x)) {
+                        out.move_to(to_u16(x), to_u16(y));
+                    }
+                    if current_style != Some(cell.style) {
+                        out.set_style(cell.style);
+                        current_style = Some(cell.style);
+                    }
+                    if cell.extra.is_empty() {
+                        out.print(cell.ch.encode_utf8(&mut [0; 4]));
+                    } else {
+                        out.print(&format!("{}{}", cell.ch, cell.extra));
+                    }
+                    cursor = Some((y, x + width));
+                }
+                if changed {
+                    self.changed_cells += 1;
+                }
+                x += width;
+            }
+        }
+        if current_style.is_some_and(|style| style != Style::PLAIN) {
+            out.set_style(Style::PLAIN);
+        }
+
+        self.front.clone_from(&self.back);
+    }
+}
+
+fn to_u16(value: usize) -> u16 {
+    u16::try_from(value).unwrap_or(u16::MAX)
+}
+
+/// Input-to-render latency readout drawn in the top-left corner by `play --debug-overlay`.
+#[derive(Default)]
+struct LatencyOverlay {
+    /// Oldest input not yet reflected in a presented frame.
+    pending_input: Option<Instant>,
+    last: Duration,
+    worst: Duration,
+}
+
+impl LatencyOverlay {
+    fn text(&self) -> String {
+        format!(
+            " input->render {:>6.1}ms  max {:>6.1}ms ",
+            self.last.as_secs_f64() * 1000.0,
+            self.worst.as_secs_f64() * 1000.0
+        )
+    }
+
+    fn frame_presented(&mut self, now: Instant) {
+        if let Some(input_at) = self.pending_input.take() {
+            self.last = now.saturating_duration_since(input_at);
+            self.worst = self.worst.max(self.last);
+        }
+    }
+}
+
+/// Owns the back buffer and the backend. Every draw function takes the
+/// renderer; `present` then sends the changes through the backend and ends the
+/// frame.
+pub struct Renderer {
+    screen: ScreenBuffer,
+    backend: Box<dyn Backend>,
+    /// Which terminal backend the settings asked for.
+    backend_kind: RenderBackend,
+    /// The board image of the pixel renderer.
+    pixels: Option<PixelBoard>,
+    theme: Theme,
+    /// Whether the UI language is written right to left, which lays out
+    /// every line drawn from the right.
+    right_to_left: bool,
+    effects: Effects,
+    toasts: Toasts,
+    hud_lines: HudLines,
+    latency_overlay: Option<LatencyOverlay>,
+    debug_overlay: Option<DebugOverlay>,
+    /// Board coordinates and the food's offset are drawn on the border.
+    practice_overlay: bool,
+    /// Board cell of the personal-best ghost's head this frame.
+    ghost: Option<Position>,
+    /// Whole seconds left before a paused run resumes by itself.
+    resume_countdown: Option<u64>,
+    /// The game-over panel offers to retry the end of the run.
+    retry_offered: bool,
+    /// When the renderer was made; blinking keeps time from here.
+    started: Instant,
+    /// The window title set last; `None` while the terminal shows its own.
+    title: Option<String>,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Self::with_backend(Box::new(CrosstermBackend::stdout()))
+    }
+
+    pub fn with_backend(backend: Box<dyn Backend>) -> Self {
+        Self {
+            screen: ScreenBuffer::new(),
+            backend,
+            backend_kind: RenderBackend::Ansi,
+            pixels: None,
+            theme: Theme::CLASSIC,
+            right_to_left: false,
+            effects: Effects::default(),
+            toasts: Toasts::default(),
+            hud_lines: HudLines::default(),
+            latency_overlay: None,
+            debug_overlay: None,
+            practice_overlay: false,
+            ghost: None,
+            resume_countdown: None,
+            retry_offered: false,
+            started: Instant::now(),
+            title: None,
+        }
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub(crate) fn theme(&self) -> Theme {
+        self.theme
+    }
+
+    pub fn set_right_to_left(&mut self, right_to_left: bool) {
+        self.right_to_left = right_to_left;
+    }
+
+    pub(crate) fn is_right_to_left(&self) -> bool {
+        self.right_to_left
+    }
+
+    /// Time since the renderer was made, as of `now`.
+    pub(crate) fn running_time(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.started)
+    }
+
+    pub(crate) fn effects(&mut self) -> &mut Effects {
+        &mut self.effects
+    }
+
+    pub(crate) fn toasts(&mut self) -> &mut Toasts {
+        &mut self.toasts
+    }
+
+    pub(crate) fn hud_lines(&mut self) -> &mut HudLines {
+        &mut self.hud_lines
+    }
+
+    /// When the oldest toast on screen should disappear.
+    pub fn next_toast_expiry(&self) -> Option<Instant> {
+        self.toasts.next_expiry()
+    }
+
+    pub fn show_latency_overlay(&mut self) {
+        self.latency_overlay = Some(LatencyOverlay::default());
+    }
+
+    /// Shows or hides the F3 debug overlay and returns whether it is now shown.
+    /// Hiding it leaves its cells behind until the view is redrawn in full.
+    pub fn toggle_debug_overlay(&mut self) -> bool {
+        self.debug_overlay = match self.debug_overlay {
+            Some(_) => None,
+            None => Some(DebugOverlay::new(Instant::now())),
+        };
+        self.debug_overlay.is_some()
+    }
+
+    /// Shows or hides the F2 practice overlay and returns whether it is now
+    /// shown. Like the debug overlay, hiding it leaves row numbers beside the
+    /// board until the view is redrawn in full.
+    pub fn toggle_practice_overlay(&mut self) -> bool {
+        self.practice_overlay = !self.practice_overlay;
+        self.practice_overlay
+    }
+
+    pub(crate) fn practice_overlay(&self) -> bool {
+        self.practice_overlay
+    }
+
+    /// Where the personal-best ghost's head is, or `None` to draw no ghost.
+    pub fn set_ghost(&mut self, head: Option<Position>) {
+        self.ghost = head;
+    }
+
+    pub(crate) fn ghost(&self) -> Option<Position> {
+        self.ghost
+    }
+
+    /// Seconds left before the paused run resumes, shown in the HUD in place
+    /// of the paused status, or `None` while it waits for a key.
+    pub fn set_resume_countdown(&mut self, seconds: Option<u64>) {
+        self.resume_countdown = seconds;
+    }
+
+    pub(crate) fn resume_countdown(&self) -> Option<u64> {
+        self.resume_countdown
+    }
+
+    /// Whether the game-over panel lists the key that retries the last few
+    /// ticks of the run.
+    pub fn set_retry_offered(&mut self, offered: bool) {
+        self.retry_offered = offered;
+    }
+
+    pub(crate) fn retry_offered(&self) -> bool {
+        self.retry_offered
+    }
+
+    /// The run the debug overlay reports on, or `None` outside of one.
+    pub fn set_debug_run(&mut self, run: Option<DebugRun>) {
+        if let Some(overlay) = &mut self.debug_overlay {
+            overlay.set_run(run);
+        }
+    }
+
+    /// Records that input arrived at `at`; the next `present` reports how long
+    /// it took to reach the screen.
+    pub fn note_input(&mut self, at: Instant) {
+        if let Some(overlay) = &mut self.latency_overlay {
+            overlay.pending_input.get_or_insert(at);
+        }
+    }
+
+    /// Switches how frames reach the terminal. The next frame redraws the
+    /// whole screen.
+    pub fn set_backend(&mut self, backend: RenderBackend) -> Result<(), String> {
+        if backend == self.backend_kind {
+            return Ok(());
+        }
+        let mut pixels = None;
+        let new_backend: Box<dyn Backend> = match backend {
+            RenderBackend::Ansi => Box::new(CrosstermBackend::stdout()),
+            RenderBackend::Pixels => {
+                let protocol = Protocol::detect().ok_or_else(|| {
+                    "the terminal supports neither kitty graphics nor sixel".to_string()
+                })?;
+                pixels = Some(PixelBoard::new(protocol));
+                Box::new(CrosstermBackend::stdout())
+            }
+            #[cfg(feature = "ratatui")]
+            RenderBackend::Ratatui => Box::new(
+                TuiBackend::new()
+                    .map_err(|err| format!("cannot start the ratatui renderer: {err}"))?,
+            ),
+            #[cfg(not(feature = "ratatui"))]
+            RenderBackend::Ratatui => {
+                return Err(
+                    "this build has no ratatui renderer (build with `--features ratatui`)"
+                        .to_string(),
+                );
+            }
+        };
+        if let Some(old) = &mut self.pixels {
+            old.cleared(self.backend.as_mut());
+            self.backend.flush();
+        }
+        self.backend = new_backend;
+        self.pixels = pixels;
+        self.backend_kind = backend;
+        self.screen.clear_pending = true;
+        Ok(())
+    }
+
+    /// Columns and rows of the screen the backend draws on.
+    pub fn size(&self) -> (u16, u16) {
+        self.backend.size()
+    }
+
+    /// Draws `text` starting at 1-based column `x` of row `y`, with any
+    /// right-to-left words turned around to read correctly.
+    pub(crate) fn put(&mut self, y: u16, x: u16, text: &str, style: Style) {
+        let text = visual_order(text, self.right_to_left);
+        self.screen.put(y, x, &text, style);
+    }
+
+    /// Blanks the whole screen, e.g. on entering a new view or after a resize.
+    pub(crate) fn clear(&mut self) {
+        self.screen.clear();
+        self.hud_lines.forget();
+        if let Some(pixels) = &mut self.pixels {
+            pixels.cleared(self.backend.as_mut());
+        }
+    }
+
+    /// Blanks the whole screen for a frame much like the last one, such as
+    /// after a camera scroll. Unlike `clear`, the terminal is not cleared
+    /// first, and the next `present` sends only what changed.
+    pub(crate) fn redraw(&mut self) {
+        self.screen.redraw();
+        self.hud_lines.forget();
+        if let Some(pixels) = &mut self.pixels {
+            pixels.cleared(self.backend.as_mut());
+        }
+    }
+
+    /// Blanks the whole screen after the terminal became `width` by
+    /// `height`. Unlike `clear`, the next `present` paints over the old
+    /// frame instead of clearing the terminal first.
+    pub(crate) fn resized(&mut self, width: u16, height: u16) {
+        self.screen.repaint(width, height);
+        self.backend.resized(width, height);
+        self.hud_lines.forget();
+        if let Some(pixels) = &mut self.pixels {
+            pixels.cleared(self.backend.as_mut());
+        }
+    }
+
+    /// The graphics protocol the board image is drawn with, if any.
+    pub(crate) fn pixel_protocol(&self) -> Option<Protocol> {
+        self.pixels.as_ref().map(PixelBoard::protocol)
+    }
+
+    /// What the board image shows from the next `present` on; `None` hides
+    /// it. Without the pixel renderer this does nothing.
+    pub(crate) fn set_board_image(&mut self, image: Option<BoardImage>) {
+        if let Some(pixels) = &mut self.pixels {
+            pixels.set(image);
+        }
+    }
+
+    pub(crate) fn clear_row(&mut self, y: u16) {
+        self.screen.clear_row(y);
+    }
+
+    /// Sends everything drawn since the last call to the backend in one go.
+    pub(crate) fn present(&mut self) {
+        let started = Instant::now();
+        if let Some(overlay) = &self.latency_overlay {
+            self.screen
+                .put(1, 1, &overlay.text(), Style::PLAIN.reversed());
+        }
+        if let Some(overlay) = &self.debug_overlay {
+            let first_row = 1 + u16::from(self.latency_overlay.is_some());
+            for (row, line) in (first_row..).zip(overlay.lines()) {
+                self.screen.put(row, 1, &line, Style::PLAIN.reversed());
+            }
+        }
+        self.screen.diff(self.backend.as_mut());
+        if let Some(pixels) = &mut self.pixels {
+            let cells_changed = self.screen.changed_cells > 0;
+            if pixels.present(self.backend.as_mut(), cells_changed) {
+                self.screen.clear_pending = true;
+                self.screen.diff(self.backend.as_mut());
+            }
+        }
+        self.backend.flush();
+        let now = Instant::now();
+        if let Some(overlay) = &mut self.latency_overlay {
+            overlay.frame_presented(now);
+        }
+        if let Some(overlay) = &mut self.debug_overlay {
+            overlay.frame_presented(
+                now,
+                now.saturating_duration_since(started),
+                self.screen.changed_cells,
+            );
+        }
+    }
+
+    /// Sets the window title, or with `None` gives the terminal its own title
+    /// back. Setting the title already shown sends nothing.
+    pub fn set_title(&mut self, title: Option<&str>) {
+        if self.title.as_deref() != title {
+            self.backend.set_title(title);
+            self.title = title.map(str::to_string);
+        }
+    }
+
+    /// Shows `text` as a toast on the board from the next frame on.
+    pub fn show_toast(&mut self, text: String) {
+        self.toasts.push(text, Instant::now());
+    }
+
+    pub(crate) fn copy_to_clipboard(&mut self, text: &str) {
+        self.backend.copy_to_clipboard(text);
+    }
+
+    /// The terminal bell stands in for every sound effect.
+    pub(crate) fn bell(&mut self) {
+        self.backend.bell();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use super::super::style::Color;
+    use crate::render::MemoryBackend;
+
+    /// The escape sequences `diff` sends.
+    fn diff(screen: &mut ScreenBuffer) -> String {
+        let memory = MemoryBackend::new(80, 24);
+        screen.diff(&mut memory.clone());
+        memory.take_transcript()
+    }
+
+    #[test]
+    fn present_writes_only_changed_cells() {
+        let mut screen = ScreenBuffer::new();
+        screen.put(2, 3, "abc", Style::fg(Color::BRIGHT_GREEN));
+        assert_eq!(
+            diff(&mut screen),
+            "\x1b[0m\x1b[2J\x1b[2;3H\x1b[0m\x1b[92mabc\x1b[0m"
+        );
+
+        screen.put(2, 3, "abc", Style::fg(Color::BRIGHT_GREEN));
+        assert_eq!(diff(&mut screen), "");
+
+        screen.put(2, 4, "X", Style::fg(Color::BRIGHT_GREEN));
+        screen.put(2, 5, "d", Style::PLAIN);
+        assert_eq!(diff(&mut screen), "\x1b[2;4H\x1b[0m\x1b[92mX\x1b[0md");
+    }
+
+    /// Keeps the last frame it was handed whole.
+    #[derive(Default)]
+    struct FrameBackend {
+        frame: Vec<Vec<Cell>>,
+        cells_printed: usize,
+    }
+
+    impl Backend for FrameBackend {
+        fn move_to(&mut self, _x: u16, _y: u16) {}
+        fn set_style(&mut self, _style: Style) {}
+        fn print(&mut self, _text: &str) {
+            self.cells_printed += 1;
+        }
+        fn clear(&mut self) {}
+        fn present_frame(&mut self, rows: &[Vec<Cell>]) -> bool {
+            self.frame = rows.to_vec();
+            true
+        }
+        fn flush(&mut self) {}
+        fn size(&self) -> (u16, u16) {
+            (80, 24)
+        }
+    }
+
+    #[test]
+    fn backends_that_take_whole_frames_get_every_cell() {
+        let mut screen = ScreenBuffer::new();
+        let mut backend = FrameBackend::default();
+        screen.put(1, 1, "ab", Style::PLAIN);
+        screen.diff(&mut backend);
+
+        screen.put(1, 1, "ab", Style::PLAIN);
+        screen.put(2, 1, "c", Style::fg(Color::BRIGHT_GREEN));
+        screen.diff(&mut backend);
+        assert_eq!(rows_text(&backend.frame, false), "ab\nc\n");
+        assert_eq!(screen.changed_cells, 1);
+        assert_eq!(backend.cells_printed, 0);
+    }
+
+    #[test]
+    fn wide_characters_span_two_cells() {
+        let mut screen = ScreenBuffer::new();
+        screen.put(1, 1, "日本", Style::PLAIN);
+        diff(&mut screen);
+
+        screen.put(1, 2, "x", Style::PLAIN);
+        assert_eq!(diff(&mut screen), "\x1b[1;1H\x1b[0m x");
+
+        screen.clear_row(1);
+        assert_eq!(diff(&mut screen), "\x1b[1;2H\x1b[0m   ");
+    }
+
+    #[test]
+    fn a_resize_repaints_every_cell_without_clearing() {
+        let mut screen = ScreenBuffer::new();
+        screen.put(1, 2, "ab", Style::PLAIN);
+        diff(&mut screen);
+
+        screen.repaint(3, 2);
+        screen.put(2, 1, "c", Style::PLAIN);
+        assert_eq!(diff(&mut screen), "\x1b[1;1H\x1b[0m   \x1b[2;1Hc  ");
+        assert_eq!(diff(&mut screen), "");
+    }
+
+    #[test]
+    fn window_title_is_sent_only_when_it_changes() {
+        let memory = MemoryBackend::new(80, 24);
+        let mut renderer = Renderer::with_backend(Box::new(memory.clone()));
+        renderer.set_title(None);
+        renderer.set_title(Some("Rustnake — Score: 10"));
+        renderer.set_title(Some("Rustnake — Score: 10"));
+        assert_eq!(memory.take_transcript(), "\x1b]0;Rustnake — Score: 10\x07");
+
+        renderer.set_title(None);
+        assert_eq!(memory.take_transcript(), "\x1b[23;0t\x1b[22;0t");
+    }
+
+    #[test]
+    fn latency_overlay_measures_the_oldest_pending_input() {
+        let start = Instant::now();
+        let mut overlay = LatencyOverlay::default();
+        overlay.frame_presented(start + Duration::from_millis(5));
+        assert_eq!(overlay.last, Duration::ZERO);
+
+        overlay.pending_input.get_or_insert(start);
+        overlay
+            .pending_input
+            .get_or_insert(start + Duration::from_millis(10));
+        overlay.frame_presented(start + Duration::from_millis(12));
+        overlay
+            .pending_input
+            .get_or_insert(start + Duration::from_millis(20));
+        overlay.frame_presented(start + Duration::from_millis(23));
+
+        assert_eq!(overlay.last, Duration::from_millis(3));
+        assert_eq!(overlay.worst, Duration::from_millis(12));
+        assert_eq!(overlay.text(), " input->render    3.0ms  max   12.0ms ");
+    }
+}
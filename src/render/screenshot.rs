@@ -0,0 +1,70 @@
+//! `screenshot`: one frame of a game drawn into the back buffer and written
+//! out as text, for the docs and for bug reports about rendering.
+
+use super::{MemoryBackend, Renderer, Theme, gameplay};
+use crate::core::Game;
+use crate::i18n;
+use crate::layout::{self, Hud};
+use crate::utils::Language;
+
+/// Terminal width the frame is laid out for, when the board fits: wide
+/// enough for the full status bar, where the minimum gets a shortened one.
+const SCREENSHOT_WIDTH: u16 = 100;
+
+/// The frame `game` shows right now, as plain text or with ANSI colors.
+pub fn screenshot(
+    game: &mut Game,
+    theme: Theme,
+    square_cells: bool,
+    language: Language,
+    ansi: bool,
+) -> String {
+    let size = layout::min_terminal_size(
+        game.width,
+        game.height,
+        square_cells,
+        Hud::default(),
+        language,
+    );
+    let layout = layout::compute_layout(
+        size.width.max(SCREENSHOT_WIDTH),
+        size.height,
+        game.width,
+        game.height,
+        square_cells,
+        Hud::default(),
+        language,
+    )
+    .expect("the minimum terminal size fits the board");
+    let width = size.width.max(SCREENSHOT_WIDTH);
+    let memory = MemoryBackend::new(width, size.height);
+    let mut renderer = Renderer::with_backend(Box::new(memory.clone()));
+    renderer.set_theme(theme);
+    renderer.set_right_to_left(i18n::is_right_to_left(language));
+    gameplay::draw_static_frame(&mut renderer, &layout);
+    gameplay::draw(&mut renderer, game, &layout, language);
+    memory.contents(ansi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::backend::sgr;
+    use super::*;
+    use crate::utils::{self, Difficulty};
+
+    #[test]
+    fn screenshot_shows_the_board_and_hud() {
+        let _guard = super::super::render_test_lock()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut game = Game::new_seeded(Difficulty::Easy, utils::WIDTH, utils::HEIGHT, 0, 7);
+
+        let plain = screenshot(&mut game, Theme::CLASSIC, false, Language::En, false);
+        assert!(plain.contains('┌') && plain.contains('◀') && plain.contains("Best:"));
+        assert!(!plain.contains('\x1b'));
+        assert!(plain.lines().all(|line| line == line.trim_end()));
+
+        let ansi = screenshot(&mut game, Theme::CLASSIC, false, Language::En, true);
+        assert!(ansi.contains(&sgr(Theme::CLASSIC.snake_head)));
+    }
+}
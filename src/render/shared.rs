@@ -1,16 +1,11 @@
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
-
-pub(crate) const ANSI_RESET: &str = "\x1b[0m";
-pub(crate) const STYLE_MENU_BORDER: &str = "\x1b[38;2;89;138;207m";
-pub(crate) const STYLE_MENU_LOGO: &str = "\x1b[1;38;2;219;224;232m";
-pub(crate) const STYLE_MENU_TITLE: &str = "\x1b[1;97m";
-pub(crate) const STYLE_MENU_SUBTITLE: &str = "\x1b[2;37m";
-pub(crate) const STYLE_MENU_HINT: &str = "\x1b[2;37m";
-pub(crate) const STYLE_MENU_OPTION: &str = "\x1b[97m";
-pub(crate) const STYLE_MENU_OPTION_DANGER: &str = "\x1b[91m";
-pub(crate) const STYLE_MENU_OPTION_SELECTED_MID: &str = "\x1b[1;38;2;255;255;255;48;2;89;138;207m";
-pub(crate) const STYLE_MENU_OPTION_SELECTED_DANGER: &str = "\x1b[1;97;41m";
-pub(crate) const STYLE_MENU_TEXTURE: &str = "\x1b[38;2;96;103;117m";
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+use super::Renderer;
+use super::style::Style;
 
 pub(crate) const MENU_LOGO: &str = "Rustnake";
 
@@ -36,8 +31,48 @@ pub(crate) fn center_start(total: u16, content: u16) -> u16 {
     total.saturating_sub(content) / 2 + 1
 }
 
+/// Whether East Asian ambiguous-width characters, such as `─`, `★`, and
+/// `→`, take two columns, as in terminals set up for CJK text.
+static WIDE_AMBIGUOUS: AtomicBool = AtomicBool::new(false);
+
+/// Tells the width helpers how the terminal shows ambiguous-width
+/// characters (`wide_ambiguous` in the settings).
+pub fn set_wide_ambiguous(wide: bool) {
+    WIDE_AMBIGUOUS.store(wide, Ordering::Relaxed);
+}
+
+/// Columns one grapheme cluster takes on screen. Emoji joined with ZWJ,
+/// with a skin tone or the emoji variation selector, and flag pairs show as
+/// one wide emoji; otherwise the first character sets the width, and
+/// combining marks after it add none.
+pub(crate) fn cluster_width(cluster: &str) -> usize {
+    cluster_columns(cluster, WIDE_AMBIGUOUS.load(Ordering::Relaxed))
+}
+
+fn cluster_columns(cluster: &str, wide_ambiguous: bool) -> usize {
+    let mut chars = cluster.chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+    let mut rest = chars.peekable();
+    let flag = ('\u{1F1E6}'..='\u{1F1FF}').contains(&first) && rest.peek().is_some();
+    if flag || rest.any(|ch| matches!(ch, '\u{200D}' | '\u{FE0F}' | '\u{1F3FB}'..='\u{1F3FF}')) {
+        return 2;
+    }
+    let width = if wide_ambiguous {
+        first.width_cjk()
+    } else {
+        first.width()
+    };
+    width.unwrap_or(0)
+}
+
+/// Columns `text` takes on screen, cluster by cluster as the cell buffer
+/// lays it out. That is not always `UnicodeWidthStr::width`, which counts
+/// an Arabic lam-alef pair as one column where terminals that do not shape
+/// Arabic show two letters.
 pub(crate) fn display_width(text: &str) -> u16 {
-    UnicodeWidthStr::width(text) as u16
+    text.graphemes(true).map(cluster_width).sum::<usize>() as u16
 }
 
 pub(crate) fn clip_by_display_width(text: &str, max_width: u16) -> String {
@@ -48,24 +83,30 @@ pub(crate) fn clip_by_display_width(text: &str, max_width: u16) -> String {
     let mut clipped = String::new();
     let mut width_used: u16 = 0;
 
-    for ch in text.chars() {
-        let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0) as u16;
-        if ch_width > 0 && width_used.saturating_add(ch_width) > max_width {
+    for cluster in text.graphemes(true) {
+        let cluster_width = cluster_width(cluster) as u16;
+        if cluster_width > 0 && width_used.saturating_add(cluster_width) > max_width {
             break;
         }
-        clipped.push(ch);
-        width_used = width_used.saturating_add(ch_width);
+        clipped.push_str(cluster);
+        width_used = width_used.saturating_add(cluster_width);
     }
 
     clipped
 }
 
-pub(crate) fn print_clipped(y: u16, x: u16, text: &str, max_width: u16) {
+pub(crate) fn put_clipped(
+    renderer: &mut Renderer,
+    y: u16,
+    x: u16,
+    text: &str,
+    max_width: u16,
+    style: Style,
+) {
     if max_width == 0 {
         return;
     }
-    let clipped = clip_by_display_width(text, max_width);
-    print!("\x1b[{};{}H{}", y, x, clipped);
+    renderer.put(y, x, &clip_by_display_width(text, max_width), style);
 }
 
 pub(crate) fn pad_to_display_width(text: &str, target_width: u16) -> String {
@@ -76,94 +117,90 @@ pub(crate) fn pad_to_display_width(text: &str, target_width: u16) -> String {
     format!("{}{}", text, " ".repeat((target_width - current) as usize))
 }
 
-pub(crate) fn draw_centered_line(y: u16, term_width: u16, text: &str) {
-    draw_centered_line_styled(y, term_width, text, "");
+pub(crate) fn draw_centered_line(renderer: &mut Renderer, y: u16, term_width: u16, text: &str) {
+    draw_centered_line_styled(renderer, y, term_width, text, Style::PLAIN);
 }
 
-pub(crate) fn draw_centered_line_styled(y: u16, term_width: u16, text: &str, style: &str) {
-    print!("\x1b[{};1H\x1b[K", y);
+pub(crate) fn draw_centered_line_styled(
+    renderer: &mut Renderer,
+    y: u16,
+    term_width: u16,
+    text: &str,
+    style: Style,
+) {
+    renderer.clear_row(y);
     if term_width == 0 {
         return;
     }
     let text_len = display_width(text);
     let draw_len = text_len.min(term_width);
     let start_x = center_start(term_width, draw_len);
-    if !style.is_empty() {
-        print!("{}", style);
-    }
-    print_clipped(y, start_x, text, draw_len);
-    if !style.is_empty() {
-        print!("{}", ANSI_RESET);
-    }
+    put_clipped(renderer, y, start_x, text, draw_len, style);
 }
 
-pub(crate) fn draw_box_line_styled(y: u16, x: u16, inner_width: u16, text: &str, text_style: &str) {
-    print!(
-        "{}\x1b[{};{}H│{}│{}",
-        STYLE_MENU_BORDER,
+pub(crate) fn draw_box_line_styled(
+    renderer: &mut Renderer,
+    y: u16,
+    x: u16,
+    inner_width: u16,
+    text: &str,
+    text_style: Style,
+) {
+    let theme = renderer.theme();
+    renderer.put(
         y,
         x,
-        " ".repeat(inner_width as usize),
-        ANSI_RESET
+        &format!("│{}│", " ".repeat(inner_width as usize)),
+        theme.border,
     );
     let clipped = clip_by_display_width(text, inner_width);
     let text_x = x + 1 + (inner_width.saturating_sub(display_width(&clipped)) / 2);
-    if !text_style.is_empty() {
-        print!("{}", text_style);
-    }
-    print_clipped(y, text_x, &clipped, inner_width);
-    if !text_style.is_empty() {
-        print!("{}", ANSI_RESET);
-    }
+    put_clipped(renderer, y, text_x, &clipped, inner_width, text_style);
 }
 
 pub(crate) fn draw_panel_frame(
+    renderer: &mut Renderer,
     y: u16,
     x: u16,
     inner_width: u16,
     inner_height: u16,
-    border_style: &str,
+    border_style: Style,
 ) {
-    print!(
-        "{}\x1b[{};{}H┌{}┐{}",
-        border_style,
-        y,
-        x,
-        "─".repeat(inner_width as usize),
-        ANSI_RESET
-    );
+    let horizontal = "─".repeat(inner_width as usize);
+    renderer.put(y, x, &format!("┌{horizontal}┐"), border_style);
+    let middle = format!("│{}│", " ".repeat(inner_width as usize));
     for line_y in (y + 1)..=(y + inner_height) {
-        print!(
-            "{}\x1b[{};{}H│{}│{}",
-            border_style,
-            line_y,
-            x,
-            " ".repeat(inner_width as usize),
-            ANSI_RESET
-        );
+        renderer.put(line_y, x, &middle, border_style);
     }
-    print!(
-        "{}\x1b[{};{}H└{}┘{}",
-        border_style,
+    renderer.put(
         y + inner_height + 1,
         x,
-        "─".repeat(inner_width as usize),
-        ANSI_RESET
+        &format!("└{horizontal}┘"),
+        border_style,
     );
 }
 
-pub(crate) fn draw_panel_separator(y: u16, x: u16, inner_width: u16, border_style: &str) {
-    print!(
-        "{}\x1b[{};{}H├{}┤{}",
-        border_style,
+pub(crate) fn draw_panel_separator(
+    renderer: &mut Renderer,
+    y: u16,
+    x: u16,
+    inner_width: u16,
+    border_style: Style,
+) {
+    renderer.put(
         y,
         x,
-        "─".repeat(inner_width as usize),
-        ANSI_RESET
+        &format!("├{}┤", "─".repeat(inner_width as usize)),
+        border_style,
     );
 }
 
-pub(crate) fn draw_menu_texture_region(texture: TextureContext, region: Rect) {
+pub(crate) fn draw_menu_texture_region(
+    renderer: &mut Renderer,
+    texture: TextureContext,
+    region: Rect,
+) {
+    let theme = renderer.theme();
     let region_start_x = region.start_x.max(1).min(texture.term_width.max(1));
     let region_end_x = region
         .end_x
@@ -189,18 +226,15 @@ pub(crate) fn draw_menu_texture_region(texture: TextureContext, region: Rect) {
             // Keep menu background fully clean to avoid visual speckles across terminals.
             row.push(' ');
         }
-        print!(
-            "{}\x1b[{};{}H{}{}",
-            STYLE_MENU_TEXTURE, y, region_start_x, row, ANSI_RESET
-        );
+        renderer.put(y, region_start_x, &row, theme.texture);
     }
 }
 
-pub(crate) fn clear_rect(rect: Rect) {
+pub(crate) fn clear_rect(renderer: &mut Renderer, rect: Rect) {
     let width = rect.end_x.saturating_sub(rect.start_x).saturating_add(1) as usize;
     let blank = " ".repeat(width);
     for y in rect.start_y..=rect.end_y {
-        print!("\x1b[{};{}H{}", y, rect.start_x, blank);
+        renderer.put(y, rect.start_x, &blank, Style::PLAIN);
     }
 }
 
@@ -236,12 +270,153 @@ pub(crate) fn clip_rect_to_bounds(
     })
 }
 
-pub(crate) fn clear_rect_clipped(rect: Rect, bounds_width: u16, bounds_height: u16) {
+pub(crate) fn clear_rect_clipped(
+    renderer: &mut Renderer,
+    rect: Rect,
+    bounds_width: u16,
+    bounds_height: u16,
+) {
     if let Some(clipped) = clip_rect_to_bounds(rect, bounds_width, bounds_height) {
-        clear_rect(clipped);
+        clear_rect(renderer, clipped);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Strength {
+    LeftToRight,
+    RightToLeft,
+    Neutral,
+}
+
+fn strength(ch: char) -> Strength {
+    match ch {
+        // Arabic-Indic digits read left to right like any other number.
+        '\u{0660}'..='\u{0669}' | '\u{06F0}'..='\u{06F9}' => Strength::LeftToRight,
+        // Hebrew, Arabic, Syriac, Thaana, N'Ko and their presentation forms.
+        '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}' => {
+            Strength::RightToLeft
+        }
+        _ if ch.is_alphanumeric() => Strength::LeftToRight,
+        _ => Strength::Neutral,
+    }
+}
+
+/// The character a mirrored run shows in place of `ch`, so brackets still
+/// open towards the text they enclose.
+fn mirrored(ch: char) -> char {
+    match ch {
+        '(' => ')',
+        ')' => '(',
+        '<' => '>',
+        '>' => '<',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '«' => '»',
+        '»' => '«',
+        other => other,
     }
 }
 
+/// `text` in the order its characters appear on screen, left to right. The
+/// cell buffer writes characters one after another, so right-to-left words
+/// have to be turned around first; this is a cut-down Unicode bidi pass
+/// without embedding levels, which is enough for single lines of UI text.
+///
+/// `right_to_left` is the paragraph direction: in a right-to-left UI the
+/// runs of the line are laid out from the right, so a row padded at the end
+/// comes out right-aligned and labels stand to the right of their values.
+/// Text without right-to-left letters is returned as is either way.
+pub(crate) fn visual_order(text: &str, right_to_left: bool) -> Cow<'_, str> {
+    if !text.chars().any(|ch| strength(ch) == Strength::RightToLeft) {
+        return Cow::Borrowed(text);
+    }
+    let base = if right_to_left {
+        Strength::RightToLeft
+    } else {
+        Strength::LeftToRight
+    };
+
+    // Whole clusters move, so marks such as Arabic vowel signs stay after
+    // their letter.
+    let mut clusters: Vec<(Strength, &str)> = text
+        .graphemes(true)
+        .map(|cluster| {
+            let first = cluster.chars().next().unwrap_or(' ');
+            (strength(first), cluster)
+        })
+        .collect();
+
+    // Neutrals between two runs of the same direction take it; the others
+    // take the paragraph direction.
+    let mut index = 0;
+    while index < clusters.len() {
+        if clusters[index].0 != Strength::Neutral {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < clusters.len() && clusters[index].0 == Strength::Neutral {
+            index += 1;
+        }
+        let before = start
+            .checked_sub(1)
+            .map_or(base, |before| clusters[before].0);
+        let after = clusters.get(index).map_or(base, |(after, _)| *after);
+        let resolved = if before == after { before } else { base };
+        for (direction, _) in &mut clusters[start..index] {
+            *direction = resolved;
+        }
+    }
+
+    let mut runs: Vec<(Strength, Vec<&str>)> = Vec::new();
+    for (direction, cluster) in clusters {
+        match runs.last_mut() {
+            Some((run_direction, run)) if *run_direction == direction => run.push(cluster),
+            _ => runs.push((direction, vec![cluster])),
+        }
+    }
+    if right_to_left {
+        runs.reverse();
+    }
+    let mut visual = String::with_capacity(text.len());
+    for (direction, run) in runs {
+        if direction == Strength::RightToLeft {
+            for cluster in run.iter().rev() {
+                let mut chars = cluster.chars();
+                visual.extend(chars.next().map(mirrored));
+                visual.extend(chars);
+            }
+        } else {
+            visual.extend(run);
+        }
+    }
+    Cow::Owned(visual)
+}
+
+/// Standard base64 with padding, as OSC 52 and the kitty graphics protocol
+/// expect.
+pub(crate) fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (index, byte)| {
+            group | u32::from(*byte) << (16 - 8 * index)
+        });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                out.push(char::from(
+                    ALPHABET[(group >> (18 - 6 * index) & 63) as usize],
+                ));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,4 +453,33 @@ mod tests {
 
         assert_eq!(clip_rect_to_bounds(rect, 120, 40), None);
     }
+
+    #[test]
+    fn right_to_left_text_is_turned_around_for_the_screen() {
+        assert_eq!(visual_order("Score: 40", true), "Score: 40");
+        // A right-to-left word inside left-to-right text.
+        assert_eq!(visual_order("Language: עברית", false), "Language: תירבע");
+        // A right-to-left line: runs from the right, brackets mirrored,
+        // numbers kept in reading order, trailing padding on the left.
+        assert_eq!(visual_order("> [1] אבג 120  ", true), "  120 גבא [1] <");
+        assert_eq!(visual_order("(אב)", true), "(בא)");
+        // Vowel marks stay after their letter.
+        assert_eq!(visual_order("كبّر", true), "ربّك");
+        assert_eq!(display_width("خلايا"), 5);
+    }
+
+    #[test]
+    fn width_counts_grapheme_clusters() {
+        // A family joined with ZWJ, a flag, and a skin-toned hand.
+        assert_eq!(display_width("👨\u{200D}👩\u{200D}👧"), 2);
+        assert_eq!(display_width("🇪🇸 ok"), 5);
+        assert_eq!(display_width("👍\u{1F3FD}"), 2);
+        // A combining accent takes no column of its own.
+        assert_eq!(display_width("cafe\u{301}"), 4);
+        assert_eq!(clip_by_display_width("e\u{301}e\u{301}", 1), "e\u{301}");
+
+        assert_eq!(cluster_columns("★", false), 1);
+        assert_eq!(cluster_columns("★", true), 2);
+        assert_eq!(cluster_columns("a", true), 1);
+    }
 }
@@ -0,0 +1,103 @@
+//! The effects panel to the right of the board: the timed effects in play,
+//! each with a bar of the time it has left, and what every power-up letter
+//! on the board stands for.
+
+use crate::core::Game;
+use crate::i18n;
+use crate::layout::{Layout, SIDEBAR_WIDTH};
+use crate::utils::{Language, PowerUpType};
+
+use super::Renderer;
+use super::gameplay::power_up_symbol;
+use super::shared::{clip_by_display_width, display_width};
+use super::style::Style;
+
+/// One line of the panel, as runs of text in their own styles.
+type Line = Vec<(String, Style)>;
+
+/// Draws the panel when the layout has room for it. Every row beside the
+/// board is written in full, so effects that ran out leave nothing behind.
+pub(crate) fn draw_sidebar(
+    renderer: &mut Renderer,
+    game: &Game,
+    layout: &Layout,
+    language: Language,
+) {
+    let Some(x) = layout.sidebar_x else {
+        return;
+    };
+    let lines = sidebar_lines(renderer, game, language);
+    let rows = layout.origin_y..=layout.map_bottom();
+    for (y, line) in rows.zip(lines.into_iter().chain(std::iter::repeat(Vec::new()))) {
+        let mut column = 0;
+        for (text, style) in line {
+            let text = clip_by_display_width(&text, SIDEBAR_WIDTH - column);
+            renderer.put(y, x + column, &text, style);
+            column += display_width(&text);
+        }
+        if column < SIDEBAR_WIDTH {
+            let blank = " ".repeat(usize::from(SIDEBAR_WIDTH - column));
+            renderer.put(y, x + column, &blank, Style::PLAIN);
+        }
+    }
+}
+
+fn sidebar_lines(renderer: &Renderer, game: &Game, language: Language) -> Vec<Line> {
+    let theme = renderer.theme();
+    let mut lines: Vec<Line> = vec![vec![(
+        i18n::sidebar_effects_title(language).to_string(),
+        theme.title,
+    )]];
+    if game.active_effects.is_empty() {
+        lines.push(vec![("-".to_string(), theme.hint)]);
+    }
+    let duration = game.effect_duration_ticks().max(1);
+    for effect in &game.active_effects {
+        let mut name = i18n::power_up_name(language, effect.kind).to_string();
+        if let Some(multiplier) = effect.score_multiplier() {
+            name.push_str(&format!(" x{multiplier}"));
+        }
+        lines.push(vec![(name, theme.option)]);
+        let filled = effect_bar_fill(effect.ticks_left, duration);
+        lines.push(vec![
+            (
+                theme.glyphs.bar_fill.repeat(usize::from(filled)),
+                theme.power_up(effect.kind),
+            ),
+            (
+                theme
+                    .glyphs
+                    .bar_track
+                    .repeat(usize::from(SIDEBAR_WIDTH - filled)),
+                theme.hint,
+            ),
+        ]);
+    }
+
+    lines.push(Vec::new());
+    lines.push(vec![(
+        i18n::sidebar_legend_title(language).to_string(),
+        theme.title,
+    )]);
+    for kind in PowerUpType::ALL
+        .iter()
+        .filter(|kind| !game.disabled_power_ups.contains(**kind))
+    {
+        lines.push(vec![
+            (
+                format!("{} ", power_up_symbol(*kind)),
+                theme.power_up(*kind),
+            ),
+            (i18n::power_up_name(language, *kind).to_string(), theme.hint),
+        ]);
+    }
+    lines
+}
+
+/// Filled columns of an effect bar with `ticks_left` of `duration` ticks to
+/// go. Any time left at all shows as at least one column.
+fn effect_bar_fill(ticks_left: u32, duration: u32) -> u16 {
+    let filled = (u64::from(ticks_left.min(duration)) * u64::from(SIDEBAR_WIDTH))
+        .div_ceil(u64::from(duration));
+    u16::try_from(filled).unwrap_or(SIDEBAR_WIDTH)
+}
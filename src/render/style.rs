@@ -0,0 +1,105 @@
+//! Text styles as the draw code names them: a foreground, a background, and
+//! attributes. Backends turn them into whatever they draw with, escape
+//! sequences for the terminal itself.
+
+/// A color from the terminal's palette or a 24-bit one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// One of the 16 palette colors: 0-7 normal, 8-15 bright.
+    Named(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    pub const BLACK: Self = Self::Named(0);
+    pub const RED: Self = Self::Named(1);
+    pub const GREEN: Self = Self::Named(2);
+    pub const YELLOW: Self = Self::Named(3);
+    pub const MAGENTA: Self = Self::Named(5);
+    pub const WHITE: Self = Self::Named(7);
+    pub const GRAY: Self = Self::Named(8);
+    pub const BRIGHT_RED: Self = Self::Named(9);
+    pub const BRIGHT_GREEN: Self = Self::Named(10);
+    pub const BRIGHT_YELLOW: Self = Self::Named(11);
+    pub const BRIGHT_BLUE: Self = Self::Named(12);
+    pub const BRIGHT_MAGENTA: Self = Self::Named(13);
+    pub const BRIGHT_CYAN: Self = Self::Named(14);
+    pub const BRIGHT_WHITE: Self = Self::Named(15);
+}
+
+/// A set of text attributes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Attrs(u8);
+
+impl Attrs {
+    pub const BOLD: Self = Self(1);
+    pub const DIM: Self = Self(1 << 1);
+    pub const UNDERLINE: Self = Self(1 << 2);
+    pub const REVERSE: Self = Self(1 << 3);
+
+    pub const fn contains(self, attrs: Self) -> bool {
+        self.0 & attrs.0 == attrs.0
+    }
+}
+
+/// How a cell is drawn. The default is the terminal's own style.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub attrs: Attrs,
+}
+
+impl Style {
+    pub const PLAIN: Self = Self {
+        fg: None,
+        bg: None,
+        attrs: Attrs(0),
+    };
+
+    /// Text in `color`.
+    pub const fn fg(color: Color) -> Self {
+        Self {
+            fg: Some(color),
+            ..Self::PLAIN
+        }
+    }
+
+    /// This style over a `color` background.
+    pub const fn on(self, color: Color) -> Self {
+        Self {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    pub const fn with(self, attrs: Attrs) -> Self {
+        Self {
+            attrs: Attrs(self.attrs.0 | attrs.0),
+            ..self
+        }
+    }
+
+    pub const fn without(self, attrs: Attrs) -> Self {
+        Self {
+            attrs: Attrs(self.attrs.0 & !attrs.0),
+            ..self
+        }
+    }
+
+    pub const fn bold(self) -> Self {
+        self.with(Attrs::BOLD)
+    }
+
+    pub const fn dim(self) -> Self {
+        self.with(Attrs::DIM)
+    }
+
+    pub const fn underlined(self) -> Self {
+        self.with(Attrs::UNDERLINE)
+    }
+
+    pub const fn reversed(self) -> Self {
+        self.with(Attrs::REVERSE)
+    }
+}
@@ -0,0 +1,113 @@
+//! One-line descriptions of the game for `text-mode`, meant to be read out by
+//! a screen reader instead of drawing the board.
+
+use crate::core::Game;
+use crate::i18n;
+use crate::utils::{Language, Position};
+
+use super::hud::format_play_time;
+
+/// Where the food (and any power-up) is relative to the head, plus length and score.
+pub fn describe_state(game: &Game, language: Language) -> String {
+    let head = game.snake.head_position();
+    let mut parts = vec![format!(
+        "{} {}",
+        i18n::text_food_label(language),
+        bearing(language, head, game.food)
+    )];
+    if let Some(power_up) = game.power_up {
+        parts.push(format!(
+            "{} {}",
+            i18n::power_up_name(language, power_up.power_up_type),
+            bearing(language, head, power_up.position)
+        ));
+    }
+    parts.push(format!(
+        "{} {}",
+        i18n::text_length_label(language),
+        game.snake.body().len()
+    ));
+    parts.push(format!(
+        "{} {}",
+        i18n::status_score_label(language),
+        game.score
+    ));
+    parts.join(", ")
+}
+
+pub fn describe_game_over(game: &Game, language: Language) -> String {
+    format!(
+        "{} {} {}",
+        i18n::game_over_title(language),
+        i18n::status_score_label(language),
+        game.score
+    )
+}
+
+/// A finished run in one line to paste elsewhere, such as
+/// "Rustnake — 430 points, Hard, 3:12".
+pub fn share_result(game: &Game, language: Language) -> String {
+    format!(
+        "Rustnake — {}, {}, {}",
+        i18n::points(language, game.score),
+        i18n::difficulty_label(language, game.difficulty),
+        format_play_time(game.stats.play_time)
+    )
+}
+
+/// Compass direction and step count, ignoring wrap-around edges.
+fn bearing(language: Language, from: Position, to: Position) -> String {
+    let dx = i32::from(to.x) - i32::from(from.x);
+    let dy = i32::from(to.y) - i32::from(from.y);
+    format!(
+        "{} {}",
+        i18n::text_bearing(language, dx, dy),
+        dx.unsigned_abs() + dy.unsigned_abs()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Difficulty;
+    use std::time::Duration;
+
+    #[test]
+    fn state_names_food_direction_distance_length_and_score() {
+        let mut game = Game::new_seeded(Difficulty::Easy, 20, 12, 0, 7);
+        let head = game.snake.head_position();
+        game.food = Position {
+            x: head.x - 2,
+            y: head.y - 3,
+        };
+        game.power_up = None;
+        game.score = 120;
+
+        assert_eq!(
+            describe_state(&game, Language::En),
+            format!(
+                "Food up-left 5, Length {}, Score 120",
+                game.snake.body().len()
+            )
+        );
+        assert_eq!(
+            describe_game_over(&game, Language::En),
+            "GAME OVER! Score 120"
+        );
+    }
+
+    #[test]
+    fn share_result_names_score_difficulty_and_time() {
+        let mut game = Game::new_seeded(Difficulty::Hard, 20, 12, 0, 7);
+        game.score = 430;
+        game.stats.play_time = Duration::from_secs(192);
+        assert_eq!(
+            share_result(&game, Language::En),
+            "Rustnake — 430 points, Hard, 3:12"
+        );
+        assert_eq!(
+            share_result(&game, Language::Ja),
+            "Rustnake — 430点, 難しい, 3:12"
+        );
+    }
+}
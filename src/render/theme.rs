@@ -0,0 +1,423 @@
+//! Color themes: the built-in palettes plus the custom ones from the config.
+
+use super::style::{Attrs, Color, Style};
+use crate::storage::{CustomTheme, Rgb, Settings};
+use crate::utils::{BoardPattern, PowerUpType, SymbolSet};
+
+/// Styles for every themed part of the UI.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Theme {
+    pub(crate) border: Style,
+    pub(crate) logo: Style,
+    pub(crate) title: Style,
+    pub(crate) subtitle: Style,
+    pub(crate) hint: Style,
+    pub(crate) option: Style,
+    pub(crate) option_danger: Style,
+    pub(crate) option_selected: Style,
+    pub(crate) option_selected_danger: Style,
+    /// The caret cell of a text field.
+    pub(crate) text_cursor: Style,
+    pub(crate) texture: Style,
+    /// The board pattern, which should stay in the background.
+    pub(crate) board: Style,
+    pub(crate) snake_head: Style,
+    pub(crate) snake_body: Style,
+    pub(crate) snake_mid: Style,
+    pub(crate) snake_tail: Style,
+    /// The snake during the death animation.
+    pub(crate) snake_dead: Style,
+    pub(crate) food: Style,
+    /// One style per power-up, in `PowerUpType::ALL` order.
+    power_ups: [Style; PowerUpType::ALL.len()],
+    /// Body segments cycle through `RAINBOW_BODY` instead of the snake colors.
+    pub(crate) rainbow_snake: bool,
+    pub(crate) glyphs: &'static Glyphs,
+    pub(crate) board_pattern: BoardPattern,
+}
+
+/// Characters for the board sprites and the bars that not every font
+/// has. The border and body segments are box drawing, which they all do.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Glyphs {
+    pub(crate) food: &'static str,
+    /// Food while the score is a nonzero multiple of 50.
+    pub(crate) bonus_food: &'static str,
+    /// The snake's head facing up, down, left, and right.
+    pub(crate) head: [&'static str; 4],
+    /// A body segment with no neighbor to join.
+    pub(crate) lone_segment: &'static str,
+    /// One per sparkle frame, first to last.
+    pub(crate) sparkles: [&'static str; 3],
+    /// The filled and empty parts of menu scrollbars and effect timers.
+    pub(crate) bar_fill: &'static str,
+    pub(crate) bar_track: &'static str,
+    /// `BoardPattern::Dots` in every cell.
+    pub(crate) board_dot: &'static str,
+    /// The shaded cells of `BoardPattern::Checkerboard`.
+    pub(crate) board_shade: &'static str,
+    /// The personal-best ghost's head.
+    pub(crate) ghost: &'static str,
+}
+
+impl Glyphs {
+    pub(crate) const FANCY: Self = Self {
+        food: "●",
+        bonus_food: "★",
+        head: ["▲", "▼", "◀", "▶"],
+        lone_segment: "■",
+        sparkles: ["✦", "✧", "·"],
+        bar_fill: "█",
+        bar_track: "░",
+        board_dot: "·",
+        board_shade: "░",
+        ghost: "○",
+    };
+
+    /// ASCII only. Power-ups already are, so the head and lone segments use
+    /// characters none of them does.
+    pub(crate) const COMPATIBLE: Self = Self {
+        food: "o",
+        bonus_food: "*",
+        head: ["@"; 4],
+        lone_segment: "=",
+        sparkles: ["*", "*", "."],
+        bar_fill: "#",
+        bar_track: ".",
+        board_dot: ".",
+        board_shade: ":",
+        ghost: "%",
+    };
+
+    pub(crate) fn of(symbols: SymbolSet) -> &'static Self {
+        match symbols {
+            SymbolSet::Fancy => &Self::FANCY,
+            SymbolSet::Compatible => &Self::COMPATIBLE,
+        }
+    }
+}
+
+/// Body colors of the rainbow theme, repeating from the neck to the tail.
+pub(crate) const RAINBOW_BODY: [Style; 6] = [
+    Style::fg(Color::BRIGHT_RED),
+    Style::fg(Color::Rgb(255, 165, 0)),
+    Style::fg(Color::BRIGHT_YELLOW),
+    Style::fg(Color::BRIGHT_GREEN),
+    Style::fg(Color::BRIGHT_BLUE),
+    Style::fg(Color::BRIGHT_MAGENTA),
+];
+
+impl Theme {
+    pub const CLASSIC: Self = Self {
+        border: Style::fg(Color::Rgb(89, 138, 207)),
+        logo: Style::fg(Color::Rgb(219, 224, 232)).bold(),
+        title: Style::fg(Color::BRIGHT_WHITE).bold(),
+        subtitle: Style::fg(Color::WHITE).dim(),
+        hint: Style::fg(Color::WHITE).dim(),
+        option: Style::fg(Color::BRIGHT_WHITE),
+        option_danger: Style::fg(Color::BRIGHT_RED),
+        option_selected: Style::fg(Color::Rgb(255, 255, 255))
+            .on(Color::Rgb(89, 138, 207))
+            .bold(),
+        option_selected_danger: Style::fg(Color::BRIGHT_WHITE).on(Color::RED).bold(),
+        text_cursor: Style::fg(Color::BLACK).on(Color::BRIGHT_WHITE),
+        texture: Style::fg(Color::Rgb(96, 103, 117)),
+        board: Style::fg(Color::Rgb(52, 56, 64)),
+        snake_head: Style::fg(Color::BRIGHT_GREEN),
+        snake_body: Style::fg(Color::GREEN),
+        snake_mid: Style::fg(Color::YELLOW),
+        snake_tail: Style::fg(Color::GRAY),
+        snake_dead: Style::fg(Color::BRIGHT_RED).bold(),
+        food: Style::fg(Color::BRIGHT_RED),
+        power_ups: [
+            Style::fg(Color::BRIGHT_BLUE),
+            Style::fg(Color::BRIGHT_CYAN),
+            Style::fg(Color::BRIGHT_YELLOW),
+            Style::fg(Color::BRIGHT_GREEN),
+            Style::fg(Color::BRIGHT_MAGENTA),
+            Style::fg(Color::BRIGHT_WHITE),
+            Style::fg(Color::YELLOW),
+        ],
+        rainbow_snake: false,
+        glyphs: &Glyphs::FANCY,
+        board_pattern: BoardPattern::Plain,
+    };
+
+    /// No colors at all, only bold, dim, underline, and reverse video.
+    pub const MONO: Self = Self {
+        border: Style::PLAIN,
+        logo: Style::PLAIN.bold(),
+        title: Style::PLAIN.bold(),
+        subtitle: Style::PLAIN.dim(),
+        hint: Style::PLAIN.dim(),
+        option: Style::PLAIN,
+        option_danger: Style::PLAIN.underlined(),
+        option_selected: Style::PLAIN.bold().reversed(),
+        option_selected_danger: Style::PLAIN.bold().underlined().reversed(),
+        text_cursor: Style::PLAIN.underlined(),
+        texture: Style::PLAIN,
+        board: Style::PLAIN.dim(),
+        snake_head: Style::PLAIN.bold(),
+        snake_body: Style::PLAIN,
+        snake_mid: Style::PLAIN,
+        snake_tail: Style::PLAIN.dim(),
+        snake_dead: Style::PLAIN.reversed(),
+        food: Style::PLAIN.bold(),
+        power_ups: [Style::PLAIN.bold(); PowerUpType::ALL.len()],
+        rainbow_snake: false,
+        glyphs: &Glyphs::FANCY,
+        board_pattern: BoardPattern::Plain,
+    };
+
+    /// Classic with a rainbow snake. Hidden until `Settings::rainbow_unlocked`.
+    pub const RAINBOW: Self = Self {
+        snake_head: Style::fg(Color::BRIGHT_WHITE).bold(),
+        rainbow_snake: true,
+        ..Self::CLASSIC
+    };
+
+    const BUILT_IN: [(&'static str, Self); 3] = [
+        ("classic", Self::CLASSIC),
+        ("mono", Self::MONO),
+        ("rainbow", Self::RAINBOW),
+    ];
+
+    /// The built-in themes `settings` can pick.
+    fn built_in(settings: &Settings) -> impl Iterator<Item = &'static (&'static str, Self)> {
+        let rainbow_unlocked = settings.rainbow_unlocked;
+        Self::BUILT_IN
+            .iter()
+            .filter(move |(_, theme)| rainbow_unlocked || !theme.rainbow_snake)
+    }
+
+    /// The theme `settings.theme` names, or classic if there is no such theme,
+    /// with the colorblind palette on top when that setting is on and the
+    /// glyphs and board pattern of the settings.
+    pub fn from_settings(settings: &Settings) -> Self {
+        let theme = Self::built_in(settings)
+            .find(|(name, _)| *name == settings.theme)
+            .map(|(_, theme)| *theme)
+            .or_else(|| settings.themes.get(&settings.theme).map(Self::custom))
+            .unwrap_or(Self::CLASSIC);
+        let theme = if settings.colorblind {
+            theme.colorblind_safe()
+        } else {
+            theme
+        };
+        let theme = if settings.high_contrast {
+            theme.high_contrast()
+        } else {
+            theme
+        };
+        Self {
+            glyphs: Glyphs::of(settings.symbols),
+            board_pattern: settings.board_pattern,
+            ..theme
+        }
+    }
+
+    /// Drops the dim attribute everywhere and lifts the dark-gray tail.
+    fn high_contrast(self) -> Self {
+        Self {
+            logo: self.logo.without(Attrs::DIM),
+            title: self.title.without(Attrs::DIM),
+            subtitle: self.subtitle.without(Attrs::DIM),
+            hint: self.hint.without(Attrs::DIM),
+            option: self.option.without(Attrs::DIM),
+            snake_tail: self.snake_mid.without(Attrs::DIM),
+            ..self
+        }
+    }
+
+    /// Swaps the board colors for the Okabe-Ito palette, which stays
+    /// distinguishable under the common forms of color blindness.
+    fn colorblind_safe(self) -> Self {
+        Self {
+            snake_head: Style::fg(Color::Rgb(86, 180, 233)).bold(),
+            snake_body: Style::fg(Color::Rgb(0, 114, 178)),
+            snake_mid: Style::fg(Color::Rgb(0, 114, 178)),
+            snake_tail: Style::fg(Color::GRAY),
+            snake_dead: Style::fg(Color::Rgb(213, 94, 0)).bold(),
+            food: Style::fg(Color::Rgb(213, 94, 0)).bold(),
+            rainbow_snake: false,
+            power_ups: [
+                Style::fg(Color::Rgb(230, 159, 0)),
+                Style::fg(Color::Rgb(86, 180, 233)),
+                Style::fg(Color::Rgb(240, 228, 66)),
+                Style::fg(Color::Rgb(0, 158, 115)),
+                Style::fg(Color::Rgb(204, 121, 167)),
+                Style::fg(Color::BRIGHT_WHITE),
+                Style::fg(Color::Rgb(230, 159, 0)).bold(),
+            ],
+            ..self
+        }
+    }
+
+    pub(crate) fn power_up(&self, kind: PowerUpType) -> Style {
+        let index = PowerUpType::ALL
+            .iter()
+            .position(|power_up_type| *power_up_type == kind)
+            .unwrap_or(0);
+        self.power_ups[index]
+    }
+
+    /// Built-in themes first, then the custom ones in name order.
+    pub fn names(settings: &Settings) -> Vec<String> {
+        let built_in = Self::built_in(settings).map(|(name, _)| name.to_string());
+        let custom = settings
+            .themes
+            .keys()
+            .filter(|name| Self::BUILT_IN.iter().all(|(built_in, _)| built_in != name))
+            .cloned();
+        built_in.chain(custom).collect()
+    }
+
+    fn custom(colors: &CustomTheme) -> Self {
+        let classic = Self::CLASSIC;
+        let fg = |color: Option<Rgb>, bold: bool, fallback: Style| {
+            color.map_or(fallback, |color| {
+                let style = Style::fg(rgb(color));
+                if bold { style.bold() } else { style }
+            })
+        };
+        let selected = |color: Option<Rgb>, fallback: Style| {
+            color.map_or(fallback, |color| {
+                Style::fg(Color::Rgb(255, 255, 255)).on(rgb(color)).bold()
+            })
+        };
+        Self {
+            border: fg(colors.border, false, classic.border),
+            logo: fg(colors.logo, true, classic.logo),
+            title: fg(colors.title, true, classic.title),
+            subtitle: fg(colors.subtitle, false, classic.subtitle),
+            hint: fg(colors.hint, false, classic.hint),
+            option: fg(colors.option, false, classic.option),
+            option_danger: fg(colors.danger, false, classic.option_danger),
+            option_selected: selected(colors.highlight, classic.option_selected),
+            option_selected_danger: selected(colors.danger, classic.option_selected_danger),
+            texture: classic.texture,
+            snake_head: fg(colors.snake_head, false, classic.snake_head),
+            snake_body: fg(colors.snake_body, false, classic.snake_body),
+            snake_mid: fg(colors.snake_body, false, classic.snake_mid),
+            snake_tail: fg(colors.snake_tail, false, classic.snake_tail),
+            food: fg(colors.food, false, classic.food),
+            ..classic
+        }
+    }
+}
+
+fn rgb(color: Rgb) -> Color {
+    Color::Rgb(color.r, color.g, color.b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_themes_override_only_the_colors_they_set() {
+        let mut settings = Settings::default();
+        settings.themes.insert(
+            "dusk".to_string(),
+            CustomTheme {
+                title: Some(Rgb {
+                    r: 255,
+                    g: 128,
+                    b: 0,
+                }),
+                highlight: Some(Rgb { r: 1, g: 2, b: 3 }),
+                ..CustomTheme::default()
+            },
+        );
+        assert_eq!(Theme::names(&settings), ["classic", "mono", "dusk"]);
+
+        settings.theme = "dusk".to_string();
+        let theme = Theme::from_settings(&settings);
+        assert_eq!(theme.title, Style::fg(Color::Rgb(255, 128, 0)).bold());
+        assert_eq!(
+            theme.option_selected,
+            Style::fg(Color::Rgb(255, 255, 255))
+                .on(Color::Rgb(1, 2, 3))
+                .bold()
+        );
+        assert_eq!(theme.border, Theme::CLASSIC.border);
+
+        settings.theme = "missing".to_string();
+        assert_eq!(Theme::from_settings(&settings), Theme::CLASSIC);
+    }
+
+    #[test]
+    fn colorblind_mode_replaces_board_colors() {
+        let mut settings = Settings {
+            theme: "mono".to_string(),
+            colorblind: true,
+            ..Settings::default()
+        };
+        let theme = Theme::from_settings(&settings);
+        assert_eq!(theme.title, Theme::MONO.title);
+        assert_ne!(theme.food, Theme::MONO.food);
+        assert_ne!(
+            theme.power_up(PowerUpType::SpeedBoost),
+            theme.power_up(PowerUpType::SlowDown)
+        );
+
+        settings.colorblind = false;
+        assert_eq!(Theme::from_settings(&settings).food, Theme::MONO.food);
+    }
+
+    #[test]
+    fn rainbow_theme_stays_hidden_until_unlocked() {
+        let mut settings = Settings {
+            theme: "rainbow".to_string(),
+            ..Settings::default()
+        };
+        assert_eq!(Theme::names(&settings), ["classic", "mono"]);
+        assert_eq!(Theme::from_settings(&settings), Theme::CLASSIC);
+
+        settings.rainbow_unlocked = true;
+        assert_eq!(Theme::names(&settings), ["classic", "mono", "rainbow"]);
+        assert!(Theme::from_settings(&settings).rainbow_snake);
+    }
+
+    #[test]
+    fn high_contrast_removes_dim_styles() {
+        let settings = Settings {
+            high_contrast: true,
+            ..Settings::default()
+        };
+        let theme = Theme::from_settings(&settings);
+        assert_eq!(theme.subtitle, Style::fg(Color::WHITE));
+        assert_eq!(theme.snake_tail, Theme::CLASSIC.snake_mid);
+
+        let mono = Theme::MONO.high_contrast();
+        assert_eq!(mono.hint, Style::PLAIN);
+        assert_eq!(mono.logo, Style::PLAIN.bold());
+    }
+
+    #[test]
+    fn compatible_symbols_are_ascii() {
+        let settings = Settings {
+            symbols: SymbolSet::Compatible,
+            ..Settings::default()
+        };
+        let glyphs = Theme::from_settings(&settings).glyphs;
+        assert_eq!(glyphs, &Glyphs::COMPATIBLE);
+        let all = [
+            glyphs.food,
+            glyphs.bonus_food,
+            glyphs.lone_segment,
+            glyphs.bar_fill,
+            glyphs.bar_track,
+            glyphs.board_dot,
+            glyphs.board_shade,
+            glyphs.ghost,
+        ];
+        assert!(
+            all.iter()
+                .chain(&glyphs.head)
+                .chain(&glyphs.sparkles)
+                .all(|glyph| glyph.is_ascii() && glyph.len() == 1)
+        );
+        assert_eq!(Theme::from_settings(&Settings::default()), Theme::CLASSIC);
+    }
+}
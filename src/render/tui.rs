@@ -0,0 +1,214 @@
+//! ratatui output backend (`renderer = "ratatui"`, in builds with the
+//! `ratatui` feature). The views draw into the renderer's cell buffer as they
+//! do for every backend; each finished frame goes to ratatui whole, as one
+//! widget, and ratatui's double-buffered terminal works out what to send.
+
+use std::io::{self, Stdout, Write};
+
+use crossterm::execute;
+use crossterm::style::Print;
+use crossterm::terminal::SetTitle;
+use ratatui::Terminal;
+use ratatui::backend::CrosstermBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget;
+
+use super::backend::{Backend, RESTORE_TITLE, SAVE_TITLE, osc52};
+use super::screen::{CONTINUATION, Cell};
+use super::style::{self, Attrs};
+
+/// The 16 palette colors, in order.
+const NAMED_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// Draws each frame the renderer presents through ratatui.
+pub(super) struct TuiBackend {
+    terminal: Terminal<CrosstermBackend<Stdout>>,
+    clear_pending: bool,
+}
+
+impl TuiBackend {
+    pub(super) fn new() -> io::Result<Self> {
+        let terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+        Ok(Self {
+            terminal,
+            clear_pending: true,
+        })
+    }
+}
+
+impl Backend for TuiBackend {
+    // Frames arrive whole through `present_frame`, never cell by cell.
+    fn move_to(&mut self, _x: u16, _y: u16) {}
+
+    fn set_style(&mut self, _style: style::Style) {}
+
+    fn print(&mut self, _text: &str) {}
+
+    fn clear(&mut self) {
+        self.clear_pending = true;
+    }
+
+    fn present_frame(&mut self, rows: &[Vec<Cell>]) -> bool {
+        if std::mem::take(&mut self.clear_pending) {
+            let _ = self.terminal.clear();
+        }
+        let _ = self.terminal.draw(|frame| {
+            let area = frame.area();
+            frame.render_widget(Cells { rows }, area);
+        });
+        true
+    }
+
+    fn flush(&mut self) {
+        let _ = Write::flush(self.terminal.backend_mut());
+    }
+
+    fn size(&self) -> (u16, u16) {
+        self.terminal
+            .size()
+            .map_or((80, 24), |size| (size.width, size.height))
+    }
+
+    fn bell(&mut self) {
+        let backend = self.terminal.backend_mut();
+        let _ = backend.write_all(b"\x07");
+        let _ = Write::flush(backend);
+    }
+
+    fn set_title(&mut self, title: Option<&str>) {
+        let backend = self.terminal.backend_mut();
+        let _ = match title {
+            Some(title) => execute!(backend, SetTitle(title)),
+            None => execute!(backend, Print(RESTORE_TITLE), Print(SAVE_TITLE)),
+        };
+    }
+
+    fn copy_to_clipboard(&mut self, text: &str) {
+        let _ = execute!(self.terminal.backend_mut(), Print(osc52(text)));
+    }
+}
+
+/// The cell buffer as a widget.
+struct Cells<'a> {
+    rows: &'a [Vec<Cell>],
+}
+
+impl Widget for Cells<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for (y, row) in (area.top()..area.bottom()).zip(self.rows) {
+            for (x, cell) in (area.left()..area.right()).zip(row) {
+                // ratatui skips the column after a wide character itself.
+                if cell.ch == CONTINUATION {
+                    continue;
+                }
+                if let Some(target) = buf.cell_mut((x, y)) {
+                    if cell.extra.is_empty() {
+                        target.set_char(cell.ch);
+                    } else {
+                        target.set_symbol(&format!("{}{}", cell.ch, cell.extra));
+                    }
+                    target.set_style(tui_style(cell.style));
+                }
+            }
+        }
+    }
+}
+
+/// `style` as ratatui's own.
+fn tui_style(style: style::Style) -> Style {
+    const MODIFIERS: [(Attrs, Modifier); 4] = [
+        (Attrs::BOLD, Modifier::BOLD),
+        (Attrs::DIM, Modifier::DIM),
+        (Attrs::UNDERLINE, Modifier::UNDERLINED),
+        (Attrs::REVERSE, Modifier::REVERSED),
+    ];
+    let color = |color: style::Color| match color {
+        style::Color::Named(index) => NAMED_COLORS
+            .get(usize::from(index))
+            .copied()
+            .unwrap_or(Color::Indexed(index)),
+        style::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    };
+    let mut tui = Style::default();
+    for (attrs, modifier) in MODIFIERS {
+        if style.attrs.contains(attrs) {
+            tui = tui.add_modifier(modifier);
+        }
+    }
+    if let Some(fg) = style.fg {
+        tui = tui.fg(color(fg));
+    }
+    if let Some(bg) = style.bg {
+        tui = tui.bg(color(bg));
+    }
+    tui
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cells_keep_their_styles_in_the_ratatui_buffer() {
+        assert_eq!(tui_style(style::Style::PLAIN), Style::default());
+        assert_eq!(
+            tui_style(style::Style::fg(style::Color::Rgb(0, 200, 10)).bold()),
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Rgb(0, 200, 10))
+        );
+        assert_eq!(
+            tui_style(
+                style::Style::fg(style::Color::BRIGHT_GREEN)
+                    .on(style::Color::GRAY)
+                    .dim()
+            ),
+            Style::default()
+                .add_modifier(Modifier::DIM)
+                .fg(Color::LightGreen)
+                .bg(Color::DarkGray)
+        );
+
+        let rows = vec![vec![
+            Cell {
+                ch: '日',
+                extra: "",
+                style: style::Style::fg(style::Color::BRIGHT_RED),
+            },
+            Cell {
+                ch: CONTINUATION,
+                extra: "",
+                style: style::Style::fg(style::Color::BRIGHT_RED),
+            },
+            Cell {
+                ch: 'x',
+                extra: "",
+                style: style::Style::PLAIN,
+            },
+        ]];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        Cells { rows: &rows }.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["日x", ""]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::default().fg(Color::LightRed));
+        assert_eq!(buf, expected);
+    }
+}
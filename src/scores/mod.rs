@@ -0,0 +1,324 @@
+//! Ranked high-score tables. Entries carry the date they were set on, but the
+//! caller supplies it, so nothing here reads the clock.
+
+use crate::utils::{Difficulty, GameMode, HEIGHT, Initials, WIDTH};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+pub const HIGH_SCORE_TABLE_SIZE: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HighScoreEntry {
+    pub score: u32,
+    pub initials: Initials,
+    /// UTC date as `YYYY-MM-DD`; empty for scores migrated from older configs.
+    #[serde(default)]
+    pub date: String,
+}
+
+impl HighScoreEntry {
+    pub fn new(score: u32, initials: Initials, date: impl Into<String>) -> Self {
+        Self {
+            score,
+            initials,
+            date: date.into(),
+        }
+    }
+}
+
+/// Which table a score goes in. Runs only compete with runs of the same
+/// difficulty and mode on a board of the same size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScoreKey {
+    pub difficulty: Difficulty,
+    pub mode: GameMode,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl ScoreKey {
+    pub fn new(difficulty: Difficulty, mode: GameMode, width: u16, height: u16) -> Self {
+        Self {
+            difficulty,
+            mode,
+            width,
+            height,
+        }
+    }
+
+    /// The classic mode on the default board, where the one table per
+    /// difficulty of older files belongs.
+    pub fn classic(difficulty: Difficulty) -> Self {
+        Self::new(difficulty, GameMode::Classic, WIDTH, HEIGHT)
+    }
+
+    pub fn is_default_board(&self) -> bool {
+        (self.width, self.height) == (WIDTH, HEIGHT)
+    }
+}
+
+/// Written as `difficulty/mode/WIDTHxHEIGHT`, e.g. `medium/walls/40x20`.
+impl fmt::Display for ScoreKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}/{}x{}",
+            self.difficulty.name(),
+            self.mode.name(),
+            self.width,
+            self.height
+        )
+    }
+}
+
+/// Also takes a bare difficulty name, the table key before scores were kept
+/// per mode and board size.
+impl FromStr for ScoreKey {
+    type Err = String;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("`{key}` is not a score table");
+        let difficulty_named = |name: &str| {
+            Difficulty::ALL
+                .into_iter()
+                .find(|difficulty| difficulty.name() == name)
+        };
+        let parts: Vec<&str> = key.split('/').collect();
+        match parts[..] {
+            [difficulty] => difficulty_named(difficulty)
+                .map(Self::classic)
+                .ok_or_else(invalid),
+            [difficulty, mode, size] => {
+                let difficulty = difficulty_named(difficulty).ok_or_else(invalid)?;
+                let mode = GameMode::ALL
+                    .into_iter()
+                    .find(|candidate| candidate.name() == mode)
+                    .ok_or_else(invalid)?;
+                let (width, height) = size.split_once('x').ok_or_else(invalid)?;
+                let width = width.parse().map_err(|_| invalid())?;
+                let height = height.parse().map_err(|_| invalid())?;
+                Ok(Self::new(difficulty, mode, width, height))
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Ranked top-10 tables per `ScoreKey`, highest score first. Stored as a
+/// table of tables named by their keys.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "BTreeMap<String, Vec<HighScoreEntry>>",
+    into = "BTreeMap<String, Vec<HighScoreEntry>>"
+)]
+pub struct HighScores {
+    tables: BTreeMap<ScoreKey, Vec<HighScoreEntry>>,
+}
+
+impl HighScores {
+    pub fn entries(&self, key: ScoreKey) -> &[HighScoreEntry] {
+        self.tables.get(&key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Replaces the table for `key`, keeping its best `HIGH_SCORE_TABLE_SIZE`
+    /// entries in rank order.
+    pub fn set_entries(&mut self, key: ScoreKey, mut entries: Vec<HighScoreEntry>) {
+        entries.sort_by(|a, b| b.score.cmp(&a.score));
+        entries.truncate(HIGH_SCORE_TABLE_SIZE);
+        if entries.is_empty() {
+            self.tables.remove(&key);
+        } else {
+            self.tables.insert(key, entries);
+        }
+    }
+
+    /// Drops the table for `key`.
+    pub fn clear_table(&mut self, key: ScoreKey) {
+        self.tables.remove(&key);
+    }
+
+    /// Drops every table of `difficulty`, whatever its mode and board size.
+    pub fn clear_difficulty(&mut self, difficulty: Difficulty) {
+        self.tables.retain(|key, _| key.difficulty != difficulty);
+    }
+
+    /// How many scores the tables of `difficulty` hold between them.
+    pub fn count_for(&self, difficulty: Difficulty) -> usize {
+        self.tables
+            .iter()
+            .filter(|(key, _)| key.difficulty == difficulty)
+            .map(|(_, entries)| entries.len())
+            .sum()
+    }
+
+    /// Keys of the tables that have scores.
+    pub fn keys(&self) -> impl Iterator<Item = ScoreKey> + '_ {
+        self.tables.keys().copied()
+    }
+
+    /// The tables worth showing: every difficulty of the classic mode on the
+    /// default board, scored or not, then any other table with scores. They
+    /// come grouped by mode, then board size, then difficulty.
+    pub fn listed_keys(&self) -> Vec<ScoreKey> {
+        let classic = Difficulty::ALL.map(ScoreKey::classic);
+        let mut keys: Vec<ScoreKey> = self.keys().filter(|key| !classic.contains(key)).collect();
+        keys.extend(classic);
+        keys.sort_by_key(|key| {
+            (
+                key.mode,
+                !key.is_default_board(),
+                key.width,
+                key.height,
+                key.difficulty,
+            )
+        });
+        keys
+    }
+
+    pub fn best(&self, key: ScoreKey) -> u32 {
+        self.entries(key).first().map_or(0, |entry| entry.score)
+    }
+
+    /// Zero-based rank `score` would take, or `None` if it does not make the table.
+    /// Ties rank below existing entries.
+    pub fn rank_for(&self, key: ScoreKey, score: u32) -> Option<usize> {
+        if score == 0 {
+            return None;
+        }
+        let rank = self
+            .entries(key)
+            .iter()
+            .take_while(|entry| entry.score >= score)
+            .count();
+        (rank < HIGH_SCORE_TABLE_SIZE).then_some(rank)
+    }
+
+    pub fn insert(&mut self, key: ScoreKey, entry: HighScoreEntry) -> Option<usize> {
+        let rank = self.rank_for(key, entry.score)?;
+        let entries = self.tables.entry(key).or_default();
+        entries.insert(rank, entry);
+        entries.truncate(HIGH_SCORE_TABLE_SIZE);
+        Some(rank)
+    }
+}
+
+impl TryFrom<BTreeMap<String, Vec<HighScoreEntry>>> for HighScores {
+    type Error = String;
+
+    fn try_from(tables: BTreeMap<String, Vec<HighScoreEntry>>) -> Result<Self, Self::Error> {
+        let mut high_scores = Self::default();
+        for (key, entries) in tables {
+            // A bare difficulty and its full key name the same table.
+            let key = key.parse()?;
+            let mut merged = high_scores.entries(key).to_vec();
+            merged.extend(entries);
+            high_scores.set_entries(key, merged);
+        }
+        Ok(high_scores)
+    }
+}
+
+impl From<HighScores> for BTreeMap<String, Vec<HighScoreEntry>> {
+    fn from(high_scores: HighScores) -> Self {
+        high_scores
+            .tables
+            .into_iter()
+            .map(|(key, entries)| (key.to_string(), entries))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(score: u32) -> HighScoreEntry {
+        HighScoreEntry::new(score, Initials::UNKNOWN, "")
+    }
+
+    #[test]
+    fn leaderboard_keeps_top_ten_in_rank_order() {
+        let hard = ScoreKey::classic(Difficulty::Hard);
+        let mut high_scores = HighScores::default();
+        for score in [50, 10, 30, 20, 40, 60, 70, 80, 90, 100] {
+            high_scores.insert(hard, entry(score));
+        }
+
+        assert_eq!(high_scores.rank_for(hard, 0), None);
+        assert_eq!(high_scores.rank_for(hard, 10), None);
+        // Ties rank below the existing entry.
+        assert_eq!(high_scores.rank_for(hard, 50), Some(6));
+        assert_eq!(high_scores.insert(hard, entry(55)), Some(5));
+
+        let scores: Vec<u32> = high_scores
+            .entries(hard)
+            .iter()
+            .map(|entry| entry.score)
+            .collect();
+        assert_eq!(scores, vec![100, 90, 80, 70, 60, 55, 50, 40, 30, 20]);
+        assert_eq!(high_scores.best(hard), 100);
+        assert_eq!(high_scores.best(ScoreKey::classic(Difficulty::Easy)), 0);
+    }
+
+    #[test]
+    fn modes_and_board_sizes_keep_separate_tables() {
+        let classic = ScoreKey::classic(Difficulty::Medium);
+        let walls = ScoreKey::new(Difficulty::Medium, GameMode::Walls, WIDTH, HEIGHT);
+        let small = ScoreKey::new(Difficulty::Medium, GameMode::Classic, 20, 12);
+        let mut high_scores = HighScores::default();
+        high_scores.insert(classic, entry(300));
+        high_scores.insert(walls, entry(40));
+        high_scores.insert(small, entry(90));
+        assert_eq!(high_scores.best(classic), 300);
+        assert_eq!(high_scores.best(walls), 40);
+
+        let listed = high_scores.listed_keys();
+        assert_eq!(listed.len(), Difficulty::ALL.len() + 2);
+        assert_eq!(listed[Difficulty::ALL.len()], small);
+        assert_eq!(listed.last(), Some(&walls));
+
+        let toml = toml::to_string(&high_scores).unwrap();
+        assert!(toml.contains("[[\"medium/walls/40x20\"]]"), "{toml}");
+        assert_eq!(toml::from_str::<HighScores>(&toml).unwrap(), high_scores);
+    }
+
+    #[test]
+    fn tables_are_cleared_one_at_a_time_or_by_difficulty() {
+        let medium = ScoreKey::classic(Difficulty::Medium);
+        let medium_walls = ScoreKey::new(Difficulty::Medium, GameMode::Walls, WIDTH, HEIGHT);
+        let hard = ScoreKey::classic(Difficulty::Hard);
+        let mut high_scores = HighScores::default();
+        for score in [300, 200] {
+            high_scores.insert(medium, entry(score));
+        }
+        high_scores.insert(medium_walls, entry(40));
+        high_scores.insert(hard, entry(90));
+        assert_eq!(high_scores.count_for(Difficulty::Medium), 3);
+
+        high_scores.clear_table(medium_walls);
+        assert_eq!(high_scores.count_for(Difficulty::Medium), 2);
+        assert_eq!(high_scores.best(medium), 300);
+
+        high_scores.clear_difficulty(Difficulty::Medium);
+        assert_eq!(high_scores.count_for(Difficulty::Medium), 0);
+        assert_eq!(high_scores.keys().collect::<Vec<_>>(), [hard]);
+    }
+
+    #[test]
+    fn score_keys_parse_their_own_names_and_bare_difficulties() {
+        let key = ScoreKey::new(Difficulty::Extreme, GameMode::Walls, 30, 15);
+        assert_eq!(key.to_string(), "extreme/walls/30x15");
+        assert_eq!("extreme/walls/30x15".parse(), Ok(key));
+        assert_eq!("easy".parse(), Ok(ScoreKey::classic(Difficulty::Easy)));
+        for invalid in [
+            "insane",
+            "easy/maze/40x20",
+            "easy/walls/40",
+            "easy/walls/axb",
+        ] {
+            assert!(invalid.parse::<ScoreKey>().is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! Spectator streaming for `play --broadcast <addr>` and `spectate <addr>`.
+//!
+//! The host sends one JSON `SpectatorFrame` per line over TCP whenever the game
+//! state may have changed. Spectators rebuild a `Game` from each frame and draw
+//! it with the normal renderer, so they see exactly what the host sees, laid
+//! out for their own terminal.
+
+use crate::core::{ActiveEffect, DifficultyProfile, Game, RunStats};
+use crate::utils::{self, Difficulty, Direction, Position, PowerUp};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
+
+/// Bumped whenever `SpectatorFrame` changes shape.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// A slow spectator is dropped rather than allowed to stall the broadcast.
+const WRITE_TIMEOUT: Duration = Duration::from_millis(250);
+/// How often the broadcast thread checks for new spectators while the host is idle.
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(100);
+/// Longest line a spectator reads; a full snake on the largest board fits
+/// well within it.
+const MAX_FRAME_BYTES: u64 = 1024 * 1024;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SpectatorFrame {
+    pub version: u32,
+    pub difficulty: Difficulty,
+    pub profile: DifficultyProfile,
+    pub width: u16,
+    pub height: u16,
+    pub snake: Vec<Position>,
+    pub direction: Direction,
+    pub food: Position,
+    pub power_up: Option<PowerUp>,
+    pub active_effects: Vec<ActiveEffect>,
+    pub score: u32,
+    pub high_score: u32,
+    pub paused: bool,
+    pub game_over: bool,
+    pub muted: bool,
+    pub stats: RunStats,
+}
+
+impl SpectatorFrame {
+    pub fn capture(game: &Game) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            difficulty: game.difficulty,
+            profile: game.profile,
+            width: game.width,
+            height: game.height,
+            snake: game.snake.body().iter().copied().collect(),
+            direction: game.snake.direction,
+            food: game.food,
+            power_up: game.power_up,
+            active_effects: game.active_effects.clone(),
+            score: game.score,
+            high_score: game.high_score,
+            paused: game.paused,
+            game_over: game.game_over,
+            muted: game.muted,
+            stats: game.stats.clone(),
+        }
+    }
+
+    /// Whether `game` was built for the same board and can take this frame via `apply`.
+    pub fn fits(&self, game: &Game) -> bool {
+        game.difficulty == self.difficulty && game.width == self.width && game.height == self.height
+    }
+
+    /// Checks what a host could have sent wrong before the frame reaches a
+    /// `Game`: the protocol version, the board size and the snake's head.
+    fn validate(&self) -> Result<(), String> {
+        if self.version != PROTOCOL_VERSION {
+            return Err(format!(
+                "host uses spectator protocol {} (expected {PROTOCOL_VERSION})",
+                self.version
+            ));
+        }
+        let (min_width, max_width) = utils::WIDTH_RANGE;
+        let (min_height, max_height) = utils::HEIGHT_RANGE;
+        if !(min_width..=max_width).contains(&self.width)
+            || !(min_height..=max_height).contains(&self.height)
+        {
+            return Err(format!(
+                "invalid frame: board of {}x{} cells",
+                self.width, self.height
+            ));
+        }
+        if !self.snake.first().is_some_and(|head| self.on_board(*head)) {
+            return Err("invalid frame: snake head off the board".to_string());
+        }
+        Ok(())
+    }
+
+    /// Whether `pos` is an interior cell of the frame's board.
+    fn on_board(&self, pos: Position) -> bool {
+        (2..self.width).contains(&pos.x) && (2..self.height).contains(&pos.y)
+    }
+
+    /// A spectator-side game showing this frame.
+    pub fn to_game(&self) -> Game {
+        let mut game = Game::with_profile(
+            self.difficulty,
+            self.profile,
+            self.width,
+            self.height,
+            self.high_score,
+        );
+        // Forget the locally spawned food and power-up before taking the host's.
+        game.dirty_positions.clear();
+        self.apply(&mut game);
+        game
+    }
+
+    /// Copies the frame into `game`, marking every cell that changed for redraw.
+    /// Positions off the board are dropped, and food off it keeps its old cell.
+    pub fn apply(&self, game: &mut Game) {
+        let stale = game
+            .snake
+            .body()
+            .iter()
+            .copied()
+            .chain([game.food])
+            .chain(game.power_up.map(|power_up| power_up.position))
+            .collect::<Vec<_>>();
+        game.dirty_positions.extend(stale);
+
+        game.profile = self.profile;
+        let snake = self.snake.iter().copied();
+        game.snake
+            .set_body(snake.filter(|pos| self.on_board(*pos)).collect());
+        game.snake.direction = self.direction;
+        if self.on_board(self.food) {
+            game.food = self.food;
+        }
+        game.power_up = self
+            .power_up
+            .filter(|power_up| self.on_board(power_up.position));
+        game.active_effects = self.active_effects.clone();
+        game.score = self.score;
+        game.high_score = self.high_score;
+        game.paused = self.paused;
+        game.game_over = self.game_over;
+        game.muted = self.muted;
+        game.stats = self.stats.clone();
+    }
+}
+
+/// Host side of the stream: accepts spectators and sends them the latest frame
+/// from a background thread so the game loop never blocks on the network.
+pub struct Broadcaster {
+    frames: Sender<String>,
+}
+
+fn send_to_all(clients: &mut Vec<TcpStream>, line: &str) {
+    clients.retain_mut(|client| client.write_all(line.as_bytes()).is_ok());
+}
+
+fn accept_pending(listener: &TcpListener, clients: &mut Vec<TcpStream>, last_line: Option<&str>) {
+    while let Ok((mut client, _)) = listener.accept() {
+        let ready = client.set_nonblocking(false).is_ok()
+            && client.set_write_timeout(Some(WRITE_TIMEOUT)).is_ok()
+            && last_line.is_none_or(|line| client.write_all(line.as_bytes()).is_ok());
+        if ready {
+            clients.push(client);
+        }
+    }
+}
+
+impl Broadcaster {
+    pub fn bind(addr: &str) -> Result<Self, String> {
+        let listener =
+            TcpListener::bind(addr).map_err(|err| format!("cannot listen on {addr}: {err}"))?;
+        listener
+            .set_nonblocking(true)
+            .map_err(|err| err.to_string())?;
+        let (frames, frame_rx) = mpsc::channel::<String>();
+        thread::spawn(move || {
+            let mut clients = Vec::new();
+            // Sent to late joiners so they do not wait for the next change.
+            let mut last_line: Option<String> = None;
+            loop {
+                match frame_rx.recv_timeout(ACCEPT_INTERVAL) {
+                    Ok(mut line) => {
+                        // Only the newest frame matters when the game outpaces the network.
+                        while let Ok(newer) = frame_rx.try_recv() {
+                            line = newer;
+                        }
+                        send_to_all(&mut clients, &line);
+                        last_line = Some(line);
+                    }
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+                accept_pending(&listener, &mut clients, last_line.as_deref());
+            }
+        });
+        Ok(Self { frames })
+    }
+
+    pub fn send(&self, game: &Game) {
+        if let Ok(mut line) = serde_json::to_string(&SpectatorFrame::capture(game)) {
+            line.push('\n');
+            let _ = self.frames.send(line);
+        }
+    }
+}
+
+/// Reads the next frame from a host, or `None` once the stream ends.
+fn read_frame(
+    reader: &mut impl BufRead,
+    line: &mut String,
+) -> Result<Option<SpectatorFrame>, String> {
+    line.clear();
+    let read = reader
+        .take(MAX_FRAME_BYTES)
+        .read_line(line)
+        .map_err(|err| err.to_string())?;
+    if read == 0 {
+        return Ok(None);
+    }
+    if read as u64 == MAX_FRAME_BYTES && !line.ends_with('\n') {
+        return Err(format!(
+            "invalid frame: longer than {MAX_FRAME_BYTES} bytes"
+        ));
+    }
+    let frame = serde_json::from_str::<SpectatorFrame>(line)
+        .map_err(|err| format!("invalid frame: {err}"))?;
+    frame.validate()?;
+    Ok(Some(frame))
+}
+
+pub enum StreamEvent {
+    Frame(Box<SpectatorFrame>),
+    Ended(Option<String>),
+}
+
+/// Spectator side: connects to `addr` and forwards parsed frames from a
+/// background reader thread. The last event is always `Ended`.
+pub fn connect(addr: &str) -> Result<Receiver<StreamEvent>, String> {
+    let stream =
+        TcpStream::connect(addr).map_err(|err| format!("cannot connect to {addr}: {err}"))?;
+    let (events, event_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        let error = loop {
+            let frame = match read_frame(&mut reader, &mut line) {
+                Ok(Some(frame)) => frame,
+                Ok(None) => break None,
+                Err(err) => break Some(err),
+            };
+            if events.send(StreamEvent::Frame(Box::new(frame))).is_err() {
+                return;
+            }
+        };
+        let _ = events.send(StreamEvent::Ended(error));
+    });
+    Ok(event_rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_round_trip_reproduces_the_game() {
+        let mut host = Game::new_seeded(Difficulty::Hard, 30, 16, 250, 9);
+        for _ in 0..5 {
+            host.tick();
+        }
+        host.update_snake_direction(Direction::Up);
+        host.tick();
+
+        let line = serde_json::to_string(&SpectatorFrame::capture(&host)).unwrap();
+        let frame: SpectatorFrame = serde_json::from_str(&line).unwrap();
+        let spectator = frame.to_game();
+
+        assert!(frame.fits(&spectator));
+        assert_eq!(spectator.snake.body(), host.snake.body());
+        assert_eq!(spectator.snake.direction, Direction::Up);
+        assert_eq!(spectator.food, host.food);
+        assert_eq!(spectator.score, host.score);
+        assert_eq!(spectator.stats, host.stats);
+        assert_eq!(spectator.pace_percent(), host.pace_percent());
+    }
+
+    fn frame_line(frame: &SpectatorFrame) -> String {
+        let mut line = serde_json::to_string(frame).unwrap();
+        line.push('\n');
+        line
+    }
+
+    #[test]
+    fn oversized_frames_are_rejected_without_reading_them_whole() {
+        let mut stream = std::io::Cursor::new(vec![b'{'; 3 * MAX_FRAME_BYTES as usize]);
+        let mut line = String::new();
+        let err = read_frame(&mut stream, &mut line).err().unwrap();
+        assert!(err.contains("longer than"), "{err}");
+        assert_eq!(line.len() as u64, MAX_FRAME_BYTES);
+    }
+
+    #[test]
+    fn frames_for_impossible_boards_are_rejected() {
+        let game = Game::new_seeded(Difficulty::Easy, 20, 12, 0, 3);
+        let mut line = String::new();
+        let good = SpectatorFrame::capture(&game);
+        let mut stream = std::io::Cursor::new(frame_line(&good));
+        assert!(read_frame(&mut stream, &mut line).unwrap().is_some());
+        assert!(read_frame(&mut stream, &mut line).unwrap().is_none());
+
+        let mut huge = good.clone();
+        huge.width = u16::MAX;
+        let mut stream = std::io::Cursor::new(frame_line(&huge));
+        let err = read_frame(&mut stream, &mut line).err().unwrap();
+        assert!(err.contains("65535x12"), "{err}");
+
+        let mut stray = good.clone();
+        stray.snake.push(Position { x: 500, y: 3 });
+        stray.food = Position { x: 0, y: 900 };
+        let spectator = stray.to_game();
+        assert_eq!(spectator.snake.body(), game.snake.body());
+        assert_ne!(spectator.food, stray.food);
+    }
+
+    #[test]
+    fn broadcaster_streams_frames_to_spectators() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        drop(listener);
+        let broadcaster = Broadcaster::bind(&addr).unwrap();
+        let events = connect(&addr).unwrap();
+
+        let game = Game::new_seeded(Difficulty::Easy, 20, 12, 0, 3);
+        // The first sends may go out before the spectator has been accepted.
+        let frame = (0..20)
+            .find_map(|_| {
+                broadcaster.send(&game);
+                match events.recv_timeout(Duration::from_millis(250)) {
+                    Ok(StreamEvent::Frame(frame)) => Some(frame),
+                    Ok(StreamEvent::Ended(err)) => panic!("stream ended: {err:?}"),
+                    Err(_) => None,
+                }
+            })
+            .expect("spectator should receive a frame");
+
+        assert_eq!(*game.snake.body(), frame.snake);
+        drop(broadcaster);
+    }
+}
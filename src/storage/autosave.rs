@@ -0,0 +1,83 @@
+//! The run in progress, saved every few seconds beside the config. A run
+//! that ends normally removes it, so one still there at launch was cut
+//! short by a crash or a power loss and can be picked up again.
+
+use super::{companion_path, save_atomic};
+use crate::core::Game;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Far more than a snapshot of the longest run takes.
+const MAX_AUTOSAVE_BYTES: u64 = 4 * 1024 * 1024;
+
+pub(super) fn autosave_path_for(config_path: &Path) -> PathBuf {
+    companion_path(config_path, "autosave").with_extension("json")
+}
+
+pub(super) fn save_autosave_to_path(path: &Path, game: &Game) -> Result<(), String> {
+    save_atomic(path, &game.to_snapshot())
+}
+
+/// The saved run, or `None` when there is none or it cannot be read.
+pub(super) fn load_autosave_from_path(path: &Path) -> Option<Game> {
+    let metadata = fs::metadata(path).ok()?;
+    if metadata.len() > MAX_AUTOSAVE_BYTES {
+        return None;
+    }
+    let contents = fs::read_to_string(path).ok()?;
+    match Game::from_snapshot(&contents) {
+        Ok(game) => Some(game),
+        Err(err) => {
+            log::warn!("ignoring autosave {}: {err}", path.display());
+            None
+        }
+    }
+}
+
+pub(super) fn clear_autosave_at_path(path: &Path) -> Result<(), String> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.to_string()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Difficulty;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn autosave_round_trips_until_cleared() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "rustnake-autosave-{}-{}.json",
+            std::process::id(),
+            nanos
+        ));
+        let mut game = Game::new_seeded(Difficulty::Easy, 40, 20, 0, 5);
+        for _ in 0..12 {
+            game.tick();
+        }
+
+        assert!(load_autosave_from_path(&path).is_none());
+        save_autosave_to_path(&path, &game).unwrap();
+        let recovered = load_autosave_from_path(&path).expect("autosave loads");
+        assert_eq!(recovered.stats.ticks, 12);
+        assert_eq!(recovered.snake.body(), game.snake.body());
+
+        clear_autosave_at_path(&path).unwrap();
+        assert!(load_autosave_from_path(&path).is_none());
+        // Nothing left to clear is not an error.
+        clear_autosave_at_path(&path).unwrap();
+
+        fs::write(&path, "{ not a snapshot").unwrap();
+        assert!(load_autosave_from_path(&path).is_none());
+        let _ = fs::remove_file(path);
+    }
+}
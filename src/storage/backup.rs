@@ -0,0 +1,121 @@
+//! Config snapshots for `config backup` and `config restore`.
+
+use super::check::{check_config_file, check_data_file};
+use super::{AppConfig, data_path_for, save_snapshot_to_path};
+use std::{fs, path::Path, time::SystemTime};
+
+/// Reads and validates a config file or backup. Unlike normal startup loading,
+/// problems are reported instead of falling back to defaults.
+pub(super) fn read_config_strict(path: &Path) -> Result<AppConfig, String> {
+    check_config_file(path)
+        .map(|(config, _)| config)
+        .map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Snapshots settings and scores into a single file.
+pub(super) fn backup_config_to(config_path: &Path, backup_path: &Path) -> Result<(), String> {
+    if fs::metadata(config_path).is_err() {
+        return Err(format!("no config found at {}", config_path.display()));
+    }
+    let mut config = read_config_strict(config_path)?;
+    let data_path = data_path_for(config_path);
+    if fs::metadata(&data_path).is_ok() {
+        config.high_scores = check_data_file(&data_path)
+            .map(|(high_scores, _)| high_scores)
+            .map_err(|err| format!("{}: {err}", data_path.display()))?;
+    }
+    save_snapshot_to_path(backup_path, &config)
+}
+
+/// True when the live config or data file was written after the backup, so
+/// restoring would roll back more recent scores or settings.
+pub(super) fn config_is_newer_than(config_path: &Path, backup_path: &Path) -> bool {
+    let modified = |path: &Path| {
+        fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    };
+    let live: Option<SystemTime> = modified(config_path).max(modified(&data_path_for(config_path)));
+    match (live, modified(backup_path)) {
+        (Some(live), Some(backup)) => live > backup,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{HighScoreEntry, ScoreKey, save_config_to_path};
+    use crate::utils::{Difficulty, Initials, Language};
+    use std::{
+        fs::File,
+        path::PathBuf,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    fn temp_path(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!(
+            "rustnake-backup-{}-{}-{}.toml",
+            name,
+            std::process::id(),
+            nanos
+        ))
+    }
+
+    #[test]
+    fn backup_round_trips_scores_and_settings() {
+        let config_path = temp_path("config");
+        let backup_path = temp_path("snapshot");
+        let mut config = AppConfig::default();
+        config.high_scores.insert(
+            ScoreKey::classic(Difficulty::Hard),
+            HighScoreEntry::new(88, Initials::from("ZED".to_string()), "2026-10-16"),
+        );
+        config.settings.language = Language::Ja;
+        save_config_to_path(&config_path, &config).unwrap();
+
+        backup_config_to(&config_path, &backup_path).unwrap();
+
+        assert_eq!(read_config_strict(&backup_path).unwrap(), config);
+        assert!(backup_config_to(&temp_path("missing"), &backup_path).is_err());
+
+        fs::write(data_path_for(&config_path), "high_scores = 1").unwrap();
+        assert!(backup_config_to(&config_path, &backup_path).is_err());
+
+        let _ = fs::remove_file(data_path_for(&config_path));
+        let _ = fs::remove_file(config_path);
+        let _ = fs::remove_file(backup_path);
+    }
+
+    #[test]
+    fn newer_config_is_detected_by_modification_time() {
+        let config_path = temp_path("live");
+        let backup_path = temp_path("older");
+        fs::write(&config_path, "").unwrap();
+        fs::write(&backup_path, "").unwrap();
+        let now = SystemTime::now();
+        File::options()
+            .write(true)
+            .open(&backup_path)
+            .unwrap()
+            .set_modified(now - Duration::from_secs(60))
+            .unwrap();
+        File::options()
+            .write(true)
+            .open(&config_path)
+            .unwrap()
+            .set_modified(now)
+            .unwrap();
+
+        assert!(config_is_newer_than(&config_path, &backup_path));
+        assert!(!config_is_newer_than(&backup_path, &config_path));
+        assert!(!config_is_newer_than(&temp_path("absent"), &backup_path));
+
+        let _ = fs::remove_file(config_path);
+        let _ = fs::remove_file(backup_path);
+    }
+}
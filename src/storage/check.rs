@@ -0,0 +1,365 @@
+//! Strict config/data validation behind `load_config_checked` and `config check`.
+
+use super::{
+    AppConfig, CURRENT_CONFIG_VERSION, CURRENT_DATA_VERSION, ConfigSnapshot, CustomTheme,
+    DataFileV2, HighScores, MAX_CONFIG_BYTES, RawConfigFile, RawDataFile, ScoreKey, data_path_for,
+    migrate_config,
+};
+use serde::de::DeserializeOwned;
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    TooLarge {
+        path: PathBuf,
+        limit_bytes: u64,
+    },
+    Unreadable {
+        path: PathBuf,
+        message: String,
+    },
+    Invalid {
+        path: PathBuf,
+        line: Option<usize>,
+        message: String,
+    },
+    NewerVersion {
+        path: PathBuf,
+        version: u32,
+    },
+    UnknownKey {
+        path: PathBuf,
+        key: String,
+    },
+}
+
+impl ConfigError {
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::TooLarge { path, .. }
+            | Self::Unreadable { path, .. }
+            | Self::Invalid { path, .. }
+            | Self::NewerVersion { path, .. }
+            | Self::UnknownKey { path, .. } => path,
+        }
+    }
+
+    /// Unknown keys are skipped on load; the other problems mean the whole file
+    /// was replaced by defaults.
+    pub fn is_fatal(&self) -> bool {
+        !matches!(self, Self::UnknownKey { .. })
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.path().file_name().map_or_else(
+            || self.path().display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        );
+        match self {
+            Self::TooLarge { limit_bytes, .. } => {
+                write!(f, "{file}: larger than {} KiB", limit_bytes / 1024)
+            }
+            Self::Unreadable { message, .. } => write!(f, "{file}: {message}"),
+            Self::Invalid {
+                line: Some(line),
+                message,
+                ..
+            } => write!(f, "{file} line {line}: {message}"),
+            Self::Invalid { message, .. } => write!(f, "{file}: {message}"),
+            Self::NewerVersion { version, .. } => {
+                write!(f, "{file}: written by a newer rustnake (version {version})")
+            }
+            Self::UnknownKey { key, .. } => write!(f, "{file}: unknown key `{key}`"),
+        }
+    }
+}
+
+fn parse_file<T: DeserializeOwned>(path: &Path) -> Result<(T, toml::Table), ConfigError> {
+    let metadata = fs::metadata(path).map_err(|err| ConfigError::Unreadable {
+        path: path.to_path_buf(),
+        message: err.to_string(),
+    })?;
+    if metadata.len() > MAX_CONFIG_BYTES {
+        return Err(ConfigError::TooLarge {
+            path: path.to_path_buf(),
+            limit_bytes: MAX_CONFIG_BYTES,
+        });
+    }
+    let contents = fs::read_to_string(path).map_err(|err| ConfigError::Unreadable {
+        path: path.to_path_buf(),
+        message: err.to_string(),
+    })?;
+    let invalid = |err: toml::de::Error| ConfigError::Invalid {
+        path: path.to_path_buf(),
+        line: err
+            .span()
+            .map(|span| contents[..span.start].matches('\n').count() + 1),
+        message: err.message().trim().to_string(),
+    };
+    let table = contents.parse::<toml::Table>().map_err(invalid)?;
+    let parsed = toml::from_str::<T>(&contents).map_err(invalid)?;
+    Ok((parsed, table))
+}
+
+/// Dotted names of keys in `actual` that the current schema (`reference`) does not know.
+fn collect_unknown_keys(
+    actual: &toml::Table,
+    reference: &toml::Table,
+    prefix: &str,
+    unknown: &mut Vec<String>,
+) {
+    for (key, value) in actual {
+        let name = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match (reference.get(key), value) {
+            (None, _) => unknown.push(name),
+            (Some(toml::Value::Table(reference)), toml::Value::Table(actual)) => {
+                collect_unknown_keys(actual, reference, &name, unknown);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn unknown_key_errors(
+    path: &Path,
+    actual: &toml::Table,
+    reference: toml::Table,
+) -> Vec<ConfigError> {
+    let mut unknown = Vec::new();
+    collect_unknown_keys(actual, &reference, "", &mut unknown);
+    unknown
+        .into_iter()
+        .map(|key| ConfigError::UnknownKey {
+            path: path.to_path_buf(),
+            key,
+        })
+        .collect()
+}
+
+/// The known keys, with every theme named in `actual` taking the full set of color keys.
+fn config_schema(actual: &toml::Table) -> toml::Table {
+    let mut settings = super::Settings::default();
+    if let Some(toml::Value::Table(themes)) = actual
+        .get("settings")
+        .and_then(|settings| settings.get("themes"))
+    {
+        settings.themes = themes
+            .keys()
+            .map(|name| (name.clone(), CustomTheme::schema()))
+            .collect();
+    }
+    let mut schema = toml::Table::try_from(ConfigSnapshot {
+        config_version: CURRENT_CONFIG_VERSION,
+        high_scores: HighScores::default(),
+        settings,
+    })
+    .unwrap_or_default();
+    // Single score from configs older than version 1.
+    schema.insert("high_score".to_string(), toml::Value::Integer(0));
+    schema.insert("high_scores".to_string(), score_tables_schema(actual));
+    schema
+}
+
+fn data_schema(actual: &toml::Table) -> toml::Table {
+    let mut schema = toml::Table::try_from(DataFileV2 {
+        data_version: CURRENT_DATA_VERSION,
+        high_scores: HighScores::default(),
+    })
+    .unwrap_or_default();
+    schema.insert("high_scores".to_string(), score_tables_schema(actual));
+    schema
+}
+
+/// The score tables in `actual` whose names are a `ScoreKey`, so only the
+/// tables loading skips count as unknown.
+fn score_tables_schema(actual: &toml::Table) -> toml::Value {
+    let tables = actual
+        .get("high_scores")
+        .and_then(toml::Value::as_table)
+        .map(|tables| {
+            tables
+                .keys()
+                .filter(|name| name.parse::<ScoreKey>().is_ok())
+                .map(|name| (name.clone(), toml::Value::Array(Vec::new())))
+                .collect()
+        })
+        .unwrap_or_default();
+    toml::Value::Table(tables)
+}
+
+/// Reads a config file or backup, migrating older formats. Unknown keys are
+/// returned alongside the config since they do not stop it from loading.
+pub(super) fn check_config_file(path: &Path) -> Result<(AppConfig, Vec<ConfigError>), ConfigError> {
+    let (raw, table): (RawConfigFile, _) = parse_file(path)?;
+    let version = raw.config_version.unwrap_or(0);
+    if version > CURRENT_CONFIG_VERSION {
+        return Err(ConfigError::NewerVersion {
+            path: path.to_path_buf(),
+            version,
+        });
+    }
+    let warnings = unknown_key_errors(path, &table, config_schema(&table));
+    Ok((migrate_config(raw).0, warnings))
+}
+
+pub(super) fn check_data_file(path: &Path) -> Result<(HighScores, Vec<ConfigError>), ConfigError> {
+    let (raw, table): (RawDataFile, _) = parse_file(path)?;
+    let version = raw.data_version.unwrap_or(0);
+    if version > CURRENT_DATA_VERSION {
+        return Err(ConfigError::NewerVersion {
+            path: path.to_path_buf(),
+            version,
+        });
+    }
+    let warnings = unknown_key_errors(path, &table, data_schema(&table));
+    Ok((raw.high_scores.into(), warnings))
+}
+
+/// Every problem with the config and data files beside `config_path`. Missing
+/// files are not a problem; they are created with defaults on first save.
+pub(super) fn check_files(config_path: &Path) -> Vec<ConfigError> {
+    let mut problems = Vec::new();
+    if fs::metadata(config_path).is_ok() {
+        match check_config_file(config_path) {
+            Ok((_, warnings)) => problems.extend(warnings),
+            Err(err) => problems.push(err),
+        }
+    }
+    let data_path = data_path_for(config_path);
+    if fs::metadata(&data_path).is_ok() {
+        match check_data_file(&data_path) {
+            Ok((_, warnings)) => problems.extend(warnings),
+            Err(err) => problems.push(err),
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{Difficulty, Language};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_path(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!(
+            "rustnake-check-{}-{}-{}.toml",
+            name,
+            std::process::id(),
+            nanos
+        ))
+    }
+
+    #[test]
+    fn reports_invalid_values_with_line_numbers() {
+        let path = temp_path("invalid");
+        fs::write(
+            &path,
+            "config_version = 3\n\n[settings]\nlanguage = \"xx\"\n",
+        )
+        .unwrap();
+
+        let err = check_config_file(&path).unwrap_err();
+        assert!(
+            matches!(err, ConfigError::Invalid { line: Some(4), .. }),
+            "{err:?}"
+        );
+        assert!(err.to_string().contains("line 4"));
+        assert!(err.is_fatal());
+
+        fs::write(&path, "config_version = 99").unwrap();
+        let err = check_config_file(&path).unwrap_err();
+        assert_eq!(
+            err,
+            ConfigError::NewerVersion {
+                path: path.clone(),
+                version: 99
+            }
+        );
+        assert!(err.to_string().contains("newer rustnake"));
+
+        fs::write(&path, "high_score = 12").unwrap();
+        let (migrated, warnings) = check_config_file(&path).unwrap();
+        assert_eq!(
+            migrated
+                .high_scores
+                .best(ScoreKey::classic(Difficulty::Easy)),
+            12
+        );
+        assert!(warnings.is_empty());
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn unknown_keys_are_non_fatal_warnings() {
+        let path = temp_path("unknown");
+        fs::write(
+            &path,
+            r##"
+config_version = 3
+themes = 1
+
+[settings]
+language = "es"
+sound = false
+
+[settings.custom_difficulty]
+tick = 90
+
+[settings.themes.dusk]
+border = "#ff8000"
+glow = "#ffffff"
+"##,
+        )
+        .unwrap();
+
+        let (config, warnings) = check_config_file(&path).unwrap();
+        assert_eq!(config.settings.language, Language::Es);
+        let keys: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+        assert_eq!(warnings.len(), 4, "{keys:?}");
+        assert!(keys.iter().any(|key| key.ends_with("`themes`")));
+        assert!(keys.iter().any(|key| key.ends_with("`settings.sound`")));
+        assert!(
+            keys.iter()
+                .any(|key| key.ends_with("`settings.custom_difficulty.tick`"))
+        );
+        assert!(
+            keys.iter()
+                .any(|key| key.ends_with("`settings.themes.dusk.glow`"))
+        );
+        assert!(config.settings.themes["dusk"].border.is_some());
+        assert!(warnings.iter().all(|warning| !warning.is_fatal()));
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn check_files_covers_config_and_data() {
+        let path = temp_path("files");
+        assert!(check_files(&path).is_empty());
+
+        fs::write(&path, "config_version = 3\n[settings]\nsound_on = true\n").unwrap();
+        fs::write(data_path_for(&path), "data_version = 1\nhigh_scores = 4\n").unwrap();
+
+        let problems = check_files(&path);
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].path(), data_path_for(&path));
+
+        let _ = fs::remove_file(data_path_for(&path));
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,227 @@
+//! Score export for `export-scores`: leaderboards, lifetime stats, and run history
+//! as JSON or CSV, picked by the output file extension.
+
+use super::{AppConfig, HighScoreEntry, RunRecord, current_utc_date, save_atomic};
+use crate::utils::{Difficulty, GameMode};
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("json") => Ok(Self::Json),
+            Some("csv") => Ok(Self::Csv),
+            _ => Err(format!(
+                "unsupported export format for {} (use a .json or .csv file)",
+                path.display()
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct LifetimeStats {
+    pub games_played: usize,
+    pub total_score: u64,
+    pub best_score: u32,
+    pub total_play_secs: u64,
+    pub total_food_eaten: u64,
+    pub total_power_ups: u64,
+    pub best_combo: u32,
+    pub max_length: u32,
+}
+
+impl LifetimeStats {
+    pub fn from_history(history: &[RunRecord]) -> Self {
+        history.iter().fold(Self::default(), |mut stats, run| {
+            stats.games_played += 1;
+            stats.total_score += u64::from(run.score);
+            stats.best_score = stats.best_score.max(run.score);
+            stats.total_play_secs += run.play_secs;
+            stats.total_food_eaten += u64::from(run.food_eaten);
+            stats.total_power_ups += u64::from(run.power_ups);
+            stats.best_combo = stats.best_combo.max(run.best_combo);
+            stats.max_length = stats.max_length.max(run.max_length);
+            stats
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct ScoreTable<'a> {
+    difficulty: Difficulty,
+    mode: GameMode,
+    width: u16,
+    height: u16,
+    entries: &'a [HighScoreEntry],
+}
+
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    exported_on: String,
+    high_scores: Vec<ScoreTable<'a>>,
+    stats: LifetimeStats,
+    history: &'a [RunRecord],
+}
+
+pub fn render_json(config: &AppConfig, history: &[RunRecord]) -> Result<String, String> {
+    let export = JsonExport {
+        exported_on: current_utc_date(),
+        high_scores: config
+            .high_scores
+            .listed_keys()
+            .into_iter()
+            .map(|key| ScoreTable {
+                difficulty: key.difficulty,
+                mode: key.mode,
+                width: key.width,
+                height: key.height,
+                entries: config.high_scores.entries(key),
+            })
+            .collect(),
+        stats: LifetimeStats::from_history(history),
+        history,
+    };
+    serde_json::to_string_pretty(&export).map_err(|err| err.to_string())
+}
+
+const CSV_HEADER: &str = "kind,difficulty,mode,board,rank,initials,score,date,play_secs,food_eaten,power_ups,best_combo,max_length";
+
+/// One flat table: leaderboard rows (`kind = high_score`) then runs (`kind = run`).
+/// Lifetime stats are left out since they are sums over the run rows.
+pub fn render_csv(config: &AppConfig, history: &[RunRecord]) -> String {
+    let mut lines = vec![CSV_HEADER.to_string()];
+    for key in config.high_scores.listed_keys() {
+        for (rank, entry) in config.high_scores.entries(key).iter().enumerate() {
+            // Initials and dates are restricted to [A-Z0-9-], so no CSV quoting is needed.
+            lines.push(format!(
+                "high_score,{},{},{}x{},{},{},{},{},,,,,",
+                key.difficulty.name(),
+                key.mode.name(),
+                key.width,
+                key.height,
+                rank + 1,
+                entry.initials,
+                entry.score,
+                entry.date
+            ));
+        }
+    }
+    for run in history {
+        lines.push(format!(
+            "run,{},,,,,{},{},{},{},{},{},{}",
+            run.difficulty.name(),
+            run.score,
+            run.date,
+            run.play_secs,
+            run.food_eaten,
+            run.power_ups,
+            run.best_combo,
+            run.max_length
+        ));
+    }
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+pub fn export_scores(path: &Path, config: &AppConfig, history: &[RunRecord]) -> Result<(), String> {
+    let contents = match ExportFormat::from_path(path)? {
+        ExportFormat::Json => render_json(config, history)?,
+        ExportFormat::Csv => render_csv(config, history),
+    };
+    save_atomic(path, &contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::ScoreKey;
+    use crate::utils::Initials;
+
+    fn sample() -> (AppConfig, Vec<RunRecord>) {
+        let mut config = AppConfig::default();
+        config.high_scores.insert(
+            ScoreKey::classic(Difficulty::Hard),
+            HighScoreEntry::new(120, Initials::from("ABC".to_string()), "2026-10-16"),
+        );
+        let history = vec![
+            RunRecord {
+                date: "2026-10-15".to_string(),
+                difficulty: Difficulty::Hard,
+                score: 120,
+                play_secs: 75,
+                food_eaten: 12,
+                power_ups: 2,
+                best_combo: 4,
+                max_length: 15,
+            },
+            RunRecord {
+                date: "2026-10-16".to_string(),
+                difficulty: Difficulty::Easy,
+                score: 30,
+                play_secs: 20,
+                food_eaten: 3,
+                power_ups: 0,
+                best_combo: 1,
+                max_length: 6,
+            },
+        ];
+        (config, history)
+    }
+
+    #[test]
+    fn format_is_chosen_by_extension() {
+        assert_eq!(
+            ExportFormat::from_path(Path::new("scores.JSON")),
+            Ok(ExportFormat::Json)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("out/scores.csv")),
+            Ok(ExportFormat::Csv)
+        );
+        assert!(ExportFormat::from_path(Path::new("scores.txt")).is_err());
+        assert!(ExportFormat::from_path(Path::new("scores")).is_err());
+    }
+
+    #[test]
+    fn csv_lists_leaderboards_then_runs() {
+        let (config, history) = sample();
+        let csv = render_csv(&config, &history);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "high_score,hard,classic,40x20,1,ABC,120,2026-10-16,,,,,"
+        );
+        assert_eq!(lines[2], "run,hard,,,,,120,2026-10-15,75,12,2,4,15");
+        assert_eq!(lines[3], "run,easy,,,,,30,2026-10-16,20,3,0,1,6");
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn json_includes_scores_stats_and_history() {
+        let (config, history) = sample();
+        let json: serde_json::Value =
+            serde_json::from_str(&render_json(&config, &history).unwrap()).unwrap();
+
+        assert_eq!(json["high_scores"].as_array().unwrap().len(), 5);
+        assert_eq!(json["high_scores"][2]["difficulty"], "hard");
+        assert_eq!(json["high_scores"][2]["mode"], "classic");
+        assert_eq!(json["high_scores"][2]["entries"][0]["initials"], "ABC");
+        assert_eq!(json["stats"]["games_played"], 2);
+        assert_eq!(json["stats"]["total_score"], 150);
+        assert_eq!(json["stats"]["best_combo"], 4);
+        assert_eq!(json["stats"]["max_length"], 15);
+        assert_eq!(json["history"][1]["difficulty"], "easy");
+    }
+}
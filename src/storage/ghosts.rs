@@ -0,0 +1,94 @@
+//! Personal-best runs kept beside the config, one per score table, for the
+//! ghost to replay. JSON rather than TOML, which cannot hold seeds past
+//! `i64::MAX`.
+
+use super::{ScoreKey, companion_path, save_atomic};
+use crate::core::GhostRun;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Long runs log a few turns per food, so this holds hours of play.
+const MAX_GHOSTS_BYTES: u64 = 4 * 1024 * 1024;
+
+pub(super) fn ghosts_path_for(config_path: &Path) -> PathBuf {
+    companion_path(config_path, "ghosts").with_extension("json")
+}
+
+fn load_ghosts_from_path(path: &Path) -> BTreeMap<String, GhostRun> {
+    let Ok(metadata) = fs::metadata(path) else {
+        return BTreeMap::new();
+    };
+    if metadata.len() > MAX_GHOSTS_BYTES {
+        return BTreeMap::new();
+    }
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub(super) fn load_ghost_from_path(path: &Path, key: ScoreKey) -> Option<GhostRun> {
+    load_ghosts_from_path(path).remove(&key.to_string())
+}
+
+/// Keeps `run` as the ghost for `key` if it beats the one stored there.
+/// Returns whether it did.
+pub(super) fn save_ghost_to_path(
+    path: &Path,
+    key: ScoreKey,
+    run: GhostRun,
+) -> Result<bool, String> {
+    let mut ghosts = load_ghosts_from_path(path);
+    if ghosts
+        .get(&key.to_string())
+        .is_some_and(|best| best.score >= run.score)
+    {
+        return Ok(false);
+    }
+    ghosts.insert(key.to_string(), run);
+    let serialized = serde_json::to_string(&ghosts).map_err(|err| err.to_string())?;
+    save_atomic(path, &serialized)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Game;
+    use crate::utils::Difficulty;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn only_a_better_run_replaces_the_ghost() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "rustnake-ghosts-{}-{}.json",
+            std::process::id(),
+            nanos
+        ));
+        let mut game = Game::new_seeded(Difficulty::Hard, 40, 20, 0, u64::MAX);
+        let key = ScoreKey::classic(Difficulty::Hard);
+
+        game.score = 30;
+        assert!(save_ghost_to_path(&path, key, GhostRun::of(&game)).unwrap());
+        assert!(!save_ghost_to_path(&path, key, GhostRun::of(&game)).unwrap());
+        game.score = 50;
+        assert!(save_ghost_to_path(&path, key, GhostRun::of(&game)).unwrap());
+
+        let ghost = load_ghost_from_path(&path, key).unwrap();
+        assert_eq!(ghost.score, 50);
+        assert_eq!(ghost.replay.seed, u64::MAX);
+        assert_eq!(
+            load_ghost_from_path(&path, ScoreKey::classic(Difficulty::Easy)),
+            None
+        );
+
+        let _ = fs::remove_file(path);
+    }
+}
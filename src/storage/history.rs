@@ -0,0 +1,117 @@
+//! Per-run history kept in a file beside the config, used for score exports.
+
+use super::{companion_path, current_utc_date, save_atomic};
+use crate::utils::Difficulty;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Oldest runs are dropped past this many entries.
+pub const MAX_HISTORY_RUNS: usize = 200;
+const MAX_HISTORY_BYTES: u64 = 256 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub date: String,
+    pub difficulty: Difficulty,
+    pub score: u32,
+    pub play_secs: u64,
+    pub food_eaten: u32,
+    pub power_ups: u32,
+    pub best_combo: u32,
+    /// Missing from runs recorded before it was tracked.
+    #[serde(default)]
+    pub max_length: u32,
+}
+
+impl RunRecord {
+    pub fn new(difficulty: Difficulty, score: u32) -> Self {
+        Self {
+            date: current_utc_date(),
+            difficulty,
+            score,
+            play_secs: 0,
+            food_eaten: 0,
+            power_ups: 0,
+            best_combo: 0,
+            max_length: 0,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct HistoryFile {
+    #[serde(default)]
+    runs: Vec<RunRecord>,
+}
+
+pub(super) fn history_path_for(config_path: &Path) -> PathBuf {
+    companion_path(config_path, "history")
+}
+
+pub(super) fn load_history_from_path(path: &Path) -> Vec<RunRecord> {
+    let Ok(metadata) = fs::metadata(path) else {
+        return Vec::new();
+    };
+    if metadata.len() > MAX_HISTORY_BYTES {
+        return Vec::new();
+    }
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| toml::from_str::<HistoryFile>(&contents).ok())
+        .map(|file| file.runs)
+        .unwrap_or_default()
+}
+
+pub(super) fn append_history_to_path(path: &Path, record: RunRecord) -> Result<(), String> {
+    let mut runs = load_history_from_path(path);
+    runs.push(record);
+    let overflow = runs.len().saturating_sub(MAX_HISTORY_RUNS);
+    runs.drain(..overflow);
+    let serialized = toml::to_string(&HistoryFile { runs }).map_err(|err| err.to_string())?;
+    save_atomic(path, &serialized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn history_path_sits_beside_config() {
+        assert_eq!(
+            history_path_for(Path::new("/tmp/Rustnake/config.toml")),
+            PathBuf::from("/tmp/Rustnake/history.toml")
+        );
+        assert_eq!(
+            history_path_for(Path::new("/home/user/.rustnake.toml")),
+            PathBuf::from("/home/user/.rustnake-history.toml")
+        );
+    }
+
+    #[test]
+    fn append_keeps_only_the_newest_runs() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "rustnake-history-{}-{}.toml",
+            std::process::id(),
+            nanos
+        ));
+
+        for score in 0..(MAX_HISTORY_RUNS as u32 + 2) {
+            append_history_to_path(&path, RunRecord::new(Difficulty::Easy, score)).unwrap();
+        }
+
+        let runs = load_history_from_path(&path);
+        assert_eq!(runs.len(), MAX_HISTORY_RUNS);
+        assert_eq!(runs[0].score, 2);
+        assert_eq!(runs.last().unwrap().score, MAX_HISTORY_RUNS as u32 + 1);
+
+        let _ = fs::remove_file(path);
+    }
+}
@@ -1,59 +1,155 @@
 //! Persistence helpers for local game data.
 
-use crate::utils::{Difficulty, Language};
+use crate::core::{Game, GhostRun};
+use crate::i18n;
+use crate::utils::{
+    AxisPace, BoardPattern, ControlScheme, CustomDifficulty, CustomKeys, Difficulty, GameSpeed,
+    HudPosition, Initials, Language, PowerUpSet, RenderBackend, SoundSettings, SymbolSet,
+};
 use serde::{Deserialize, Serialize};
 #[cfg(unix)]
 use std::os::unix::fs::OpenOptionsExt;
 use std::{
+    collections::BTreeMap,
     fs::{self, OpenOptions},
     io::Write,
     path::{Path, PathBuf},
+    time::Duration,
 };
 
-const CURRENT_CONFIG_VERSION: u32 = 1;
+mod autosave;
+mod backup;
+mod check;
+mod export;
+mod ghosts;
+mod history;
+mod theme;
+
+pub use check::ConfigError;
+pub use export::export_scores;
+pub use history::RunRecord;
+pub use rustnake::scores::{HIGH_SCORE_TABLE_SIZE, HighScoreEntry, HighScores, ScoreKey};
+pub use theme::{CustomTheme, Rgb};
+
+/// Version 4 backups keep their scores in tables keyed by `ScoreKey`.
+const CURRENT_CONFIG_VERSION: u32 = 4;
+/// Version 2 keys the score tables by `ScoreKey` rather than by difficulty.
+const CURRENT_DATA_VERSION: u32 = 2;
 const MAX_CONFIG_BYTES: u64 = 64 * 1024;
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(default)]
-pub struct HighScores {
-    pub easy: u32,
-    pub medium: u32,
-    pub hard: u32,
-    pub extreme: u32,
-}
-
-impl HighScores {
-    pub fn get(&self, difficulty: Difficulty) -> u32 {
-        match difficulty {
-            Difficulty::Easy => self.easy,
-            Difficulty::Medium => self.medium,
-            Difficulty::Hard => self.hard,
-            Difficulty::Extreme => self.extreme,
+/// Accepts both the current table and the single score stored before config version 2.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged, expecting = "expected a list of score entries")]
+enum RawScoreList {
+    Legacy(u32),
+    Table(Vec<HighScoreEntry>),
+}
+
+impl Default for RawScoreList {
+    fn default() -> Self {
+        Self::Table(Vec::new())
+    }
+}
+
+impl From<RawScoreList> for Vec<HighScoreEntry> {
+    fn from(value: RawScoreList) -> Self {
+        match value {
+            RawScoreList::Legacy(0) => Vec::new(),
+            RawScoreList::Legacy(score) => vec![legacy_entry(score)],
+            RawScoreList::Table(mut entries) => {
+                entries.sort_by(|a, b| b.score.cmp(&a.score));
+                entries.truncate(HIGH_SCORE_TABLE_SIZE);
+                entries
+            }
         }
     }
+}
 
-    pub fn set(&mut self, difficulty: Difficulty, score: u32) {
-        match difficulty {
-            Difficulty::Easy => self.easy = score,
-            Difficulty::Medium => self.medium = score,
-            Difficulty::Hard => self.hard = score,
-            Difficulty::Extreme => self.extreme = score,
+/// Score tables as any version wrote them: one per `ScoreKey`, or one per
+/// difficulty before data version 2. Tables named neither way are skipped
+/// here and reported by `config check`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(from = "BTreeMap<String, RawScoreList>")]
+struct RawHighScores(HighScores);
+
+impl From<BTreeMap<String, RawScoreList>> for RawHighScores {
+    fn from(tables: BTreeMap<String, RawScoreList>) -> Self {
+        let mut high_scores = HighScores::default();
+        for (name, list) in tables {
+            if let Ok(key) = name.parse::<ScoreKey>() {
+                let mut entries = high_scores.entries(key).to_vec();
+                entries.extend(Vec::<HighScoreEntry>::from(list));
+                high_scores.set_entries(key, entries);
+            }
         }
+        Self(high_scores)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl From<RawHighScores> for HighScores {
+    fn from(value: RawHighScores) -> Self {
+        value.0
+    }
+}
+
+fn legacy_entry(score: u32) -> HighScoreEntry {
+    HighScoreEntry {
+        score,
+        initials: Initials::UNKNOWN,
+        date: String::new(),
+    }
+}
+
+/// Today's UTC date as `YYYY-MM-DD`, without pulling in a date crate.
+pub fn current_utc_date() -> String {
+    let days = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() / 86_400);
+    let (year, month, day) = civil_from_days(days as i64);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+// Howard Hinnant's days-to-civil conversion for the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[derive(Debug, Deserialize)]
 struct RawConfigFile {
     config_version: Option<u32>,
     #[serde(default)]
-    high_scores: HighScores,
+    high_scores: RawHighScores,
     #[serde(default)]
     settings: Settings,
     high_score: Option<u32>,
 }
 
+/// Settings-only config file; since version 3 scores live in the data file.
 #[derive(Debug, Serialize, Deserialize)]
-struct ConfigFileV1 {
+struct ConfigFileV3 {
+    config_version: u32,
+    #[serde(default)]
+    settings: Settings,
+}
+
+/// Scores and settings in one file, the layout used for backups (and by
+/// config versions 1-2, which `RawConfigFile` still reads).
+#[derive(Debug, Serialize, Deserialize)]
+struct ConfigSnapshot {
     config_version: u32,
     #[serde(default)]
     high_scores: HighScores,
@@ -61,34 +157,152 @@ struct ConfigFileV1 {
     settings: Settings,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Deserialize)]
+struct RawDataFile {
+    data_version: Option<u32>,
+    #[serde(default)]
+    high_scores: RawHighScores,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DataFileV2 {
+    data_version: u32,
+    #[serde(default)]
+    high_scores: HighScores,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Settings {
     pub language: Language,
     pub pause_on_focus_loss: bool,
+    /// Counts down and resumes a run paused on focus loss once the focus is
+    /// back.
+    pub resume_on_focus_gain: bool,
+    /// Seconds without input before a run pauses itself; 0 for never.
+    pub idle_pause_secs: u16,
     pub sound_on: bool,
+    pub sounds: SoundSettings,
     pub ui_compact: bool,
     pub default_difficulty: Difficulty,
+    /// How fast the snake moves, whatever the difficulty.
+    pub speed: GameSpeed,
+    // Stored as the disabled set so power-up types added later start enabled.
+    pub disabled_power_ups: PowerUpSet,
+    pub custom_difficulty: CustomDifficulty,
+    // Pre-filled on the next new-record prompt.
+    pub last_initials: Initials,
+    /// A built-in theme name or a key of `themes`.
+    pub theme: String,
+    /// The hidden rainbow theme, unlocked with the Konami code in the menu.
+    pub rainbow_unlocked: bool,
+    /// Colorblind-safe board colors, with the snake's head drawn as an arrow.
+    pub colorblind: bool,
+    /// No dim text styles.
+    pub high_contrast: bool,
+    /// No blinking and a slower top speed.
+    pub reduced_motion: bool,
+    /// Shortest tick in milliseconds, however fast a run gets; 0 for no cap.
+    pub speed_cap_ms: u16,
+    /// Two terminal columns per board cell, so the board looks square.
+    pub square_cells: bool,
+    pub axis_pace: AxisPace,
+    /// Height of a terminal cell over its width, in percent, for
+    /// `axis_pace = "ratio"`.
+    pub cell_aspect_percent: u16,
+    /// Count East Asian ambiguous-width characters such as `★` and `→` as
+    /// two columns, as CJK terminals usually draw them.
+    pub wide_ambiguous: bool,
+    /// Fancy Unicode symbols, or ASCII for fonts that lack them.
+    pub symbols: SymbolSet,
+    pub board_pattern: BoardPattern,
+    pub hud_position: HudPosition,
+    /// The HUD as a single status line, without the controls.
+    pub hud_compact: bool,
+    /// Active effects and a power-up legend to the right of the board.
+    pub sidebar: bool,
+    /// Mark where the best earlier run on the same table had its head.
+    pub ghost: bool,
+    /// Show the score in the terminal's window title during a run.
+    pub window_title: bool,
+    pub control_scheme: ControlScheme,
+    pub custom_keys: CustomKeys,
+    /// Held (like Shift) to boost.
+    pub boost_key: char,
+    /// How often `text-mode` reads out the game state.
+    pub text_mode_interval_ms: u64,
+    pub renderer: RenderBackend,
+    pub themes: BTreeMap<String, CustomTheme>,
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
-            language: Language::En,
+            language: i18n::detect(),
             pause_on_focus_loss: true,
+            resume_on_focus_gain: false,
+            idle_pause_secs: 0,
             sound_on: true,
+            sounds: SoundSettings::default(),
             ui_compact: false,
             default_difficulty: Difficulty::Medium,
+            speed: GameSpeed::Difficulty,
+            disabled_power_ups: PowerUpSet::default(),
+            custom_difficulty: CustomDifficulty::default(),
+            last_initials: Initials::default(),
+            theme: "classic".to_string(),
+            rainbow_unlocked: false,
+            colorblind: false,
+            high_contrast: false,
+            reduced_motion: false,
+            speed_cap_ms: 0,
+            square_cells: false,
+            axis_pace: AxisPace::Auto,
+            cell_aspect_percent: 200,
+            wide_ambiguous: false,
+            symbols: SymbolSet::Fancy,
+            board_pattern: BoardPattern::Plain,
+            hud_position: HudPosition::Below,
+            hud_compact: false,
+            sidebar: false,
+            ghost: false,
+            window_title: true,
+            control_scheme: ControlScheme::Wasd,
+            custom_keys: CustomKeys::default(),
+            boost_key: 'b',
+            text_mode_interval_ms: 2_000,
+            renderer: RenderBackend::Ansi,
+            themes: BTreeMap::new(),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+impl Settings {
+    /// `speed_cap_ms` as a duration, held to the slowest custom tick rate since
+    /// the config is hand-editable.
+    pub fn speed_cap(&self) -> Duration {
+        let ms = self.speed_cap_ms.min(CustomDifficulty::TICK_MS_RANGE.1);
+        Duration::from_millis(u64::from(ms))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct AppConfig {
     pub high_scores: HighScores,
     pub settings: Settings,
 }
 
+/// Server for an optional online feature (`sync.toml`, `leaderboard.toml`),
+/// read from beside the config. The game never writes these files, so the
+/// token stays where the user put it.
+#[cfg(any(feature = "sync", feature = "leaderboard"))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct EndpointSettings {
+    pub url: String,
+    pub token: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct LegacyHighScoreFile {
     high_score: u32,
@@ -96,12 +310,17 @@ struct LegacyHighScoreFile {
 
 impl From<LegacyHighScoreFile> for HighScores {
     fn from(value: LegacyHighScoreFile) -> Self {
-        Self {
-            easy: value.high_score,
-            medium: value.high_score,
-            hard: value.high_score,
-            extreme: value.high_score,
+        let entries: Vec<HighScoreEntry> = RawScoreList::Legacy(value.high_score).into();
+        let mut high_scores = Self::default();
+        for difficulty in [
+            Difficulty::Easy,
+            Difficulty::Medium,
+            Difficulty::Hard,
+            Difficulty::Extreme,
+        ] {
+            high_scores.set_entries(ScoreKey::classic(difficulty), entries.clone());
         }
+        high_scores
     }
 }
 
@@ -155,9 +374,23 @@ fn config_path() -> PathBuf {
     legacy_local_config_path()
 }
 
+/// XDG base-directory lookup: `$XDG_CONFIG_HOME` if it is an absolute path,
+/// otherwise `$HOME/.config`.
+#[cfg(any(all(unix, not(target_os = "macos")), test))]
+fn xdg_config_path(xdg_config_home: Option<PathBuf>, home: Option<PathBuf>) -> Option<PathBuf> {
+    let base = xdg_config_home
+        .filter(|path| path.is_absolute())
+        .or_else(|| home.map(|home| home.join(".config")))?;
+    Some(base.join("rustnake").join("config.toml"))
+}
+
 #[cfg(all(unix, not(target_os = "macos")))]
 fn config_path() -> PathBuf {
-    legacy_home_config_path().unwrap_or_else(legacy_local_config_path)
+    xdg_config_path(
+        std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from),
+        std::env::var_os("HOME").map(PathBuf::from),
+    )
+    .unwrap_or_else(legacy_local_config_path)
 }
 
 #[cfg(not(any(unix, target_os = "windows")))]
@@ -165,25 +398,66 @@ fn config_path() -> PathBuf {
     legacy_local_config_path()
 }
 
+/// Logs are state, not configuration: `$XDG_STATE_HOME` if it is an absolute
+/// path, otherwise `$HOME/.local/state`.
+#[cfg(any(all(unix, not(target_os = "macos")), test))]
+fn xdg_log_path(xdg_state_home: Option<PathBuf>, home: Option<PathBuf>) -> Option<PathBuf> {
+    let base = xdg_state_home
+        .filter(|path| path.is_absolute())
+        .or_else(|| home.map(|home| home.join(".local").join("state")))?;
+    Some(base.join("rustnake").join("rustnake.log"))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn log_path() -> PathBuf {
+    xdg_log_path(
+        std::env::var_os("XDG_STATE_HOME").map(PathBuf::from),
+        std::env::var_os("HOME").map(PathBuf::from),
+    )
+    .unwrap_or_else(|| log_path_for(&config_path()))
+}
+
+#[cfg(target_os = "macos")]
+fn log_path() -> PathBuf {
+    if let Ok(home) = std::env::var("HOME") {
+        return PathBuf::from(home)
+            .join("Library")
+            .join("Logs")
+            .join("Rustnake")
+            .join("rustnake.log");
+    }
+
+    log_path_for(&config_path())
+}
+
+#[cfg(target_os = "windows")]
+fn log_path() -> PathBuf {
+    if let Ok(local_app_data) = std::env::var("LOCALAPPDATA") {
+        return PathBuf::from(local_app_data)
+            .join("Rustnake")
+            .join("rustnake.log");
+    }
+
+    log_path_for(&config_path())
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn log_path() -> PathBuf {
+    log_path_for(&config_path())
+}
+
 fn migrate_config(raw: RawConfigFile) -> (AppConfig, bool) {
     let version = raw.config_version.unwrap_or(0);
-    let migrated = if version == 0 {
-        let high_scores = if raw.high_scores == HighScores::default() {
-            raw.high_score
-                .map(|high_score| HighScores::from(LegacyHighScoreFile { high_score }))
-                .unwrap_or_default()
-        } else {
-            raw.high_scores
-        };
-        AppConfig {
-            high_scores,
-            settings: raw.settings,
-        }
-    } else {
-        AppConfig {
-            high_scores: raw.high_scores,
-            settings: raw.settings,
+    let mut high_scores = HighScores::from(raw.high_scores);
+    // Version 0 configs kept a single `high_score` before the tables existed.
+    if version == 0 && high_scores == HighScores::default() {
+        if let Some(high_score) = raw.high_score {
+            high_scores = HighScores::from(LegacyHighScoreFile { high_score });
         }
+    }
+    let migrated = AppConfig {
+        high_scores,
+        settings: raw.settings,
     };
 
     let should_persist_migration = version < CURRENT_CONFIG_VERSION;
@@ -199,38 +473,101 @@ fn load_raw_config(path: &Path) -> Option<RawConfigFile> {
     toml::from_str::<RawConfigFile>(&contents).ok()
 }
 
-fn load_config_from_path(path: &Path) -> AppConfig {
-    if let Some(raw) = load_raw_config(path) {
-        let (config, migrated) = migrate_config(raw);
-        if migrated {
-            let _ = save_config_to_path(path, &config);
-        }
-        return config;
+fn load_raw_data(path: &Path) -> Option<RawDataFile> {
+    let metadata = fs::metadata(path).ok()?;
+    if metadata.len() > MAX_CONFIG_BYTES {
+        return None;
     }
+    let contents = fs::read_to_string(path).ok()?;
+    toml::from_str::<RawDataFile>(&contents).ok()
+}
+
+/// `config.toml` -> `data.toml`, `.rustnake.toml` -> `.rustnake-data.toml`.
+fn companion_path(config_path: &Path, name: &str) -> PathBuf {
+    let stem = config_path
+        .file_stem()
+        .map_or_else(|| "rustnake".into(), |stem| stem.to_string_lossy());
+    let file_name = if stem == "config" {
+        format!("{name}.toml")
+    } else {
+        format!("{stem}-{name}.toml")
+    };
+    config_path.with_file_name(file_name)
+}
 
-    AppConfig::default()
+fn data_path_for(config_path: &Path) -> PathBuf {
+    companion_path(config_path, "data")
 }
 
-fn migrate_legacy_config_if_needed(target_path: &Path) {
-    if fs::metadata(target_path).is_ok() {
-        return;
+/// Where the log goes when there is no platform location for it:
+/// `config.toml` -> `rustnake.log`, `.rustnake.toml` -> `.rustnake.log`.
+fn log_path_for(config_path: &Path) -> PathBuf {
+    let stem = config_path
+        .file_stem()
+        .map_or_else(|| "rustnake".into(), |stem| stem.to_string_lossy());
+    let stem = if stem == "config" {
+        "rustnake".into()
+    } else {
+        stem
+    };
+    config_path.with_file_name(format!("{stem}.log"))
+}
+
+fn load_config_from_path(path: &Path) -> AppConfig {
+    let data_path = data_path_for(path);
+    let (mut config, migrated) =
+        load_raw_config(path).map_or_else(|| (AppConfig::default(), false), migrate_config);
+    log::debug!("loaded {} (migrated: {migrated})", path.display());
+
+    // The data file is authoritative once it exists. Scores from a pre-split
+    // config are moved there before the config is rewritten without them.
+    let scores_saved = match load_raw_data(&data_path) {
+        Some(data) => {
+            config.high_scores = data.high_scores.into();
+            if data.data_version.unwrap_or(0) < CURRENT_DATA_VERSION {
+                let _ = save_high_scores_to_path(&data_path, &config.high_scores);
+            }
+            true
+        }
+        None if migrated => save_high_scores_to_path(&data_path, &config.high_scores).is_ok(),
+        None => true,
+    };
+    if migrated && scores_saved {
+        let _ = save_settings_to_path(path, &config.settings);
     }
+    config
+}
 
+fn migrate_legacy_config_if_needed(target_path: &Path) {
     let mut legacy_paths = Vec::with_capacity(2);
     if let Some(path) = legacy_home_config_path() {
         legacy_paths.push(path);
     }
     legacy_paths.push(legacy_local_config_path());
+    migrate_config_from_legacy_paths(target_path, &legacy_paths);
+}
+
+/// Copies the first readable legacy config (and its run history) to `target_path`
+/// unless a config already exists there. Legacy files are left in place.
+fn migrate_config_from_legacy_paths(target_path: &Path, legacy_paths: &[PathBuf]) {
+    if fs::metadata(target_path).is_ok() {
+        return;
+    }
 
     for legacy_path in legacy_paths {
         if legacy_path == target_path {
             continue;
         }
-        let Some(raw) = load_raw_config(&legacy_path) else {
+        let Some(raw) = load_raw_config(legacy_path) else {
             continue;
         };
         let (config, _) = migrate_config(raw);
         if save_config_to_path(target_path, &config).is_ok() {
+            let legacy_history = history::history_path_for(legacy_path);
+            let target_history = history::history_path_for(target_path);
+            if fs::metadata(&target_history).is_err() {
+                let _ = fs::copy(legacy_history, target_history);
+            }
             break;
         }
     }
@@ -279,19 +616,44 @@ fn save_atomic(path: &Path, contents: &str) -> Result<(), String> {
             return Err(err.to_string());
         }
 
+        log::debug!("saved {} ({} bytes)", path.display(), contents.len());
         return Ok(());
     }
 
     Err("failed to create temporary config file".to_string())
 }
 
+fn save_settings_to_path(path: &Path, settings: &Settings) -> Result<(), String> {
+    let file = ConfigFileV3 {
+        config_version: CURRENT_CONFIG_VERSION,
+        settings: settings.clone(),
+    };
+    let serialized = toml::to_string(&file).map_err(|err| err.to_string())?;
+    save_atomic(path, &serialized)
+}
+
+fn save_high_scores_to_path(path: &Path, high_scores: &HighScores) -> Result<(), String> {
+    let file = DataFileV2 {
+        data_version: CURRENT_DATA_VERSION,
+        high_scores: high_scores.clone(),
+    };
+    let serialized = toml::to_string(&file).map_err(|err| err.to_string())?;
+    save_atomic(path, &serialized)
+}
+
+/// Writes the data file, then the config beside it. Each write is atomic on its own.
 fn save_config_to_path(path: &Path, config: &AppConfig) -> Result<(), String> {
-    let data = ConfigFileV1 {
+    save_high_scores_to_path(&data_path_for(path), &config.high_scores)?;
+    save_settings_to_path(path, &config.settings)
+}
+
+fn save_snapshot_to_path(path: &Path, config: &AppConfig) -> Result<(), String> {
+    let snapshot = ConfigSnapshot {
         config_version: CURRENT_CONFIG_VERSION,
-        high_scores: config.high_scores,
-        settings: config.settings,
+        high_scores: config.high_scores.clone(),
+        settings: config.settings.clone(),
     };
-    let serialized = toml::to_string(&data).map_err(|err| err.to_string())?;
+    let serialized = toml::to_string(&snapshot).map_err(|err| err.to_string())?;
     save_atomic(path, &serialized)
 }
 
@@ -301,18 +663,155 @@ pub fn load_config() -> AppConfig {
     load_config_from_path(&path)
 }
 
+pub struct CheckedConfig {
+    pub config: AppConfig,
+    /// Problems found before falling back to defaults; empty for a clean load.
+    pub problems: Vec<ConfigError>,
+}
+
+/// Like `load_config`, but also reports why parts of the files were ignored.
+pub fn load_config_checked() -> CheckedConfig {
+    let path = config_path();
+    migrate_legacy_config_if_needed(&path);
+    let problems = check::check_files(&path);
+    CheckedConfig {
+        config: load_config_from_path(&path),
+        problems,
+    }
+}
+
+/// Validates the config and data files without loading or rewriting them.
+pub fn check_config() -> Vec<ConfigError> {
+    check::check_files(&config_path())
+}
+
+/// Endpoint from `{name}.toml` beside the config, or `None` when that feature
+/// is not set up.
+#[cfg(any(feature = "sync", feature = "leaderboard"))]
+pub fn load_endpoint_settings(name: &str) -> Result<Option<EndpointSettings>, String> {
+    let path = companion_path(&config_path(), name);
+    if fs::metadata(&path).is_err() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(&path).map_err(|err| err.to_string())?;
+    let settings = toml::from_str::<EndpointSettings>(&contents)
+        .map_err(|err| format!("{}: {}", path.display(), err.message().trim()))?;
+    if settings.url.is_empty() {
+        return Err(format!("{}: `url` is not set", path.display()));
+    }
+    Ok(Some(settings))
+}
+
+/// The `lang-<code>.toml` translation packs beside the config, by name.
+pub fn translation_pack_paths() -> Vec<PathBuf> {
+    let Some(dir) = config_path().parent().map(Path::to_path_buf) else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("lang-") && name.ends_with(".toml"))
+        })
+        .collect();
+    paths.sort();
+    paths
+}
+
+pub fn data_path_for_current_user() -> PathBuf {
+    data_path_for(&config_path())
+}
+
+pub fn log_path_for_current_user() -> PathBuf {
+    log_path()
+}
+
+pub fn history_path_for_current_user() -> PathBuf {
+    history::history_path_for(&config_path())
+}
+
+pub fn ghosts_path_for_current_user() -> PathBuf {
+    ghosts::ghosts_path_for(&config_path())
+}
+
 pub fn save_config(config: &AppConfig) -> Result<(), String> {
     let path = config_path();
     save_config_to_path(&path, config)
 }
 
+pub fn save_settings(settings: &Settings) -> Result<(), String> {
+    save_settings_to_path(&config_path(), settings)
+}
+
+pub fn save_high_scores(high_scores: &HighScores) -> Result<(), String> {
+    save_high_scores_to_path(&data_path_for(&config_path()), high_scores)
+}
+
 pub fn config_path_for_current_user() -> PathBuf {
     config_path()
 }
 
+pub fn backup_config(backup_path: &Path) -> Result<(), String> {
+    backup::backup_config_to(&config_path(), backup_path)
+}
+
+/// Validates a backup without touching the live config.
+pub fn read_backup(backup_path: &Path) -> Result<AppConfig, String> {
+    backup::read_config_strict(backup_path)
+}
+
+/// Re-reads the config after an external edit. Unlike `load_config`, a file
+/// that fails to parse is an error rather than a silent reset to defaults.
+pub fn reload_config() -> Result<AppConfig, String> {
+    backup::read_config_strict(&config_path())
+}
+
+pub fn config_is_newer_than(backup_path: &Path) -> bool {
+    backup::config_is_newer_than(&config_path(), backup_path)
+}
+
+pub fn load_history() -> Vec<RunRecord> {
+    history::load_history_from_path(&history::history_path_for(&config_path()))
+}
+
+pub fn append_history(record: RunRecord) -> Result<(), String> {
+    history::append_history_to_path(&history::history_path_for(&config_path()), record)
+}
+
+/// The best run kept for `key`, if any.
+pub fn load_ghost(key: ScoreKey) -> Option<GhostRun> {
+    ghosts::load_ghost_from_path(&ghosts::ghosts_path_for(&config_path()), key)
+}
+
+/// Keeps `run` as the ghost for `key` if it beats the stored one, returning
+/// whether it did.
+pub fn save_ghost(key: ScoreKey, run: GhostRun) -> Result<bool, String> {
+    ghosts::save_ghost_to_path(&ghosts::ghosts_path_for(&config_path()), key, run)
+}
+
+/// Saves `game` as the run to offer back if this one never ends normally.
+pub fn save_autosave(game: &Game) -> Result<(), String> {
+    autosave::save_autosave_to_path(&autosave::autosave_path_for(&config_path()), game)
+}
+
+/// The run an earlier launch was still playing when it stopped, if any.
+pub fn load_autosave() -> Option<Game> {
+    autosave::load_autosave_from_path(&autosave::autosave_path_for(&config_path()))
+}
+
+pub fn clear_autosave() -> Result<(), String> {
+    autosave::clear_autosave_at_path(&autosave::autosave_path_for(&config_path()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::GameMode;
     #[cfg(unix)]
     use std::os::unix::fs::PermissionsExt;
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -344,10 +843,11 @@ language = "en"
         let raw: RawConfigFile = toml::from_str(data).unwrap();
         let (config, migrated) = migrate_config(raw);
 
-        assert_eq!(config.high_scores.easy, 10);
-        assert_eq!(config.high_scores.medium, 20);
-        assert_eq!(config.high_scores.hard, 30);
-        assert_eq!(config.high_scores.extreme, 0);
+        let best = |difficulty| config.high_scores.best(ScoreKey::classic(difficulty));
+        assert_eq!(best(Difficulty::Easy), 10);
+        assert_eq!(best(Difficulty::Medium), 20);
+        assert_eq!(best(Difficulty::Hard), 30);
+        assert_eq!(best(Difficulty::Extreme), 0);
         assert_eq!(config.settings.language, Language::En);
         assert!(config.settings.pause_on_focus_loss);
         assert!(config.settings.sound_on);
@@ -364,23 +864,51 @@ high_score = 42
         let raw: RawConfigFile = toml::from_str(data).unwrap();
         let (config, migrated) = migrate_config(raw);
 
-        assert_eq!(config.high_scores.easy, 42);
-        assert_eq!(config.high_scores.medium, 42);
-        assert_eq!(config.high_scores.hard, 42);
-        assert_eq!(config.high_scores.extreme, 42);
+        for difficulty in [
+            Difficulty::Easy,
+            Difficulty::Medium,
+            Difficulty::Hard,
+            Difficulty::Extreme,
+        ] {
+            assert_eq!(config.high_scores.best(ScoreKey::classic(difficulty)), 42);
+        }
         assert!(migrated);
     }
 
     #[test]
-    fn keeps_current_version_without_migration() {
+    fn migrates_v1_single_scores_into_leaderboard_tables() {
         let data = r#"
 config_version = 1
 
 [high_scores]
 easy = 7
-medium = 8
-hard = 9
+medium = 0
 extreme = 10
+"#;
+        let raw: RawConfigFile = toml::from_str(data).unwrap();
+        let (config, migrated) = migrate_config(raw);
+
+        let entries = |difficulty| config.high_scores.entries(ScoreKey::classic(difficulty));
+        assert_eq!(entries(Difficulty::Easy), [legacy_entry(7)]);
+        assert!(entries(Difficulty::Medium).is_empty());
+        assert_eq!(entries(Difficulty::Extreme)[0].score, 10);
+        assert_eq!(entries(Difficulty::Extreme)[0].initials, Initials::UNKNOWN);
+        assert!(migrated);
+    }
+
+    #[test]
+    fn keeps_current_version_without_migration() {
+        let data = r#"
+config_version = 4
+
+[[high_scores."easy/classic/40x20"]]
+score = 7
+initials = "ABC"
+date = "2026-01-02"
+
+[[high_scores."easy/classic/40x20"]]
+score = 9
+initials = "xy!"
 
 [settings]
 language = "pt"
@@ -388,10 +916,15 @@ language = "pt"
         let raw: RawConfigFile = toml::from_str(data).unwrap();
         let (config, migrated) = migrate_config(raw);
 
-        assert_eq!(config.high_scores.easy, 7);
-        assert_eq!(config.high_scores.medium, 8);
-        assert_eq!(config.high_scores.hard, 9);
-        assert_eq!(config.high_scores.extreme, 10);
+        let easy = config
+            .high_scores
+            .entries(ScoreKey::classic(Difficulty::Easy));
+        assert_eq!(easy.len(), 2);
+        assert_eq!(easy[0].score, 9);
+        assert_eq!(easy[0].initials.to_string(), "XY-");
+        assert_eq!(easy[0].date, "");
+        assert_eq!(easy[1].initials.to_string(), "ABC");
+        assert_eq!(easy[1].date, "2026-01-02");
         assert_eq!(config.settings.language, Language::Pt);
         assert!(config.settings.pause_on_focus_loss);
         assert!(config.settings.sound_on);
@@ -401,40 +934,148 @@ language = "pt"
     }
 
     #[test]
-    fn save_format_includes_config_version() {
-        let config = AppConfig {
-            high_scores: HighScores {
-                easy: 1,
-                medium: 2,
-                hard: 3,
-                extreme: 4,
+    fn snapshot_format_includes_config_version() {
+        let mut high_scores = HighScores::default();
+        high_scores.insert(
+            ScoreKey::new(Difficulty::Extreme, GameMode::Walls, 30, 15),
+            HighScoreEntry {
+                score: 4,
+                initials: Initials::from("ABC".to_string()),
+                date: "2026-10-16".to_string(),
             },
+        );
+        let config = AppConfig {
+            high_scores,
             settings: Settings {
                 language: Language::Ja,
                 pause_on_focus_loss: false,
+                resume_on_focus_gain: true,
+                idle_pause_secs: 60,
                 sound_on: true,
+                sounds: SoundSettings {
+                    ui: true,
+                    ..SoundSettings::default()
+                },
                 ui_compact: true,
                 default_difficulty: Difficulty::Extreme,
+                speed: GameSpeed::Slow,
+                disabled_power_ups: PowerUpSet::default(),
+                custom_difficulty: CustomDifficulty {
+                    tick_ms: 80,
+                    seed: Some(42),
+                    ..CustomDifficulty::default()
+                },
+                last_initials: Initials::from("ZED".to_string()),
+                theme: "dusk".to_string(),
+                rainbow_unlocked: true,
+                colorblind: true,
+                high_contrast: false,
+                reduced_motion: true,
+                speed_cap_ms: 100,
+                square_cells: true,
+                axis_pace: AxisPace::Ratio,
+                cell_aspect_percent: 180,
+                wide_ambiguous: true,
+                symbols: SymbolSet::Compatible,
+                board_pattern: BoardPattern::Checkerboard,
+                hud_position: HudPosition::Right,
+                hud_compact: true,
+                sidebar: true,
+                ghost: true,
+                window_title: false,
+                control_scheme: ControlScheme::Hjkl,
+                custom_keys: CustomKeys {
+                    up: 'e',
+                    ..CustomKeys::default()
+                },
+                boost_key: 'n',
+                text_mode_interval_ms: 1_500,
+                renderer: RenderBackend::Ratatui,
+                themes: BTreeMap::from([(
+                    "dusk".to_string(),
+                    CustomTheme {
+                        border: Some(Rgb {
+                            r: 0xff,
+                            g: 0x80,
+                            b: 0,
+                        }),
+                        ..CustomTheme::default()
+                    },
+                )]),
             },
         };
-        let serialized = toml::to_string(&ConfigFileV1 {
+        let serialized = toml::to_string(&ConfigSnapshot {
             config_version: CURRENT_CONFIG_VERSION,
-            high_scores: config.high_scores,
-            settings: config.settings,
+            high_scores: config.high_scores.clone(),
+            settings: config.settings.clone(),
         })
         .unwrap();
 
-        assert!(serialized.contains("config_version = 1"));
-        assert!(serialized.contains("extreme = 4"));
+        assert!(serialized.contains("config_version = 4"));
+        assert!(serialized.contains("[[high_scores.\"extreme/walls/30x15\"]]"));
+        assert!(serialized.contains("score = 4"));
+        assert!(serialized.contains("initials = \"ABC\""));
+        assert!(serialized.contains("date = \"2026-10-16\""));
+        assert!(serialized.contains("last_initials = \"ZED\""));
         assert!(serialized.contains("language = \"ja\""));
         assert!(serialized.contains("pause_on_focus_loss = false"));
+        assert!(serialized.contains("resume_on_focus_gain = true"));
+        assert!(serialized.contains("idle_pause_secs = 60"));
+        assert!(serialized.contains("speed = \"slow\""));
+        assert!(serialized.contains("window_title = false"));
         assert!(serialized.contains("sound_on = true"));
         assert!(serialized.contains("ui_compact = true"));
         assert!(serialized.contains("default_difficulty = \"extreme\""));
+        assert!(serialized.contains("disabled_power_ups = 0"));
+        assert!(serialized.contains("[settings.custom_difficulty]"));
+        assert!(serialized.contains("tick_ms = 80"));
+        assert!(serialized.contains("theme = \"dusk\""));
+        assert!(serialized.contains("rainbow_unlocked = true"));
+        assert!(serialized.contains("colorblind = true"));
+        assert!(serialized.contains("reduced_motion = true"));
+        assert!(serialized.contains("speed_cap_ms = 100"));
+        assert!(serialized.contains("square_cells = true"));
+        assert!(serialized.contains("axis_pace = \"ratio\""));
+        assert!(serialized.contains("cell_aspect_percent = 180"));
+        assert!(serialized.contains("control_scheme = \"hjkl\""));
+        assert!(serialized.contains("up = \"e\""));
+        assert!(serialized.contains("text_mode_interval_ms = 1500"));
+        assert!(serialized.contains("renderer = \"ratatui\""));
+        assert!(serialized.contains("[settings.themes.dusk]"));
+        assert!(serialized.contains("border = \"#ff8000\""));
+
+        let reparsed: RawConfigFile = toml::from_str(&serialized).unwrap();
+        assert_eq!(reparsed.settings, config.settings);
+        assert_eq!(HighScores::from(reparsed.high_scores), config.high_scores);
+    }
+
+    #[test]
+    fn disabled_power_ups_round_trip_as_bitmask() {
+        let data = r#"
+config_version = 1
+
+[settings]
+disabled_power_ups = 16
+"#;
+        let raw: RawConfigFile = toml::from_str(data).unwrap();
+        let (config, _) = migrate_config(raw);
+
+        assert!(
+            config
+                .settings
+                .disabled_power_ups
+                .contains(crate::utils::PowerUpType::Shrink)
+        );
+        assert!(
+            !config
+                .settings
+                .disabled_power_ups
+                .contains(crate::utils::PowerUpType::Grow)
+        );
     }
 
     #[test]
-    fn load_migrates_unversioned_file_and_persists_v1_format() {
+    fn load_migrates_unversioned_file_into_split_config_and_data() {
         let path = temp_config_path("migration");
         let legacy_data = r#"
 [high_scores]
@@ -448,10 +1089,11 @@ language = "es"
         fs::write(&path, legacy_data).unwrap();
 
         let loaded = load_config_from_path(&path);
-        assert_eq!(loaded.high_scores.easy, 11);
-        assert_eq!(loaded.high_scores.medium, 22);
-        assert_eq!(loaded.high_scores.hard, 33);
-        assert_eq!(loaded.high_scores.extreme, 0);
+        let best = |difficulty| loaded.high_scores.best(ScoreKey::classic(difficulty));
+        assert_eq!(best(Difficulty::Easy), 11);
+        assert_eq!(best(Difficulty::Medium), 22);
+        assert_eq!(best(Difficulty::Hard), 33);
+        assert_eq!(best(Difficulty::Extreme), 0);
         assert_eq!(loaded.settings.language, Language::Es);
         assert!(loaded.settings.pause_on_focus_loss);
         assert!(loaded.settings.sound_on);
@@ -459,12 +1101,118 @@ language = "es"
         assert_eq!(loaded.settings.default_difficulty, Difficulty::Medium);
 
         let rewritten = fs::read_to_string(&path).unwrap();
-        assert!(rewritten.contains("config_version = 1"));
-        assert!(rewritten.contains("extreme = 0"));
+        assert!(rewritten.contains("config_version = 4"));
+        assert!(rewritten.contains("language = \"es\""));
+        assert!(!rewritten.contains("high_scores"));
+
+        let data = fs::read_to_string(data_path_for(&path)).unwrap();
+        assert!(data.contains("data_version = 2"));
+        assert!(data.contains("[[high_scores.\"easy/classic/40x20\"]]"));
+        assert!(!data.contains("extreme"));
 
+        assert_eq!(load_config_from_path(&path), loaded);
+
+        let _ = fs::remove_file(data_path_for(&path));
         let _ = fs::remove_file(path);
     }
 
+    #[test]
+    fn settings_and_scores_save_independently() {
+        let path = temp_config_path("split");
+        let data_path = data_path_for(&path);
+        let mut high_scores = HighScores::default();
+        high_scores.insert(ScoreKey::classic(Difficulty::Easy), legacy_entry(12));
+        save_high_scores_to_path(&data_path, &high_scores).unwrap();
+
+        let settings = Settings {
+            language: Language::Zh,
+            ..Settings::default()
+        };
+        save_settings_to_path(&path, &settings).unwrap();
+        // A score write must leave the settings file untouched.
+        let settings_file = fs::read_to_string(&path).unwrap();
+        high_scores.insert(ScoreKey::classic(Difficulty::Easy), legacy_entry(40));
+        save_high_scores_to_path(&data_path, &high_scores).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), settings_file);
+
+        let loaded = load_config_from_path(&path);
+        assert_eq!(loaded.settings, settings);
+        assert_eq!(loaded.high_scores, high_scores);
+
+        // A settings file copied from another machine keeps the local scores.
+        fs::write(&path, "config_version = 4\n[settings]\nlanguage = \"ja\"\n").unwrap();
+        let loaded = load_config_from_path(&path);
+        assert_eq!(loaded.settings.language, Language::Ja);
+        assert_eq!(
+            loaded.high_scores.best(ScoreKey::classic(Difficulty::Easy)),
+            40
+        );
+
+        let _ = fs::remove_file(data_path);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn data_v1_tables_become_classic_tables_on_the_default_board() {
+        let path = temp_config_path("data-v1");
+        let data_path = data_path_for(&path);
+        fs::write(&path, "config_version = 4\n").unwrap();
+        fs::write(
+            &data_path,
+            r#"
+data_version = 1
+
+[high_scores]
+extreme = []
+
+[[high_scores.hard]]
+score = 70
+initials = "ABC"
+"#,
+        )
+        .unwrap();
+
+        let loaded = load_config_from_path(&path);
+        let hard = ScoreKey::new(Difficulty::Hard, GameMode::Classic, 40, 20);
+        assert_eq!(loaded.high_scores.best(hard), 70);
+        assert_eq!(loaded.high_scores.keys().collect::<Vec<_>>(), [hard]);
+
+        let data = fs::read_to_string(&data_path).unwrap();
+        assert!(data.contains("data_version = 2"));
+        assert!(data.contains("[[high_scores.\"hard/classic/40x20\"]]"));
+        assert_eq!(load_config_from_path(&path), loaded);
+
+        let _ = fs::remove_file(data_path);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn companion_files_sit_beside_config() {
+        assert_eq!(
+            data_path_for(Path::new("/tmp/rustnake/config.toml")),
+            PathBuf::from("/tmp/rustnake/data.toml")
+        );
+        assert_eq!(
+            companion_path(Path::new("/home/user/.rustnake.toml"), "data"),
+            PathBuf::from("/home/user/.rustnake-data.toml")
+        );
+        assert_eq!(
+            log_path_for(Path::new("/tmp/rustnake/config.toml")),
+            PathBuf::from("/tmp/rustnake/rustnake.log")
+        );
+        assert_eq!(
+            log_path_for(Path::new("/home/user/.rustnake.toml")),
+            PathBuf::from("/home/user/.rustnake.log")
+        );
+    }
+
+    #[test]
+    fn civil_from_days_matches_known_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(20_742), (2026, 10, 16));
+    }
+
     #[test]
     fn oversized_config_file_is_ignored() {
         let path = temp_config_path("oversized");
@@ -485,14 +1233,100 @@ language = "es"
         let config = AppConfig::default();
         save_config_to_path(&path, &config).unwrap();
 
-        let metadata = fs::metadata(&path).unwrap();
-        let mode = metadata.permissions().mode();
+        for written in [path.clone(), data_path_for(&path)] {
+            let metadata = fs::metadata(&written).unwrap();
+            let mode = metadata.permissions().mode();
+            assert_eq!(
+                mode & 0o077,
+                0,
+                "config and data files must not be group/world readable"
+            );
+        }
+
+        let _ = fs::remove_file(data_path_for(&path));
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn xdg_config_path_prefers_absolute_xdg_config_home() {
+        let home = Some(PathBuf::from("/home/snake"));
+        assert_eq!(
+            xdg_config_path(Some(PathBuf::from("/xdg")), home.clone()),
+            Some(PathBuf::from("/xdg/rustnake/config.toml"))
+        );
+        // Relative values are invalid per the XDG spec and fall back to ~/.config.
         assert_eq!(
-            mode & 0o077,
-            0,
-            "config file must not be group/world readable"
+            xdg_config_path(Some(PathBuf::from("relative")), home.clone()),
+            Some(PathBuf::from("/home/snake/.config/rustnake/config.toml"))
         );
+        assert_eq!(
+            xdg_config_path(Some(PathBuf::new()), home.clone()),
+            Some(PathBuf::from("/home/snake/.config/rustnake/config.toml"))
+        );
+        assert_eq!(
+            xdg_config_path(None, home),
+            Some(PathBuf::from("/home/snake/.config/rustnake/config.toml"))
+        );
+        assert_eq!(xdg_config_path(None, None), None);
+    }
 
-        let _ = fs::remove_file(path);
+    #[test]
+    fn xdg_log_path_prefers_absolute_xdg_state_home() {
+        let home = Some(PathBuf::from("/home/snake"));
+        assert_eq!(
+            xdg_log_path(Some(PathBuf::from("/state")), home.clone()),
+            Some(PathBuf::from("/state/rustnake/rustnake.log"))
+        );
+        assert_eq!(
+            xdg_log_path(Some(PathBuf::from("relative")), home.clone()),
+            Some(PathBuf::from(
+                "/home/snake/.local/state/rustnake/rustnake.log"
+            ))
+        );
+        assert_eq!(
+            xdg_log_path(None, home),
+            Some(PathBuf::from(
+                "/home/snake/.local/state/rustnake/rustnake.log"
+            ))
+        );
+        assert_eq!(xdg_log_path(None, None), None);
+    }
+
+    #[test]
+    fn legacy_config_and_history_migrate_to_new_path() {
+        let root = temp_config_path("xdg-migration").with_extension("");
+        let legacy_path = root.join(".rustnake.toml");
+        let target_path = root.join("rustnake").join("config.toml");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(&legacy_path, "high_score = 33").unwrap();
+        fs::write(root.join(".rustnake-history.toml"), "runs = []").unwrap();
+
+        migrate_config_from_legacy_paths(
+            &target_path,
+            &[root.join("missing.toml"), legacy_path.clone()],
+        );
+
+        let migrated = load_config_from_path(&target_path);
+        assert_eq!(
+            migrated
+                .high_scores
+                .best(ScoreKey::classic(Difficulty::Hard)),
+            33
+        );
+        assert!(root.join("rustnake").join("data.toml").exists());
+        assert!(root.join("rustnake").join("history.toml").exists());
+        assert!(legacy_path.exists());
+
+        // An existing config at the new path is never overwritten.
+        fs::write(&legacy_path, "high_score = 99").unwrap();
+        migrate_config_from_legacy_paths(&target_path, &[legacy_path]);
+        assert_eq!(
+            load_config_from_path(&target_path)
+                .high_scores
+                .best(ScoreKey::classic(Difficulty::Hard)),
+            33
+        );
+
+        let _ = fs::remove_dir_all(root);
     }
 }
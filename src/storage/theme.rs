@@ -0,0 +1,100 @@
+//! User-defined color themes, `[settings.themes.<name>]` tables in the config.
+
+use serde::{Deserialize, Serialize};
+
+/// A 24-bit color written as `"#rrggbb"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl TryFrom<String> for Rgb {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let invalid = || format!("invalid color \"{value}\", expected \"#rrggbb\"");
+        let hex = value.strip_prefix('#').ok_or_else(invalid)?;
+        if hex.len() != 6 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16);
+        Ok(Self {
+            r: channel(0).map_err(|_| invalid())?,
+            g: channel(2).map_err(|_| invalid())?,
+            b: channel(4).map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl From<Rgb> for String {
+    fn from(value: Rgb) -> Self {
+        format!("#{:02x}{:02x}{:02x}", value.r, value.g, value.b)
+    }
+}
+
+/// Colors a custom theme sets; anything left out keeps the classic color.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomTheme {
+    pub border: Option<Rgb>,
+    pub logo: Option<Rgb>,
+    pub title: Option<Rgb>,
+    pub subtitle: Option<Rgb>,
+    pub hint: Option<Rgb>,
+    pub option: Option<Rgb>,
+    /// Background of the selected menu row.
+    pub highlight: Option<Rgb>,
+    /// Destructive menu entries such as resetting scores.
+    pub danger: Option<Rgb>,
+    pub snake_head: Option<Rgb>,
+    pub snake_body: Option<Rgb>,
+    pub snake_tail: Option<Rgb>,
+    pub food: Option<Rgb>,
+}
+
+impl CustomTheme {
+    /// Every key set, for spotting unknown keys in hand-written themes.
+    pub(super) fn schema() -> Self {
+        let color = Some(Rgb::default());
+        Self {
+            border: color,
+            logo: color,
+            title: color,
+            subtitle: color,
+            hint: color,
+            option: color,
+            highlight: color,
+            danger: color,
+            snake_head: color,
+            snake_body: color,
+            snake_tail: color,
+            food: color,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_parse_from_hex_and_round_trip() {
+        let color = Rgb::try_from("#1E90ff".to_string()).unwrap();
+        assert_eq!(
+            color,
+            Rgb {
+                r: 0x1e,
+                g: 0x90,
+                b: 0xff
+            }
+        );
+        assert_eq!(String::from(color), "#1e90ff");
+
+        for invalid in ["1e90ff", "#1e90f", "#1e90fg", "#+1e90f"] {
+            assert!(Rgb::try_from(invalid.to_string()).is_err(), "{invalid}");
+        }
+    }
+}
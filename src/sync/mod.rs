@@ -0,0 +1,132 @@
+//! Optional high-score sync with a user-provided HTTP endpoint (`sync` feature).
+//!
+//! The endpoint stores one JSON document shaped like the `high_scores` table in
+//! `data.toml`: `GET` returns it (404 counts as empty) and `POST` replaces it
+//! with the merged table.
+
+use crate::storage::{EndpointSettings, HighScoreEntry, HighScores, ScoreKey};
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+/// Sync runs outside the game loop, so keep an unreachable endpoint from
+/// stalling startup or exit for long.
+const SYNC_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Best-of-both tables: entries from either side, duplicates collapsed, top 10 kept.
+pub fn merge_high_scores(local: &HighScores, remote: &HighScores) -> HighScores {
+    let mut merged = HighScores::default();
+    let keys: BTreeSet<ScoreKey> = local.keys().chain(remote.keys()).collect();
+    for key in keys {
+        let mut entries: Vec<&HighScoreEntry> = local
+            .entries(key)
+            .iter()
+            .chain(remote.entries(key))
+            .collect();
+        // Stable sort keeps local entries ahead of equal remote ones.
+        entries.sort_by(|a, b| b.score.cmp(&a.score));
+        for (index, entry) in entries.iter().enumerate() {
+            if entries[..index].contains(entry) {
+                continue;
+            }
+            if merged.insert(key, (*entry).clone()).is_none() {
+                break;
+            }
+        }
+    }
+    merged
+}
+
+fn authorized(request: ureq::Request, settings: &EndpointSettings) -> ureq::Request {
+    if settings.token.is_empty() {
+        request
+    } else {
+        request.set("Authorization", &format!("Bearer {}", settings.token))
+    }
+}
+
+fn fetch(agent: &ureq::Agent, settings: &EndpointSettings) -> Result<HighScores, String> {
+    match authorized(agent.get(&settings.url), settings).call() {
+        Ok(response) => response
+            .into_json::<HighScores>()
+            .map_err(|err| format!("invalid response from {}: {err}", settings.url)),
+        Err(ureq::Error::Status(404, _)) => Ok(HighScores::default()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+fn push(
+    agent: &ureq::Agent,
+    settings: &EndpointSettings,
+    scores: &HighScores,
+) -> Result<(), String> {
+    authorized(agent.post(&settings.url), settings)
+        .send_json(scores)
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+/// Pulls the remote table, merges it with `local`, and pushes the result back
+/// when the remote is missing anything. Returns the merged table.
+pub fn sync_high_scores(
+    settings: &EndpointSettings,
+    local: &HighScores,
+) -> Result<HighScores, String> {
+    let agent = ureq::AgentBuilder::new().timeout(SYNC_TIMEOUT).build();
+    let remote = fetch(&agent, settings)?;
+    let merged = merge_high_scores(local, &remote);
+    if merged != remote {
+        push(&agent, settings, &merged)?;
+    }
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{Difficulty, GameMode, Initials};
+
+    fn entry(score: u32, initials: &str, date: &str) -> HighScoreEntry {
+        HighScoreEntry {
+            score,
+            initials: Initials::from(initials.to_string()),
+            date: date.to_string(),
+        }
+    }
+
+    #[test]
+    fn merge_keeps_best_entries_from_both_sides_without_duplicates() {
+        let easy = ScoreKey::classic(Difficulty::Easy);
+        let hard = ScoreKey::classic(Difficulty::Hard);
+        let walls = ScoreKey::new(Difficulty::Hard, GameMode::Walls, 30, 15);
+        let shared = entry(50, "ABC", "2026-10-01");
+        let mut local = HighScores::default();
+        local.set_entries(easy, vec![entry(90, "LOC", "2026-10-02"), shared.clone()]);
+        let mut remote = HighScores::default();
+        remote.set_entries(easy, vec![shared.clone(), entry(50, "REM", "2026-10-03")]);
+        remote.set_entries(
+            hard,
+            (1..=12).map(|score| entry(score * 10, "REM", "")).collect(),
+        );
+        remote.set_entries(walls, vec![entry(30, "REM", "")]);
+
+        let merged = merge_high_scores(&local, &remote);
+
+        let easy: Vec<(u32, String)> = merged
+            .entries(easy)
+            .iter()
+            .map(|entry| (entry.score, entry.initials.to_string()))
+            .collect();
+        assert_eq!(
+            easy,
+            vec![
+                (90, "LOC".to_string()),
+                (50, "ABC".to_string()),
+                (50, "REM".to_string())
+            ]
+        );
+        assert_eq!(merged.entries(hard).len(), 10);
+        assert_eq!(merged.best(hard), 120);
+        assert_eq!(merged.best(walls), 30);
+        assert_eq!(merge_high_scores(&merged, &merged), merged);
+    }
+}
@@ -0,0 +1,144 @@
+//! Fixed-timestep pacing for the game loops.
+//!
+//! Real time accumulates between wake-ups and the simulation spends it in whole
+//! ticks, so a late wake-up is made up on the next pass instead of stretching
+//! every tick after it. Frames are paced on their own by `FramePacer`, about
+//! 60 a second while anything on screen moves, and show the latest simulated
+//! state as-is, so timers and animations move on between ticks.
+
+use std::time::{Duration, Instant};
+
+/// Shortest gap between two drawn frames (about 60 FPS).
+pub const FRAME_INTERVAL: Duration = Duration::from_micros(16_667);
+
+/// Most ticks a single pass may catch up on; anything older is dropped so a
+/// stalled terminal or a suspended process does not fast-forward the snake.
+const MAX_CATCH_UP_TICKS: u32 = 3;
+
+pub struct FixedTimestep {
+    accumulator: Duration,
+    last_update: Instant,
+}
+
+impl FixedTimestep {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            accumulator: Duration::ZERO,
+            last_update: now,
+        }
+    }
+
+    /// Credits the simulation with the real time since the previous update,
+    /// and returns how much that was.
+    pub fn advance(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.last_update);
+        self.accumulator += elapsed;
+        self.last_update = now;
+        elapsed
+    }
+
+    /// Lets time pass without crediting it, e.g. while the game is paused.
+    pub fn hold(&mut self, now: Instant) {
+        self.last_update = now;
+    }
+
+    /// Spends one tick of length `step` if enough time has accumulated.
+    pub fn try_step(&mut self, step: Duration) -> bool {
+        self.accumulator = self.accumulator.min(step * MAX_CATCH_UP_TICKS);
+        if self.accumulator < step {
+            return false;
+        }
+        self.accumulator -= step;
+        true
+    }
+
+    /// When a tick of length `step` will next be due.
+    pub fn next_step_at(&self, step: Duration) -> Instant {
+        self.last_update + step.saturating_sub(self.accumulator)
+    }
+}
+
+/// When frames are due, independent of simulation ticks. Frames keep to a
+/// `FRAME_INTERVAL` grid; one drawn late does not push back the ones after
+/// it, and one that comes after a quiet spell is due right away.
+#[derive(Default)]
+pub struct FramePacer {
+    next_frame: Option<Instant>,
+}
+
+impl FramePacer {
+    /// When the next frame may be drawn.
+    pub fn next_frame_at(&self, now: Instant) -> Instant {
+        self.next_frame.unwrap_or(now)
+    }
+
+    pub fn is_due(&self, now: Instant) -> bool {
+        now >= self.next_frame_at(now)
+    }
+
+    /// Notes a frame drawn at `now`.
+    pub fn drawn(&mut self, now: Instant) {
+        let next = self.next_frame.unwrap_or(now) + FRAME_INTERVAL;
+        self.next_frame = Some(if next > now {
+            next
+        } else {
+            now + FRAME_INTERVAL
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn late_wake_ups_are_made_up_without_drift() {
+        let start = Instant::now();
+        let step = Duration::from_millis(100);
+        let mut clock = FixedTimestep::new(start);
+
+        clock.advance(start + Duration::from_millis(130));
+        assert!(clock.try_step(step));
+        assert!(!clock.try_step(step));
+        // The 30ms overshoot counts toward the next tick.
+        assert_eq!(clock.next_step_at(step), start + Duration::from_millis(200));
+
+        clock.advance(start + Duration::from_millis(1_000));
+        let caught_up = std::iter::from_fn(|| clock.try_step(step).then_some(())).count();
+        assert_eq!(caught_up, MAX_CATCH_UP_TICKS as usize);
+    }
+
+    #[test]
+    fn frames_keep_to_their_grid() {
+        let start = Instant::now();
+        let mut frames = FramePacer::default();
+        assert!(frames.is_due(start));
+
+        frames.drawn(start);
+        assert!(!frames.is_due(start + Duration::from_millis(10)));
+        // A frame drawn 4ms late leaves the next one on time.
+        frames.drawn(start + FRAME_INTERVAL + Duration::from_millis(4));
+        assert_eq!(frames.next_frame_at(start), start + FRAME_INTERVAL * 2);
+
+        // After a quiet spell the grid starts over.
+        let later = start + Duration::from_secs(2);
+        assert!(frames.is_due(later));
+        frames.drawn(later);
+        assert_eq!(frames.next_frame_at(later), later + FRAME_INTERVAL);
+    }
+
+    #[test]
+    fn held_time_is_not_credited() {
+        let start = Instant::now();
+        let step = Duration::from_millis(100);
+        let mut clock = FixedTimestep::new(start);
+
+        clock.advance(start + Duration::from_millis(60));
+        clock.hold(start + Duration::from_millis(5_000));
+        assert!(!clock.try_step(step));
+        assert_eq!(
+            clock.next_step_at(step),
+            start + Duration::from_millis(5_040)
+        );
+    }
+}
@@ -1,23 +1,161 @@
 //! Utility module for the Snake game.
 //! Contains common types, constants, and utilities used throughout the game.
 
+use std::sync::OnceLock;
+
 use serde::{Deserialize, Serialize};
 
 // Define the game board dimensions
 pub const WIDTH: u16 = 40;
 pub const HEIGHT: u16 = 20;
+/// Board sizes the game accepts from outside, such as a spectated host's
+/// frames, border included.
+pub const WIDTH_RANGE: (u16, u16) = (8, 200);
+pub const HEIGHT_RANGE: (u16, u16) = (6, 100);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Difficulty {
     Easy,
     Medium,
     Hard,
     Extreme,
+    Custom,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+impl Difficulty {
+    pub const ALL: [Difficulty; 5] = [
+        Difficulty::Easy,
+        Difficulty::Medium,
+        Difficulty::Hard,
+        Difficulty::Extreme,
+        Difficulty::Custom,
+    ];
+
+    /// The name config and score files use.
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+            Difficulty::Extreme => "extreme",
+            Difficulty::Custom => "custom",
+        }
+    }
+}
+
+/// How the board's edges play, which keeps scores from different rules apart.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 #[serde(rename_all = "lowercase")]
+pub enum GameMode {
+    /// Leaving the board comes back in on the opposite side, as in every preset.
+    #[default]
+    Classic,
+    /// The border is a wall.
+    Walls,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 2] = [GameMode::Classic, GameMode::Walls];
+
+    /// The name score files use.
+    pub fn name(self) -> &'static str {
+        match self {
+            GameMode::Classic => "classic",
+            GameMode::Walls => "walls",
+        }
+    }
+}
+
+/// Player-tuned values behind `Difficulty::Custom`, edited on the custom difficulty screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomDifficulty {
+    pub tick_ms: u16,
+    pub progression_steps: u8,
+    pub power_up_chance_percent: u8,
+    pub wrap_edges: bool,
+    /// Spawns follow this seed when set, so every custom run plays out alike.
+    pub seed: Option<u64>,
+}
+
+impl Default for CustomDifficulty {
+    fn default() -> Self {
+        Self {
+            tick_ms: 100,
+            progression_steps: 15,
+            power_up_chance_percent: 30,
+            wrap_edges: true,
+            seed: None,
+        }
+    }
+}
+
+impl CustomDifficulty {
+    pub const TICK_MS_RANGE: (u16, u16) = (30, 300);
+    pub const PROGRESSION_STEPS_MAX: u8 = 20;
+    pub const POWER_UP_CHANCE_MAX: u8 = 60;
+
+    /// Number of adjustable fields, in the order `adjust` indexes them.
+    pub const FIELD_COUNT: usize = 4;
+    /// Longest seed the menu takes; 18 digits always fit the config's TOML integers.
+    pub const SEED_DIGITS: usize = 18;
+
+    /// Config files are hand-editable, so values are clamped before use.
+    pub fn clamped(self) -> Self {
+        Self {
+            tick_ms: self
+                .tick_ms
+                .clamp(Self::TICK_MS_RANGE.0, Self::TICK_MS_RANGE.1),
+            progression_steps: self.progression_steps.min(Self::PROGRESSION_STEPS_MAX),
+            power_up_chance_percent: self.power_up_chance_percent.min(Self::POWER_UP_CHANCE_MAX),
+            wrap_edges: self.wrap_edges,
+            seed: self.seed,
+        }
+    }
+
+    /// Moves one slider a single step; `increase` raises the value (or toggles wrap).
+    pub fn adjust(&mut self, field: usize, increase: bool) {
+        let current = self.clamped();
+        *self = match field {
+            0 => Self {
+                tick_ms: if increase {
+                    current.tick_ms + 10
+                } else {
+                    current.tick_ms.saturating_sub(10)
+                },
+                ..current
+            },
+            1 => Self {
+                progression_steps: if increase {
+                    current.progression_steps + 1
+                } else {
+                    current.progression_steps.saturating_sub(1)
+                },
+                ..current
+            },
+            2 => Self {
+                power_up_chance_percent: if increase {
+                    current.power_up_chance_percent + 5
+                } else {
+                    current.power_up_chance_percent.saturating_sub(5)
+                },
+                ..current
+            },
+            3 => Self {
+                wrap_edges: !current.wrap_edges,
+                ..current
+            },
+            _ => current,
+        }
+        .clamped();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(try_from = "String", into = "String")]
 pub enum Language {
     #[default]
     En,
@@ -25,17 +163,54 @@ pub enum Language {
     Ja,
     Pt,
     Zh,
+    De,
+    Fr,
+    Ru,
+    Ko,
+    Ar,
+    /// A translation pack loaded at startup, by its place among the codes
+    /// given to `Language::register_packs`.
+    Pack(u8),
 }
 
+/// Codes of the translation packs, set once at startup.
+static PACK_CODES: OnceLock<Vec<String>> = OnceLock::new();
+
 impl Language {
-    pub const ALL: [Language; 5] = [
+    /// The languages built into the game.
+    pub const ALL: [Language; 10] = [
         Language::En,
         Language::Es,
         Language::Ja,
         Language::Pt,
         Language::Zh,
+        Language::De,
+        Language::Fr,
+        Language::Ru,
+        Language::Ko,
+        Language::Ar,
     ];
 
+    /// Makes the translation packs with `codes` known as `Language::Pack`s,
+    /// in that order. Only the first call counts, and it has to come before
+    /// any config is read, since configs may name a pack's language.
+    pub fn register_packs(codes: Vec<String>) {
+        let _ = PACK_CODES.set(codes);
+    }
+
+    fn pack_codes() -> &'static [String] {
+        PACK_CODES.get().map_or(&[], Vec::as_slice)
+    }
+
+    /// The built-in languages followed by the translation packs.
+    pub fn available() -> Vec<Language> {
+        let packs = (0..Self::pack_codes().len())
+            .filter_map(|index| u8::try_from(index).ok())
+            .map(Language::Pack);
+        Self::ALL.into_iter().chain(packs).collect()
+    }
+
+    /// Position in `Language::available()`.
     pub fn to_index(self) -> usize {
         match self {
             Language::En => 0,
@@ -43,11 +218,58 @@ impl Language {
             Language::Ja => 2,
             Language::Pt => 3,
             Language::Zh => 4,
+            Language::De => 5,
+            Language::Fr => 6,
+            Language::Ru => 7,
+            Language::Ko => 8,
+            Language::Ar => 9,
+            Language::Pack(index) => Self::ALL.len() + usize::from(index),
+        }
+    }
+
+    /// The code configs store, such as `"pt"`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Es => "es",
+            Language::Ja => "ja",
+            Language::Pt => "pt",
+            Language::Zh => "zh",
+            Language::De => "de",
+            Language::Fr => "fr",
+            Language::Ru => "ru",
+            Language::Ko => "ko",
+            Language::Ar => "ar",
+            Language::Pack(index) => Self::pack_codes()
+                .get(usize::from(index))
+                .map_or("en", String::as_str),
         }
     }
+
+    /// The language stored as `code`, built in or from a registered pack.
+    pub fn from_code(code: &str) -> Option<Language> {
+        Self::available()
+            .into_iter()
+            .find(|language| language.code() == code)
+    }
+}
+
+impl TryFrom<String> for Language {
+    type Error = String;
+
+    fn try_from(code: String) -> Result<Self, Self::Error> {
+        Language::from_code(&code).ok_or_else(|| format!("unknown language `{code}`"))
+    }
+}
+
+impl From<Language> for String {
+    fn from(language: Language) -> Self {
+        language.code().to_string()
+    }
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Direction {
     Up,
     Down,
@@ -55,24 +277,412 @@ pub enum Direction {
     Right,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+impl Direction {
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+/// Which letter keys steer the snake; the arrow keys steer under every scheme.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ControlScheme {
+    Arrows,
+    #[default]
+    Wasd,
+    Hjkl,
+    Custom,
+}
+
+impl ControlScheme {
+    pub const ALL: [ControlScheme; 4] = [
+        ControlScheme::Arrows,
+        ControlScheme::Wasd,
+        ControlScheme::Hjkl,
+        ControlScheme::Custom,
+    ];
+
+    pub fn cycle(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let index = Self::ALL
+            .iter()
+            .position(|scheme| *scheme == self)
+            .unwrap_or(0);
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+
+    /// Up, down, left, and right letters, or `None` when only the arrows steer.
+    pub fn letters(self, custom: CustomKeys) -> Option<[char; 4]> {
+        match self {
+            ControlScheme::Arrows => None,
+            ControlScheme::Wasd => Some(['w', 's', 'a', 'd']),
+            ControlScheme::Hjkl => Some(['k', 'j', 'h', 'l']),
+            ControlScheme::Custom => Some([custom.up, custom.down, custom.left, custom.right]),
+        }
+    }
+}
+
+/// How frames reach the terminal. `Ratatui` needs a build with the `ratatui`
+/// feature, and `Pixels` a terminal with kitty graphics or sixel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderBackend {
+    /// The built-in renderer, which writes escape sequences itself.
+    #[default]
+    Ansi,
+    /// Frames are presented through ratatui's terminal.
+    Ratatui,
+    /// The built-in renderer, with the snake, food, and power-ups drawn as an
+    /// image (experimental).
+    Pixels,
+}
+
+/// Which characters draw the food, the snake's head, and menu scrollbars.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SymbolSet {
+    /// `★ ● █ ░` and friends.
+    #[default]
+    Fancy,
+    /// Plain ASCII such as `* o # .`, for fonts that lack the fancy ones.
+    Compatible,
+}
+
+impl SymbolSet {
+    pub const ALL: [SymbolSet; 2] = [SymbolSet::Fancy, SymbolSet::Compatible];
+
+    pub fn toggled(self) -> Self {
+        match self {
+            SymbolSet::Fancy => SymbolSet::Compatible,
+            SymbolSet::Compatible => SymbolSet::Fancy,
+        }
+    }
+}
+
+/// Marks drawn on the empty board so distances are easier to judge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardPattern {
+    #[default]
+    Plain,
+    /// A dot in every cell.
+    Dots,
+    /// Every other cell shaded.
+    Checkerboard,
+}
+
+impl BoardPattern {
+    pub const ALL: [BoardPattern; 3] = [
+        BoardPattern::Plain,
+        BoardPattern::Dots,
+        BoardPattern::Checkerboard,
+    ];
+
+    pub fn cycle(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let index = Self::ALL
+            .iter()
+            .position(|pattern| *pattern == self)
+            .unwrap_or(0);
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+}
+
+/// Where the score, status, and controls go around the board.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HudPosition {
+    #[default]
+    Below,
+    Above,
+    /// A panel to the right of the board, one field per line.
+    Right,
+}
+
+impl HudPosition {
+    pub const ALL: [HudPosition; 3] = [HudPosition::Below, HudPosition::Above, HudPosition::Right];
+
+    pub fn cycle(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let index = Self::ALL
+            .iter()
+            .position(|position| *position == self)
+            .unwrap_or(0);
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+}
+
+/// How vertical moves are paced against horizontal ones. Terminal cells are
+/// taller than wide, so a step down covers more of the screen than a step
+/// across.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AxisPace {
+    /// From the cell size the terminal reports.
+    #[default]
+    Auto,
+    /// From the configured cell aspect.
+    Ratio,
+    /// The same tick both ways.
+    Off,
+}
+
+/// How fast the snake moves, picked apart from the difficulty, which then
+/// only sets the progression and the power-ups.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GameSpeed {
+    /// The difficulty's own pace.
+    #[default]
+    Difficulty,
+    Slow,
+    Normal,
+    Fast,
+    Insane,
+}
+
+impl GameSpeed {
+    pub const ALL: [GameSpeed; 5] = [
+        GameSpeed::Difficulty,
+        GameSpeed::Slow,
+        GameSpeed::Normal,
+        GameSpeed::Fast,
+        GameSpeed::Insane,
+    ];
+
+    pub fn cycle(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let index = Self::ALL
+            .iter()
+            .position(|speed| *speed == self)
+            .unwrap_or(0);
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+}
+
+/// Letters for `ControlScheme::Custom`. Keys that already do something else,
+/// such as `p` for pause, keep doing that instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomKeys {
+    pub up: char,
+    pub down: char,
+    pub left: char,
+    pub right: char,
+}
+
+impl Default for CustomKeys {
+    fn default() -> Self {
+        Self {
+            up: 'i',
+            down: 'k',
+            left: 'j',
+            right: 'l',
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct Position {
     pub x: u16,
     pub y: u16,
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum PowerUpType {
     SpeedBoost,
     SlowDown,
     ExtraPoints,
     Grow,
     Shrink,
+    Shield,
+    ScoreMultiplier,
+}
+
+impl PowerUpType {
+    pub const ALL: [PowerUpType; 7] = [
+        PowerUpType::SpeedBoost,
+        PowerUpType::SlowDown,
+        PowerUpType::ExtraPoints,
+        PowerUpType::Grow,
+        PowerUpType::Shrink,
+        PowerUpType::Shield,
+        PowerUpType::ScoreMultiplier,
+    ];
+
+    fn bit(self) -> u8 {
+        let index = Self::ALL
+            .iter()
+            .position(|power_up_type| *power_up_type == self)
+            .unwrap_or(0);
+        1 << index
+    }
+}
+
+/// Set of power-up types stored as a bitmask; bit `n` is `PowerUpType::ALL[n]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PowerUpSet(u8);
+
+impl PowerUpSet {
+    pub fn contains(self, power_up_type: PowerUpType) -> bool {
+        self.0 & power_up_type.bit() != 0
+    }
+
+    pub fn toggle(&mut self, power_up_type: PowerUpType) {
+        self.0 ^= power_up_type.bit();
+    }
+}
+
+/// Sound categories; `Game` reports these and the frontend decides what to play.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SoundEvent {
+    Food,
+    PowerUp,
+    Death,
+    Ui,
+}
+
+impl SoundEvent {
+    pub const ALL: [SoundEvent; 4] = [
+        SoundEvent::Food,
+        SoundEvent::PowerUp,
+        SoundEvent::Death,
+        SoundEvent::Ui,
+    ];
+}
+
+/// Per-category switches, applied on top of the master `sound_on` setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SoundSettings {
+    pub food: bool,
+    pub power_up: bool,
+    pub death: bool,
+    pub ui: bool,
+}
+
+impl Default for SoundSettings {
+    fn default() -> Self {
+        // Menus were silent before sounds were configurable, so UI sounds start off.
+        Self {
+            food: true,
+            power_up: true,
+            death: true,
+            ui: false,
+        }
+    }
+}
+
+impl SoundSettings {
+    fn slot(&mut self, event: SoundEvent) -> &mut bool {
+        match event {
+            SoundEvent::Food => &mut self.food,
+            SoundEvent::PowerUp => &mut self.power_up,
+            SoundEvent::Death => &mut self.death,
+            SoundEvent::Ui => &mut self.ui,
+        }
+    }
+
+    pub fn enabled(mut self, event: SoundEvent) -> bool {
+        *self.slot(event)
+    }
+
+    pub fn toggle(&mut self, event: SoundEvent) {
+        let slot = self.slot(event);
+        *slot = !*slot;
+    }
+}
+
+/// Arcade-style initials for leaderboard entries, always three letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct Initials([u8; 3]);
+
+impl Initials {
+    pub const LEN: usize = 3;
+    const ALPHABET: &'static [u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ-";
+    /// Used for scores recorded before initials existed.
+    pub const UNKNOWN: Self = Self([b'-'; 3]);
+
+    pub fn letter(self, index: usize) -> char {
+        char::from(self.0[index.min(Self::LEN - 1)])
+    }
+
+    /// The letter a typed `ch` stands for, if initials can hold it.
+    pub fn typed(ch: char) -> Option<char> {
+        let letter = u8::try_from(ch.to_ascii_uppercase()).ok()?;
+        Self::ALPHABET
+            .contains(&letter)
+            .then_some(char::from(letter))
+    }
+}
+
+impl Default for Initials {
+    fn default() -> Self {
+        Self([b'A'; 3])
+    }
+}
+
+impl From<String> for Initials {
+    // Lenient so a hand-edited config never discards the whole score table.
+    fn from(value: String) -> Self {
+        let mut letters = [b'-'; 3];
+        for (slot, letter) in letters.iter_mut().zip(value.bytes()) {
+            let letter = letter.to_ascii_uppercase();
+            if Self::ALPHABET.contains(&letter) {
+                *slot = letter;
+            }
+        }
+        Self(letters)
+    }
+}
+
+impl From<Initials> for String {
+    fn from(value: Initials) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::fmt::Display for Initials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for index in 0..Self::LEN {
+            write!(f, "{}", self.letter(index))?;
+        }
+        Ok(())
+    }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct PowerUp {
     pub position: Position,
     pub power_up_type: PowerUpType,
     pub active: bool,
+    pub ticks_left: u32, // Despawns when this reaches zero
 }